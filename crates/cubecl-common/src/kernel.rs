@@ -58,4 +58,7 @@ pub enum ExecutionMode {
     Checked,
     /// Unchecked kernels are unsafe.
     Unchecked,
+    /// Validated kernels are checked, but every out-of-bounds access is also recorded in an error
+    /// buffer that gets reported after the next sync. Meant for debugging only, since it's slow.
+    Validate,
 }
@@ -21,6 +21,11 @@ pub trait Algorithm {
     type TileMatmul: TileMatmulFamily;
     type StageMatmul: StageMatmulFamily;
     type GlobalConvolution: ConvolutionFamily;
+    /// Computes the gradient with respect to the input. Backward passes always read plain
+    /// tensors, regardless of the `Args` used by the forward pass.
+    type BackwardData: ConvolutionFamily;
+    /// Computes the gradient with respect to the weights.
+    type BackwardWeight: ConvolutionFamily;
 
     type Args: MatmulArgs;
 
@@ -30,7 +35,7 @@ pub trait Algorithm {
         let cubes_needed_m = (problem.m as u32).div_ceil(m_stage);
         let cubes_needed_n = (problem.n as u32).div_ceil(n_stage);
 
-        CubeCount::Static(cubes_needed_m, cubes_needed_n, 1)
+        CubeCount::Static(cubes_needed_m, cubes_needed_n, problem.groups as u32)
     }
 
     fn num_stages() -> NumStages;
@@ -8,7 +8,11 @@ use cubecl_core::{
 };
 
 use crate::{
-    base::ConvolutionProblem, homogeneous::multi_stage_tma::MultiStageTmaConvolutionFamily,
+    base::ConvolutionProblem,
+    homogeneous::{
+        backward_data::SimpleBackwardDataFamily, backward_weight::SimpleBackwardWeightFamily,
+        multi_stage_tma::MultiStageTmaConvolutionFamily,
+    },
     selection::convolution_matmul_selection,
 };
 
@@ -34,6 +38,8 @@ impl<TMM: TileMatmulFamily> Algorithm for MultiStageTmaConvAlgorithm<TMM> {
     type TileMatmul = TMM;
     type StageMatmul = PlaneMatmulFamily<Self::TileMatmul, FullReaderFamily, FullReaderFamily>;
     type GlobalConvolution = MultiStageTmaConvolutionFamily<Self::StageMatmul>;
+    type BackwardData = SimpleBackwardDataFamily<Self::StageMatmul>;
+    type BackwardWeight = SimpleBackwardWeightFamily<Self::StageMatmul>;
 
     type Args = TensorMapArgs;
 
@@ -9,7 +9,11 @@ use cubecl_core::{
 use cubecl_matmul::components::MatmulSelection;
 
 use crate::{
-    base::ConvolutionProblem, homogeneous::simple::SimpleConvolutionFamily,
+    base::ConvolutionProblem,
+    homogeneous::{
+        backward_data::SimpleBackwardDataFamily, backward_weight::SimpleBackwardWeightFamily,
+        simple::SimpleConvolutionFamily,
+    },
     selection::convolution_matmul_selection,
 };
 use cubecl_matmul::components::stage::NumStages;
@@ -33,6 +37,8 @@ impl<TMM: TileMatmulFamily> Algorithm for SimpleConvAlgorithm<TMM> {
     type TileMatmul = TMM;
     type StageMatmul = PlaneMatmulFamily<Self::TileMatmul, FullReaderFamily, FullReaderFamily>;
     type GlobalConvolution = SimpleConvolutionFamily<Self::StageMatmul>;
+    type BackwardData = SimpleBackwardDataFamily<Self::StageMatmul>;
+    type BackwardWeight = SimpleBackwardWeightFamily<Self::StageMatmul>;
 
     type Args = TensorArgs;
 
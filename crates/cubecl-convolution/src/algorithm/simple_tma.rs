@@ -10,7 +10,10 @@ use cubecl_matmul::components::MatmulSelection;
 
 use crate::{
     base::{ConvolutionProblem, Dimensionality},
-    homogeneous::simple_tma::SimpleTmaConvolutionFamily,
+    homogeneous::{
+        backward_data::SimpleBackwardDataFamily, backward_weight::SimpleBackwardWeightFamily,
+        simple_tma::SimpleTmaConvolutionFamily,
+    },
     selection::convolution_matmul_selection,
 };
 use cubecl_matmul::components::stage::NumStages;
@@ -36,6 +39,8 @@ impl<TMM: TileMatmulFamily> Algorithm for SimpleTmaConvAlgorithm<TMM> {
     type TileMatmul = TMM;
     type StageMatmul = PlaneMatmulFamily<Self::TileMatmul, FullReaderFamily, FullReaderFamily>;
     type GlobalConvolution = SimpleTmaConvolutionFamily<Self::StageMatmul>;
+    type BackwardData = SimpleBackwardDataFamily<Self::StageMatmul>;
+    type BackwardWeight = SimpleBackwardWeightFamily<Self::StageMatmul>;
 
     type Args = TensorMapArgs;
 
@@ -45,7 +50,7 @@ impl<TMM: TileMatmulFamily> Algorithm for SimpleTmaConvAlgorithm<TMM> {
         let cubes_needed_m = (problem.m as u32).div_ceil(m_stage);
         let cubes_needed_n = (problem.n as u32).div_ceil(n_stage);
 
-        CubeCount::Static(cubes_needed_m, cubes_needed_n, 1)
+        CubeCount::Static(cubes_needed_m, cubes_needed_n, problem.groups as u32)
     }
 
     fn into_tensor_handle<R: Runtime, E: Numeric>(
@@ -145,6 +150,13 @@ pub(crate) fn check_problem_tma(problem: &ConvolutionProblem) -> Result<(), Inva
         }
     }
 
+    if problem.groups != 1 {
+        return Err(Box::new(format!(
+            "TMA convolution doesn't support groups, got {} groups",
+            problem.groups
+        )));
+    }
+
     let (corner_min, corner_max) = match problem.dimensionality {
         Dimensionality::Dim1 => (-(2isize.pow(15)), 2isize.pow(15) - 1),
         Dimensionality::Dim2 => (-(2isize.pow(7)), 2isize.pow(7) - 1),
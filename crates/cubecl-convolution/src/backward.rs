@@ -0,0 +1,340 @@
+use std::any::TypeId;
+
+use cubecl_core::{Runtime, client::ComputeClient, prelude::*};
+use cubecl_matmul::components::global::GlobalConfig;
+use cubecl_matmul::components::global::args::{TensorArgs, TensorInputs};
+use cubecl_matmul::components::stage::StageMatmulFamily;
+use cubecl_matmul::components::tile::TileMatmulFamily;
+use cubecl_matmul::components::{self, AvailableLineSizes, MatmulPrecision, MatmulSelection};
+use cubecl_std::tensor::into_contiguous;
+use half::f16;
+
+use crate::ConvGemmConfig;
+use crate::base::{ConvolutionFamily, ConvolutionLaunch};
+
+use super::{
+    ConvLaunchError, ConvolutionArgs,
+    algorithm::Algorithm,
+    args::ConvInputsLaunch,
+    base::{ConvolutionProblem, Dimensionality},
+};
+
+/// Compute the gradient of an n-dimensional convolution with respect to its input, using the
+/// backward data convolution of the specified algorithm.
+///
+/// * `out_grad` - The gradient of the output feature map, layout should be [batches, out_depth, out_height, out_width, out_channels]
+/// * `weight` - The weights (filter) applied to each kernel, layout should be [out_channels, kernel_d, kernel_h, kernel_w, in_channels / groups]
+/// * `in_grad` - The gradient of the input feature map, layout should be [batches, depth, height, width, in_channels]
+/// * `args` - The options of the forward convolution
+#[allow(clippy::result_large_err)]
+pub fn launch_conv_backward_data<
+    R: Runtime,
+    MP: MatmulPrecision,
+    Alg: Algorithm,
+    const N_SPATIAL: usize,
+>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    out_grad: &TensorHandleRef<'_, R>,
+    weight: &TensorHandleRef<'_, R>,
+    in_grad: &TensorHandleRef<'_, R>,
+    args: ConvolutionArgs<N_SPATIAL>,
+) -> Result<(), ConvLaunchError> {
+    let rank = weight.shape.len();
+    let dim_c = rank - 1;
+
+    let forward = forward_problem(out_grad, weight.shape, in_grad, &args)?;
+    let problem = forward.backward_data();
+    let groups = problem.groups;
+
+    // Only expose the out channels of a single group, the kernel offsets into the right one
+    let mut out_grad_shape = out_grad.shape.to_vec();
+    out_grad_shape[dim_c] = forward.n;
+    let out_grad = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            out_grad.handle,
+            out_grad.strides,
+            &out_grad_shape,
+            out_grad.elem_size,
+        )
+    };
+
+    // The weights of each group are read as a `(kernel_size * out_channels, in_channels)` matrix,
+    // so the out channels are split into groups and moved after the kernel positions
+    let mut permuted_shape = vec![groups];
+    permuted_shape.extend(&weight.shape[1..dim_c]);
+    permuted_shape.extend([forward.n, weight.shape[dim_c]]);
+    let mut permuted_strides = vec![forward.n * weight.strides[0]];
+    permuted_strides.extend(&weight.strides[1..dim_c]);
+    permuted_strides.extend([weight.strides[0], weight.strides[dim_c]]);
+    let permuted = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            weight.handle,
+            &permuted_strides,
+            &permuted_shape,
+            weight.elem_size,
+        )
+    };
+    let weight = into_contiguous::<R, MP::EI>(client, &permuted);
+
+    let weight_shape = [groups, problem.k, problem.n];
+    let weight_strides = [problem.k * problem.n, problem.n, 1];
+    let weight = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            &weight.handle,
+            &weight_strides,
+            &weight_shape,
+            size_of::<MP::EI>(),
+        )
+    };
+
+    // Reshape in_grad to (groups, M, N)
+    let in_grad_shape = [groups, problem.m, problem.n];
+    let in_grad_strides = [
+        problem.n * in_grad.strides[dim_c],
+        in_grad.strides[rank - 2],
+        in_grad.strides[dim_c],
+    ];
+    let in_grad = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            in_grad.handle,
+            &in_grad_strides,
+            &in_grad_shape,
+            in_grad.elem_size,
+        )
+    };
+
+    launch_backward::<R, MP, Alg, Alg::BackwardData>(client, &out_grad, &weight, &in_grad, problem)
+}
+
+/// Compute the gradient of an n-dimensional convolution with respect to its weights, using the
+/// backward weight convolution of the specified algorithm.
+///
+/// * `input` - The input feature map, layout should be [batches, depth, height, width, in_channels]
+/// * `out_grad` - The gradient of the output feature map, layout should be [batches, out_depth, out_height, out_width, out_channels]
+/// * `weight_grad` - The gradient of the weights, should be contiguous with layout [out_channels, kernel_d, kernel_h, kernel_w, in_channels / groups]
+/// * `args` - The options of the forward convolution
+#[allow(clippy::result_large_err)]
+pub fn launch_conv_backward_weight<
+    R: Runtime,
+    MP: MatmulPrecision,
+    Alg: Algorithm,
+    const N_SPATIAL: usize,
+>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &TensorHandleRef<'_, R>,
+    out_grad: &TensorHandleRef<'_, R>,
+    weight_grad: &TensorHandleRef<'_, R>,
+    args: ConvolutionArgs<N_SPATIAL>,
+) -> Result<(), ConvLaunchError> {
+    let rank = out_grad.shape.len();
+    let dim_c = rank - 1;
+
+    let problem = forward_problem(out_grad, weight_grad.shape, input, &args)?.backward_weight();
+    let groups = problem.groups;
+
+    // Only expose the in channels of a single group, the kernel offsets into the right one
+    let mut input_shape = input.shape.to_vec();
+    input_shape[dim_c] = problem.channels_per_group();
+    let input = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            input.handle,
+            input.strides,
+            &input_shape,
+            input.elem_size,
+        )
+    };
+
+    // The batches and output positions of out_grad are read as a single dimension
+    let can_flatten = (0..dim_c - 1)
+        .all(|i| out_grad.strides[i] == out_grad.strides[i + 1] * out_grad.shape[i + 1]);
+    let contiguous = (!can_flatten).then(|| into_contiguous::<R, MP::EI>(client, out_grad));
+    let (out_grad_strides, elem_size) = match &contiguous {
+        Some(it) => (it.strides.as_slice(), size_of::<MP::EI>()),
+        None => (out_grad.strides, out_grad.elem_size),
+    };
+    let out_grad_handle = contiguous
+        .as_ref()
+        .map(|it| &it.handle)
+        .unwrap_or(out_grad.handle);
+
+    let out_grad_shape = [groups, problem.m, problem.k];
+    let out_grad_strides = [
+        problem.m * out_grad_strides[dim_c],
+        out_grad_strides[dim_c],
+        out_grad_strides[dim_c - 1],
+    ];
+    let out_grad = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            out_grad_handle,
+            &out_grad_strides,
+            &out_grad_shape,
+            elem_size,
+        )
+    };
+
+    // Reshape weight_grad to (groups, M, N)
+    let weight_grad_shape = [groups, problem.m, problem.n];
+    let weight_grad_strides = [
+        problem.m * weight_grad.strides[0],
+        weight_grad.strides[0],
+        weight_grad.strides[dim_c],
+    ];
+    let weight_grad = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            weight_grad.handle,
+            &weight_grad_strides,
+            &weight_grad_shape,
+            weight_grad.elem_size,
+        )
+    };
+
+    launch_backward::<R, MP, Alg, Alg::BackwardWeight>(
+        client,
+        &out_grad,
+        &input,
+        &weight_grad,
+        problem,
+    )
+}
+
+/// Build the problem of the forward convolution that produced `out`.
+#[allow(clippy::result_large_err)]
+fn forward_problem<R: Runtime, const N_SPATIAL: usize>(
+    out: &TensorHandleRef<'_, R>,
+    weight_shape: &[usize],
+    input: &TensorHandleRef<'_, R>,
+    args: &ConvolutionArgs<N_SPATIAL>,
+) -> Result<ConvolutionProblem, ConvLaunchError> {
+    let dimensionality = match N_SPATIAL {
+        1 => Dimensionality::Dim1,
+        2 => Dimensionality::Dim2,
+        3 => Dimensionality::Dim3,
+        other => unimplemented!("Unsupported dimensionality {other}"),
+    };
+
+    let rank = input.shape.len();
+    let dim_c = rank - 1;
+
+    let n = input.shape[0];
+    let c = input.shape[dim_c];
+    let out_c = out.shape[dim_c];
+    let groups = args.groups;
+
+    if groups == 0 || c % groups != 0 || out_c % groups != 0 {
+        return Err(ConvLaunchError::Groups(groups));
+    }
+
+    let in_shape = &input.shape[1..dim_c];
+    let out_shape = &out.shape[1..dim_c];
+    let kernel_shape = &weight_shape[1..dim_c];
+
+    Ok(ConvolutionProblem {
+        m: n * out_shape.iter().product::<usize>(),
+        n: out_c / groups,
+        k: c / groups * kernel_shape.iter().product::<usize>(),
+        lhs_layout: components::MatrixLayout::RowMajor,
+        rhs_layout: components::MatrixLayout::ColMajor,
+        kernel_size: kernel_shape.iter().map(|it| *it as u32).collect(),
+        stride: args.stride.iter().map(|it| *it as u32).collect(),
+        padding: args.padding.iter().map(|it| *it as i32).collect(),
+        dilation: args.dilation.iter().map(|it| *it as u32).collect(),
+
+        batches: n,
+        shape: in_shape.to_vec(),
+        out_shape: out_shape.to_vec(),
+        channels: c,
+        groups,
+
+        dimensionality,
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn launch_backward<R: Runtime, MP: MatmulPrecision, Alg: Algorithm, F: ConvolutionFamily>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+    problem: ConvolutionProblem,
+) -> Result<(), ConvLaunchError> {
+    let plane_dim = client.properties().hardware.plane_size_max;
+
+    let selection = Alg::selection::<R>(
+        client,
+        &problem,
+        plane_dim,
+        MP::ES::as_elem_native_unchecked(),
+        MP::EA::as_elem_native_unchecked(),
+    );
+
+    let launch = if TypeId::of::<MP::EI>() == TypeId::of::<f32>() {
+        if tf32::is_supported(client) {
+            launch_backward_kernel::<R, (MP::EI, tf32, f32, MP::EO), Alg, F>
+        } else {
+            launch_backward_kernel::<R, (MP::EI, f16, f32, MP::EO), Alg, F>
+        }
+    } else {
+        launch_backward_kernel::<R, MP, Alg, F>
+    };
+
+    launch(client, lhs, rhs, out, problem, selection)
+}
+
+/// Launch a backward convolution `F` with already reshaped tensors. `problem` should be the
+/// backward problem, see [ConvolutionProblem::backward_data] and
+/// [ConvolutionProblem::backward_weight].
+#[allow(clippy::result_large_err)]
+pub fn launch_backward_kernel<
+    R: Runtime,
+    MP: MatmulPrecision,
+    Alg: Algorithm,
+    F: ConvolutionFamily,
+>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+    problem: ConvolutionProblem,
+    selection: MatmulSelection,
+) -> Result<(), ConvLaunchError> {
+    let line_sizes = AvailableLineSizes::from_elem_types::<R>(
+        &MP::EI::as_elem_native_unchecked(),
+        &MP::EO::as_elem_native_unchecked(),
+    )
+    .filter_lhs_with_tensor(lhs.strides, lhs.shape, problem.lhs_layout)
+    .filter_rhs_with_tensor(rhs.strides, rhs.shape, problem.rhs_layout)
+    .filter_out_with_tensor(out.strides, out.shape);
+
+    let line_sizes = F::filter_line_sizes(Alg::StageMatmul::filter_line_sizes(
+        Alg::TileMatmul::filter_line_sizes(line_sizes),
+    ))
+    .pick_max()?;
+
+    let config = F::setup::<R, MP>(client, &problem, &selection, &line_sizes)?;
+
+    let line_sizes = config.line_sizes();
+
+    let input = <TensorInputs<MP::EI> as ConvInputsLaunch>::create(
+        lhs,
+        rhs,
+        &selection,
+        &problem,
+        &line_sizes,
+    );
+    let output = out.as_tensor_arg(line_sizes.out);
+
+    unsafe {
+        <F as ConvolutionLaunch>::launch_unchecked::<(MP, TensorArgs), R>(
+            client,
+            config.cube_dim(),
+            Alg::cube_count(&selection, &problem),
+            input,
+            None,
+            output,
+            &problem,
+            config,
+        )?;
+    }
+
+    Ok(())
+}
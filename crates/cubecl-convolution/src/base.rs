@@ -59,6 +59,7 @@ pub trait Convolution<MP: MatmulPrecision>: 'static + Send + Sync {
         lhs: VirtualTensor<MP::EI>,
         x_offset: u32,
         y_offset: u32,
+        group: u32,
         runtime_args: &RuntimeArgs,
         #[comptime] config: Self::Config,
     ) -> Self::LhsLoader;
@@ -67,6 +68,7 @@ pub trait Convolution<MP: MatmulPrecision>: 'static + Send + Sync {
         rhs: VirtualTensor<MP::EI>,
         x_offset: u32,
         y_offset: u32,
+        group: u32,
         runtime_args: &RuntimeArgs,
         #[comptime] config: Self::Config,
     ) -> Self::RhsLoader;
@@ -81,6 +83,7 @@ pub trait Convolution<MP: MatmulPrecision>: 'static + Send + Sync {
         out: VirtualTensor<MP::EO, ReadWrite>,
         x_offset: u32,
         y_offset: u32,
+        group: u32,
     ) -> Self::Writer;
 
     fn init_accumulator(#[comptime] config: Self::Config) -> Self::Accumulator;
@@ -116,7 +119,7 @@ pub trait ConvolutionLaunch: ConvolutionConfigFactory {
         output: OutputRuntimeArg<'a, MS, R>,
         problem: &ConvolutionProblem,
         config: <Self as ConvolutionConfigFactory>::Config,
-    );
+    ) -> Result<(), CompilationError>;
 }
 
 #[derive(Clone, Debug)]
@@ -135,6 +138,9 @@ pub struct ConvolutionProblem {
 
     pub batches: usize,
     pub channels: usize,
+    /// Number of groups the input and output channels are split into. `m`, `n` and `k` describe
+    /// the matmul of a single group.
+    pub groups: usize,
     pub shape: Vec<usize>,
     pub out_shape: Vec<usize>,
 
@@ -153,6 +159,47 @@ impl ConvolutionProblem {
             rhs_layout: self.rhs_layout,
         }
     }
+
+    /// Number of input channels in each group.
+    pub fn channels_per_group(&self) -> usize {
+        self.channels / self.groups
+    }
+
+    /// The problem solved to compute the gradient with respect to the input of this
+    /// convolution. Rows are input positions, columns are the in channels of a group, and `k`
+    /// spans the kernel positions and the out channels of that group.
+    pub fn backward_data(&self) -> Self {
+        let kernel_len = self.kernel_size.iter().product::<u32>() as usize;
+        Self {
+            m: self.batches * self.shape.iter().product::<usize>(),
+            n: self.channels_per_group(),
+            k: kernel_len * self.n,
+            lhs_layout: MatrixLayout::RowMajor,
+            rhs_layout: MatrixLayout::RowMajor,
+            ..self.clone()
+        }
+    }
+
+    /// The problem solved to compute the gradient with respect to the weights of this
+    /// convolution. Rows are the out channels of a group, columns are kernel positions and the in
+    /// channels of that group, and `k` spans the batches and output positions.
+    pub fn backward_weight(&self) -> Self {
+        let kernel_len = self.kernel_size.iter().product::<u32>() as usize;
+        Self {
+            m: self.n,
+            n: kernel_len * self.channels_per_group(),
+            k: self.batches * self.out_shape.iter().product::<usize>(),
+            lhs_layout: MatrixLayout::ColMajor,
+            rhs_layout: MatrixLayout::RowMajor,
+            ..self.clone()
+        }
+    }
+
+    /// Whether each input channel is convolved on its own, which is better served by a direct
+    /// kernel than by implicit GEMM.
+    pub fn is_depthwise(&self) -> bool {
+        self.groups > 1 && self.groups == self.channels
+    }
 }
 
 /// Spatial dimensionality of an operation
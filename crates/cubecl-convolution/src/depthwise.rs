@@ -0,0 +1,221 @@
+use cubecl_core as cubecl;
+use cubecl_core::{intrinsic, prelude::*};
+use cubecl_std::{CubeOption, CubeOptionExpand, FastDivmod, FastDivmodArgs};
+
+use crate::{
+    ConvLaunchError, base::Dimensionality, homogeneous::base::shape_divmod,
+    loader::im2col_tma::div_mod_seq,
+};
+
+/// Compile time parameters of a depthwise convolution.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct DepthwiseConfig {
+    kernel_size: [u32; 3],
+    stride: [u32; 3],
+    dilation: [u32; 3],
+    padding: [i32; 3],
+    dimensionality: Dimensionality,
+}
+
+impl DepthwiseConfig {
+    pub fn new(
+        kernel_size: &[u32],
+        stride: &[u32],
+        dilation: &[u32],
+        padding: &[i32],
+        dimensionality: Dimensionality,
+    ) -> Self {
+        let dims = kernel_size.len();
+
+        let mut this = Self {
+            kernel_size: [0; 3],
+            stride: [0; 3],
+            dilation: [0; 3],
+            padding: [0; 3],
+            dimensionality,
+        };
+        this.kernel_size[0..dims].copy_from_slice(kernel_size);
+        this.stride[0..dims].copy_from_slice(stride);
+        this.dilation[0..dims].copy_from_slice(dilation);
+        this.padding[0..dims].copy_from_slice(padding);
+        this
+    }
+
+    pub fn kernel_size(&self, dim: u32) -> u32 {
+        self.kernel_size[dim as usize]
+    }
+
+    pub fn stride(&self, dim: u32) -> u32 {
+        self.stride[dim as usize]
+    }
+
+    pub fn dilation(&self, dim: u32) -> u32 {
+        self.dilation[dim as usize]
+    }
+
+    pub fn padding(&self, dim: u32) -> i32 {
+        self.padding[dim as usize]
+    }
+
+    /// Number of elements in the kernel window.
+    pub fn kernel_len(&self) -> u32 {
+        let dims = match self.dimensionality {
+            Dimensionality::Dim1 => 1,
+            Dimensionality::Dim2 => 2,
+            Dimensionality::Dim3 => 3,
+        };
+        self.kernel_size[0..dims].iter().product()
+    }
+}
+
+#[derive(CubeType, CubeLaunch, Clone)]
+pub struct DepthwiseArgs {
+    /// Number of out channels for each in channel.
+    pub channel_multiplier: u32,
+    pub out_channels: FastDivmod,
+    pub out_shape: Sequence<FastDivmod>,
+}
+
+/// Direct depthwise convolution, where each unit computes one output element. Every out channel
+/// only reads a single in channel, so there's no reduction over channels that implicit GEMM could
+/// make use of.
+#[cube(launch_unchecked)]
+fn depthwise_conv<EI: Numeric, EA: Numeric, EO: Numeric>(
+    input: &Tensor<EI>,
+    weight: &Tensor<EI>,
+    bias: &CubeOption<Tensor<EO>>,
+    output: &mut Tensor<EO>,
+    args: DepthwiseArgs,
+    #[comptime] config: DepthwiseConfig,
+) {
+    let spatial_dims = comptime![args.out_shape.len()];
+    let dim_c = comptime![spatial_dims + 1];
+
+    let (pos, out_c) = args.out_channels.div_mod(ABSOLUTE_POS);
+    let (batch, out_pos) = div_mod_seq(pos, &args.out_shape);
+
+    if batch >= output.shape(0) {
+        terminate!();
+    }
+
+    let in_c = out_c / args.channel_multiplier;
+
+    let in_offset = batch * input.stride(0) + in_c * input.stride(dim_c);
+    let weight_offset = out_c * weight.stride(0);
+
+    let mut acc = EA::from_int(0);
+
+    for k in 0..comptime![config.kernel_len()] {
+        let mut rem = k;
+        let mut in_offset = in_offset;
+        let mut weight_offset = weight_offset;
+        let mut in_bounds = true;
+
+        #[unroll]
+        for i in 0..spatial_dims {
+            let i = unwrap(i);
+            let dim = comptime![spatial_dims - i - 1];
+            let ksize = comptime![config.kernel_size(dim)];
+            let k_pos = rem % ksize;
+            rem /= ksize;
+
+            let stride = comptime![config.stride(dim)];
+            let dilate = comptime![config.dilation(dim)];
+            let pad = comptime![config.padding(dim)];
+
+            let pos = (*out_pos.index(dim) * stride + k_pos * dilate) as i32 - pad;
+            in_bounds &= pos >= 0 && (pos as u32) < input.shape(dim + 1);
+            in_offset += pos as u32 * input.stride(dim + 1);
+            weight_offset += k_pos * weight.stride(dim + 1);
+        }
+
+        if in_bounds {
+            acc += EA::cast_from(input[in_offset]) * EA::cast_from(weight[weight_offset]);
+        }
+    }
+
+    let bias = match bias {
+        CubeOption::Some(bias) => EA::cast_from(bias[out_c]),
+        CubeOption::None => EA::from_int(0),
+    };
+
+    let mut out_offset = batch * output.stride(0) + out_c * output.stride(dim_c);
+
+    #[unroll]
+    for i in 0..spatial_dims {
+        let i = unwrap(i);
+        out_offset += *out_pos.index(i) * output.stride(i + 1);
+    }
+
+    output[out_offset] = EO::cast_from(acc + bias);
+}
+
+/// Perform an n-dimensional depthwise convolution, where `groups == in_channels`. The number of
+/// out channels must be a multiple of the number of in channels.
+///
+/// * `input` - The input feature map, layout should be [batches, depth, height, width, in_channels]
+/// * `weight` - The weights (filter) applied to each kernel, layout should be [out_channels, kernel_d, kernel_h, kernel_w, 1]
+/// * `out` - The output feature map, layout should be [batches, out_depth, out_height, out_width, out_channels]
+/// * `bias` - The bias added to each out channel
+#[allow(clippy::result_large_err, clippy::too_many_arguments)]
+pub fn launch_depthwise<R: Runtime, EI: Numeric, EA: Numeric, EO: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &TensorHandleRef<'_, R>,
+    weight: &TensorHandleRef<'_, R>,
+    bias: &Option<TensorHandleRef<'_, R>>,
+    out: &TensorHandleRef<'_, R>,
+    (stride, padding, dilation): (&[usize], &[usize], &[usize]),
+    dimensionality: Dimensionality,
+) -> Result<(), ConvLaunchError> {
+    let rank = input.shape.len();
+    let dim_c = rank - 1;
+
+    let channels = input.shape[dim_c];
+    let out_channels = out.shape[dim_c];
+
+    if weight.shape[dim_c] != 1 || out_channels % channels != 0 {
+        return Err(ConvLaunchError::Groups(channels));
+    }
+
+    let kernel_size = weight.shape[1..dim_c]
+        .iter()
+        .map(|it| *it as u32)
+        .collect::<Vec<_>>();
+    let stride = stride.iter().map(|it| *it as u32).collect::<Vec<_>>();
+    let padding = padding.iter().map(|it| *it as i32).collect::<Vec<_>>();
+    let dilation = dilation.iter().map(|it| *it as u32).collect::<Vec<_>>();
+
+    let config = DepthwiseConfig::new(&kernel_size, &stride, &dilation, &padding, dimensionality);
+
+    let args = DepthwiseArgsLaunch::new(
+        ScalarArg::new((out_channels / channels) as u32),
+        FastDivmodArgs::new(client, out_channels as u32),
+        shape_divmod(client, &out.shape[1..dim_c]),
+    );
+
+    let num_elems = out.shape.iter().product::<usize>();
+    let cube_dim = CubeDim::default();
+    let cube_count = cubecl_core::calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        depthwise_conv::launch_unchecked::<EI, EA, EO, R>(
+            client,
+            cube_count,
+            cube_dim,
+            input.as_tensor_arg(1),
+            weight.as_tensor_arg(1),
+            bias.as_ref().map(|bias| bias.as_tensor_arg(1)).into(),
+            out.as_tensor_arg(1),
+            args,
+            config,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[allow(unused_variables)]
+#[cube]
+fn unwrap(v: u32) -> comptime_type!(u32) {
+    intrinsic!(|_| v.constant().expect("Must be constant").as_u32())
+}
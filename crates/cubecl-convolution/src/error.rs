@@ -1,12 +1,13 @@
 use std::fmt::Debug;
 
-use cubecl_core::tune::AutotuneError;
+use cubecl_core::{CompilationError, tune::AutotuneError};
 use cubecl_matmul::components::{MatmulAvailabilityError, MatmulSetupError};
 
 #[allow(clippy::large_enum_variant)]
 pub enum ConvLaunchError {
     Matmul(MatmulSetupError),
     Groups(usize),
+    Compilation(CompilationError),
     Unknown,
 }
 
@@ -19,7 +20,13 @@ impl Debug for ConvLaunchError {
             ConvLaunchError::Groups(groups) => {
                 writeln!(
                     f,
-                    "Unable to launch matmul because groups must be one, is actually {groups}",
+                    "Unable to launch convolution with {groups} groups, groups must divide the number of in and out channels",
+                )
+            }
+            ConvLaunchError::Compilation(err) => {
+                writeln!(
+                    f,
+                    "Unable to launch convolution because it failed to compile: {err}"
                 )
             }
             ConvLaunchError::Unknown => write!(f, "Unknown"),
@@ -33,6 +40,12 @@ impl From<MatmulSetupError> for ConvLaunchError {
     }
 }
 
+impl From<CompilationError> for ConvLaunchError {
+    fn from(value: CompilationError) -> Self {
+        Self::Compilation(value)
+    }
+}
+
 impl From<MatmulAvailabilityError> for ConvLaunchError {
     fn from(value: MatmulAvailabilityError) -> Self {
         Self::Matmul(MatmulSetupError::Unavailable(value))
@@ -42,6 +55,10 @@ impl From<MatmulAvailabilityError> for ConvLaunchError {
 #[allow(clippy::from_over_into)]
 impl Into<AutotuneError> for ConvLaunchError {
     fn into(self) -> AutotuneError {
-        AutotuneError::Unknown(format!("{self:?}"))
+        match self {
+            ConvLaunchError::Matmul(err) => err.into(),
+            ConvLaunchError::Compilation(err) => AutotuneError::Compilation(err),
+            err => AutotuneError::Unknown(format!("{err:?}")),
+        }
     }
 }
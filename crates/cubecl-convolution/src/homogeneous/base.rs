@@ -45,6 +45,7 @@ pub(crate) fn implicit_conv<
 
     let x_offset = CUBE_POS_X * config.tiling_scheme().elements_in_stage_m();
     let y_offset = CUBE_POS_Y * config.tiling_scheme().elements_in_stage_n();
+    let group = CUBE_POS_Z;
     let k_range = (0, runtime_args.size_k);
 
     let bias = match bias {
@@ -59,6 +60,7 @@ pub(crate) fn implicit_conv<
             lhs,
             x_offset,
             k_range.0,
+            group,
             &runtime_args,
             config,
         ),
@@ -66,11 +68,16 @@ pub(crate) fn implicit_conv<
             rhs,
             k_range.0,
             y_offset,
+            group,
             &runtime_args,
             config,
         ),
-        GMM::Convolution::<(EI, ES, EA, EO)>::init_bias_loader(bias, y_offset, config),
-        GMM::Convolution::<(EI, ES, EA, EO)>::init_writer(out, x_offset, y_offset),
+        GMM::Convolution::<(EI, ES, EA, EO)>::init_bias_loader(
+            bias,
+            group * runtime_args.size_n + y_offset,
+            config,
+        ),
+        GMM::Convolution::<(EI, ES, EA, EO)>::init_writer(out, x_offset, y_offset, group),
         &mut GMM::Convolution::<(EI, ES, EA, EO)>::init_accumulator(config),
         k_range,
         config,
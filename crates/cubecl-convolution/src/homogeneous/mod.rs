@@ -1,3 +1,5 @@
+pub mod backward_data;
+pub mod backward_weight;
 pub mod base;
 pub mod multi_stage_tma;
 pub mod simple;
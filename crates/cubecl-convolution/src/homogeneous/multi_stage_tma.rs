@@ -188,6 +188,7 @@ where
         lhs: VirtualTensor<MP::EI>,
         x_offset: u32,
         y_offset: u32,
+        _group: u32,
         runtime_args: &RuntimeArgs,
         #[comptime] config: Self::Config,
     ) -> Self::LhsLoader {
@@ -205,6 +206,7 @@ where
         rhs: VirtualTensor<MP::EI>,
         x_offset: u32,
         y_offset: u32,
+        _group: u32,
         runtime_args: &RuntimeArgs,
         #[comptime] config: Self::Config,
     ) -> Self::RhsLoader {
@@ -231,6 +233,7 @@ where
         out: VirtualTensor<MP::EO, ReadWrite>,
         x_offset: u32,
         y_offset: u32,
+        _group: u32,
     ) -> Self::Writer {
         SMM::init_writer(out, x_offset, y_offset, 0)
     }
@@ -332,7 +335,7 @@ impl<SMM: StageMatmulFamily<LhsReader = FullReaderFamily, RhsReader = FullReader
         output: OutputRuntimeArg<'a, MS, R>,
         problem: &ConvolutionProblem,
         config: <Self as ConvolutionConfigFactory>::Config,
-    ) {
+    ) -> Result<(), CompilationError> {
         let padded_channels =
             (problem.channels as u32).next_multiple_of(config.tiling_scheme().elements_in_tile_k());
 
@@ -356,7 +359,7 @@ impl<SMM: StageMatmulFamily<LhsReader = FullReaderFamily, RhsReader = FullReader
                 output,
                 runtime_args,
                 config,
-            );
+            )
         }
     }
 }
@@ -120,24 +120,28 @@ where
         lhs: VirtualTensor<MP::EI>,
         x_offset: u32,
         y_offset: u32,
+        group: u32,
         runtime_args: &RuntimeArgs,
         #[comptime] config: Self::Config,
     ) -> Self::LhsLoader {
-        Self::LhsLoader::new(lhs, x_offset, y_offset, runtime_args, config)
+        Self::LhsLoader::new(lhs, x_offset, y_offset, group, runtime_args, config)
     }
 
     fn init_rhs_loader(
         rhs: VirtualTensor<MP::EI>,
         x_offset: u32,
         y_offset: u32,
-        _runtime_args: &RuntimeArgs,
+        group: u32,
+        runtime_args: &RuntimeArgs,
         #[comptime] config: Self::Config,
     ) -> Self::RhsLoader {
+        // The weights of each group are a contiguous range of out channels
+        let group_offset = group * runtime_args.size_n * rhs.stride(1);
         Self::RhsLoader::new(
             rhs,
             x_offset,
             y_offset,
-            0,
+            group_offset,
             CubeOption::new_None(),
             MatmulIdent::Rhs,
             config,
@@ -156,8 +160,9 @@ where
         out: VirtualTensor<MP::EO, ReadWrite>,
         x_offset: u32,
         y_offset: u32,
+        group: u32,
     ) -> Self::Writer {
-        SMM::init_writer(out, x_offset, y_offset, 0)
+        SMM::init_writer(out, x_offset, y_offset, group * out.stride(0))
     }
 
     fn init_accumulator(#[comptime] config: Self::Config) -> Self::Accumulator {
@@ -240,12 +245,12 @@ impl<SMM: StageMatmulFamily<LhsReader = FullReaderFamily, RhsReader = FullReader
         output: OutputRuntimeArg<'a, MS, R>,
         problem: &ConvolutionProblem,
         config: <Self as ConvolutionConfigFactory>::Config,
-    ) {
+    ) -> Result<(), CompilationError> {
         let runtime_args = RuntimeArgsLaunch::new(
             ScalarArg::new(problem.m as u32),
             ScalarArg::new(problem.n as u32),
             ScalarArg::new(problem.k as u32),
-            FastDivmodArgs::new(client, problem.channels as u32),
+            FastDivmodArgs::new(client, problem.channels_per_group() as u32),
             shape_divmod(client, &problem.out_shape),
         );
 
@@ -259,7 +264,7 @@ impl<SMM: StageMatmulFamily<LhsReader = FullReaderFamily, RhsReader = FullReader
                 output,
                 runtime_args,
                 config,
-            );
+            )
         }
     }
 }
@@ -128,6 +128,7 @@ where
         lhs: VirtualTensor<MP::EI>,
         x_offset: u32,
         y_offset: u32,
+        _group: u32,
         runtime_args: &RuntimeArgs,
         #[comptime] config: Self::Config,
     ) -> Self::LhsLoader {
@@ -138,6 +139,7 @@ where
         rhs: VirtualTensor<MP::EI>,
         x_offset: u32,
         y_offset: u32,
+        _group: u32,
         runtime_args: &RuntimeArgs,
         #[comptime] config: Self::Config,
     ) -> Self::RhsLoader {
@@ -164,6 +166,7 @@ where
         out: VirtualTensor<MP::EO, ReadWrite>,
         x_offset: u32,
         y_offset: u32,
+        _group: u32,
     ) -> Self::Writer {
         SMM::init_writer(out, x_offset, y_offset, 0)
     }
@@ -256,7 +259,7 @@ impl<SMM: StageMatmulFamily<LhsReader = FullReaderFamily, RhsReader = FullReader
         output: OutputRuntimeArg<'a, MS, R>,
         problem: &ConvolutionProblem,
         config: <Self as ConvolutionConfigFactory>::Config,
-    ) {
+    ) -> Result<(), CompilationError> {
         let padded_channels =
             (problem.channels as u32).next_multiple_of(config.tiling_scheme().elements_in_tile_k());
 
@@ -280,7 +283,7 @@ impl<SMM: StageMatmulFamily<LhsReader = FullReaderFamily, RhsReader = FullReader
                 output,
                 runtime_args,
                 config,
-            );
+            )
         }
     }
 }
@@ -16,6 +16,7 @@ use super::{
     algorithm::Algorithm,
     args::ConvInputsLaunch,
     base::{ConvolutionProblem, Dimensionality},
+    depthwise::launch_depthwise,
 };
 
 type Input<Alg, MP> = <<Alg as Algorithm>::Args as MatmulArgs>::Input<<MP as MatmulPrecision>::EI>;
@@ -27,13 +28,16 @@ pub struct ConvolutionArgs<const N_SPATIAL: usize> {
     pub stride: [usize; N_SPATIAL],
     pub padding: [usize; N_SPATIAL],
     pub dilation: [usize; N_SPATIAL],
+    /// Number of groups the channels are split into. Must divide both the in and out channels.
+    pub groups: usize,
 }
 
 /// Perform an n-dimensional convolution using the implicit GEMM (im2col) algorithm, using cubecl
-/// tiling matmul components, using the specified algorithm.
+/// tiling matmul components, using the specified algorithm. Depthwise convolutions
+/// (`groups == in_channels`) use a direct kernel instead.
 ///
 /// * `input` - The input feature map, layout should be [batches, depth, height, width, in_channels]
-/// * `weight` - The weights (filter) applied to each kernel, layout should be [out_channels, kernel_d, kernel_h, kernel_w, in_channels]
+/// * `weight` - The weights (filter) applied to each kernel, layout should be [out_channels, kernel_d, kernel_h, kernel_w, in_channels / groups]
 /// * `out` - The output feature map, layout should be [batches, out_depth, out_height, out_width, out_channels]
 /// * `bias` - The bias added to each out channel
 /// * `options` - The options to use for the convolution
@@ -54,6 +58,7 @@ where
         stride,
         padding,
         dilation,
+        groups,
     } = args;
 
     let dimensionality = match N_SPATIAL {
@@ -70,10 +75,12 @@ where
         bias,
         out,
         (&stride, &padding, &dilation),
+        groups,
         dimensionality,
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn launch<R: Runtime, MP: MatmulPrecision, Alg: Algorithm>(
     client: &ComputeClient<R::Server, R::Channel>,
     input: &TensorHandleRef<'_, R>,
@@ -81,6 +88,7 @@ fn launch<R: Runtime, MP: MatmulPrecision, Alg: Algorithm>(
     bias: &Option<TensorHandleRef<'_, R>>,
     out: &TensorHandleRef<'_, R>,
     (stride, padding, dilation): (&[usize], &[usize], &[usize]),
+    groups: usize,
     dimensionality: Dimensionality,
 ) -> Result<(), ConvLaunchError>
 where
@@ -95,6 +103,22 @@ where
 
     let out_c = weight.shape[0];
 
+    if groups == 0 || c % groups != 0 || out_c % groups != 0 {
+        return Err(ConvLaunchError::Groups(groups));
+    }
+
+    if groups > 1 && groups == c {
+        return launch_depthwise::<R, MP::EI, MP::EA, MP::EO>(
+            client,
+            input,
+            weight,
+            bias,
+            out,
+            (stride, padding, dilation),
+            dimensionality,
+        );
+    }
+
     let in_shape = &input.shape[1..dim_c];
     let kernel_shape = &weight.shape[1..dim_c];
     let out_shape = &out.shape[1..dim_c];
@@ -106,8 +130,8 @@ where
 
     let problem = ConvolutionProblem {
         m: n * out_shape.iter().product::<usize>(),
-        n: out_c,
-        k: c * kernel_shape.iter().product::<usize>(),
+        n: out_c / groups,
+        k: c / groups * kernel_shape.iter().product::<usize>(),
         lhs_layout: components::MatrixLayout::RowMajor,
         rhs_layout: components::MatrixLayout::ColMajor,
         kernel_size: kernel_shape.iter().map(|it| *it as u32).collect(),
@@ -119,6 +143,7 @@ where
         shape: in_shape.to_vec(),
         out_shape: out_shape.to_vec(),
         channels: c,
+        groups,
 
         dimensionality,
     };
@@ -169,13 +194,30 @@ where
     let rank = out.shape.len();
     let dim_c = rank - 1;
 
-    // Reshape out to (M, N)
-    let out_shape = [out.shape[0..dim_c].iter().product(), out.shape[dim_c]];
-    let out_strides = [out.strides[rank - 2], out.strides[dim_c]];
+    // Only expose the channels of a single group, the kernel offsets into the right one
+    let mut input_shape = input.shape.to_vec();
+    input_shape[dim_c] = problem.channels_per_group();
+
+    let input = unsafe {
+        TensorHandleRef::from_raw_parts(input.handle, input.strides, &input_shape, input.elem_size)
+    };
+
+    // Reshape out to (groups, M, N)
+    let out_shape = [
+        problem.groups,
+        out.shape[0..dim_c].iter().product(),
+        problem.n,
+    ];
+    let out_strides = [
+        problem.n * out.strides[dim_c],
+        out.strides[rank - 2],
+        out.strides[dim_c],
+    ];
 
     let out = unsafe {
         TensorHandleRef::from_raw_parts(out.handle, &out_strides, &out_shape, out.elem_size)
     };
+    let input = &input;
 
     let line_sizes = AvailableLineSizes::from_elem_types::<R>(
         &MP::EI::as_elem_native_unchecked(),
@@ -216,7 +258,7 @@ where
             output,
             &problem,
             config,
-        );
+        )?;
     }
 
     Ok(())
@@ -5,7 +5,9 @@ mod config;
 
 pub mod algorithm;
 pub mod args;
+pub mod backward;
 pub mod base;
+pub mod depthwise;
 pub mod error;
 pub mod homogeneous;
 pub mod launch;
@@ -15,6 +17,7 @@ pub mod selection;
 #[cfg(feature = "export_tests")]
 pub mod tests;
 
+pub use backward::*;
 pub use config::*;
 pub use error::*;
 pub use launch::*;
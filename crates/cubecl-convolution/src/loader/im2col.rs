@@ -1,8 +1,8 @@
 use cubecl_core as cubecl;
 use cubecl_core::prelude::*;
 
+use cubecl_matmul::components::MatmulIdent;
 use cubecl_matmul::components::global::load::LoaderMode;
-use cubecl_matmul::components::{MatmulIdent, StageIdent};
 use cubecl_std::div_ceil;
 use cubecl_std::tensor::r#virtual::VirtualTensor;
 use std::marker::PhantomData;
@@ -20,6 +20,8 @@ pub struct SimpleIm2colLoader<MP: MatmulPrecision, G: ConvGemmConfig> {
     pub tensor_view: Im2colReader<MP::EI>,
     pub stage: StageMemory<MP::ES, ContiguousTilingLayout<RowMajorTilingOrder>>,
     #[cube(comptime)]
+    ident: MatmulIdent,
+    #[cube(comptime)]
     _config: PhantomData<G>,
 }
 
@@ -29,50 +31,92 @@ impl<MP: MatmulPrecision, G: ConvGemmConfig> SimpleIm2colLoader<MP, G> {
         tensor: VirtualTensor<MP::EI>,
         x_offset: u32,
         y_offset: u32,
+        group: u32,
+        runtime_args: &RuntimeArgs,
+        #[comptime] config: G,
+    ) -> Self {
+        Self::new_with_ident(
+            tensor,
+            x_offset,
+            y_offset,
+            group,
+            runtime_args,
+            MatmulIdent::Lhs,
+            false,
+            config,
+        )
+    }
+
+    /// Create a loader for either side of the matmul. The im2col matrix is always read with the
+    /// spatial positions as rows, so for `Rhs` the rows are the `k` dimension. If `transposed`
+    /// is set, the tensor is read as the output gradient of the convolution.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_ident(
+        tensor: VirtualTensor<MP::EI>,
+        x_offset: u32,
+        y_offset: u32,
+        group: u32,
         runtime_args: &RuntimeArgs,
+        #[comptime] ident: MatmulIdent,
+        #[comptime] transposed: bool,
         #[comptime] config: G,
     ) -> Self {
         let stage = StageMemory::new::<G::StageMemoryConfig>(
             1u32,
-            StageIdent::Lhs,
+            comptime!(ident.into_stage()),
             config.stage_memory_config(),
         );
 
-        let shape_m = runtime_args.size_m;
-        let shape_k = runtime_args.size_k;
+        let mut shape_rows = runtime_args.size_m;
+        let mut shape_cols = runtime_args.size_k;
+        if comptime![ident == MatmulIdent::Rhs] {
+            shape_rows = runtime_args.size_k;
+            shape_cols = runtime_args.size_n;
+        }
 
         let tensor_view = Im2colReader::<MP::EI>::new(
             tensor,
             comptime![runtime_args.out_shape.clone()],
             x_offset,
             y_offset,
-            shape_k,
-            shape_m,
+            group,
+            shape_cols,
+            shape_rows,
+            transposed,
         );
 
         SimpleIm2colLoader::<MP, G> {
             tensor_view,
             stage,
+            ident,
             _config: PhantomData::<G>,
         }
     }
 
     pub fn advance_view(this: &mut Self, k_offset: u32) {
-        this.tensor_view.update_view(k_offset);
+        match comptime!(this.ident) {
+            MatmulIdent::Lhs => {
+                this.tensor_view.update_view(k_offset);
+            }
+            MatmulIdent::Rhs => {
+                this.tensor_view.m_offset += k_offset;
+            }
+            MatmulIdent::Out => comptime!(unreachable!()),
+        }
     }
 
     pub fn reader(
         this: &Self,
     ) -> FullStageToTileReader<MP::ES, ContiguousTilingLayout<RowMajorTilingOrder>> {
-        FullStageToTileReader::new(this.stage, StageIdent::Lhs)
+        FullStageToTileReader::new(this.stage, comptime!(this.ident.into_stage()))
     }
 
     pub fn fill_stage(this: &mut Self, #[comptime] config: G) {
-        let line_size = config.global_line_size(MatmulIdent::Lhs);
+        let line_size = comptime!(config.global_line_size(this.ident));
         SimpleIm2col::load_to_slice::<MP, G>(
             &this.tensor_view,
             &mut this.stage.as_slice_mut(line_size),
-            MatmulIdent::Lhs,
+            this.ident,
             config,
         );
     }
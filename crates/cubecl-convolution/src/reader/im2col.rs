@@ -13,6 +13,8 @@ pub struct Im2colReader<E: Numeric> {
     pub tensor: VirtualTensor<E>,
     pub m_offset: u32,
     pub k_offset: u32,
+    /// Offset of the first channel of the group being read.
+    pub channel_offset: u32,
 
     pub stride_batch: u32,
     pub strides_spatial: Sequence<u32>,
@@ -25,6 +27,12 @@ pub struct Im2colReader<E: Numeric> {
 
     pub shape_m: u32,
     pub shape_k: u32,
+
+    /// Read the output gradient of the convolution for the input gradient, instead of the input
+    /// for the output. `shape_out` is then the shape of the input gradient, and the tensor has
+    /// the shape of the output.
+    #[cube(comptime)]
+    pub transposed: bool,
 }
 
 #[cube]
@@ -35,8 +43,10 @@ impl<E: Numeric> Im2colReader<E> {
         shape_out: Sequence<FastDivmod>,
         x_offset: u32,
         y_offset: u32,
+        group: u32,
         shape_k: u32,
         shape_m: u32,
+        #[comptime] transposed: bool,
     ) -> Im2colReader<E> {
         let spatial_dims = comptime![shape_out.len()];
         let mut strides_spatial = Sequence::new();
@@ -51,12 +61,16 @@ impl<E: Numeric> Im2colReader<E> {
         let stride_batch = tensor.stride(0);
         let stride_channel = tensor.stride(spatial_dims + 1);
 
+        // The channel dimension only spans a single group, but the strides are still those of the
+        // full tensor
         let shape_channel = tensor.shape(spatial_dims + 1);
+        let channel_offset = group * shape_channel * stride_channel;
 
         Im2colReader::<E> {
             tensor,
             m_offset: x_offset,
             k_offset: y_offset,
+            channel_offset,
             stride_batch,
             strides_spatial,
             stride_channel,
@@ -65,6 +79,7 @@ impl<E: Numeric> Im2colReader<E> {
             shape_out,
             shape_m,
             shape_k,
+            transposed,
         }
     }
 }
@@ -117,6 +132,7 @@ impl<E: Numeric> Im2colReader<E> {
 
         let spatial_dims = comptime![self.shapes_spatial.len()];
         let mut in_pos = Sequence::<i32>::new();
+        let mut spatial_in_bounds = true;
 
         #[unroll]
         for i in 0..spatial_dims {
@@ -131,8 +147,19 @@ impl<E: Numeric> Im2colReader<E> {
             let dilate = comptime![config.dilation(dim)];
             let pad = comptime![config.padding(dim)];
 
-            let pos = (out_pos * stride + k_pos * dilate) as i32 - pad;
-            in_pos.push(pos);
+            if comptime![self.transposed] {
+                // Find the output position this kernel position was applied at, if any
+                let stride = comptime![stride as i32];
+                let pos = out_pos as i32 + pad - (k_pos * dilate) as i32;
+                let pos_strided = pos / stride;
+                spatial_in_bounds &= pos >= 0
+                    && pos_strided * stride == pos
+                    && (pos_strided as u32) < *self.shapes_spatial.index(dim);
+                in_pos.push(pos_strided);
+            } else {
+                let pos = (out_pos * stride + k_pos * dilate) as i32 - pad;
+                in_pos.push(pos);
+            }
         }
 
         let in_pos = in_pos.rev();
@@ -145,13 +172,10 @@ impl<E: Numeric> Im2colReader<E> {
             has_padding
         };
 
-        let m_in_bounds =
-            comptime!(!config.check_row_bounds(MatmulIdent::Lhs)) || view_m < self.shape_m;
-        let k_in_bounds =
-            comptime!(!config.check_col_bounds(MatmulIdent::Lhs)) || view_k < self.shape_k;
-        let mut spatial_in_bounds = true;
+        let m_in_bounds = comptime!(!config.check_row_bounds(ident)) || view_m < self.shape_m;
+        let k_in_bounds = comptime!(!config.check_col_bounds(ident)) || view_k < self.shape_k;
 
-        if has_padding {
+        if comptime![has_padding && !self.transposed] {
             #[unroll]
             for i in 0..spatial_dims {
                 let i = unwrap(i);
@@ -162,7 +186,8 @@ impl<E: Numeric> Im2colReader<E> {
 
         let in_bounds = m_in_bounds && k_in_bounds && spatial_in_bounds;
 
-        let mut read_pos = batch * self.stride_batch + channel * self.stride_channel;
+        let mut read_pos =
+            batch * self.stride_batch + channel * self.stride_channel + self.channel_offset;
 
         #[unroll]
         for i in 0..spatial_dims {
@@ -100,10 +100,13 @@ pub fn convolution_matmul_selection<TMM: TileMatmulFamily, R: Runtime>(
         .unwrap_or(NUM_TENSOR_CORES_APPROX);
     let max_tensor_cores = hardware.num_tensor_cores.unwrap_or(NUM_SM_APPROX);
 
+    // Groups are dispatched along the z axis, so each group only needs to fill its share of SMs
+    let num_sm = (num_sm as usize).div_ceil(problem.groups);
+
     let (stage_size_m, stage_size_n) = find_stage_size_m_n(
         problem.m,
         problem.n,
-        num_sm as usize,
+        num_sm,
         max_tensor_cores as usize,
         tile_size.m() as usize,
         tile_size.n() as usize,
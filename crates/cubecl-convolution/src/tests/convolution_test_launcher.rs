@@ -6,15 +6,20 @@ use cubecl_matmul::components::MatmulSelection;
 use cubecl_matmul::components::global::GlobalConfig;
 
 use crate::ConvGemmConfig;
+use crate::ConvolutionArgs;
 use crate::algorithm::Algorithm;
 use crate::args::ConvInputsLaunch;
+use crate::backward::{launch_conv_backward_data, launch_conv_backward_weight};
 use crate::base::ConvolutionLaunch;
 use crate::base::ConvolutionProblem;
+use crate::depthwise::launch_depthwise;
 use cubecl_matmul::components::global::args::{ConcreteOutputFactory, MatmulArgs};
 use cubecl_matmul::tests::layered::matmul_test_launcher::TensorRawParts;
 use cubecl_matmul::tests::test_utils::Sample;
 
-use super::test_utils::TestPrecision;
+use super::test_utils::{
+    TestPrecision, conv_backward_data_cpu_reference, conv_backward_weight_cpu_reference,
+};
 
 type Input<Args, EI> = <Args as MatmulArgs>::Input<EI>;
 type Output<Args, EO> = <Args as MatmulArgs>::Output<EO>;
@@ -33,16 +38,8 @@ pub fn test_convolution_algorithm<A, Args, P, R>(
     Args::Input<P::EG>: ConvInputsLaunch,
     Args::Output<P::EG>: ConcreteOutputFactory,
 {
-    let env = std::env::var("MATMUL_TEST_MODE");
+    let panic_on_launch_err = panic_on_launch_err();
 
-    let panic_on_launch_err = match env {
-        Ok(val) => match val.as_str() {
-            "panic" => true,
-            "skip" => false,
-            _ => false,
-        },
-        Err(_) => false,
-    };
     let lhs = tensor_raw_parts::<P, R>(&client, &problem, MatmulIdent::Lhs);
     let rhs = tensor_raw_parts::<P, R>(&client, &problem, MatmulIdent::Rhs);
     let out = tensor_raw_parts::<P, R>(&client, &problem, MatmulIdent::Out);
@@ -54,7 +51,7 @@ pub fn test_convolution_algorithm<A, Args, P, R>(
     }
     .filter_lhs_with_tensor(&lhs.strides, &lhs.shape, problem.lhs_layout)
     .filter_rhs_with_tensor(&rhs.strides, &rhs.shape, problem.rhs_layout)
-    .filter_out_with_tensor(&out.strides, &out.shape)
+    .filter_out_with_tensor(&out.strides, &[problem.m, problem.n])
     .pick_max()
     .unwrap();
 
@@ -80,9 +77,6 @@ pub fn test_convolution_algorithm<A, Args, P, R>(
     let rhs_handle = unsafe {
         TensorHandleRef::from_raw_parts(&rhs.handle, &rhs.strides, &rhs.shape, elem_size)
     };
-    let out_handle = unsafe {
-        TensorHandleRef::from_raw_parts(&out.handle, &out.strides, &out.shape, elem_size)
-    };
 
     let lhs_handle = A::into_tensor_handle::<R, P::EG>(&client, &lhs_handle, MatmulIdent::Lhs);
     let rhs_handle = A::into_tensor_handle::<R, P::EG>(&client, &rhs_handle, MatmulIdent::Rhs);
@@ -90,6 +84,24 @@ pub fn test_convolution_algorithm<A, Args, P, R>(
     let lhs_handle = lhs_handle.as_ref();
     let rhs_handle = rhs_handle.as_ref();
 
+    // Only expose the channels of a single group, and split the output into groups
+    let mut lhs_shape = lhs_handle.shape.to_vec();
+    lhs_shape[3] = problem.channels_per_group();
+    let lhs_handle = unsafe {
+        TensorHandleRef::from_raw_parts(
+            lhs_handle.handle,
+            lhs_handle.strides,
+            &lhs_shape,
+            elem_size,
+        )
+    };
+
+    let out_shape = [problem.groups, problem.m, problem.n];
+    let out_strides = [problem.n * out.strides[1], out.strides[0], out.strides[1]];
+    let out_handle = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(&out.handle, &out_strides, &out_shape, elem_size)
+    };
+
     let inputs = <Input<Args, P::EG> as ConvInputsLaunch>::create(
         &lhs_handle,
         &rhs_handle,
@@ -114,7 +126,8 @@ pub fn test_convolution_algorithm<A, Args, P, R>(
             output,
             &problem,
             config,
-        );
+        )
+        .unwrap();
     }
 
     P::assert_result::<R>(
@@ -128,75 +141,301 @@ pub fn test_convolution_algorithm<A, Args, P, R>(
     );
 }
 
+/// Test the correctness of the direct depthwise kernel on the given device,
+/// against a naive CPU implementation over the given problem
+pub fn test_depthwise_algorithm<P, R>(
+    client: ComputeClient<R::Server, R::Channel>,
+    problem: ConvolutionProblem,
+) where
+    P: TestPrecision,
+    R: Runtime,
+{
+    let lhs = tensor_raw_parts::<P, R>(&client, &problem, MatmulIdent::Lhs);
+    let rhs = tensor_raw_parts::<P, R>(&client, &problem, MatmulIdent::Rhs);
+    let out = tensor_raw_parts::<P, R>(&client, &problem, MatmulIdent::Out);
+
+    let elem_size = size_of::<P::EG>();
+    let lhs_handle = unsafe {
+        TensorHandleRef::from_raw_parts(&lhs.handle, &lhs.strides, &lhs.shape, elem_size)
+    };
+    let rhs_handle = unsafe {
+        TensorHandleRef::from_raw_parts(&rhs.handle, &rhs.strides, &rhs.shape, elem_size)
+    };
+
+    // The output is contiguous, so it can be viewed as [batches, out_h, out_w, out_c]
+    let out_channels = problem.n * problem.groups;
+    let out_shape = [
+        problem.batches,
+        problem.out_shape[0],
+        problem.out_shape[1],
+        out_channels,
+    ];
+    let out_strides = [
+        problem.out_shape[0] * problem.out_shape[1] * out_channels,
+        problem.out_shape[1] * out_channels,
+        out_channels,
+        1,
+    ];
+    let out_handle = unsafe {
+        TensorHandleRef::from_raw_parts(&out.handle, &out_strides, &out_shape, elem_size)
+    };
+
+    let stride = problem
+        .stride
+        .iter()
+        .map(|it| *it as usize)
+        .collect::<Vec<_>>();
+    let padding = problem
+        .padding
+        .iter()
+        .map(|it| *it as usize)
+        .collect::<Vec<_>>();
+    let dilation = problem
+        .dilation
+        .iter()
+        .map(|it| *it as usize)
+        .collect::<Vec<_>>();
+
+    launch_depthwise::<R, P::EG, P::EA, P::EG>(
+        &client,
+        &lhs_handle,
+        &rhs_handle,
+        &None,
+        &out_handle,
+        (&stride, &padding, &dilation),
+        problem.dimensionality,
+    )
+    .unwrap();
+
+    P::assert_result::<R>(
+        &lhs.original_data.unwrap(),
+        &rhs.original_data.unwrap(),
+        &problem,
+        &client,
+        out.handle,
+        &out.shape,
+        &out.strides,
+    );
+}
+
+/// Test the correctness of the backward data convolution of the specified algorithm on the given
+/// device, against a naive CPU implementation over the given forward problem
+pub fn test_backward_data_algorithm<A, P, R>(
+    client: ComputeClient<R::Server, R::Channel>,
+    problem: ConvolutionProblem,
+) where
+    A: Algorithm,
+    P: TestPrecision,
+    R: Runtime,
+{
+    let panic_on_launch_err = panic_on_launch_err();
+
+    let out_grad = sample_raw_parts::<P, R>(&client, out_shape(&problem));
+    let weight = tensor_raw_parts::<P, R>(&client, &problem, MatmulIdent::Rhs);
+    let in_grad = zeros_raw_parts::<P, R>(&client, shape(&problem, MatmulIdent::Lhs));
+
+    let elem_size = size_of::<P::EG>();
+    let out_grad_handle = unsafe {
+        TensorHandleRef::from_raw_parts(
+            &out_grad.handle,
+            &out_grad.strides,
+            &out_grad.shape,
+            elem_size,
+        )
+    };
+    let weight_handle = unsafe {
+        TensorHandleRef::from_raw_parts(&weight.handle, &weight.strides, &weight.shape, elem_size)
+    };
+    let in_grad_handle = unsafe {
+        TensorHandleRef::from_raw_parts(
+            &in_grad.handle,
+            &in_grad.strides,
+            &in_grad.shape,
+            elem_size,
+        )
+    };
+
+    let result = launch_conv_backward_data::<R, (P::EG, P::ES, P::EA, P::EG), A, 2>(
+        &client,
+        &out_grad_handle,
+        &weight_handle,
+        &in_grad_handle,
+        convolution_args(&problem),
+    );
+    if let Err(err) = result {
+        let msg = format!("Can't launch the test: {err:?}");
+        if panic_on_launch_err {
+            panic!("{msg}");
+        } else {
+            println!("{msg}");
+            return;
+        }
+    }
+
+    let expected = conv_backward_data_cpu_reference::<P>(
+        &out_grad.original_data.unwrap(),
+        &weight.original_data.unwrap(),
+        &problem,
+    );
+    P::assert_expected::<R>(
+        expected,
+        &client,
+        in_grad.handle,
+        &in_grad.shape,
+        &in_grad.strides,
+    );
+}
+
+/// Test the correctness of the backward weight convolution of the specified algorithm on the
+/// given device, against a naive CPU implementation over the given forward problem
+pub fn test_backward_weight_algorithm<A, P, R>(
+    client: ComputeClient<R::Server, R::Channel>,
+    problem: ConvolutionProblem,
+) where
+    A: Algorithm,
+    P: TestPrecision,
+    R: Runtime,
+{
+    let panic_on_launch_err = panic_on_launch_err();
+
+    let input = tensor_raw_parts::<P, R>(&client, &problem, MatmulIdent::Lhs);
+    let out_grad = sample_raw_parts::<P, R>(&client, out_shape(&problem));
+    let weight_grad = zeros_raw_parts::<P, R>(&client, shape(&problem, MatmulIdent::Rhs));
+
+    let elem_size = size_of::<P::EG>();
+    let input_handle = unsafe {
+        TensorHandleRef::from_raw_parts(&input.handle, &input.strides, &input.shape, elem_size)
+    };
+    let out_grad_handle = unsafe {
+        TensorHandleRef::from_raw_parts(
+            &out_grad.handle,
+            &out_grad.strides,
+            &out_grad.shape,
+            elem_size,
+        )
+    };
+    let weight_grad_handle = unsafe {
+        TensorHandleRef::from_raw_parts(
+            &weight_grad.handle,
+            &weight_grad.strides,
+            &weight_grad.shape,
+            elem_size,
+        )
+    };
+
+    let result = launch_conv_backward_weight::<R, (P::EG, P::ES, P::EA, P::EG), A, 2>(
+        &client,
+        &input_handle,
+        &out_grad_handle,
+        &weight_grad_handle,
+        convolution_args(&problem),
+    );
+    if let Err(err) = result {
+        let msg = format!("Can't launch the test: {err:?}");
+        if panic_on_launch_err {
+            panic!("{msg}");
+        } else {
+            println!("{msg}");
+            return;
+        }
+    }
+
+    let expected = conv_backward_weight_cpu_reference::<P>(
+        &input.original_data.unwrap(),
+        &out_grad.original_data.unwrap(),
+        &problem,
+    );
+    P::assert_expected::<R>(
+        expected,
+        &client,
+        weight_grad.handle,
+        &weight_grad.shape,
+        &weight_grad.strides,
+    );
+}
+
+/// The arguments of the forward 2D convolution described by `problem`
+fn convolution_args(problem: &ConvolutionProblem) -> ConvolutionArgs<2> {
+    ConvolutionArgs {
+        stride: [problem.stride[0] as usize, problem.stride[1] as usize],
+        padding: [problem.padding[0] as usize, problem.padding[1] as usize],
+        dilation: [problem.dilation[0] as usize, problem.dilation[1] as usize],
+        groups: problem.groups,
+    }
+}
+
+fn panic_on_launch_err() -> bool {
+    match std::env::var("MATMUL_TEST_MODE") {
+        Ok(val) => match val.as_str() {
+            "panic" => true,
+            "skip" => false,
+            _ => false,
+        },
+        Err(_) => false,
+    }
+}
+
+/// The shape of the output feature map, `[batches, out_h, out_w, out_channels]`
+fn out_shape(problem: &ConvolutionProblem) -> Vec<usize> {
+    vec![
+        problem.batches,
+        problem.out_shape[0],
+        problem.out_shape[1],
+        problem.n * problem.groups,
+    ]
+}
+
 fn tensor_raw_parts<P: TestPrecision, R: Runtime>(
     client: &ComputeClient<R::Server, R::Channel>,
     problem: &ConvolutionProblem,
     ident: MatmulIdent,
 ) -> TensorRawParts<P::EG> {
     match ident {
-        MatmulIdent::Lhs => {
-            let shape = shape(problem, ident);
-
-            let handle = P::EG::sample::<R>(client, &shape, 1234);
-
-            let data = client.read_one(handle.handle.clone().binding());
-            let data = P::EG::from_bytes(&data);
-            let original_data = data.to_owned();
-
-            TensorRawParts {
-                handle: handle.handle,
-                scale: None,
-                shape,
-                strides: handle.strides,
-                original_data: Some(original_data),
-                quant_params: None,
-            }
-        }
-        MatmulIdent::Rhs => {
-            let shape = shape(problem, ident);
-
-            let handle = P::EG::sample::<R>(client, &shape, 1234);
-
-            let data = client.read_one(handle.handle.clone().binding());
-            let data = P::EG::from_bytes(&data);
-            let original_data = data.to_owned();
-
-            TensorRawParts {
-                handle: handle.handle,
-                scale: None,
-                shape,
-                strides: handle.strides,
-                original_data: Some(original_data),
-                quant_params: None,
-            }
-        }
-        MatmulIdent::Out => {
-            let zero = P::EG::from_int(0);
-
-            let data = vec![zero; tensor_size(problem, MatmulIdent::Out)];
-
-            let shape = shape(problem, MatmulIdent::Out);
-            let (handle, strides) =
-                client.create_tensor(P::EG::as_bytes(&data), &shape, size_of::<P::EG>());
-
-            TensorRawParts {
-                handle,
-                scale: None,
-                shape,
-                strides,
-                original_data: None,
-                quant_params: None,
-            }
+        MatmulIdent::Lhs | MatmulIdent::Rhs => {
+            sample_raw_parts::<P, R>(client, shape(problem, ident))
         }
+        MatmulIdent::Out => zeros_raw_parts::<P, R>(client, shape(problem, ident)),
     }
 }
 
-/// Returns the total number of elements for the identified tensor, inferred by the problem definition
-pub(crate) fn tensor_size(problem: &ConvolutionProblem, ident: MatmulIdent) -> usize {
-    match ident {
-        MatmulIdent::Lhs => problem.m * problem.k,
-        MatmulIdent::Rhs => problem.k * problem.n,
-        MatmulIdent::Out => problem.m * problem.n,
+fn sample_raw_parts<P: TestPrecision, R: Runtime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    shape: Vec<usize>,
+) -> TensorRawParts<P::EG> {
+    let handle = P::EG::sample::<R>(client, &shape, 1234);
+
+    let data = client.read_one(handle.handle.clone().binding());
+    let data = P::EG::from_bytes(&data);
+    let original_data = data.to_owned();
+
+    TensorRawParts {
+        handle: handle.handle,
+        scale: None,
+        shape,
+        strides: handle.strides,
+        original_data: Some(original_data),
+        quant_params: None,
+    }
+}
+
+fn zeros_raw_parts<P: TestPrecision, R: Runtime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    shape: Vec<usize>,
+) -> TensorRawParts<P::EG> {
+    let zero = P::EG::from_int(0);
+
+    let data = vec![zero; shape.iter().product()];
+
+    let (handle, strides) =
+        client.create_tensor(P::EG::as_bytes(&data), &shape, size_of::<P::EG>());
+
+    TensorRawParts {
+        handle,
+        scale: None,
+        shape,
+        strides,
+        original_data: None,
+        quant_params: None,
     }
 }
 
@@ -210,14 +449,14 @@ pub(crate) fn shape(problem: &ConvolutionProblem, ident: MatmulIdent) -> Vec<usi
             problem.channels,
         ],
         MatmulIdent::Rhs => vec![
-            problem.n,
+            problem.n * problem.groups,
             problem.kernel_size[0] as usize,
             problem.kernel_size[1] as usize,
-            problem.channels,
+            problem.channels_per_group(),
         ],
         MatmulIdent::Out => vec![
             problem.batches * problem.out_shape.iter().product::<usize>(),
-            problem.n,
+            problem.n * problem.groups,
         ],
     }
 }
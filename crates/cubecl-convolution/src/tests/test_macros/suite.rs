@@ -1,5 +1,8 @@
 use crate::base::ConvolutionProblem;
-use crate::tests::convolution_test_launcher::test_convolution_algorithm;
+use crate::tests::convolution_test_launcher::{
+    test_backward_data_algorithm, test_backward_weight_algorithm, test_convolution_algorithm,
+    test_depthwise_algorithm,
+};
 use crate::{
     algorithm::Algorithm, args::ConvInputsLaunch, base::Dimensionality,
     tests::test_utils::TestPrecision,
@@ -19,6 +22,7 @@ pub struct ConvolutionSize {
     pub c: usize,
 
     pub out_c: usize,
+    pub groups: usize,
 }
 
 pub fn test_algo<A: Algorithm, Args: MatmulArgs, P: TestPrecision, R: Runtime>(
@@ -33,6 +37,49 @@ pub fn test_algo<A: Algorithm, Args: MatmulArgs, P: TestPrecision, R: Runtime>(
     let client = R::client(&Default::default());
     let plane_dim = client.properties().hardware.plane_size_max;
 
+    let problem = conv2d_problem(problem);
+    let selection = selection(tile_size, partition_size, stage_size, plane_dim);
+
+    test_convolution_algorithm::<A, Args, P, R>(client, problem, selection);
+}
+
+/// Test the backward data convolution of `A` through its public entry point.
+pub fn test_backward_data<A: Algorithm, P: TestPrecision, R: Runtime>(problem: ConvolutionSize) {
+    let client = R::client(&Default::default());
+    test_backward_data_algorithm::<A, P, R>(client, conv2d_problem(problem));
+}
+
+/// Test the backward weight convolution of `A` through its public entry point.
+pub fn test_backward_weight<A: Algorithm, P: TestPrecision, R: Runtime>(problem: ConvolutionSize) {
+    let client = R::client(&Default::default());
+    test_backward_weight_algorithm::<A, P, R>(client, conv2d_problem(problem));
+}
+
+fn selection(
+    tile_size: TileSize,
+    partition_size: PartitionSize,
+    stage_size: StageSize,
+    plane_dim: u32,
+) -> MatmulSelection {
+    let tiling_scheme = TilingScheme::builder()
+        .with_tile_size(tile_size)
+        .with_partition_size(partition_size)
+        .with_stage_size(stage_size)
+        .build()
+        .unwrap();
+
+    MatmulSelection::builder(tiling_scheme, plane_dim)
+        .partition_buffering(PartitionBuffering::Single)
+        .build()
+}
+
+/// Test the direct depthwise kernel, `problem.groups` should be equal to `problem.c`.
+pub fn test_depthwise<P: TestPrecision, R: Runtime>(problem: ConvolutionSize) {
+    let client = R::client(&Default::default());
+    test_depthwise_algorithm::<P, R>(client, conv2d_problem(problem));
+}
+
+fn conv2d_problem(problem: ConvolutionSize) -> ConvolutionProblem {
     // TODO: Automate more params
     let batches = 2;
     let kernel_size = vec![4, 3];
@@ -55,10 +102,10 @@ pub fn test_algo<A: Algorithm, Args: MatmulArgs, P: TestPrecision, R: Runtime>(
         problem.w,
     );
 
-    let problem = ConvolutionProblem {
+    ConvolutionProblem {
         m: batches * out_h * out_w,
-        n: problem.out_c,
-        k: kernel_size.iter().product::<u32>() as usize * problem.c,
+        n: problem.out_c / problem.groups,
+        k: kernel_size.iter().product::<u32>() as usize * problem.c / problem.groups,
         lhs_layout: MatrixLayout::RowMajor,
         rhs_layout: MatrixLayout::ColMajor,
         kernel_size,
@@ -68,22 +115,10 @@ pub fn test_algo<A: Algorithm, Args: MatmulArgs, P: TestPrecision, R: Runtime>(
         batches,
         shape: vec![problem.h, problem.w],
         channels: problem.c,
+        groups: problem.groups,
         out_shape: vec![out_h, out_w],
         dimensionality: Dimensionality::Dim2,
-    };
-
-    let tiling_scheme = TilingScheme::builder()
-        .with_tile_size(tile_size)
-        .with_partition_size(partition_size)
-        .with_stage_size(stage_size)
-        .build()
-        .unwrap();
-
-    let selection = MatmulSelection::builder(tiling_scheme, plane_dim)
-        .partition_buffering(PartitionBuffering::Single)
-        .build();
-
-    test_convolution_algorithm::<A, Args, P, R>(client, problem, selection);
+    }
 }
 
 /// Calculate the expected output size when doing a convolution operation.
@@ -138,6 +173,136 @@ macro_rules! conv2d_standard_tests {
             use super::*;
             $crate::conv2d_standard_tests!(TileSize { m: 16, n: 16, k: 8 });
         }
+
+        mod depthwise {
+            use super::*;
+
+            #[test]
+            pub fn g16x16x8x8() {
+                cubecl_convolution::tests::test_depthwise::<Precision, TestRuntime>(
+                    ConvolutionSize {
+                        h: 16,
+                        w: 16,
+                        c: 8,
+                        out_c: 8,
+                        groups: 8,
+                    },
+                );
+            }
+
+            #[test]
+            pub fn g23x10x17x34() {
+                cubecl_convolution::tests::test_depthwise::<Precision, TestRuntime>(
+                    ConvolutionSize {
+                        h: 23,
+                        w: 10,
+                        c: 17,
+                        out_c: 34,
+                        groups: 17,
+                    },
+                );
+            }
+        }
+
+        mod backward {
+            use super::*;
+
+            $crate::conv2d_standard_tests!(
+                backward: g4x4x1x1,
+                ConvolutionSize {
+                    h: 4,
+                    w: 4,
+                    c: 1,
+                    out_c: 1,
+                    groups: 1
+                }
+            );
+            $crate::conv2d_standard_tests!(
+                backward: g16x16x16x32,
+                ConvolutionSize {
+                    h: 16,
+                    w: 16,
+                    c: 16,
+                    out_c: 32,
+                    groups: 1
+                }
+            );
+            $crate::conv2d_standard_tests!(
+                backward: g23x10x17x20,
+                ConvolutionSize {
+                    h: 23,
+                    w: 10,
+                    c: 17,
+                    out_c: 20,
+                    groups: 1
+                }
+            );
+            $crate::conv2d_standard_tests!(
+                backward: g32x32x64x3,
+                ConvolutionSize {
+                    h: 32,
+                    w: 32,
+                    c: 64,
+                    out_c: 3,
+                    groups: 1
+                }
+            );
+            $crate::conv2d_standard_tests!(
+                backward: g16x16x16x32x4,
+                ConvolutionSize {
+                    h: 16,
+                    w: 16,
+                    c: 16,
+                    out_c: 32,
+                    groups: 4
+                }
+            );
+            $crate::conv2d_standard_tests!(
+                backward: g23x10x9x6x3,
+                ConvolutionSize {
+                    h: 23,
+                    w: 10,
+                    c: 9,
+                    out_c: 6,
+                    groups: 3
+                }
+            );
+            $crate::conv2d_standard_tests!(
+                backward: g16x16x8x16x8,
+                ConvolutionSize {
+                    h: 16,
+                    w: 16,
+                    c: 8,
+                    out_c: 16,
+                    groups: 8
+                }
+            );
+        }
+    };
+
+    (backward: $name:ident, $problem:expr) => {
+        mod $name {
+            use super::*;
+            use $crate::algorithm::simple::SimpleConvAlgorithm;
+
+            #[test]
+            pub fn simple_backward_data() {
+                cubecl_convolution::tests::test_backward_data::<
+                    SimpleConvAlgorithm<TMM>,
+                    Precision,
+                    TestRuntime,
+                >($problem);
+            }
+
+            #[test]
+            pub fn simple_backward_weight() {
+                cubecl_convolution::tests::test_backward_weight::<
+                    SimpleConvAlgorithm<TMM>,
+                    Precision,
+                    TestRuntime,
+                >($problem);
+            }
+        }
     };
 
     ($tile:expr) => {
@@ -195,7 +360,8 @@ macro_rules! conv2d_standard_tests {
                     h: 4,
                     w: 4,
                     c: 1,
-                    out_c: 1
+                    out_c: 1,
+                    groups: 1
                 }
             );
         }
@@ -210,7 +376,8 @@ macro_rules! conv2d_standard_tests {
                     h: 17,
                     w: 17,
                     c: 1,
-                    out_c: 1
+                    out_c: 1,
+                    groups: 1
                 }
             );
         }
@@ -225,7 +392,8 @@ macro_rules! conv2d_standard_tests {
                     h: 16,
                     w: 16,
                     c: 16,
-                    out_c: 32
+                    out_c: 32,
+                    groups: 1
                 }
             );
         }
@@ -240,7 +408,8 @@ macro_rules! conv2d_standard_tests {
                     h: 32,
                     w: 32,
                     c: 32,
-                    out_c: 16
+                    out_c: 16,
+                    groups: 1
                 }
             );
         }
@@ -255,7 +424,8 @@ macro_rules! conv2d_standard_tests {
                     h: 64,
                     w: 32,
                     c: 32,
-                    out_c: 128
+                    out_c: 128,
+                    groups: 1
                 }
             );
         }
@@ -270,7 +440,8 @@ macro_rules! conv2d_standard_tests {
                     h: 32,
                     w: 32,
                     c: 64,
-                    out_c: 3
+                    out_c: 3,
+                    groups: 1
                 }
             );
         }
@@ -285,7 +456,8 @@ macro_rules! conv2d_standard_tests {
                     h: 100,
                     w: 100,
                     c: 100,
-                    out_c: 100
+                    out_c: 100,
+                    groups: 1
                 }
             );
         }
@@ -300,7 +472,8 @@ macro_rules! conv2d_standard_tests {
                     h: 20,
                     w: 20,
                     c: 16,
-                    out_c: 32
+                    out_c: 32,
+                    groups: 1
                 }
             );
         }
@@ -315,7 +488,40 @@ macro_rules! conv2d_standard_tests {
                     h: 23,
                     w: 10,
                     c: 17,
-                    out_c: 20
+                    out_c: 20,
+                    groups: 1
+                }
+            );
+        }
+
+        mod g16x16x16x32x4 {
+            use super::*;
+            $crate::conv2d_standard_tests!(
+                $tile,
+                $partition,
+                $stage,
+                ConvolutionSize {
+                    h: 16,
+                    w: 16,
+                    c: 16,
+                    out_c: 32,
+                    groups: 4
+                }
+            );
+        }
+
+        mod g20x20x12x18x3 {
+            use super::*;
+            $crate::conv2d_standard_tests!(
+                $tile,
+                $partition,
+                $stage,
+                ConvolutionSize {
+                    h: 20,
+                    w: 20,
+                    c: 12,
+                    out_c: 18,
+                    groups: 3
                 }
             );
         }
@@ -356,5 +562,6 @@ macro_rules! conv2d_standard_tests {
                 TestRuntime,
             >($tile, $partition, $stage, $problem);
         }
+
     };
 }
@@ -24,6 +24,15 @@ pub trait TestPrecision {
         shape: &[usize],
         strides: &[usize],
     );
+
+    /// Compare the output to an expected result computed on the CPU.
+    fn assert_expected<R: Runtime>(
+        expected: Vec<Self::EA>,
+        client: &ComputeClient<R::Server, R::Channel>,
+        out: server::Handle,
+        shape: &[usize],
+        strides: &[usize],
+    );
 }
 
 impl<EG, ES> TestPrecision for (EG, ES)
@@ -44,6 +53,17 @@ where
         out: server::Handle,
         shape: &[usize],
         strides: &[usize],
+    ) {
+        let expected = conv_cpu_reference::<Self>(lhs, rhs, problem);
+        Self::assert_expected::<R>(expected, client, out, shape, strides);
+    }
+
+    fn assert_expected<R: Runtime>(
+        expected: Vec<f32>,
+        client: &ComputeClient<R::Server, R::Channel>,
+        out: server::Handle,
+        shape: &[usize],
+        strides: &[usize],
     ) {
         let maybe_f16 = client.properties().feature_enabled(Feature::Cmma {
             a: ES::as_elem_native().expect("To be a native type"),
@@ -68,7 +88,7 @@ where
             false => 10e-6,
         };
 
-        let expected = conv_cpu_reference::<Self>(lhs, rhs, problem)
+        let expected = expected
             .into_iter()
             .map(|x| x.cast_into())
             .collect::<Vec<EG>>();
@@ -136,10 +156,11 @@ where
     let h = problem.shape[0];
     let w = problem.shape[1];
     let c = problem.channels;
+    let c_per_group = problem.channels_per_group();
 
     let out_h = problem.out_shape[0];
     let out_w = problem.out_shape[1];
-    let out_channels = problem.n;
+    let out_channels = problem.n * problem.groups;
 
     let kh = problem.kernel_size[0] as usize;
     let kw = problem.kernel_size[1] as usize;
@@ -153,9 +174,9 @@ where
     let lhs_stride_w = c;
     let lhs_stride_c = 1;
 
-    let rhs_stride_out_c = kh * kw * c;
-    let rhs_stride_kh = kw * c;
-    let rhs_stride_kw = c;
+    let rhs_stride_out_c = kh * kw * c_per_group;
+    let rhs_stride_kh = kw * c_per_group;
+    let rhs_stride_kw = c_per_group;
     let rhs_stride_in_c = 1;
 
     let out_stride_n = out_h * out_w * out_channels;
@@ -178,10 +199,11 @@ where
                 for out_c in 0..out_channels {
                     let out_pos = out_offset + out_c * out_stride_c;
                     let weight_offset = out_c * rhs_stride_out_c;
+                    let group = out_c / problem.n;
 
                     let mut acc = P::EA::from_int(0);
-                    for in_c in 0..c {
-                        let in_offset = batch_in + in_c * lhs_stride_c;
+                    for in_c in 0..c_per_group {
+                        let in_offset = batch_in + (group * c_per_group + in_c) * lhs_stride_c;
                         let weight_offset = weight_offset + in_c * rhs_stride_in_c;
 
                         for ky in 0..kh {
@@ -217,3 +239,135 @@ where
 
     out
 }
+
+/// Computes the gradient of a convolution with respect to its input, given the gradient of its
+/// output and its weights.
+///
+/// This is a naive CPU implementation, very slow on large payloads,
+/// not designed to be used for other purposes than testing.
+pub(crate) fn conv_backward_data_cpu_reference<P: TestPrecision>(
+    out_grad: &[P::EG],
+    weight: &[P::EG],
+    problem: &ConvolutionProblem,
+) -> Vec<P::EA> {
+    let n = problem.batches;
+    let h = problem.shape[0];
+    let w = problem.shape[1];
+    let c = problem.channels;
+    let c_per_group = problem.channels_per_group();
+
+    let out_h = problem.out_shape[0];
+    let out_w = problem.out_shape[1];
+    let out_channels = problem.n * problem.groups;
+
+    let kh = problem.kernel_size[0] as usize;
+    let kw = problem.kernel_size[1] as usize;
+
+    let padding = &problem.padding;
+    let stride = &problem.stride;
+    let dilation = &problem.dilation;
+
+    let mut in_grad = vec![P::EA::from_int(0); n * h * w * c];
+
+    for nth_batch in 0..n {
+        for out_y in 0..out_h {
+            for out_x in 0..out_w {
+                let out_pos = ((nth_batch * out_h + out_y) * out_w + out_x) * out_channels;
+
+                for ky in 0..kh {
+                    let in_y = out_y as i32 * stride[0] as i32 + ky as i32 * dilation[0] as i32
+                        - padding[0];
+                    for kx in 0..kw {
+                        let in_x = out_x as i32 * stride[1] as i32 + kx as i32 * dilation[1] as i32
+                            - padding[1];
+
+                        if in_y < 0 || in_y >= h as i32 || in_x < 0 || in_x >= w as i32 {
+                            continue;
+                        }
+
+                        let in_pos = ((nth_batch * h + in_y as usize) * w + in_x as usize) * c;
+
+                        for out_c in 0..out_channels {
+                            let grad: P::ES = out_grad[out_pos + out_c].cast_into();
+                            let weight_pos = ((out_c * kh + ky) * kw + kx) * c_per_group;
+                            let in_pos = in_pos + out_c / problem.n * c_per_group;
+
+                            for in_c in 0..c_per_group {
+                                let weight: P::ES = weight[weight_pos + in_c].cast_into();
+                                in_grad[in_pos + in_c] += (grad * weight).cast_into();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    in_grad
+}
+
+/// Computes the gradient of a convolution with respect to its weights, given its input and the
+/// gradient of its output.
+///
+/// This is a naive CPU implementation, very slow on large payloads,
+/// not designed to be used for other purposes than testing.
+pub(crate) fn conv_backward_weight_cpu_reference<P: TestPrecision>(
+    input: &[P::EG],
+    out_grad: &[P::EG],
+    problem: &ConvolutionProblem,
+) -> Vec<P::EA> {
+    let n = problem.batches;
+    let h = problem.shape[0];
+    let w = problem.shape[1];
+    let c = problem.channels;
+    let c_per_group = problem.channels_per_group();
+
+    let out_h = problem.out_shape[0];
+    let out_w = problem.out_shape[1];
+    let out_channels = problem.n * problem.groups;
+
+    let kh = problem.kernel_size[0] as usize;
+    let kw = problem.kernel_size[1] as usize;
+
+    let padding = &problem.padding;
+    let stride = &problem.stride;
+    let dilation = &problem.dilation;
+
+    let mut weight_grad = vec![P::EA::from_int(0); out_channels * kh * kw * c_per_group];
+
+    for nth_batch in 0..n {
+        for out_y in 0..out_h {
+            for out_x in 0..out_w {
+                let out_pos = ((nth_batch * out_h + out_y) * out_w + out_x) * out_channels;
+
+                for ky in 0..kh {
+                    let in_y = out_y as i32 * stride[0] as i32 + ky as i32 * dilation[0] as i32
+                        - padding[0];
+                    for kx in 0..kw {
+                        let in_x = out_x as i32 * stride[1] as i32 + kx as i32 * dilation[1] as i32
+                            - padding[1];
+
+                        if in_y < 0 || in_y >= h as i32 || in_x < 0 || in_x >= w as i32 {
+                            continue;
+                        }
+
+                        let in_pos = ((nth_batch * h + in_y as usize) * w + in_x as usize) * c;
+
+                        for out_c in 0..out_channels {
+                            let grad: P::ES = out_grad[out_pos + out_c].cast_into();
+                            let weight_pos = ((out_c * kh + ky) * kw + kx) * c_per_group;
+                            let in_pos = in_pos + out_c / problem.n * c_per_group;
+
+                            for in_c in 0..c_per_group {
+                                let value: P::ES = input[in_pos + in_c].cast_into();
+                                weight_grad[weight_pos + in_c] += (grad * value).cast_into();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    weight_grad
+}
@@ -2,6 +2,8 @@ use cubecl_common::ExecutionMode;
 
 use crate::{compute::KernelDefinition, ir::Elem};
 
+pub use cubecl_runtime::kernel::{CompilationError, CompilationErrorRecorder};
+
 /// Compiles the representation into its own representation that can be formatted into tokens.
 pub trait Compiler: Sync + Send + 'static + Clone + core::fmt::Debug {
     /// The representation for the compiled code.
@@ -9,12 +11,15 @@ pub trait Compiler: Sync + Send + 'static + Clone + core::fmt::Debug {
     type CompilationOptions: Send + Default + core::fmt::Debug;
 
     /// Compiles the [kernel definition](KernelDefinition) into the compiler's representation.
+    ///
+    /// Returns an error if the kernel uses an operation or type that isn't supported by this
+    /// compiler.
     fn compile(
         &mut self,
         kernel: KernelDefinition,
         compilation_options: &Self::CompilationOptions,
         mode: ExecutionMode,
-    ) -> Self::Representation;
+    ) -> Result<Self::Representation, CompilationError>;
     /// The size of the given element in bytes.
     fn elem_size(&self, elem: Elem) -> usize;
 
@@ -4,7 +4,7 @@ use std::{
     sync::atomic::{AtomicI8, Ordering},
 };
 
-use crate::{Compiler, KernelOptions};
+use crate::{CompilationError, Compiler, KernelOptions};
 use cubecl_common::{CubeDim, ExecutionMode};
 use cubecl_ir::{Elem, Id, Item, Scope};
 use cubecl_runtime::{
@@ -207,7 +207,7 @@ pub trait CubeTask<C: Compiler>: KernelMetadata + Send + Sync {
         compiler: &mut C,
         compilation_options: &C::CompilationOptions,
         mode: ExecutionMode,
-    ) -> CompiledKernel<C>;
+    ) -> Result<CompiledKernel<C>, CompilationError>;
 }
 
 /// Wraps a [kernel](Kernel) to allow it be compiled.
@@ -235,20 +235,22 @@ impl<C: Compiler, K: CubeKernel> CubeTask<C> for KernelTask<C, K> {
         compiler: &mut C,
         compilation_options: &C::CompilationOptions,
         mode: ExecutionMode,
-    ) -> CompiledKernel<C> {
+    ) -> Result<CompiledKernel<C>, CompilationError> {
         let gpu_ir = self.kernel_definition.define();
         let entrypoint_name = gpu_ir.options.kernel_name.clone();
         let cube_dim = gpu_ir.cube_dim;
-        let lower_level_ir = compiler.compile(gpu_ir, compilation_options, mode);
+        let lower_level_ir = compiler
+            .compile(gpu_ir, compilation_options, mode)
+            .map_err(|err| err.with_kernel(self.name()))?;
 
-        CompiledKernel {
+        Ok(CompiledKernel {
             entrypoint_name,
             debug_name: Some(core::any::type_name::<K>()),
             source: lower_level_ir.to_string(),
             repr: Some(lower_level_ir),
             cube_dim,
             debug_info: None,
-        }
+        })
     }
 }
 
@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use crate::CompilationError;
 use crate::MetadataBuilder;
 use crate::Runtime;
 use crate::compute::KernelTask;
@@ -106,18 +107,18 @@ impl<R: Runtime> KernelLauncher<R> {
         self.scalar_f64.push(scalar);
     }
 
-    /// Launch the kernel.
+    /// Launch the kernel, returning an error if it can't be compiled.
     #[track_caller]
     pub fn launch<K: CubeKernel>(
         self,
         cube_count: CubeCount,
         kernel: K,
         client: &ComputeClient<R::Server, R::Channel>,
-    ) {
+    ) -> Result<(), CompilationError> {
         let bindings = self.into_bindings();
         let kernel = Box::new(KernelTask::<R::Compiler, K>::new(kernel));
 
-        client.execute(kernel, cube_count, bindings);
+        client.execute(kernel, cube_count, bindings)
     }
 
     /// Launch the kernel without check bounds.
@@ -134,12 +135,12 @@ impl<R: Runtime> KernelLauncher<R> {
         cube_count: CubeCount,
         kernel: K,
         client: &ComputeClient<R::Server, R::Channel>,
-    ) {
+    ) -> Result<(), CompilationError> {
         unsafe {
             let bindings = self.into_bindings();
             let kernel = Box::new(KernelTask::<R::Compiler, K>::new(kernel));
 
-            client.execute_unchecked(kernel, cube_count, bindings);
+            client.execute_unchecked(kernel, cube_count, bindings)
         }
     }
 
@@ -513,6 +513,10 @@ pub fn break_expand(scope: &mut Scope) {
     scope.register(Branch::Break);
 }
 
+pub fn continue_expand(scope: &mut Scope) {
+    scope.register(Branch::Continue);
+}
+
 pub fn return_expand(scope: &mut Scope) {
     scope.register(Branch::Return);
 }
@@ -5,8 +5,9 @@ use num_traits::{NumCast, ToPrimitive};
 use crate as cubecl;
 use crate::{
     frontend::{
-        Abs, Ceil, Clamp, Cos, CubePrimitive, Erf, Exp, ExpandElementTyped, Floor, Log, Log1p, Max,
-        Min, Powf, Recip, Remainder, Round, Sin, Sqrt, Tanh,
+        Abs, ArcCos, ArcCosh, ArcSin, ArcSinh, ArcTan, ArcTan2, ArcTanh, Ceil, Clamp, Cos, Cosh,
+        CubePrimitive, Erf, Exp, Exp2, ExpandElementTyped, Floor, Hypot, InverseSqrt, Log, Log1p,
+        Log2, Log10, Max, Min, Powf, Recip, Remainder, Round, Sin, Sinh, Sqrt, Tan, Tanh,
     },
     prelude::{BitwiseNot, CountOnes, FindFirstSet, LeadingZeros, ReverseBits},
     unexpanded,
@@ -241,11 +242,26 @@ impl<P: CubePrimitive + Log> Log for Line<P> {}
 impl<P: CubePrimitive + Log1p> Log1p for Line<P> {}
 impl<P: CubePrimitive + Erf> Erf for Line<P> {}
 impl<P: CubePrimitive + Exp> Exp for Line<P> {}
+impl<P: CubePrimitive + Exp2> Exp2 for Line<P> {}
+impl<P: CubePrimitive + Log2> Log2 for Line<P> {}
+impl<P: CubePrimitive + Log10> Log10 for Line<P> {}
 impl<P: CubePrimitive + Powf> Powf for Line<P> {}
+impl<P: CubePrimitive + Hypot> Hypot for Line<P> {}
 impl<P: CubePrimitive + Sqrt> Sqrt for Line<P> {}
+impl<P: CubePrimitive + InverseSqrt> InverseSqrt for Line<P> {}
 impl<P: CubePrimitive + Cos> Cos for Line<P> {}
 impl<P: CubePrimitive + Sin> Sin for Line<P> {}
+impl<P: CubePrimitive + Tan> Tan for Line<P> {}
 impl<P: CubePrimitive + Tanh> Tanh for Line<P> {}
+impl<P: CubePrimitive + Sinh> Sinh for Line<P> {}
+impl<P: CubePrimitive + Cosh> Cosh for Line<P> {}
+impl<P: CubePrimitive + ArcCos> ArcCos for Line<P> {}
+impl<P: CubePrimitive + ArcSin> ArcSin for Line<P> {}
+impl<P: CubePrimitive + ArcTan> ArcTan for Line<P> {}
+impl<P: CubePrimitive + ArcSinh> ArcSinh for Line<P> {}
+impl<P: CubePrimitive + ArcCosh> ArcCosh for Line<P> {}
+impl<P: CubePrimitive + ArcTanh> ArcTanh for Line<P> {}
+impl<P: CubePrimitive + ArcTan2> ArcTan2 for Line<P> {}
 impl<P: CubePrimitive + Recip> Recip for Line<P> {}
 impl<P: CubePrimitive + Remainder> Remainder for Line<P> {}
 impl<P: CubePrimitive + Round> Round for Line<P> {}
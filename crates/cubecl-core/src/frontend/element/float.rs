@@ -21,13 +21,28 @@ pub use typemap::*;
 pub trait Float:
     Numeric
     + Exp
+    + Exp2
     + Log
     + Log1p
+    + Log2
+    + Log10
     + Cos
     + Sin
+    + Tan
     + Tanh
+    + Sinh
+    + Cosh
+    + ArcCos
+    + ArcSin
+    + ArcTan
+    + ArcSinh
+    + ArcCosh
+    + ArcTanh
+    + ArcTan2
     + Powf
+    + Hypot
     + Sqrt
+    + InverseSqrt
     + Round
     + Floor
     + Ceil
@@ -224,6 +224,7 @@ impl<const POS: u8> Magnitude for FloatExpand<POS> {}
 impl<const POS: u8> Recip for FloatExpand<POS> {}
 impl<const POS: u8> Erf for FloatExpand<POS> {}
 impl<const POS: u8> Exp for FloatExpand<POS> {}
+impl<const POS: u8> Exp2 for FloatExpand<POS> {}
 impl<const POS: u8> Remainder for FloatExpand<POS> {}
 impl<const POS: u8> Abs for FloatExpand<POS> {}
 impl<const POS: u8> Max for FloatExpand<POS> {}
@@ -231,11 +232,25 @@ impl<const POS: u8> Min for FloatExpand<POS> {}
 impl<const POS: u8> Clamp for FloatExpand<POS> {}
 impl<const POS: u8> Log for FloatExpand<POS> {}
 impl<const POS: u8> Log1p for FloatExpand<POS> {}
+impl<const POS: u8> Log2 for FloatExpand<POS> {}
+impl<const POS: u8> Log10 for FloatExpand<POS> {}
 impl<const POS: u8> Cos for FloatExpand<POS> {}
 impl<const POS: u8> Sin for FloatExpand<POS> {}
+impl<const POS: u8> Tan for FloatExpand<POS> {}
 impl<const POS: u8> Tanh for FloatExpand<POS> {}
+impl<const POS: u8> Sinh for FloatExpand<POS> {}
+impl<const POS: u8> Cosh for FloatExpand<POS> {}
+impl<const POS: u8> ArcCos for FloatExpand<POS> {}
+impl<const POS: u8> ArcSin for FloatExpand<POS> {}
+impl<const POS: u8> ArcTan for FloatExpand<POS> {}
+impl<const POS: u8> ArcSinh for FloatExpand<POS> {}
+impl<const POS: u8> ArcCosh for FloatExpand<POS> {}
+impl<const POS: u8> ArcTanh for FloatExpand<POS> {}
+impl<const POS: u8> ArcTan2 for FloatExpand<POS> {}
 impl<const POS: u8> Powf for FloatExpand<POS> {}
+impl<const POS: u8> Hypot for FloatExpand<POS> {}
 impl<const POS: u8> Sqrt for FloatExpand<POS> {}
+impl<const POS: u8> InverseSqrt for FloatExpand<POS> {}
 impl<const POS: u8> Round for FloatExpand<POS> {}
 impl<const POS: u8> Floor for FloatExpand<POS> {}
 impl<const POS: u8> Ceil for FloatExpand<POS> {}
@@ -118,6 +118,18 @@ impl_unary_func!(
     f32,
     f64
 );
+impl_unary_func!(
+    Exp2,
+    exp2,
+    __expand_exp2,
+    Arithmetic::Exp2,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
 impl_unary_func!(
     Log,
     log,
@@ -142,6 +154,30 @@ impl_unary_func!(
     f32,
     f64
 );
+impl_unary_func!(
+    Log2,
+    log2,
+    __expand_log2,
+    Arithmetic::Log2,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
+impl_unary_func!(
+    Log10,
+    log10,
+    __expand_log10,
+    Arithmetic::Log10,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
 impl_unary_func!(
     Cos,
     cos,
@@ -166,6 +202,18 @@ impl_unary_func!(
     f32,
     f64
 );
+impl_unary_func!(
+    Tan,
+    tan,
+    __expand_tan,
+    Arithmetic::Tan,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
 impl_unary_func!(
     Tanh,
     tanh,
@@ -178,6 +226,102 @@ impl_unary_func!(
     f32,
     f64
 );
+impl_unary_func!(
+    Sinh,
+    sinh,
+    __expand_sinh,
+    Arithmetic::Sinh,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
+impl_unary_func!(
+    Cosh,
+    cosh,
+    __expand_cosh,
+    Arithmetic::Cosh,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
+impl_unary_func!(
+    ArcCos,
+    acos,
+    __expand_acos,
+    Arithmetic::ArcCos,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
+impl_unary_func!(
+    ArcSin,
+    asin,
+    __expand_asin,
+    Arithmetic::ArcSin,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
+impl_unary_func!(
+    ArcTan,
+    atan,
+    __expand_atan,
+    Arithmetic::ArcTan,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
+impl_unary_func!(
+    ArcSinh,
+    asinh,
+    __expand_asinh,
+    Arithmetic::ArcSinh,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
+impl_unary_func!(
+    ArcCosh,
+    acosh,
+    __expand_acosh,
+    Arithmetic::ArcCosh,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
+impl_unary_func!(
+    ArcTanh,
+    atanh,
+    __expand_atanh,
+    Arithmetic::ArcTanh,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
 impl_unary_func!(
     Sqrt,
     sqrt,
@@ -190,6 +334,18 @@ impl_unary_func!(
     f32,
     f64
 );
+impl_unary_func!(
+    InverseSqrt,
+    inverse_sqrt,
+    __expand_inverse_sqrt,
+    Arithmetic::InverseSqrt,
+    f16,
+    bf16,
+    flex32,
+    tf32,
+    f32,
+    f64
+);
 impl_unary_func!(
     Round,
     round,
@@ -60,6 +60,134 @@ pub mod plane_broadcast {
     }
 }
 
+/// Returns the value held by the plane unit at position `src_lane`.
+/// Unlike [plane_broadcast()], each unit can read from a different unit.
+#[allow(unused_variables)]
+pub fn plane_shuffle<E: CubePrimitive>(value: E, src_lane: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_shuffle()].
+pub mod plane_shuffle {
+
+    use super::*;
+
+    /// Expand method of [plane_shuffle()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        value: ExpandElementTyped<E>,
+        src_lane: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<E> {
+        let output = scope.create_local(value.expand.item);
+        let out = *output;
+        let lhs = *value.expand;
+        let rhs = *src_lane.expand;
+
+        scope.register(Instruction::new(
+            Plane::Shuffle(crate::ir::BinaryOperator { lhs, rhs }),
+            out,
+        ));
+
+        output.into()
+    }
+}
+
+/// Returns the value held by the plane unit at position `UNIT_POS_PLANE ^ mask`.
+/// This is the building block of butterfly reductions.
+#[allow(unused_variables)]
+pub fn plane_shuffle_xor<E: CubePrimitive>(value: E, mask: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_shuffle_xor()].
+pub mod plane_shuffle_xor {
+
+    use super::*;
+
+    /// Expand method of [plane_shuffle_xor()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        value: ExpandElementTyped<E>,
+        mask: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<E> {
+        let output = scope.create_local(value.expand.item);
+        let out = *output;
+        let lhs = *value.expand;
+        let rhs = *mask.expand;
+
+        scope.register(Instruction::new(
+            Plane::ShuffleXor(crate::ir::BinaryOperator { lhs, rhs }),
+            out,
+        ));
+
+        output.into()
+    }
+}
+
+/// Returns the value held by the plane unit at position `UNIT_POS_PLANE - delta`.
+/// The result is undefined for units with a position lower than `delta`.
+#[allow(unused_variables)]
+pub fn plane_shuffle_up<E: CubePrimitive>(value: E, delta: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_shuffle_up()].
+pub mod plane_shuffle_up {
+
+    use super::*;
+
+    /// Expand method of [plane_shuffle_up()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        value: ExpandElementTyped<E>,
+        delta: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<E> {
+        let output = scope.create_local(value.expand.item);
+        let out = *output;
+        let lhs = *value.expand;
+        let rhs = *delta.expand;
+
+        scope.register(Instruction::new(
+            Plane::ShuffleUp(crate::ir::BinaryOperator { lhs, rhs }),
+            out,
+        ));
+
+        output.into()
+    }
+}
+
+/// Returns the value held by the plane unit at position `UNIT_POS_PLANE + delta`.
+/// The result is undefined for units with a position of `PLANE_DIM - delta` or higher.
+#[allow(unused_variables)]
+pub fn plane_shuffle_down<E: CubePrimitive>(value: E, delta: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_shuffle_down()].
+pub mod plane_shuffle_down {
+
+    use super::*;
+
+    /// Expand method of [plane_shuffle_down()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        value: ExpandElementTyped<E>,
+        delta: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<E> {
+        let output = scope.create_local(value.expand.item);
+        let out = *output;
+        let lhs = *value.expand;
+        let rhs = *delta.expand;
+
+        scope.register(Instruction::new(
+            Plane::ShuffleDown(crate::ir::BinaryOperator { lhs, rhs }),
+            out,
+        ));
+
+        output.into()
+    }
+}
+
 /// Perform a reduce sum operation across all units in a plane.
 #[allow(unused_variables)]
 pub fn plane_sum<E: CubePrimitive>(value: E) -> E {
@@ -1,3 +1,5 @@
+use core::f32::consts::{LOG10_E, PI};
+
 use cubecl_ir::{Elem, ExpandElement, Variable};
 
 use crate::prelude::*;
@@ -85,6 +87,108 @@ pub fn expand_erf(scope: &mut Scope, input: Variable, out: Variable) {
     assign::expand_no_check(scope, res, ExpandElement::Plain(out).into());
 }
 
+/// Base 10 logarithm for targets without a native `log10`.
+#[cube]
+fn log10<F: Float>(x: Line<F>) -> Line<F> {
+    Log::log(x) * Line::new(F::new(LOG10_E))
+}
+
+#[allow(missing_docs)]
+pub fn expand_log10(scope: &mut Scope, input: Variable, out: Variable) {
+    scope.register_elem::<FloatExpand<0>>(input.item.elem);
+    let res = log10::expand::<FloatExpand<0>>(scope, ExpandElement::Plain(input).into());
+    assign::expand_no_check(scope, res, ExpandElement::Plain(out).into());
+}
+
+/// `sqrt(x² + y²)`, scaled by the largest magnitude so the squares can't overflow.
+#[cube]
+fn hypot<F: Float>(x: Line<F>, y: Line<F>) -> Line<F> {
+    let x = Abs::abs(x);
+    let y = Abs::abs(y);
+    let zero = Line::new(F::new(0.0));
+    let one = Line::new(F::new(1.0));
+
+    let max = Max::max(x, y);
+    let min = Min::min(x, y);
+    let ratio = min / select_many(max.equal(zero), one, max);
+
+    max * Sqrt::sqrt(one + ratio * ratio)
+}
+
+#[allow(missing_docs)]
+pub fn expand_hypot(scope: &mut Scope, lhs: Variable, rhs: Variable, out: Variable) {
+    scope.register_elem::<FloatExpand<0>>(lhs.item.elem);
+    let res = hypot::expand::<FloatExpand<0>>(
+        scope,
+        ExpandElement::Plain(lhs).into(),
+        ExpandElement::Plain(rhs).into(),
+    );
+    assign::expand_no_check(scope, res, ExpandElement::Plain(out).into());
+}
+
+/// Four quadrant arc tangent of `y / x`, built on top of the one quadrant `atan`.
+#[cube]
+fn atan2<F: Float>(y: Line<F>, x: Line<F>) -> Line<F> {
+    let zero = Line::new(F::new(0.0));
+    let pi = Line::new(F::new(PI));
+
+    let atan = ArcTan::atan(y / x);
+    let offset = select_many(y.less_than(zero), -pi, pi);
+    let res = select_many(x.less_than(zero), atan + offset, atan);
+
+    select_many(x.equal(zero).and(y.equal(zero)), zero, res)
+}
+
+#[allow(missing_docs)]
+pub fn expand_atan2(scope: &mut Scope, lhs: Variable, rhs: Variable, out: Variable) {
+    scope.register_elem::<FloatExpand<0>>(lhs.item.elem);
+    let res = atan2::expand::<FloatExpand<0>>(
+        scope,
+        ExpandElement::Plain(lhs).into(),
+        ExpandElement::Plain(rhs).into(),
+    );
+    assign::expand_no_check(scope, res, ExpandElement::Plain(out).into());
+}
+
+#[cube]
+fn asinh<F: Float>(x: Line<F>) -> Line<F> {
+    let abs = Abs::abs(x);
+    let res = Log::log(abs + Sqrt::sqrt(abs * abs + Line::new(F::new(1.0))));
+    select_many(x.less_than(Line::new(F::new(0.0))), -res, res)
+}
+
+#[cube]
+fn acosh<F: Float>(x: Line<F>) -> Line<F> {
+    Log::log(x + Sqrt::sqrt(x * x - Line::new(F::new(1.0))))
+}
+
+#[cube]
+fn atanh<F: Float>(x: Line<F>) -> Line<F> {
+    let one = Line::new(F::new(1.0));
+    Log::log((one + x) / (one - x)) * Line::new(F::new(0.5))
+}
+
+#[allow(missing_docs)]
+pub fn expand_asinh(scope: &mut Scope, input: Variable, out: Variable) {
+    scope.register_elem::<FloatExpand<0>>(input.item.elem);
+    let res = asinh::expand::<FloatExpand<0>>(scope, ExpandElement::Plain(input).into());
+    assign::expand_no_check(scope, res, ExpandElement::Plain(out).into());
+}
+
+#[allow(missing_docs)]
+pub fn expand_acosh(scope: &mut Scope, input: Variable, out: Variable) {
+    scope.register_elem::<FloatExpand<0>>(input.item.elem);
+    let res = acosh::expand::<FloatExpand<0>>(scope, ExpandElement::Plain(input).into());
+    assign::expand_no_check(scope, res, ExpandElement::Plain(out).into());
+}
+
+#[allow(missing_docs)]
+pub fn expand_atanh(scope: &mut Scope, input: Variable, out: Variable) {
+    scope.register_elem::<FloatExpand<0>>(input.item.elem);
+    let res = atanh::expand::<FloatExpand<0>>(scope, ExpandElement::Plain(input).into());
+    assign::expand_no_check(scope, res, ExpandElement::Plain(out).into());
+}
+
 #[cube]
 fn himul_i64(lhs: Line<i32>, rhs: Line<i32>) -> Line<i32> {
     let shift = Line::empty(lhs.size()).fill(32);
@@ -65,3 +65,21 @@ pub mod sync_proxy_shared {
         scope.register(Synchronization::SyncProxyShared)
     }
 }
+
+/// Orders the storage accesses of the current unit before the fence with the ones after it, for
+/// all the units of the kernel and not only the ones in the same cube.
+///
+/// A unit that observes a write made after a fence, then executes a fence itself, observes the
+/// writes made before the first fence. Units don't wait for each other, so unlike [sync_storage]
+/// it can be used by a single unit.
+///
+/// Warning: not all targets support device fences. Use the [runtime flag](crate::Feature::DeviceFence).
+pub fn device_fence() {}
+
+pub mod device_fence {
+    use super::*;
+
+    pub fn expand(scope: &mut Scope) {
+        scope.register(Synchronization::DeviceFence)
+    }
+}
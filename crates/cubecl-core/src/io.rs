@@ -31,3 +31,26 @@ pub fn read_tensor_atomic_checked<C: Numeric>(
 
     tensor.read_unchecked(index)
 }
+
+/// Records an out-of-bounds access into the validation error buffer if `index` isn't smaller than
+/// `length`. See [cubecl_runtime::validation] for the layout of the buffer.
+#[cube]
+pub fn record_out_of_bounds(
+    errors: &Array<Atomic<u32>>,
+    index: u32,
+    length: u32,
+    #[comptime] buffer: u32,
+    #[comptime] max_records: u32,
+    #[comptime] record_len: u32,
+) {
+    if index >= length {
+        let slot = Atomic::add(unsafe { errors.index_unchecked(0) }, 1);
+
+        if slot < max_records {
+            let offset = 1 + slot * record_len;
+            Atomic::store(unsafe { errors.index_unchecked(offset) }, buffer);
+            Atomic::store(unsafe { errors.index_unchecked(offset + 1) }, index);
+            Atomic::store(unsafe { errors.index_unchecked(offset + 2) }, length);
+        }
+    }
+}
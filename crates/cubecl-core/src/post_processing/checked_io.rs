@@ -1,14 +1,69 @@
 use cubecl_common::ExecutionMode;
-use cubecl_ir::{Allocator, ExpandElement, Instruction, Operation, Operator, Processor, Scope};
+use cubecl_ir::{
+    Allocator, Elem, ExpandElement, Instruction, Item, Metadata, Operation, Operator, Processor,
+    Scope, UIntKind, Variable, VariableKind,
+};
+use cubecl_runtime::validation::{MAX_RECORDS, RECORD_LEN};
 
 use crate::{
-    io::{read_tensor_atomic_checked, read_tensor_checked},
+    compute::{Binding, KernelDefinition, Location, Visibility},
+    io::{read_tensor_atomic_checked, read_tensor_checked, record_out_of_bounds},
     prelude::{Line, NumericExpand, expand_checked_index_assign},
 };
 
 #[derive(new)]
 pub struct CheckedIoProcessor {
     mode: ExecutionMode,
+    /// The buffer out-of-bounds accesses are recorded into in [validation mode](ExecutionMode::Validate).
+    error_buffer: Option<Variable>,
+}
+
+/// Add the error buffer of a kernel compiled in [validation mode](ExecutionMode::Validate). It's
+/// bound after every other buffer, and returned so it can be passed to the [CheckedIoProcessor].
+pub fn register_error_buffer(
+    kernel: &mut KernelDefinition,
+    mode: ExecutionMode,
+) -> Option<Variable> {
+    if !matches!(mode, ExecutionMode::Validate) {
+        return None;
+    }
+
+    let id = kernel.buffers.len() as u32 + kernel.tensor_maps.len() as u32;
+    let item = Item::new(Elem::AtomicUInt(UIntKind::U32));
+    kernel.buffers.push(Binding {
+        id,
+        location: Location::Storage,
+        visibility: Visibility::ReadWrite,
+        item,
+        size: None,
+        has_extended_meta: false,
+    });
+
+    Some(Variable::new(VariableKind::GlobalOutputArray(id), item))
+}
+
+impl CheckedIoProcessor {
+    /// Record the access in the error buffer if it's out of bounds, in validation mode.
+    fn validate(&self, scope: &mut Scope, list: Variable, index: Variable) {
+        let Some(errors) = self.error_buffer else {
+            return;
+        };
+
+        let length = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
+        scope.register(Instruction::new(
+            Metadata::BufferLength { var: list },
+            *length,
+        ));
+        record_out_of_bounds::expand(
+            scope,
+            ExpandElement::Plain(errors).into(),
+            ExpandElement::Plain(index).into(),
+            length.into(),
+            list.index().unwrap(),
+            MAX_RECORDS,
+            RECORD_LEN,
+        );
+    }
 }
 
 impl Processor for CheckedIoProcessor {
@@ -30,10 +85,11 @@ impl Processor for CheckedIoProcessor {
                     Operator::Index(op) => {
                         let has_length = op.list.has_length();
 
-                        if has_length {
+                        if has_length && Some(op.list) != self.error_buffer {
                             let list = ExpandElement::Plain(op.list);
                             let index = ExpandElement::Plain(op.index);
                             let mut scope = Scope::root(false).with_allocator(allocator.clone());
+                            self.validate(&mut scope, op.list, op.index);
                             scope.register_elem::<NumericExpand<0>>(op.list.elem());
 
                             let input = if op.list.elem().is_atomic() {
@@ -73,8 +129,9 @@ impl Processor for CheckedIoProcessor {
                     Operator::IndexAssign(op) => {
                         let out = instruction.out();
 
-                        if out.has_length() {
+                        if out.has_length() && Some(out) != self.error_buffer {
                             let mut scope = Scope::root(false).with_allocator(allocator.clone());
+                            self.validate(&mut scope, out, op.index);
                             expand_checked_index_assign(&mut scope, op.index, op.value, out);
 
                             let tmp_processing = scope.process([]);
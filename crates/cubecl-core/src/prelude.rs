@@ -18,7 +18,7 @@ pub use crate::runtime::Runtime;
 pub use crate::frontend::{plane_all, plane_max, plane_min, plane_prod, plane_sum};
 pub use cubecl_runtime::client::ComputeClient;
 pub use cubecl_runtime::id::KernelId;
-pub use cubecl_runtime::kernel::KernelMetadata;
+pub use cubecl_runtime::kernel::{CompilationError, KernelMetadata};
 pub use cubecl_runtime::server::CubeCount;
 
 pub use crate::frontend::*;
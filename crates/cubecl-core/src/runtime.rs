@@ -86,6 +86,8 @@ pub enum Feature {
     DynamicLineSize,
     /// Enables synchronization within a plane only
     SyncPlane,
+    /// Enables memory fences ordering storage accesses across cubes
+    DeviceFence,
 }
 
 /// Atomic features that may be supported by a [cube runtime](Runtime).
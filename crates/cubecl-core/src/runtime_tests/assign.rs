@@ -43,7 +43,8 @@ pub fn test_kernel_assign_scalar<R: Runtime, F: Float + CubeElement>(
         CubeCount::Static(1, 1, 1),
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<F>(&handle, 2, vectorization) },
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = F::from_bytes(&actual);
@@ -63,7 +64,8 @@ pub fn test_kernel_add_assign_array<R: Runtime, F: Float + CubeElement>(
         CubeCount::Static(1, 1, 1),
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<F>(&handle, 2, vectorization) },
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = F::from_bytes(&actual);
@@ -83,7 +85,8 @@ pub fn test_kernel_add_assign_line<R: Runtime, F: Float + CubeElement>(
         CubeCount::Static(1, 1, 1),
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<F>(&handle, 2, vectorization) },
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = F::from_bytes(&actual);
@@ -60,7 +60,8 @@ pub fn test_kernel_atomic_add<R: Runtime, F: Numeric + CubeElement>(
         CubeCount::Static(1, 1, 1),
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<F>(&handle, 2, 1) },
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = F::from_bytes(&actual);
@@ -93,7 +94,8 @@ pub fn test_kernel_atomic_min<R: Runtime, F: Numeric + CubeElement>(
         CubeCount::Static(1, 1, 1),
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<F>(&handle, 2, 1) },
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = F::from_bytes(&actual);
@@ -126,7 +128,8 @@ pub fn test_kernel_atomic_max<R: Runtime, F: Numeric + CubeElement>(
         CubeCount::Static(1, 1, 1),
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<F>(&handle, 2, 1) },
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = F::from_bytes(&actual);
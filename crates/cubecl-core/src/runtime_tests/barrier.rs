@@ -35,6 +35,7 @@ pub fn test_async_copy<R: Runtime, F: Float + CubeElement>(
             ArrayArg::from_raw_parts::<F>(&input, 5, 1),
             ArrayArg::from_raw_parts::<F>(&output, 1, 1),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(output.binding());
@@ -129,6 +130,59 @@ fn two_independent_loads<F: Float>(
     output[UNIT_POS_X] = dot;
 }
 
+#[cube(launch)]
+fn leader_waits<F: Float>(input: &Array<Line<F>>, output: &mut Array<Line<F>>) {
+    let mut smem = SharedMemory::<F>::new_lined(4u32, 1u32);
+
+    let barrier = Barrier::<F>::new(BarrierLevel::cube_manual(0u32));
+    sync_cube();
+
+    barrier.memcpy_async(
+        &input.slice(UNIT_POS, UNIT_POS + 1),
+        &mut smem.slice_mut(UNIT_POS, UNIT_POS + 1),
+    );
+
+    // Only the leader waits, the other units arrive and leave.
+    if UNIT_POS == 0 {
+        barrier.arrive_and_wait();
+        let mut sum = Line::cast_from(0u32);
+        for i in 0..4u32 {
+            sum += smem[i];
+        }
+        output[0] = sum;
+    } else {
+        barrier.arrive();
+    }
+}
+
+pub fn test_leader_waits<R: Runtime, F: Float + CubeElement>(
+    client: ComputeClient<R::Server, R::Channel>,
+) {
+    if !client.properties().feature_enabled(Feature::Barrier) {
+        // We can't execute the test, skip.
+        return;
+    }
+
+    let input = client.create(as_bytes![F: 1.0, 2.0, 3.0, 4.0]);
+    let output = client.empty(core::mem::size_of::<F>());
+
+    unsafe {
+        leader_waits::launch::<F, R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(4, 1, 1),
+            ArrayArg::from_raw_parts::<F>(&input, 4, 1),
+            ArrayArg::from_raw_parts::<F>(&output, 1, 1),
+        )
+        .unwrap()
+    };
+
+    let actual = client.read_one(output.binding());
+    let actual = F::from_bytes(&actual);
+
+    assert_eq!(actual[0], F::new(10.0));
+}
+
 pub fn test_memcpy_one_load<R: Runtime, F: Float + CubeElement>(
     client: ComputeClient<R::Server, R::Channel>,
 ) {
@@ -148,6 +202,7 @@ pub fn test_memcpy_one_load<R: Runtime, F: Float + CubeElement>(
             TensorArg::from_raw_parts::<F>(&lhs, &[4, 1], &[4, 4], 1),
             TensorArg::from_raw_parts::<F>(&output, &[4, 1], &[4, 4], 1),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(output.binding());
@@ -185,6 +240,7 @@ pub fn test_memcpy_two_loads<R: Runtime, F: Float + CubeElement>(
                 TensorArg::from_raw_parts::<F>(&output, &[1], &[2], 1),
                 num_data as u32,
             )
+            .unwrap()
         };
     } else {
         unsafe {
@@ -197,6 +253,7 @@ pub fn test_memcpy_two_loads<R: Runtime, F: Float + CubeElement>(
                 TensorArg::from_raw_parts::<F>(&output, &[1], &[2], 1),
                 num_data as u32,
             )
+            .unwrap()
         };
     }
 
@@ -255,5 +312,13 @@ macro_rules! testgen_barrier {
                 true, client,
             );
         }
+
+        #[test]
+        fn test_barrier_manual_leader_waits() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::barrier::test_leader_waits::<TestRuntime, FloatType>(
+                client,
+            );
+        }
     };
 }
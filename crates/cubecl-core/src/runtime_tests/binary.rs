@@ -1,3 +1,4 @@
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 use std::fmt::Display;
 
 use crate::{self as cubecl, as_type};
@@ -81,7 +82,7 @@ macro_rules! test_binary_impl {
                         ArrayArg::from_raw_parts::<$float_type>(&lhs_handle, lhs.len(), $input_vectorization),
                         ArrayArg::from_raw_parts::<$float_type>(&rhs_handle, rhs.len(), $input_vectorization),
                         ArrayArg::from_raw_parts::<$float_type>(&output_handle, $expected.len(), $out_vectorization),
-                    )
+                    ).unwrap()
                 };
 
                 assert_equals_approx::<R, F>(&client, output_handle, $expected, 0.001);
@@ -128,6 +129,50 @@ test_binary_impl!(
     ]
 );
 
+test_binary_impl!(
+    test_atan2,
+    F,
+    F::atan2,
+    [
+        {
+            input_vectorization: 1,
+            out_vectorization: 1,
+            lhs: as_type![F: 1., -1., 0., 2.],
+            rhs: as_type![F: 1., -2., -1., 0.],
+            expected: as_type![F: FRAC_PI_4, -2.6779, PI, FRAC_PI_2]
+        },
+        {
+            input_vectorization: 4,
+            out_vectorization: 4,
+            lhs: as_type![F: 1., -1., 0., 2.],
+            rhs: as_type![F: 1., -2., -1., 0.],
+            expected: as_type![F: FRAC_PI_4, -2.6779, PI, FRAC_PI_2]
+        }
+    ]
+);
+
+test_binary_impl!(
+    test_hypot,
+    F,
+    F::hypot,
+    [
+        {
+            input_vectorization: 1,
+            out_vectorization: 1,
+            lhs: as_type![F: 3., -5., 0., 1.5],
+            rhs: as_type![F: 4., 12., 0., -2.],
+            expected: as_type![F: 5., 13., 0., 2.5]
+        },
+        {
+            input_vectorization: 4,
+            out_vectorization: 4,
+            lhs: as_type![F: 3., -5., 0., 1.5],
+            rhs: as_type![F: 4., 12., 0., -2.],
+            expected: as_type![F: 5., 13., 0., 2.5]
+        }
+    ]
+);
+
 #[cube(launch_unchecked)]
 fn test_mulhi_kernel(
     lhs: &Array<Line<u32>>,
@@ -166,7 +211,7 @@ macro_rules! test_mulhi_impl {
                         ArrayArg::from_raw_parts::<u32>(&lhs_handle, lhs.len(), $input_vectorization),
                         ArrayArg::from_raw_parts::<u32>(&rhs_handle, rhs.len(), $input_vectorization),
                         ArrayArg::from_raw_parts::<u32>(&output_handle, $expected.len(), $out_vectorization),
-                    )
+                    ).unwrap()
                 };
 
                 let actual = client.read_one(output_handle.binding());
@@ -227,6 +272,8 @@ macro_rules! testgen_binary {
             }
 
             add_test!(test_dot);
+            add_test!(test_atan2);
+            add_test!(test_hypot);
         }
     };
 }
@@ -48,6 +48,40 @@ pub fn kernel_select<F: Float>(output: &mut Array<F>, cond: u32) {
     }
 }
 
+#[cube(launch)]
+pub fn kernel_for_loop_continue<F: Float>(output: &mut Array<F>) {
+    if UNIT_POS == 0 {
+        let mut sum = F::new(0.0);
+        for i in 0..output.len() {
+            if i % 2 == 1 {
+                continue;
+            }
+            sum += output[i];
+        }
+        output[0] = sum;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_loop_continue<F: Float>(output: &mut Array<F>) {
+    if UNIT_POS == 0 {
+        let mut sum = F::new(0.0);
+        let mut i = 0u32;
+        loop {
+            let index = i;
+            i += 1;
+            if index >= output.len() {
+                break;
+            }
+            if index % 2 == 1 {
+                continue;
+            }
+            sum += output[index];
+        }
+        output[0] = sum;
+    }
+}
+
 pub fn test_switch_statement<R: Runtime, F: Float + CubeElement>(
     client: ComputeClient<R::Server, R::Channel>,
 ) {
@@ -62,7 +96,8 @@ pub fn test_switch_statement<R: Runtime, F: Float + CubeElement>(
             CubeDim::default(),
             ArrayArg::from_raw_parts::<F>(&handle, 2, vectorization),
             ScalarArg::new(0),
-        );
+        )
+        .unwrap();
     }
 
     let actual = client.read_one(handle.binding());
@@ -84,7 +119,8 @@ pub fn test_switch_used_as_value<R: Runtime, F: Float + CubeElement>(
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<F>(&handle, 2, vectorization) },
         ScalarArg::new(1),
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = F::from_bytes(&actual);
@@ -105,7 +141,8 @@ pub fn test_switch_default<R: Runtime, F: Float + CubeElement>(
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<F>(&handle, 2, vectorization) },
         ScalarArg::new(5),
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = F::from_bytes(&actual);
@@ -126,7 +163,8 @@ pub fn test_switch_or_branch<R: Runtime, F: Float + CubeElement>(
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<F>(&handle, 2, vectorization) },
         ScalarArg::new(2),
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = F::from_bytes(&actual);
@@ -150,7 +188,8 @@ pub fn test_select<R: Runtime, F: Float + CubeElement>(
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<F>(&handle, 1, vectorization) },
         ScalarArg::new(cond_u32),
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = F::from_bytes(&actual);
@@ -162,6 +201,48 @@ pub fn test_select<R: Runtime, F: Float + CubeElement>(
     }
 }
 
+pub fn test_for_loop_continue<R: Runtime, F: Float + CubeElement>(
+    client: ComputeClient<R::Server, R::Channel>,
+) {
+    let handle = client.create(as_bytes![F: 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let vectorization = 1;
+
+    kernel_for_loop_continue::launch::<F, R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::default(),
+        unsafe { ArrayArg::from_raw_parts::<F>(&handle, 6, vectorization) },
+    )
+    .unwrap();
+
+    let actual = client.read_one(handle.binding());
+    let actual = F::from_bytes(&actual);
+
+    assert_eq!(actual[0], F::new(9.0));
+}
+
+pub fn test_loop_continue<R: Runtime, F: Float + CubeElement>(
+    client: ComputeClient<R::Server, R::Channel>,
+) {
+    let handle = client.create(as_bytes![F: 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let vectorization = 1;
+
+    kernel_loop_continue::launch::<F, R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::default(),
+        unsafe { ArrayArg::from_raw_parts::<F>(&handle, 6, vectorization) },
+    )
+    .unwrap();
+
+    let actual = client.read_one(handle.binding());
+    let actual = F::from_bytes(&actual);
+
+    assert_eq!(actual[0], F::new(9.0));
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_branch {
@@ -213,5 +294,21 @@ macro_rules! testgen_branch {
                 client, false,
             );
         }
+
+        #[test]
+        fn test_for_loop_continue() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::branch::test_for_loop_continue::<TestRuntime, FloatType>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_loop_continue() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::branch::test_loop_continue::<TestRuntime, FloatType>(
+                client,
+            );
+        }
     };
 }
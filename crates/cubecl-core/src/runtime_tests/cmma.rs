@@ -330,6 +330,7 @@ pub fn test_simple_1_lined<R: Runtime>(
             ArrayArg::from_raw_parts::<f16>(&rhs, 256 / 4, 4),
             ArrayArg::from_raw_parts::<f32>(&out, 256 / 4, 4),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(out.binding());
@@ -385,6 +386,7 @@ pub fn test_simple_1_lined_offset<R: Runtime>(
             ScalarArg::new(offset_rhs as u32),
             ScalarArg::new(offset_out as u32),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(out.binding());
@@ -428,6 +430,7 @@ pub fn test_simple_1<R: Runtime>(
             ArrayArg::from_raw_parts::<f16>(&rhs, 256, 1),
             ArrayArg::from_raw_parts::<f32>(&out, 256, 1),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(out.binding());
@@ -532,6 +535,7 @@ pub fn test_cmma_cast_f16<R: Runtime>(
             ArrayArg::from_raw_parts::<f32>(&input, 256, 1),
             ArrayArg::from_raw_parts::<f16>(&out, 256, 1),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(out.binding());
@@ -569,6 +573,7 @@ pub fn test_cmma_cast_bf16<R: Runtime>(
             ArrayArg::from_raw_parts::<f32>(&input, 256, 1),
             ArrayArg::from_raw_parts::<f16>(&out, 256, 1),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(out.binding());
@@ -610,6 +615,7 @@ pub fn test_simple_tf32<R: Runtime>(
             ArrayArg::from_raw_parts::<f32>(&rhs, 128, 1),
             ArrayArg::from_raw_parts::<f32>(&out, 256, 1),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(out.binding());
@@ -730,6 +736,7 @@ pub fn test_cmma_strided<R: Runtime>(
             k as u32,
             n as u32,
         )
+        .unwrap()
     };
 
     let actual = client.read_one(out.binding());
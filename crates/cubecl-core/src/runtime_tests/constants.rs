@@ -21,7 +21,8 @@ pub fn test_constant_array<R: Runtime>(client: ComputeClient<R::Server, R::Chann
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<f32>(&handle, 2, vectorization) },
         vec![3, 5, 1],
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = f32::from_bytes(&actual);
@@ -0,0 +1,89 @@
+use crate::{self as cubecl};
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_double(output: &mut Array<u32>) {
+    if ABSOLUTE_POS < output.len() {
+        output[ABSOLUTE_POS] *= 2;
+    }
+}
+
+pub fn test_copy<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let src = client.create(u32::as_bytes(&[1, 2, 3, 4, 5, 6, 7, 8]));
+    let dst = client.empty(8 * core::mem::size_of::<u32>());
+
+    client.copy(src.clone().binding(), dst.clone().binding());
+
+    let actual = client.read_one(dst.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, &[1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+pub fn test_copy_after_kernel<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let src = client.create(u32::as_bytes(&[1, 2, 3, 4, 5, 6, 7, 8]));
+    let dst = client.empty(8 * core::mem::size_of::<u32>());
+
+    kernel_double::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(8),
+        unsafe { ArrayArg::from_raw_parts::<u32>(&src, 8, 1) },
+    )
+    .unwrap();
+    client.copy(src.clone().binding(), dst.clone().binding());
+
+    let actual = client.read_one(dst.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, &[2, 4, 6, 8, 10, 12, 14, 16]);
+}
+
+/// `other` must have its own server, a client of the same device would only test a local copy.
+pub fn test_transfer_to<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+    other: ComputeClient<R::Server, R::Channel>,
+) {
+    let src = client.create(u32::as_bytes(&[1, 2, 3, 4]));
+
+    let dst = client.transfer_to(&src, &other);
+
+    let actual = other.read_one(dst.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, &[1, 2, 3, 4]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_copy {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_copy() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::copy::test_copy::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_copy_after_kernel() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::copy::test_copy_after_kernel::<TestRuntime>(client);
+        }
+    };
+}
+
+/// Generate the transfer test, `$other` builds a client that doesn't share the server of the
+/// default device.
+#[macro_export]
+macro_rules! testgen_transfer {
+    ($other:expr) => {
+        #[test]
+        fn test_transfer_to() {
+            let client = TestRuntime::client(&Default::default());
+            let other = $other;
+            cubecl_core::runtime_tests::copy::test_transfer_to::<TestRuntime>(client, other);
+        }
+    };
+}
@@ -71,7 +71,8 @@ fn test_kernel_different_rank<R: Runtime, F: Float + CubeElement>(
         lhs,
         rhs,
         out,
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle_out.binding());
     let actual = F::from_bytes(&actual);
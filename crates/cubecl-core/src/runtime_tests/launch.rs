@@ -62,7 +62,8 @@ pub fn test_kernel_with_comptime_tag<R: Runtime>(client: ComputeClient<R::Server
         CubeCount::Static(1, 1, 1),
         CubeDim::default(),
         ComptimeTagLaunch::new(array_arg, &"zero".to_string()),
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = f32::from_bytes(&actual);
@@ -77,7 +78,8 @@ pub fn test_kernel_with_comptime_tag<R: Runtime>(client: ComputeClient<R::Server
         CubeCount::Static(1, 1, 1),
         CubeDim::default(),
         ComptimeTagLaunch::new(array_arg, &"not_zero".to_string()),
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = f32::from_bytes(&actual);
@@ -95,7 +97,8 @@ pub fn test_kernel_with_generics<R: Runtime, F: Float + CubeElement>(
         CubeCount::Static(1, 1, 1),
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<F>(&handle, 2, 1) },
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = F::from_bytes(&actual);
@@ -111,7 +114,8 @@ pub fn test_kernel_without_generics<R: Runtime>(client: ComputeClient<R::Server,
         CubeCount::Static(1, 1, 1),
         CubeDim::default(),
         unsafe { ArrayArg::from_raw_parts::<f32>(&handle, 2, 1) },
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = f32::from_bytes(&actual);
@@ -135,7 +139,8 @@ pub fn test_kernel_max_shared<R: Runtime>(client: ComputeClient<R::Server, R::Ch
         unsafe { ArrayArg::from_raw_parts::<f32>(&handle, 8, 1) },
         shared_size_1 as u32,
         shared_size_2 as u32,
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = u32::from_bytes(&actual);
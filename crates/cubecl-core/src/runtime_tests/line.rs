@@ -21,7 +21,8 @@ pub fn test_line_index<R: Runtime, F: Float + CubeElement>(
                 CubeDim::new_single(),
                 ArrayArg::from_raw_parts::<F>(&handle, line_size as usize, 1),
                 line_size as u32,
-            );
+            )
+            .unwrap();
         }
         let actual = client.read_one(handle.binding());
         let actual = F::from_bytes(&actual);
@@ -53,7 +54,8 @@ pub fn test_line_index_assign<R: Runtime, F: Float + CubeElement>(
                 CubeCount::new_single(),
                 CubeDim::new_single(),
                 ArrayArg::from_raw_parts::<F>(&handle, 1, line_size),
-            );
+            )
+            .unwrap();
         }
 
         let actual = client.read_one(handle.binding());
@@ -90,7 +92,8 @@ pub fn test_line_loop_unroll<R: Runtime, F: Float + CubeElement>(
                 CubeDim::new_single(),
                 ArrayArg::from_raw_parts::<F>(&handle, 1, line_size),
                 line_size as u32,
-            );
+            )
+            .unwrap();
         }
 
         let actual = client.read_one(handle.binding());
@@ -122,7 +125,8 @@ pub fn test_shared_memory<R: Runtime, F: Float + CubeElement>(
                 CubeCount::new_single(),
                 CubeDim::new_single(),
                 ArrayArg::from_raw_parts::<F>(&output, 1, line_size),
-            );
+            )
+            .unwrap();
         }
 
         let actual = client.read_one(output.binding());
@@ -161,7 +165,7 @@ macro_rules! impl_line_comparison {
                         ArrayArg::from_raw_parts::<F>(&lhs, 1, 4),
                         ArrayArg::from_raw_parts::<F>(&rhs, 1, 4),
                         ArrayArg::from_raw_parts::<u32>(&output, 1, 4),
-                    )
+                    ).unwrap()
                 };
 
                 let actual = client.read_one(output.binding());
@@ -58,6 +58,7 @@ pub fn test_fp8<R: Runtime, F: Float + CubeElement>(
             ArrayArg::from_raw_parts::<F>(&handle1, num_out, vectorization),
             ArrayArg::from_raw_parts::<u8>(&handle2, 2 * num_out, vectorization),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(handle2.binding());
@@ -100,6 +101,7 @@ pub fn test_fp6<R: Runtime, F: Float + CubeElement>(
             ArrayArg::from_raw_parts::<F>(&handle1, num_out, vectorization),
             ArrayArg::from_raw_parts::<u8>(&handle2, 2 * num_out, vectorization),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(handle2.binding());
@@ -138,6 +140,7 @@ pub fn test_scale<R: Runtime>(client: ComputeClient<R::Server, R::Channel>, vect
             ArrayArg::from_raw_parts::<f32>(&handle1, num_out, vectorization),
             ArrayArg::from_raw_parts::<u8>(&handle2, num_out, vectorization),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(handle2.binding());
@@ -7,6 +7,7 @@ pub mod cluster;
 pub mod cmma;
 pub mod const_match;
 pub mod constants;
+pub mod copy;
 pub mod debug;
 pub mod different_rank;
 pub mod enums;
@@ -124,6 +125,7 @@ macro_rules! testgen_untyped {
         cubecl_core::testgen_topology!();
 
         cubecl_core::testgen_constants!();
+        cubecl_core::testgen_copy!();
         cubecl_core::testgen_sync_plane!();
         cubecl_core::testgen_tensor_indexing!();
         cubecl_core::testgen_debug!();
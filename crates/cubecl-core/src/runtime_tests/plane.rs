@@ -108,6 +108,40 @@ pub fn kernel_broadcast<F: Float>(output: &mut Tensor<F>) {
     }
 }
 
+#[cube(launch)]
+pub fn kernel_shuffle<F: Float>(output: &mut Tensor<F>) {
+    let val = output[UNIT_POS];
+    let val2 = plane_shuffle(val, (UNIT_POS + 3) % CUBE_DIM);
+    output[UNIT_POS] = val2;
+}
+
+#[cube(launch)]
+pub fn kernel_shuffle_xor<F: Float>(output: &mut Tensor<F>) {
+    let val = output[UNIT_POS];
+    let val2 = plane_shuffle_xor(val, 1);
+    output[UNIT_POS] = val2;
+}
+
+#[cube(launch)]
+pub fn kernel_shuffle_up<F: Float>(output: &mut Tensor<F>) {
+    let val = output[UNIT_POS];
+    let val2 = plane_shuffle_up(val, 1);
+
+    if UNIT_POS >= 1 {
+        output[UNIT_POS] = val2;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_shuffle_down<F: Float>(output: &mut Tensor<F>) {
+    let val = output[UNIT_POS];
+    let val2 = plane_shuffle_down(val, 1);
+
+    if UNIT_POS + 1 < CUBE_DIM {
+        output[UNIT_POS] = val2;
+    }
+}
+
 #[cube(launch)]
 pub fn kernel_ballot(output: &mut Tensor<Line<u32>>) {
     let val2 = plane_ballot(UNIT_POS < 8);
@@ -150,6 +184,7 @@ pub fn test_plane_sum<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
         },
     );
 }
@@ -192,6 +227,7 @@ pub fn test_plane_inclusive_sum<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
         },
     );
 }
@@ -234,6 +270,7 @@ pub fn test_plane_exclusive_sum<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
         },
     );
 }
@@ -276,6 +313,7 @@ pub fn test_plane_prod<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
         },
     );
 }
@@ -322,6 +360,7 @@ pub fn test_plane_inclusive_prod<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
         },
     );
 }
@@ -368,6 +407,7 @@ pub fn test_plane_exclusive_prod<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
         },
     );
 }
@@ -407,6 +447,7 @@ pub fn test_plane_max<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
         },
     );
 }
@@ -446,6 +487,7 @@ pub fn test_plane_min<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
         },
     );
 }
@@ -487,6 +529,7 @@ pub fn test_plane_all<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
         },
     );
 }
@@ -528,6 +571,7 @@ pub fn test_plane_any<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
         },
     );
 }
@@ -549,7 +593,8 @@ pub fn test_plane_ballot<TestRuntime: Runtime>(
             CubeCount::Static(1, 1, 1),
             CubeDim::new(32, 1, 1),
             TensorArg::from_raw_parts::<u32>(&handle, &strides, &shape, 4),
-        );
+        )
+        .unwrap();
     }
 
     let expected = [0b1111_1111, 0, 0, 0];
@@ -586,6 +631,7 @@ pub fn test_plane_elect<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
         },
     );
 }
@@ -621,10 +667,141 @@ pub fn test_plane_broadcast<
                 CubeDim::new(plane_size, 1, 1),
                 handle,
             )
+            .unwrap()
+        },
+    );
+}
+
+pub fn test_plane_shuffle<
+    TestRuntime: Runtime,
+    F: Float + num_traits::Float + CubeElement + Display,
+>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    vectorization: u8,
+) {
+    let plane_size = 32;
+    let (input, expected) =
+        shuffle_input_expected::<F>(plane_size, vectorization, |unit| (unit + 3) % plane_size);
+
+    test_plane_operation::<TestRuntime, F, _>(
+        &input,
+        &expected,
+        vectorization,
+        client.clone(),
+        |cube_count, handle| {
+            kernel_shuffle::launch::<F, TestRuntime>(
+                &client,
+                cube_count,
+                CubeDim::new(plane_size, 1, 1),
+                handle,
+            )
+            .unwrap()
+        },
+    );
+}
+
+pub fn test_plane_shuffle_xor<
+    TestRuntime: Runtime,
+    F: Float + num_traits::Float + CubeElement + Display,
+>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    vectorization: u8,
+) {
+    let plane_size = 32;
+    let (input, expected) = shuffle_input_expected::<F>(plane_size, vectorization, |unit| unit ^ 1);
+
+    test_plane_operation::<TestRuntime, F, _>(
+        &input,
+        &expected,
+        vectorization,
+        client.clone(),
+        |cube_count, handle| {
+            kernel_shuffle_xor::launch::<F, TestRuntime>(
+                &client,
+                cube_count,
+                CubeDim::new(plane_size, 1, 1),
+                handle,
+            )
+            .unwrap()
+        },
+    );
+}
+
+pub fn test_plane_shuffle_up<
+    TestRuntime: Runtime,
+    F: Float + num_traits::Float + CubeElement + Display,
+>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    vectorization: u8,
+) {
+    let plane_size = 32;
+    let (input, expected) =
+        shuffle_input_expected::<F>(plane_size, vectorization, |unit| unit.saturating_sub(1));
+
+    test_plane_operation::<TestRuntime, F, _>(
+        &input,
+        &expected,
+        vectorization,
+        client.clone(),
+        |cube_count, handle| {
+            kernel_shuffle_up::launch::<F, TestRuntime>(
+                &client,
+                cube_count,
+                CubeDim::new(plane_size, 1, 1),
+                handle,
+            )
+            .unwrap()
+        },
+    );
+}
+
+pub fn test_plane_shuffle_down<
+    TestRuntime: Runtime,
+    F: Float + num_traits::Float + CubeElement + Display,
+>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    vectorization: u8,
+) {
+    let plane_size = 32;
+    let (input, expected) = shuffle_input_expected::<F>(plane_size, vectorization, |unit| {
+        Ord::min(unit + 1, plane_size - 1)
+    });
+
+    test_plane_operation::<TestRuntime, F, _>(
+        &input,
+        &expected,
+        vectorization,
+        client.clone(),
+        |cube_count, handle| {
+            kernel_shuffle_down::launch::<F, TestRuntime>(
+                &client,
+                cube_count,
+                CubeDim::new(plane_size, 1, 1),
+                handle,
+            )
+            .unwrap()
         },
     );
 }
 
+/// Input where each unit holds distinct values, and the expected output when each unit reads the
+/// line of the unit returned by `source`.
+fn shuffle_input_expected<F: Float>(
+    plane_size: u32,
+    vectorization: u8,
+    source: impl Fn(u32) -> u32,
+) -> (Vec<F>, Vec<F>) {
+    let vectorization = vectorization as u32;
+    let input: Vec<f32> = (0..plane_size * vectorization).map(|x| x as f32).collect();
+    let expected: Vec<f32> = (0..plane_size * vectorization)
+        .map(|i| input[(source(i / vectorization) * vectorization + i % vectorization) as usize])
+        .collect();
+
+    let input = input.into_iter().map(|x| F::new(x)).collect();
+    let expected = expected.into_iter().map(|x| F::new(x)).collect();
+    (input, expected)
+}
+
 fn test_plane_operation<
     TestRuntime: Runtime,
     F: Float + num_traits::Float + CubeElement + Display,
@@ -905,6 +1082,86 @@ macro_rules! testgen_plane {
             impl_test_plane_broadcast(4);
         }
 
+        fn impl_test_plane_shuffle(vectorization: u8) {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_shuffle::<TestRuntime, FloatType>(
+                client.clone(),
+                vectorization,
+            );
+        }
+        #[test]
+        fn test_plane_shuffle_vec1() {
+            impl_test_plane_shuffle(1);
+        }
+        #[test]
+        fn test_plane_shuffle_vec2() {
+            impl_test_plane_shuffle(2);
+        }
+        #[test]
+        fn test_plane_shuffle_vec4() {
+            impl_test_plane_shuffle(4);
+        }
+
+        fn impl_test_plane_shuffle_xor(vectorization: u8) {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_shuffle_xor::<TestRuntime, FloatType>(
+                client.clone(),
+                vectorization,
+            );
+        }
+        #[test]
+        fn test_plane_shuffle_xor_vec1() {
+            impl_test_plane_shuffle_xor(1);
+        }
+        #[test]
+        fn test_plane_shuffle_xor_vec2() {
+            impl_test_plane_shuffle_xor(2);
+        }
+        #[test]
+        fn test_plane_shuffle_xor_vec4() {
+            impl_test_plane_shuffle_xor(4);
+        }
+
+        fn impl_test_plane_shuffle_up(vectorization: u8) {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_shuffle_up::<TestRuntime, FloatType>(
+                client.clone(),
+                vectorization,
+            );
+        }
+        #[test]
+        fn test_plane_shuffle_up_vec1() {
+            impl_test_plane_shuffle_up(1);
+        }
+        #[test]
+        fn test_plane_shuffle_up_vec2() {
+            impl_test_plane_shuffle_up(2);
+        }
+        #[test]
+        fn test_plane_shuffle_up_vec4() {
+            impl_test_plane_shuffle_up(4);
+        }
+
+        fn impl_test_plane_shuffle_down(vectorization: u8) {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_shuffle_down::<TestRuntime, FloatType>(
+                client.clone(),
+                vectorization,
+            );
+        }
+        #[test]
+        fn test_plane_shuffle_down_vec1() {
+            impl_test_plane_shuffle_down(1);
+        }
+        #[test]
+        fn test_plane_shuffle_down_vec2() {
+            impl_test_plane_shuffle_down(2);
+        }
+        #[test]
+        fn test_plane_shuffle_down_vec4() {
+            impl_test_plane_shuffle_down(4);
+        }
+
         #[test]
         fn test_plane_ballot() {
             let client = TestRuntime::client(&Default::default());
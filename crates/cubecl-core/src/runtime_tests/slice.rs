@@ -60,6 +60,7 @@ pub fn test_slice_select<R: Runtime, F: Float + CubeElement>(
             ArrayArg::from_raw_parts::<F>(&input, 5, 1),
             ArrayArg::from_raw_parts::<F>(&output, 1, 1),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(output.binding());
@@ -82,6 +83,7 @@ pub fn test_slice_len<R: Runtime, F: Float + CubeElement>(
             ArrayArg::from_raw_parts::<F>(&input, 5, 1),
             ArrayArg::from_raw_parts::<u32>(&output, 1, 1),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(output.binding());
@@ -104,6 +106,7 @@ pub fn test_slice_for<R: Runtime, F: Float + CubeElement>(
             ArrayArg::from_raw_parts::<F>(&input, 5, 1),
             ArrayArg::from_raw_parts::<F>(&output, 1, 1),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(output.binding());
@@ -126,6 +129,7 @@ pub fn test_slice_mut_assign<R: Runtime, F: Float + CubeElement>(
             ArrayArg::from_raw_parts::<F>(&input, 5, 1),
             ArrayArg::from_raw_parts::<F>(&output, 1, 1),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(output.binding());
@@ -144,6 +148,7 @@ pub fn test_slice_mut_len<R: Runtime>(client: ComputeClient<R::Server, R::Channe
             CubeDim::new(1, 1, 1),
             ArrayArg::from_raw_parts::<u32>(&output, 4, 1),
         )
+        .unwrap()
     };
 
     let actual = client.read_one(output.binding());
@@ -30,7 +30,8 @@ pub fn test_sync_plane<R: Runtime>(client: ComputeClient<R::Server, R::Channel>)
         CubeCount::Static(1, 1, 1),
         CubeDim::new_2d(32, 2),
         unsafe { ArrayArg::from_raw_parts::<f32>(&handle, 2, vectorization) },
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(handle.binding());
     let actual = f32::from_bytes(&actual);
@@ -37,6 +37,7 @@ pub fn test_tensor_coordinate<R: Runtime>(client: ComputeClient<R::Server, R::Ch
                 TensorArg::from_raw_parts::<f32>(&input, &stride, &shape, line_size),
                 ArrayArg::from_raw_parts::<u32>(&output, output_size, 1),
             )
+            .unwrap()
         };
 
         let actual = client.read_one(output.binding());
@@ -133,7 +133,8 @@ pub fn test_tensormap_load<R: Runtime, F: Float + CubeElement>(
             F::as_elem_native_unchecked(),
         ),
         unsafe { ArrayArg::from_raw_parts::<F>(&out, 32 * 16, 1) },
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(out.binding());
     let actual = F::from_bytes(&actual);
@@ -178,7 +179,8 @@ pub fn test_tensormap_store<R: Runtime, F: Float + CubeElement>(
             unsafe { TensorArg::from_raw_parts::<F>(&out, &out_strides, &[64, 64], 1) },
             F::as_elem_native_unchecked(),
         ),
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(out.binding());
     let actual = F::from_bytes(&actual);
@@ -260,7 +262,8 @@ pub fn test_tensormap_load_im2col<R: Runtime, F: Float + CubeElement>(
         c as u32,
         pad_h,
         pad_w,
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(out.binding());
     let actual = F::from_bytes(&actual);
@@ -329,7 +332,8 @@ pub fn test_tensormap_metadata<R: Runtime, F: Float + CubeElement>(
             F::as_elem_native_unchecked(),
         ),
         output_2,
-    );
+    )
+    .unwrap();
 
     let actual = client.read_one(out_handle_2.binding());
     let actual = u32::from_bytes(&actual);
@@ -1,3 +1,4 @@
+use core::f32::consts::{FRAC_1_SQRT_2, FRAC_PI_2, FRAC_PI_3, FRAC_PI_6, PI};
 use std::fmt::Display;
 
 use crate::{self as cubecl, as_type};
@@ -67,7 +68,7 @@ macro_rules! test_unary_impl {
                         CubeDim::new((input.len() / $input_vectorization as usize) as u32, 1, 1),
                         ArrayArg::from_raw_parts::<$float_type>(&input_handle, input.len(), $input_vectorization),
                         ArrayArg::from_raw_parts::<$float_type>(&output_handle, $expected.len(), $out_vectorization),
-                    )
+                    ).unwrap()
                 };
 
                 assert_equals_approx::<R, $float_type>(&client, output_handle, $expected, $float_type::new(0.02));
@@ -109,7 +110,7 @@ macro_rules! test_unary_impl_int {
                         CubeDim::new((input.len() / $input_vectorization as usize) as u32, 1, 1),
                         ArrayArg::from_raw_parts::<$int_type>(&input_handle, input.len(), $input_vectorization),
                         ArrayArg::from_raw_parts::<$int_type>(&output_handle, $expected.len(), $out_vectorization),
-                    )
+                    ).unwrap()
                 };
 
                 let actual = client.read_one(output_handle.binding());
@@ -155,7 +156,7 @@ macro_rules! test_unary_impl_int_fixed {
                         CubeDim::new((input.len() / $input_vectorization as usize) as u32, 1, 1),
                         ArrayArg::from_raw_parts::<$int_type>(&input_handle, input.len(), $input_vectorization),
                         ArrayArg::from_raw_parts::<$out_type>(&output_handle, $expected.len(), $out_vectorization),
-                    )
+                    ).unwrap()
                 };
 
                 let actual = client.read_one(output_handle.binding());
@@ -259,6 +260,201 @@ test_unary_impl!(
     ]
 );
 
+test_unary_impl!(test_tan, F, F::tan, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: -1., 0., 0.5, 1.2],
+        expected: as_type![F: -1.5574, 0., 0.5463, 2.5722]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: -1., 0., 0.5, 1.2],
+        expected: as_type![F: -1.5574, 0., 0.5463, 2.5722]
+    }
+]);
+
+test_unary_impl!(test_asin, F, F::asin, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: -1., 0., 0.5, 0.9],
+        expected: as_type![F: -FRAC_PI_2, 0., FRAC_PI_6, 1.1198]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: -1., 0., 0.5, 0.9],
+        expected: as_type![F: -FRAC_PI_2, 0., FRAC_PI_6, 1.1198]
+    }
+]);
+
+test_unary_impl!(test_acos, F, F::acos, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: -1., 0., 0.5, 0.9],
+        expected: as_type![F: PI, FRAC_PI_2, FRAC_PI_3, 0.451]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: -1., 0., 0.5, 0.9],
+        expected: as_type![F: PI, FRAC_PI_2, FRAC_PI_3, 0.451]
+    }
+]);
+
+test_unary_impl!(test_atan, F, F::atan, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: -2., 0., 0.5, 4.],
+        expected: as_type![F: -1.1071, 0., 0.4636, 1.3258]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: -2., 0., 0.5, 4.],
+        expected: as_type![F: -1.1071, 0., 0.4636, 1.3258]
+    }
+]);
+
+test_unary_impl!(test_sinh, F, F::sinh, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: -2., 0., 0.5, 1.5],
+        expected: as_type![F: -3.6269, 0., 0.5211, 2.1293]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: -2., 0., 0.5, 1.5],
+        expected: as_type![F: -3.6269, 0., 0.5211, 2.1293]
+    }
+]);
+
+test_unary_impl!(test_cosh, F, F::cosh, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: -2., 0., 0.5, 1.5],
+        expected: as_type![F: 3.7622, 1., 1.1276, 2.3524]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: -2., 0., 0.5, 1.5],
+        expected: as_type![F: 3.7622, 1., 1.1276, 2.3524]
+    }
+]);
+
+test_unary_impl!(test_asinh, F, F::asinh, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: -2., 0., 0.5, 3.],
+        expected: as_type![F: -1.4436, 0., 0.4812, 1.8184]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: -2., 0., 0.5, 3.],
+        expected: as_type![F: -1.4436, 0., 0.4812, 1.8184]
+    }
+]);
+
+test_unary_impl!(test_acosh, F, F::acosh, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: 1., 1.5, 2., 4.],
+        expected: as_type![F: 0., 0.9624, 1.317, 2.0634]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: 1., 1.5, 2., 4.],
+        expected: as_type![F: 0., 0.9624, 1.317, 2.0634]
+    }
+]);
+
+test_unary_impl!(test_atanh, F, F::atanh, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: -0.5, 0., 0.25, 0.8],
+        expected: as_type![F: -0.5493, 0., 0.2554, 1.0986]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: -0.5, 0., 0.25, 0.8],
+        expected: as_type![F: -0.5493, 0., 0.2554, 1.0986]
+    }
+]);
+
+test_unary_impl!(test_exp2, F, F::exp2, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: -1., 0., 1.5, 4.],
+        expected: as_type![F: 0.5, 1., 2.8284, 16.]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: -1., 0., 1.5, 4.],
+        expected: as_type![F: 0.5, 1., 2.8284, 16.]
+    }
+]);
+
+test_unary_impl!(test_log2, F, F::log2, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: 0.5, 1., 3., 8.],
+        expected: as_type![F: -1., 0., 1.585, 3.]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: 0.5, 1., 3., 8.],
+        expected: as_type![F: -1., 0., 1.585, 3.]
+    }
+]);
+
+test_unary_impl!(test_log10, F, F::log10, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: 0.1, 1., 5., 100.],
+        expected: as_type![F: -1., 0., 0.699, 2.]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: 0.1, 1., 5., 100.],
+        expected: as_type![F: -1., 0., 0.699, 2.]
+    }
+]);
+
+test_unary_impl!(test_inverse_sqrt, F, F::inverse_sqrt, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![F: 0.25, 1., 2., 16.],
+        expected: as_type![F: 2., 1., FRAC_1_SQRT_2, 0.25]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![F: 0.25, 1., 2., 16.],
+        expected: as_type![F: 2., 1., FRAC_1_SQRT_2, 0.25]
+    }
+]);
+
 test_unary_impl_int_fixed!(test_count_ones, I, u32, I::count_ones, [
     {
         input_vectorization: 1,
@@ -379,6 +575,19 @@ macro_rules! testgen_unary {
             add_test!(test_normalize);
             add_test!(test_magnitude);
             add_test!(test_abs);
+            add_test!(test_tan);
+            add_test!(test_asin);
+            add_test!(test_acos);
+            add_test!(test_atan);
+            add_test!(test_sinh);
+            add_test!(test_cosh);
+            add_test!(test_asinh);
+            add_test!(test_acosh);
+            add_test!(test_atanh);
+            add_test!(test_exp2);
+            add_test!(test_log2);
+            add_test!(test_log10);
+            add_test!(test_inverse_sqrt);
         }
     };
 }
@@ -21,6 +21,8 @@ pub struct CudaDialect<M> {
 
 impl<M: DialectWmmaCompiler<Self>> Dialect for CudaDialect<M> {
     type Architecture = CudaArchitecture;
+
+    const NAME: &'static str = "cuda";
 }
 
 impl<M: DialectWmmaCompiler<Self>> DialectIncludes<Self> for CudaDialect<M> {
@@ -30,6 +30,8 @@ pub struct HipDialect<M> {
 
 impl<M: DialectWmmaCompiler<Self>> Dialect for HipDialect<M> {
     type Architecture = AMDArchitecture;
+
+    const NAME: &'static str = "hip";
 }
 
 // Includes
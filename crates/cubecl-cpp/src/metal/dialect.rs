@@ -18,7 +18,7 @@ use cubecl_core::{
 use super::{
     AddressSpace, Extension,
     arch::MetalArchitecture,
-    extension::{format_ffs, format_mulhi},
+    extension::{format_ffs, format_hypot, format_mulhi},
     format_erf, format_global_binding_arg, format_metal_builtin_binding_arg, format_safe_tanh,
 };
 
@@ -29,6 +29,8 @@ pub struct MslDialect {}
 
 impl Dialect for MslDialect {
     type Architecture = MetalArchitecture;
+
+    const NAME: &'static str = "metal";
 }
 
 // Includes
@@ -55,6 +57,7 @@ using namespace metal;
             match extension {
                 Extension::Erf(input, output) => format_erf::<Self>(f, input, output)?,
                 Extension::Ffs(elem) => format_ffs(f, elem)?,
+                Extension::Hypot => format_hypot(f)?,
                 Extension::MulHi(elem) => format_mulhi(f, elem)?,
                 Extension::SafeTanh(item) => format_safe_tanh::<Self>(f, item)?,
                 Extension::NoExtension => {}
@@ -99,6 +102,9 @@ using namespace metal;
                     }
                 }
             }
+            shared::Instruction::<Self>::Hypot(_) => {
+                register_extension(Extension::Hypot);
+            }
             shared::Instruction::<Self>::HiMul(instruction) => {
                 register_extension(Extension::MulHi(instruction.out.elem()));
             }
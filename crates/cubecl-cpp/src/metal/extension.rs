@@ -8,6 +8,7 @@ use crate::{
 pub enum Extension<D: Dialect> {
     Erf(Elem<D>, Elem<D>),
     Ffs(Elem<D>),
+    Hypot,
     MulHi(Elem<D>),
     SafeTanh(Item<D>),
     #[default]
@@ -81,6 +82,23 @@ uint __ffsll(ulong x) {{
     }
 }
 
+pub fn format_hypot(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    write!(
+        f,
+        "
+// Metal has no hypot, scale by the largest magnitude so the squares can't overflow
+inline float hypot(float x, float y) {{
+    x = fabs(x);
+    y = fabs(y);
+    float a = max(x, y);
+    float b = min(x, y);
+    float r = b / (a == 0.0f ? 1.0f : a);
+    return a * sqrt(1.0f + r * r);
+}}
+"
+    )
+}
+
 pub fn format_mulhi<D: Dialect>(
     f: &mut core::fmt::Formatter<'_>,
     out_elem: &Elem<D>,
@@ -3,9 +3,9 @@ use std::{collections::HashSet, fmt::Debug, num::NonZero};
 use cubecl_common::ExecutionMode;
 use cubecl_core::CubeDim;
 use cubecl_core::ir::{FloatKind, Processor, UIntKind, VariableKind};
-use cubecl_core::post_processing::checked_io::CheckedIoProcessor;
+use cubecl_core::post_processing::checked_io::{CheckedIoProcessor, register_error_buffer};
 use cubecl_core::{
-    Compiler, Feature,
+    CompilationError, CompilationErrorRecorder, Compiler, Feature,
     ir::{self as gpu},
 };
 use cubecl_core::{
@@ -91,6 +91,7 @@ pub struct CppCompiler<D: Dialect> {
     barriers: Vec<BarrierOps<D>>,
     compilation_options: CompilationOptions,
     const_arrays: Vec<ConstArray<D>>,
+    error_buffer: Option<gpu::Variable>,
     ext_meta_positions: Vec<u32>,
     cluster_dim: CubeDim,
     extensions: Vec<D::Extension>,
@@ -102,6 +103,7 @@ pub struct CppCompiler<D: Dialect> {
     shared_memories: Vec<SharedMemory<D>>,
     source_loc: Option<SourceLoc>,
     strategy: ExecutionMode,
+    errors: CompilationErrorRecorder,
 }
 
 impl<D: Dialect> Compiler for CppCompiler<D> {
@@ -113,9 +115,10 @@ impl<D: Dialect> Compiler for CppCompiler<D> {
         mut kernel: KernelDefinition,
         compilation_options: &Self::CompilationOptions,
         strategy: ExecutionMode,
-    ) -> Self::Representation {
+    ) -> Result<Self::Representation, CompilationError> {
         self.compilation_options = compilation_options.clone();
         self.strategy = strategy;
+        self.error_buffer = register_error_buffer(&mut kernel, strategy);
 
         if !self.compilation_options.supports_clusters {
             kernel.options.cluster_dim = None;
@@ -137,7 +140,10 @@ impl<D: Dialect> Compiler for CppCompiler<D> {
 }
 
 impl<D: Dialect> CppCompiler<D> {
-    fn compile_ir(mut self, mut value: KernelDefinition) -> ComputeKernel<D> {
+    fn compile_ir(
+        mut self,
+        mut value: KernelDefinition,
+    ) -> Result<ComputeKernel<D>, CompilationError> {
         self.build_metadata(&value);
 
         let instructions = self.compile_scope(&mut value.body);
@@ -191,7 +197,11 @@ impl<D: Dialect> CppCompiler<D> {
             cluster_dim = None;
         }
 
-        ComputeKernel {
+        if let Some(err) = self.errors.take(D::NAME) {
+            return Err(err);
+        }
+
+        Ok(ComputeKernel {
             tensor_maps: value.tensor_maps,
             buffers,
             scalars,
@@ -203,7 +213,7 @@ impl<D: Dialect> CppCompiler<D> {
             items: self.items,
             kernel_name: value.options.kernel_name,
             cluster_dim,
-        }
+        })
     }
 
     fn build_metadata(&mut self, value: &KernelDefinition) {
@@ -253,7 +263,8 @@ impl<D: Dialect> CppCompiler<D> {
             .collect::<Vec<_>>();
         self.const_arrays.extend(const_arrays);
 
-        let checked_io: Box<dyn Processor> = Box::new(CheckedIoProcessor::new(self.strategy));
+        let checked_io: Box<dyn Processor> =
+            Box::new(CheckedIoProcessor::new(self.strategy, self.error_buffer));
         let processing = scope.process([checked_io]);
 
         for var in processing.variables {
@@ -276,6 +287,7 @@ impl<D: Dialect> CppCompiler<D> {
         instruction: gpu::Instruction,
     ) {
         self.update_debug_loc(instructions, &instruction);
+        self.errors.set_operation(&instruction);
         let out = instruction.out;
         match instruction.operation {
             gpu::Operation::Copy(variable) => {
@@ -299,6 +311,7 @@ impl<D: Dialect> CppCompiler<D> {
                     self.flags.inst_tma = true;
                     instructions.push(Instruction::ProxySharedFence)
                 }
+                gpu::Synchronization::DeviceFence => instructions.push(Instruction::ThreadFence),
             },
             gpu::Operation::Plane(op) => {
                 self.flags.indexes.plane_dim_checked = true;
@@ -392,6 +405,34 @@ impl<D: Dialect> CppCompiler<D> {
                             out,
                         }))
                     }
+                    gpu::Plane::Shuffle(op) => {
+                        instructions.push(Instruction::Warp(WarpInstruction::Shuffle {
+                            input: self.compile_variable(op.lhs),
+                            src_lane: self.compile_variable(op.rhs),
+                            out,
+                        }))
+                    }
+                    gpu::Plane::ShuffleXor(op) => {
+                        instructions.push(Instruction::Warp(WarpInstruction::ShuffleXor {
+                            input: self.compile_variable(op.lhs),
+                            mask: self.compile_variable(op.rhs),
+                            out,
+                        }))
+                    }
+                    gpu::Plane::ShuffleUp(op) => {
+                        instructions.push(Instruction::Warp(WarpInstruction::ShuffleUp {
+                            input: self.compile_variable(op.lhs),
+                            delta: self.compile_variable(op.rhs),
+                            out,
+                        }))
+                    }
+                    gpu::Plane::ShuffleDown(op) => {
+                        instructions.push(Instruction::Warp(WarpInstruction::ShuffleDown {
+                            input: self.compile_variable(op.lhs),
+                            delta: self.compile_variable(op.rhs),
+                            out,
+                        }))
+                    }
                 }
             }
             gpu::Operation::CoopMma(cmma) => instructions.push(self.compile_cmma(cmma, out)),
@@ -757,6 +798,7 @@ impl<D: Dialect> CppCompiler<D> {
             }),
             gpu::Branch::Return => instructions.push(Instruction::Return),
             gpu::Branch::Break => instructions.push(Instruction::Break),
+            gpu::Branch::Continue => instructions.push(Instruction::Continue),
             gpu::Branch::RangeLoop(mut range_loop) => instructions.push(Instruction::RangeLoop {
                 i: self.compile_variable(range_loop.i),
                 start: self.compile_variable(range_loop.start),
@@ -852,29 +894,76 @@ impl<D: Dialect> CppCompiler<D> {
             gpu::Arithmetic::Exp(op) => {
                 instructions.push(Instruction::Exp(self.compile_unary(op, out)))
             }
+            gpu::Arithmetic::Exp2(op) => {
+                instructions.push(Instruction::Exp2(self.compile_unary(op, out)))
+            }
             gpu::Arithmetic::Log(op) => {
                 instructions.push(Instruction::Log(self.compile_unary(op, out)))
             }
             gpu::Arithmetic::Log1p(op) => {
                 instructions.push(Instruction::Log1p(self.compile_unary(op, out)))
             }
+            gpu::Arithmetic::Log2(op) => {
+                instructions.push(Instruction::Log2(self.compile_unary(op, out)))
+            }
+            gpu::Arithmetic::Log10(op) => {
+                instructions.push(Instruction::Log10(self.compile_unary(op, out)))
+            }
             gpu::Arithmetic::Cos(op) => {
                 instructions.push(Instruction::Cos(self.compile_unary(op, out)))
             }
             gpu::Arithmetic::Sin(op) => {
                 instructions.push(Instruction::Sin(self.compile_unary(op, out)))
             }
+            gpu::Arithmetic::Tan(op) => {
+                instructions.push(Instruction::Tan(self.compile_unary(op, out)))
+            }
             gpu::Arithmetic::Tanh(op) => {
                 let instruction = Instruction::Tanh(self.compile_unary(op, out));
                 D::register_instruction_extension(&mut self.extensions, &instruction);
                 instructions.push(instruction)
             }
+            gpu::Arithmetic::Sinh(op) => {
+                instructions.push(Instruction::Sinh(self.compile_unary(op, out)))
+            }
+            gpu::Arithmetic::Cosh(op) => {
+                instructions.push(Instruction::Cosh(self.compile_unary(op, out)))
+            }
+            gpu::Arithmetic::ArcCos(op) => {
+                instructions.push(Instruction::ArcCos(self.compile_unary(op, out)))
+            }
+            gpu::Arithmetic::ArcSin(op) => {
+                instructions.push(Instruction::ArcSin(self.compile_unary(op, out)))
+            }
+            gpu::Arithmetic::ArcTan(op) => {
+                instructions.push(Instruction::ArcTan(self.compile_unary(op, out)))
+            }
+            gpu::Arithmetic::ArcSinh(op) => {
+                instructions.push(Instruction::ArcSinh(self.compile_unary(op, out)))
+            }
+            gpu::Arithmetic::ArcCosh(op) => {
+                instructions.push(Instruction::ArcCosh(self.compile_unary(op, out)))
+            }
+            gpu::Arithmetic::ArcTanh(op) => {
+                instructions.push(Instruction::ArcTanh(self.compile_unary(op, out)))
+            }
+            gpu::Arithmetic::ArcTan2(op) => {
+                instructions.push(Instruction::ArcTan2(self.compile_binary(op, out)))
+            }
             gpu::Arithmetic::Powf(op) => {
                 instructions.push(Instruction::Powf(self.compile_binary(op, out)))
             }
+            gpu::Arithmetic::Hypot(op) => {
+                let instruction = Instruction::Hypot(self.compile_binary(op, out));
+                D::register_instruction_extension(&mut self.extensions, &instruction);
+                instructions.push(instruction)
+            }
             gpu::Arithmetic::Sqrt(op) => {
                 instructions.push(Instruction::Sqrt(self.compile_unary(op, out)))
             }
+            gpu::Arithmetic::InverseSqrt(op) => {
+                instructions.push(Instruction::InverseSqrt(self.compile_unary(op, out)))
+            }
             gpu::Arithmetic::Erf(op) => {
                 let instruction = Instruction::Erf(self.compile_unary(op, out));
                 D::register_instruction_extension(&mut self.extensions, &instruction);
@@ -906,7 +995,8 @@ impl<D: Dialect> CppCompiler<D> {
                     gpu::Elem::AtomicInt(_)
                     | gpu::Elem::AtomicUInt(_)
                     | gpu::Elem::AtomicFloat(_) => {
-                        panic!("Cannot use recip with atomics")
+                        self.errors.unsupported("Cannot use recip with atomics");
+                        return;
                     }
                 };
 
@@ -1463,7 +1553,11 @@ impl<D: Dialect> CppCompiler<D> {
                 gpu::FloatKind::BF16 => Elem::Atomic(AtomicKind::BF16),
                 gpu::FloatKind::F32 => Elem::Atomic(AtomicKind::F32),
                 gpu::FloatKind::F64 => Elem::Atomic(AtomicKind::F64),
-                kind => unimplemented!("atomic<{kind:?}> not yet supported"),
+                kind => {
+                    self.errors
+                        .unsupported(format!("atomic<{kind:?}> not yet supported"));
+                    Elem::Atomic(AtomicKind::F32)
+                }
             },
             gpu::Elem::Int(kind) => match kind {
                 gpu::IntKind::I8 => Elem::I8,
@@ -1474,7 +1568,11 @@ impl<D: Dialect> CppCompiler<D> {
             gpu::Elem::AtomicInt(kind) => match kind {
                 gpu::IntKind::I32 => Elem::Atomic(AtomicKind::I32),
                 gpu::IntKind::I64 => Elem::Atomic(AtomicKind::I64),
-                kind => panic!("atomic<{kind:?}> isn't supported yet"),
+                kind => {
+                    self.errors
+                        .unsupported(format!("atomic<{kind:?}> isn't supported yet"));
+                    Elem::Atomic(AtomicKind::I32)
+                }
             },
             gpu::Elem::UInt(kind) => match kind {
                 gpu::UIntKind::U8 => Elem::U8,
@@ -1485,7 +1583,11 @@ impl<D: Dialect> CppCompiler<D> {
             gpu::Elem::AtomicUInt(kind) => match kind {
                 gpu::UIntKind::U32 => Elem::Atomic(AtomicKind::U32),
                 gpu::UIntKind::U64 => Elem::Atomic(AtomicKind::U64),
-                kind => unimplemented!("atomic<{kind:?}> not yet supported"),
+                kind => {
+                    self.errors
+                        .unsupported(format!("atomic<{kind:?}> not yet supported"));
+                    Elem::Atomic(AtomicKind::U32)
+                }
             },
             gpu::Elem::Bool => Elem::Bool,
         }
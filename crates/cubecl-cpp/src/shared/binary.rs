@@ -221,6 +221,56 @@ impl<D: Dialect> Binary<D> for Powf {
     }
 }
 
+macro_rules! float_function {
+    ($name:ident, $func:expr) => {
+        pub struct $name;
+
+        impl<D: Dialect> Binary<D> for $name {
+            // No half equivalent exists, so half types are computed in single precision
+            fn format_scalar<Lhs: Display, Rhs: Display>(
+                f: &mut std::fmt::Formatter<'_>,
+                lhs: Lhs,
+                rhs: Rhs,
+                item: Item<D>,
+            ) -> std::fmt::Result {
+                let elem = item.elem;
+                match elem {
+                    Elem::F16 | Elem::F16x2 | Elem::BF16 | Elem::BF16x2 => {
+                        write!(f, "{elem}({}(float({lhs}), float({rhs})))", $func)
+                    }
+                    _ => write!(f, "{}({lhs}, {rhs})", $func),
+                }
+            }
+
+            // No half equivalent exists, so the vector can't be packed
+            fn unroll_vec(
+                f: &mut Formatter<'_>,
+                lhs: &Variable<D>,
+                rhs: &Variable<D>,
+                out: &Variable<D>,
+            ) -> core::fmt::Result {
+                let item_out = out.item();
+                let index = out.item().vectorization;
+
+                let out = out.fmt_left();
+                writeln!(f, "{out} = {item_out}{{")?;
+                for i in 0..index {
+                    let lhsi = lhs.index(i);
+                    let rhsi = rhs.index(i);
+
+                    Self::format_scalar(f, lhsi, rhsi, item_out)?;
+                    f.write_str(", ")?;
+                }
+
+                f.write_str("};\n")
+            }
+        }
+    };
+}
+
+float_function!(ArcTan2, "atan2");
+float_function!(Hypot, "hypot");
+
 pub struct Max;
 
 impl<D: Dialect> Binary<D> for Max {
@@ -31,6 +31,9 @@ pub trait Dialect:
     + 'static
 {
     type Architecture: Architecture;
+
+    /// Name of the backend, used in compilation errors.
+    const NAME: &'static str;
 }
 
 // Includes
@@ -139,6 +139,7 @@ pub enum Instruction<D: Dialect> {
     },
     Return,
     Break,
+    Continue,
     Equal(BinaryInstruction<D>),
     NotEqual(BinaryInstruction<D>),
     Lower(BinaryInstruction<D>),
@@ -158,13 +159,28 @@ pub enum Instruction<D: Dialect> {
     FindFirstSet(UnaryInstruction<D>),
     Abs(UnaryInstruction<D>),
     Exp(UnaryInstruction<D>),
+    Exp2(UnaryInstruction<D>),
     Log(UnaryInstruction<D>),
     Log1p(UnaryInstruction<D>),
+    Log2(UnaryInstruction<D>),
+    Log10(UnaryInstruction<D>),
     Cos(UnaryInstruction<D>),
     Sin(UnaryInstruction<D>),
+    Tan(UnaryInstruction<D>),
     Tanh(UnaryInstruction<D>),
+    Sinh(UnaryInstruction<D>),
+    Cosh(UnaryInstruction<D>),
+    ArcCos(UnaryInstruction<D>),
+    ArcSin(UnaryInstruction<D>),
+    ArcTan(UnaryInstruction<D>),
+    ArcSinh(UnaryInstruction<D>),
+    ArcCosh(UnaryInstruction<D>),
+    ArcTanh(UnaryInstruction<D>),
+    ArcTan2(BinaryInstruction<D>),
     Powf(BinaryInstruction<D>),
+    Hypot(BinaryInstruction<D>),
     Sqrt(UnaryInstruction<D>),
+    InverseSqrt(UnaryInstruction<D>),
     Min(BinaryInstruction<D>),
     Max(BinaryInstruction<D>),
     Not(UnaryInstruction<D>),
@@ -252,6 +268,7 @@ impl<D: Dialect> Display for Instruction<D> {
         match self {
             Instruction::Return => f.write_str("return;"),
             Instruction::Break => f.write_str("break;"),
+            Instruction::Continue => f.write_str("continue;"),
             Instruction::DeclareVariable { var } => match var {
                 Variable::WmmaFragment { .. } => D::compile_wmma_fragment_declaration(f, var),
                 _ => {
@@ -498,13 +515,28 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
             Instruction::Erf(it) => Erf::format(f, &it.input, &it.out),
             Instruction::Abs(it) => Abs::format(f, &it.input, &it.out),
             Instruction::Exp(it) => Exp::format(f, &it.input, &it.out),
+            Instruction::Exp2(it) => Exp2::format(f, &it.input, &it.out),
             Instruction::Log(it) => Log::format(f, &it.input, &it.out),
             Instruction::Log1p(it) => Log1p::format(f, &it.input, &it.out),
+            Instruction::Log2(it) => Log2::format(f, &it.input, &it.out),
+            Instruction::Log10(it) => Log10::format(f, &it.input, &it.out),
             Instruction::Cos(it) => Cos::format(f, &it.input, &it.out),
             Instruction::Sin(it) => Sin::format(f, &it.input, &it.out),
+            Instruction::Tan(it) => Tan::format(f, &it.input, &it.out),
             Instruction::Tanh(it) => Tanh::format(f, &it.input, &it.out),
+            Instruction::Sinh(it) => Sinh::format(f, &it.input, &it.out),
+            Instruction::Cosh(it) => Cosh::format(f, &it.input, &it.out),
+            Instruction::ArcCos(it) => ArcCos::format(f, &it.input, &it.out),
+            Instruction::ArcSin(it) => ArcSin::format(f, &it.input, &it.out),
+            Instruction::ArcTan(it) => ArcTan::format(f, &it.input, &it.out),
+            Instruction::ArcSinh(it) => ArcSinh::format(f, &it.input, &it.out),
+            Instruction::ArcCosh(it) => ArcCosh::format(f, &it.input, &it.out),
+            Instruction::ArcTanh(it) => ArcTanh::format(f, &it.input, &it.out),
+            Instruction::ArcTan2(it) => ArcTan2::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Powf(it) => Powf::format(f, &it.lhs, &it.rhs, &it.out),
+            Instruction::Hypot(it) => Hypot::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Sqrt(it) => Sqrt::format(f, &it.input, &it.out),
+            Instruction::InverseSqrt(it) => InverseSqrt::format(f, &it.input, &it.out),
             Instruction::Max(it) => Max::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Min(it) => Min::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Not(it) => Not::format(f, &it.input, &it.out),
@@ -519,7 +551,7 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
             } => Clamp::format(f, input, min_value, max_value, out),
             Instruction::SyncThreads => D::compile_instruction_sync_threads(f),
             Instruction::SyncWarp => D::compile_instruction_sync_warp(f),
-            Instruction::ThreadFence => f.write_str("__threadfence();\n"),
+            Instruction::ThreadFence => D::compile_instruction_thread_fence(f),
             Instruction::Round(it) => Round::format(f, &it.input, &it.out),
             Instruction::Ceil(it) => Ceil::format(f, &it.input, &it.out),
             Instruction::Floor(it) => Floor::format(f, &it.input, &it.out),
@@ -149,16 +149,29 @@ macro_rules! function {
 }
 
 function!(Log, "log");
+function!(Log2, "log2");
+function!(Log10, "log10");
 function!(Cos, "cos");
 function!(Sin, "sin");
 function!(Sqrt, "sqrt");
+function!(InverseSqrt, "rsqrt");
 function!(Exp, "exp");
+function!(Exp2, "exp2");
 function!(Ceil, "ceil");
 function!(Floor, "floor");
 function!(Round, "rint");
 
 function!(Erf, "erf", false);
 function!(Abs, "abs", false);
+function!(Tan, "tan", false);
+function!(Sinh, "sinh", false);
+function!(Cosh, "cosh", false);
+function!(ArcCos, "acos", false);
+function!(ArcSin, "asin", false);
+function!(ArcTan, "atan", false);
+function!(ArcSinh, "asinh", false);
+function!(ArcCosh, "acosh", false);
+function!(ArcTanh, "atanh", false);
 
 pub struct Log1p;
 
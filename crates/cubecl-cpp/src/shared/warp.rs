@@ -58,6 +58,26 @@ pub enum WarpInstruction<D: Dialect> {
         id: Variable<D>,
         out: Variable<D>,
     },
+    Shuffle {
+        input: Variable<D>,
+        src_lane: Variable<D>,
+        out: Variable<D>,
+    },
+    ShuffleXor {
+        input: Variable<D>,
+        mask: Variable<D>,
+        out: Variable<D>,
+    },
+    ShuffleUp {
+        input: Variable<D>,
+        delta: Variable<D>,
+        out: Variable<D>,
+    },
+    ShuffleDown {
+        input: Variable<D>,
+        delta: Variable<D>,
+        out: Variable<D>,
+    },
 }
 
 impl<D: Dialect> Display for WarpInstruction<D> {
@@ -93,6 +113,24 @@ impl<D: Dialect> Display for WarpInstruction<D> {
                 writeln!(f, ", 0, 0, 0 }};")
             }
             WarpInstruction::Broadcast { input, id, out } => reduce_broadcast(f, input, out, id),
+            WarpInstruction::Shuffle {
+                input,
+                src_lane,
+                out,
+            } => shuffle(f, input, out, |f, var| {
+                D::compile_warp_shuffle(f, var, &src_lane.to_string())
+            }),
+            WarpInstruction::ShuffleXor { input, mask, out } => shuffle(f, input, out, |f, var| {
+                D::compile_warp_shuffle_xor(f, var, input.item().elem(), &mask.to_string())
+            }),
+            WarpInstruction::ShuffleUp { input, delta, out } => shuffle(f, input, out, |f, var| {
+                D::compile_warp_shuffle_up(f, var, &delta.to_string())
+            }),
+            WarpInstruction::ShuffleDown { input, delta, out } => {
+                shuffle(f, input, out, |f, var| {
+                    D::compile_warp_shuffle_down(f, var, &delta.to_string())
+                })
+            }
             WarpInstruction::Elect { out } => write!(
                 f,
                 "
@@ -223,13 +261,25 @@ fn reduce_broadcast<D: Dialect>(
     input: &Variable<D>,
     out: &Variable<D>,
     id: &Variable<D>,
+) -> core::fmt::Result {
+    shuffle(f, input, out, |f, var| {
+        D::compile_warp_shuffle(f, var, &format!("{id}"))
+    })
+}
+
+/// Shuffle each component of `input` separately, since warp intrinsics only support scalars.
+fn shuffle<D: Dialect, S: Fn(&mut core::fmt::Formatter<'_>, &str) -> std::fmt::Result>(
+    f: &mut core::fmt::Formatter<'_>,
+    input: &Variable<D>,
+    out: &Variable<D>,
+    shuffle: S,
 ) -> core::fmt::Result {
     let out_fmt = out.fmt_left();
     write!(f, "{out_fmt} = {{ ")?;
     for i in 0..input.item().vectorization {
         let comma = if i > 0 { ", " } else { "" };
         write!(f, "{comma}")?;
-        D::compile_warp_shuffle(f, &format!("{}", input.index(i)), &format!("{id}"))?;
+        shuffle(f, &format!("{}", input.index(i)))?;
     }
     writeln!(f, " }};")
 }
@@ -8,15 +8,30 @@ use tracel_llvm::melior::{
     },
 };
 
-use crate::compute::compute_task::sync_cube;
+use crate::compute::{
+    cube::{barrier_arrive, barrier_wait, cube_end, sync_cube},
+    plane::{plane_slot, plane_slots, plane_sync},
+};
 
 pub fn register_external_function(execution_engine: &ExecutionEngine) {
     unsafe {
         execution_engine.register_symbol("printf", libc::printf as *mut ());
         execution_engine.register_symbol("sync_cube", sync_cube as *mut ());
+        execution_engine.register_symbol("plane_slot", plane_slot as *mut ());
+        execution_engine.register_symbol("plane_sync", plane_sync as *mut ());
+        execution_engine.register_symbol("plane_slots", plane_slots as *mut ());
+        execution_engine.register_symbol("barrier_arrive", barrier_arrive as *mut ());
+        execution_engine.register_symbol("barrier_wait", barrier_wait as *mut ());
+        execution_engine.register_symbol("cube_end", cube_end as *mut ());
         // This is only there to fool the execution engine to generate .so for inspection even if symbol resolution will probably not work.
         execution_engine.register_symbol("_mlir_printf", libc::printf as *mut ());
         execution_engine.register_symbol("_mlir_sync_cube", sync_cube as *mut ());
+        execution_engine.register_symbol("_mlir_plane_slot", plane_slot as *mut ());
+        execution_engine.register_symbol("_mlir_plane_sync", plane_sync as *mut ());
+        execution_engine.register_symbol("_mlir_plane_slots", plane_slots as *mut ());
+        execution_engine.register_symbol("_mlir_barrier_arrive", barrier_arrive as *mut ());
+        execution_engine.register_symbol("_mlir_barrier_wait", barrier_wait as *mut ());
+        execution_engine.register_symbol("_mlir_cube_end", cube_end as *mut ());
     }
 }
 
@@ -41,18 +56,45 @@ pub fn add_external_function_to_module<'a>(
         )],
         Location::unknown(context),
     ));
-    let func_name = StringAttribute::new(context, "sync_cube");
-    let integer_type = IntegerType::new(context, 32).into();
-    let func_type = TypeAttribute::new(FunctionType::new(context, &[integer_type], &[]).into());
-    module.body().append_operation(func::func(
-        context,
-        func_name,
-        func_type,
-        Region::new(),
-        &[(
-            Identifier::new(context, "sym_visibility"),
-            StringAttribute::new(context, "private").into(),
-        )],
-        Location::unknown(context),
-    ));
+    let pointer_type = llvm::r#type::pointer(context, 0);
+    let functions = [
+        (
+            "sync_cube",
+            FunctionType::new(context, &[integer_type], &[]),
+        ),
+        (
+            "plane_slot",
+            FunctionType::new(context, &[], &[pointer_type]),
+        ),
+        (
+            "plane_sync",
+            FunctionType::new(context, &[integer_type], &[integer_type]),
+        ),
+        (
+            "plane_slots",
+            FunctionType::new(context, &[], &[pointer_type]),
+        ),
+        (
+            "barrier_arrive",
+            FunctionType::new(context, &[integer_type, integer_type], &[]),
+        ),
+        (
+            "barrier_wait",
+            FunctionType::new(context, &[integer_type], &[]),
+        ),
+        ("cube_end", FunctionType::new(context, &[], &[])),
+    ];
+    for (name, function_type) in functions {
+        module.body().append_operation(func::func(
+            context,
+            StringAttribute::new(context, name),
+            TypeAttribute::new(function_type.into()),
+            Region::new(),
+            &[(
+                Identifier::new(context, "sym_visibility"),
+                StringAttribute::new(context, "private").into(),
+            )],
+            Location::unknown(context),
+        ));
+    }
 }
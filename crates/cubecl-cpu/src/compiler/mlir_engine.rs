@@ -1,7 +1,8 @@
 use crate::compiler::mlir_data::MlirData;
 
 use super::{
-    external_function::register_external_function, passes::shared_memories::SharedMemories,
+    external_function::register_external_function,
+    passes::{concurrency::concurrent_units, shared_memories::SharedMemories},
 };
 use cubecl_opt::Optimizer;
 
@@ -21,6 +22,8 @@ use tracel_llvm::melior::{
 pub struct MlirKernel {
     execution_engine: ExecutionEngine,
     pub shared_memories: SharedMemories,
+    /// Whether the units of a cube have to run concurrently.
+    pub concurrent_units: bool,
 }
 
 #[derive(Clone)]
@@ -65,6 +68,7 @@ impl MlirEngine {
         let kernel = MlirKernel {
             execution_engine,
             shared_memories,
+            concurrent_units: concurrent_units(opt),
         };
         let mlir_kernel = Arc::new(kernel);
         Self(mlir_kernel)
@@ -11,14 +11,15 @@ use passes::shared_memories::SharedMemories;
 pub use visitor::elem::register_supported_types;
 
 use cubecl_core::{
-    Compiler, ExecutionMode,
+    CompilationError, Compiler, ExecutionMode,
     ir::{self},
+    post_processing::checked_io::register_error_buffer,
     prelude::KernelDefinition,
 };
 use cubecl_opt::OptimizerBuilder;
 use mlir_engine::MlirEngine;
 
-use crate::compiler::passes::erf_transform::ErfTransform;
+use crate::compiler::passes::{erf_transform::ErfTransform, math_transform::MathTransform};
 
 #[derive(Clone, Debug, Default)]
 pub struct MlirCompiler {}
@@ -33,14 +34,17 @@ impl Compiler for MlirCompiler {
 
     fn compile(
         &mut self,
-        kernel: KernelDefinition,
+        mut kernel: KernelDefinition,
         _compilation_options: &Self::CompilationOptions, // TODO pass this through the visitor, though it doesn't need anything for the moment
-        mode: ExecutionMode, // TODO support this by adding array bound checking
-    ) -> Self::Representation {
+        mode: ExecutionMode,
+    ) -> Result<Self::Representation, CompilationError> {
         #[cfg(feature = "mlir-dump")]
         dump_scope(&kernel.body);
+        let error_buffer = register_error_buffer(&mut kernel, mode);
         let opt = OptimizerBuilder::default()
             .with_transformer(ErfTransform)
+            .with_transformer(MathTransform)
+            .with_error_buffer(error_buffer)
             .optimize(kernel.body.clone(), kernel.cube_dim, mode);
 
         let mut shared_memories = SharedMemories::default();
@@ -48,7 +52,7 @@ impl Compiler for MlirCompiler {
 
         #[cfg(feature = "mlir-dump")]
         dump_opt(&opt);
-        MlirEngine::from_cubecl_ir(kernel, &opt, shared_memories)
+        Ok(MlirEngine::from_cubecl_ir(kernel, &opt, shared_memories))
     }
 
     fn elem_size(&self, elem: ir::Elem) -> usize {
@@ -0,0 +1,50 @@
+use cubecl_core::ir::{BarrierLevel, BarrierOps, Operation, Synchronization, VariableKind};
+use cubecl_opt::Optimizer;
+
+/// Whether the units of a cube have to be executed concurrently, either because they synchronize
+/// or because they exchange values within their plane.
+pub fn concurrent_units(opt: &Optimizer) -> bool {
+    opt.node_ids().into_iter().any(|node| {
+        opt.block(node)
+            .ops
+            .borrow()
+            .values()
+            .any(|inst| requires_concurrency(&inst.operation))
+    })
+}
+
+fn requires_concurrency(operation: &Operation) -> bool {
+    match operation {
+        // Matrix fragments are held by the first unit of the plane, see `visit_cmma`.
+        Operation::Plane(_)
+        | Operation::CoopMma(_)
+        | Operation::Synchronization(Synchronization::SyncPlane | Synchronization::SyncCube) => {
+            true
+        }
+        // Manual barriers wait on the arrivals of the other units, cooperative ones synchronize
+        // the cube when waiting.
+        Operation::Barrier(
+            BarrierOps::Arrive { barrier } | BarrierOps::ArriveTx { barrier, .. },
+        ) => {
+            matches!(
+                barrier.kind,
+                VariableKind::Barrier {
+                    level: BarrierLevel::CubeManual(_),
+                    ..
+                }
+            )
+        }
+        Operation::Barrier(
+            BarrierOps::Wait { barrier } | BarrierOps::ArriveAndWait { barrier },
+        ) => {
+            matches!(
+                barrier.kind,
+                VariableKind::Barrier {
+                    level: BarrierLevel::CubeCoop(_) | BarrierLevel::CubeManual(_),
+                    ..
+                }
+            )
+        }
+        _ => false,
+    }
+}
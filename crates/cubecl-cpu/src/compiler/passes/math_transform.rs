@@ -0,0 +1,27 @@
+use cubecl_core::{
+    ir::{Arithmetic, Instruction, Operation, Scope},
+    prelude::*,
+};
+use cubecl_opt::{IrTransformer, TransformAction};
+
+/// Expand math functions that have no LLVM intrinsic
+#[derive(Debug)]
+pub(crate) struct MathTransform;
+
+impl IrTransformer for MathTransform {
+    fn maybe_transform(&self, scope: &mut Scope, inst: &Instruction) -> TransformAction {
+        let Operation::Arithmetic(op) = &inst.operation else {
+            return TransformAction::Ignore;
+        };
+        let mut scope = scope.child();
+        match op {
+            Arithmetic::ArcSinh(op) => expand_asinh(&mut scope, op.input, inst.out()),
+            Arithmetic::ArcCosh(op) => expand_acosh(&mut scope, op.input, inst.out()),
+            Arithmetic::ArcTanh(op) => expand_atanh(&mut scope, op.input, inst.out()),
+            Arithmetic::ArcTan2(op) => expand_atan2(&mut scope, op.lhs, op.rhs, inst.out()),
+            Arithmetic::Hypot(op) => expand_hypot(&mut scope, op.lhs, op.rhs, inst.out()),
+            _ => return TransformAction::Ignore,
+        }
+        TransformAction::Replace(scope.process([]).instructions)
+    }
+}
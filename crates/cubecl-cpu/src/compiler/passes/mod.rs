@@ -1,2 +1,4 @@
+pub mod concurrency;
 pub mod erf_transform;
+pub mod math_transform;
 pub mod shared_memories;
@@ -6,12 +6,18 @@ use cubecl_core::{
     ir::{Builtin, Elem},
     prelude::KernelDefinition,
 };
-use tracel_llvm::melior::ir::{
-    Block, BlockRef, Location, Region,
-    r#type::{FunctionType, IntegerType, MemRefType},
+use tracel_llvm::melior::{
+    dialect::arith,
+    ir::{
+        Block, BlockRef, Location, Region,
+        r#type::{FunctionType, IntegerType, MemRefType},
+    },
 };
 
-use crate::compiler::{builtin::BuiltinArray, passes::shared_memories::SharedMemories};
+use crate::{
+    compiler::{builtin::BuiltinArray, passes::shared_memories::SharedMemories},
+    compute::plane::PLANE_SIZE,
+};
 
 use super::prelude::*;
 
@@ -184,6 +190,7 @@ impl<'a> ArgsManager<'a> {
     pub fn compute_derived_args_builtin(
         &mut self,
         block: BlockRef<'a, 'a>,
+        context: &'a Context,
         location: Location<'a>,
     ) {
         let cube_dim_xy = block
@@ -217,6 +224,16 @@ impl<'a> ArgsManager<'a> {
             .addi(unit_pos_xy_corrected, self.get(Builtin::UnitPosX), location)
             .unwrap();
         self.set(Builtin::UnitPos, unit_pos);
+
+        let integer_type = IntegerType::new(context, 32).into();
+        let plane_dim = block
+            .const_int_from_type(context, location, PLANE_SIZE as i64, integer_type)
+            .unwrap();
+        self.set(Builtin::PlaneDim, plane_dim);
+        let unit_pos_plane = block
+            .append_op_result(arith::remui(unit_pos, plane_dim, location))
+            .unwrap();
+        self.set(Builtin::UnitPosPlane, unit_pos_plane);
     }
 
     pub fn set(&mut self, builtin: Builtin, value: Value<'a, 'a>) {
@@ -1,5 +1,5 @@
 use cubecl_core::{
-    Feature,
+    AtomicFeature, Feature,
     ir::{Elem, FloatKind, IntKind, UIntKind},
 };
 use cubecl_runtime::DeviceProperties;
@@ -26,6 +26,9 @@ impl IntoType for Elem {
             Elem::UInt(UIntKind::U32) => IntegerType::new(context, 32).into(),
             Elem::UInt(UIntKind::U64) => IntegerType::new(context, 64).into(),
             Elem::Bool => IntegerType::new(context, 1).into(),
+            Elem::AtomicFloat(kind) => Elem::Float(kind).to_type(context),
+            Elem::AtomicInt(kind) => Elem::Int(kind).to_type(context),
+            Elem::AtomicUInt(kind) => Elem::UInt(kind).to_type(context),
             _ => todo!("This type is not implemented yet."),
         }
     }
@@ -69,10 +72,12 @@ pub fn register_supported_types(props: &mut DeviceProperties<Feature>) {
         Elem::Int(IntKind::I16),
         Elem::Int(IntKind::I32),
         Elem::Int(IntKind::I64),
-        // Elem::AtomicInt(IntKind::I32),
-        // Elem::AtomicInt(IntKind::I64),
-        // Elem::AtomicUInt(UIntKind::U32),
-        // Elem::AtomicUInt(UIntKind::U64),
+        Elem::AtomicInt(IntKind::I32),
+        Elem::AtomicInt(IntKind::I64),
+        Elem::AtomicUInt(UIntKind::U32),
+        Elem::AtomicUInt(UIntKind::U64),
+        Elem::AtomicFloat(FloatKind::F32),
+        Elem::AtomicFloat(FloatKind::F64),
         Elem::Float(FloatKind::BF16),
         Elem::Float(FloatKind::F16),
         Elem::Float(FloatKind::F32),
@@ -83,4 +88,16 @@ pub fn register_supported_types(props: &mut DeviceProperties<Feature>) {
     for ty in supported_types {
         props.register_feature(Feature::Type(ty));
     }
+
+    // Atomics are lowered to LLVM atomic instructions, which support every operation natively or
+    // through a compare-exchange loop.
+    for feature in [
+        AtomicFeature::LoadStore,
+        AtomicFeature::Add,
+        AtomicFeature::MinMax,
+    ] {
+        props.register_feature(Feature::AtomicInt(feature));
+        props.register_feature(Feature::AtomicUInt(feature));
+        props.register_feature(Feature::AtomicFloat(feature));
+    }
 }
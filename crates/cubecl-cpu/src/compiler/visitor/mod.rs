@@ -26,7 +26,9 @@ use tracel_llvm::melior::{
     },
     ir::{
         Attribute, Block, BlockRef, Identifier, Location, Module, Operation, Region, RegionRef,
-        attribute::{DenseElementsAttribute, StringAttribute, TypeAttribute},
+        attribute::{
+            DenseElementsAttribute, FlatSymbolRefAttribute, StringAttribute, TypeAttribute,
+        },
         r#type::{IntegerType, MemRefType, RankedTensorType},
     },
 };
@@ -35,11 +37,14 @@ use prelude::*;
 use variables::Variables;
 
 use super::{
-    external_function::add_external_function_to_module, passes::shared_memories::SharedMemories,
+    external_function::add_external_function_to_module,
+    passes::{concurrency::concurrent_units, shared_memories::SharedMemories},
 };
 
 pub struct Visitor<'a> {
     pub block: BlockRef<'a, 'a>,
+    /// Top block of the kernel function, outside of the loops over the cubes.
+    pub function_block: BlockRef<'a, 'a>,
     pub last_block: BlockRef<'a, 'a>,
     pub module: &'a Module<'a>,
     pub blocks: HashMap<NodeIndex, BlockRef<'a, 'a>>,
@@ -49,6 +54,8 @@ pub struct Visitor<'a> {
     pub location: Location<'a>,
 
     pub str_counter: usize,
+    /// Identifies the plane operations of the kernel, to group the units that reach the same one.
+    pub plane_sync_counter: u32,
 
     pub(self) variables: Variables<'a>,
     pub(self) args_manager: ArgsManager<'a>,
@@ -58,6 +65,7 @@ impl<'a> Visitor<'a> {
     #[allow(clippy::too_many_arguments)]
     pub(self) fn new(
         current_block: BlockRef<'a, 'a>,
+        function_block: BlockRef<'a, 'a>,
         last_block: BlockRef<'a, 'a>,
         module: &'a Module<'a>,
         current_region: RegionRef<'a, 'a>,
@@ -69,10 +77,12 @@ impl<'a> Visitor<'a> {
         let blocks = HashMap::new();
         let blocks_args = HashMap::new();
         let str_counter = 0;
+        let plane_sync_counter = 0;
         let variables = Variables::new(opt);
 
         Self {
             block: current_block,
+            function_block,
             last_block,
             module,
             blocks,
@@ -81,6 +91,7 @@ impl<'a> Visitor<'a> {
             context,
             location,
             str_counter,
+            plane_sync_counter,
             args_manager,
             variables,
         }
@@ -219,11 +230,12 @@ impl<'a> Visitor<'a> {
         mut args: ArgsManager<'a>,
     ) -> Result<(), Error> {
         let basic_block_id = opt.entry();
+        let function_block = block;
         let integer_type = IntegerType::new(context, 32).into();
         let start = block.const_int_from_type(context, location, 0, integer_type)?;
         let step = block.const_int_from_type(context, location, 1, integer_type)?;
 
-        args.compute_derived_args_builtin(block, location);
+        args.compute_derived_args_builtin(block, context, location);
 
         let cube_count_dim_x = block.muli(
             args.get(Builtin::CubeCountX),
@@ -350,11 +362,21 @@ impl<'a> Visitor<'a> {
                                 let current_region = ops.region(0)?;
 
                                 let last_block = Block::new(&[]);
+                                if concurrent_units(opt) {
+                                    last_block.append_operation(func::call(
+                                        context,
+                                        FlatSymbolRefAttribute::new(context, "cube_end"),
+                                        &[],
+                                        &[],
+                                        location,
+                                    ));
+                                }
                                 last_block.append_operation(scf::r#yield(&[], location));
                                 let last_block = current_region.append_block(last_block);
 
                                 let mut visitor = Visitor::new(
                                     current_block,
+                                    function_block,
                                     last_block,
                                     module,
                                     current_region,
@@ -25,6 +25,33 @@ impl<'a> Visitor<'a> {
                 let result = self.append_operation_with_result(operation);
                 self.insert_variable(out, result);
             }
+            Arithmetic::ArcCos(acos) => {
+                let value = self.get_variable(acos.input);
+                let result = self.append_operation_with_result(llvm_ods::intr_acos(
+                    self.context,
+                    value,
+                    self.location,
+                ));
+                self.insert_variable(out, result);
+            }
+            Arithmetic::ArcSin(asin) => {
+                let value = self.get_variable(asin.input);
+                let result = self.append_operation_with_result(llvm_ods::intr_asin(
+                    self.context,
+                    value,
+                    self.location,
+                ));
+                self.insert_variable(out, result);
+            }
+            Arithmetic::ArcTan(atan) => {
+                let value = self.get_variable(atan.input);
+                let result = self.append_operation_with_result(llvm_ods::intr_atan(
+                    self.context,
+                    value,
+                    self.location,
+                ));
+                self.insert_variable(out, result);
+            }
             Arithmetic::Ceil(ceil) => {
                 let value = self.get_variable(ceil.input);
                 let result = self.append_operation_with_result(llvm_ods::intr_ceil(
@@ -66,6 +93,15 @@ impl<'a> Visitor<'a> {
                 ));
                 self.insert_variable(out, result);
             }
+            Arithmetic::Cosh(cosh) => {
+                let value = self.get_variable(cosh.input);
+                let result = self.append_operation_with_result(llvm_ods::intr_cosh(
+                    self.context,
+                    value,
+                    self.location,
+                ));
+                self.insert_variable(out, result);
+            }
             Arithmetic::Div(div) => {
                 let (lhs, rhs) = self.get_binary_op_variable(div.lhs, div.rhs);
                 let operation = if div.lhs.elem().is_signed_int() {
@@ -101,7 +137,12 @@ impl<'a> Visitor<'a> {
                 }
                 self.insert_variable(out, operation);
             }
-            Arithmetic::Erf(_) => {
+            Arithmetic::Erf(_)
+            | Arithmetic::ArcSinh(_)
+            | Arithmetic::ArcCosh(_)
+            | Arithmetic::ArcTanh(_)
+            | Arithmetic::ArcTan2(_)
+            | Arithmetic::Hypot(_) => {
                 unreachable!("Should have been transformed in primitive in a previous passe");
             }
             Arithmetic::Exp(exp) => {
@@ -113,6 +154,15 @@ impl<'a> Visitor<'a> {
                 ));
                 self.insert_variable(out, result);
             }
+            Arithmetic::Exp2(exp) => {
+                let value = self.get_variable(exp.input);
+                let result = self.append_operation_with_result(llvm_ods::intr_exp2(
+                    self.context,
+                    value,
+                    self.location,
+                ));
+                self.insert_variable(out, result);
+            }
             Arithmetic::Floor(floor) => {
                 let value = self.get_variable(floor.input);
                 let result = self.append_operation_with_result(llvm_ods::intr_floor(
@@ -138,6 +188,18 @@ impl<'a> Visitor<'a> {
                 ));
                 self.insert_variable(out, result);
             }
+            Arithmetic::InverseSqrt(inverse_sqrt) => {
+                let value = self.get_variable(inverse_sqrt.input);
+                let one = self.create_float_constant_from_item(inverse_sqrt.input.item, 1.0);
+                let sqrt = self.append_operation_with_result(llvm_ods::intr_sqrt(
+                    self.context,
+                    value,
+                    self.location,
+                ));
+                let result =
+                    self.append_operation_with_result(arith::divf(one, sqrt, self.location));
+                self.insert_variable(out, result);
+            }
             Arithmetic::Log(log) => {
                 let value = self.get_variable(log.input);
                 let result = self.append_operation_with_result(llvm_ods::intr_log(
@@ -147,6 +209,24 @@ impl<'a> Visitor<'a> {
                 ));
                 self.insert_variable(out, result);
             }
+            Arithmetic::Log2(log) => {
+                let value = self.get_variable(log.input);
+                let result = self.append_operation_with_result(llvm_ods::intr_log2(
+                    self.context,
+                    value,
+                    self.location,
+                ));
+                self.insert_variable(out, result);
+            }
+            Arithmetic::Log10(log) => {
+                let value = self.get_variable(log.input);
+                let result = self.append_operation_with_result(llvm_ods::intr_log10(
+                    self.context,
+                    value,
+                    self.location,
+                ));
+                self.insert_variable(out, result);
+            }
             Arithmetic::Log1p(log) => {
                 let value = self.get_variable(log.input);
                 let one = self.create_float_constant_from_item(log.input.item, 1.0);
@@ -343,6 +423,15 @@ impl<'a> Visitor<'a> {
                 ));
                 self.insert_variable(out, output);
             }
+            Arithmetic::Sinh(sinh) => {
+                let input = self.get_variable(sinh.input);
+                let output = self.append_operation_with_result(llvm_ods::intr_sinh(
+                    self.context,
+                    input,
+                    self.location,
+                ));
+                self.insert_variable(out, output);
+            }
             Arithmetic::Sqrt(sqrt) => {
                 let input = self.get_variable(sqrt.input);
                 let output = self.append_operation_with_result(llvm_ods::intr_sqrt(
@@ -362,6 +451,15 @@ impl<'a> Visitor<'a> {
                 let result = self.append_operation_with_result(operation);
                 self.insert_variable(out, result);
             }
+            Arithmetic::Tan(tan) => {
+                let input = self.get_variable(tan.input);
+                let output = self.append_operation_with_result(llvm_ods::intr_tan(
+                    self.context,
+                    input,
+                    self.location,
+                ));
+                self.insert_variable(out, output);
+            }
             Arithmetic::Tanh(tanh) => {
                 let input = self.get_variable(tanh.input);
                 let output = self.append_operation_with_result(llvm_ods::intr_tanh(
@@ -0,0 +1,257 @@
+use cubecl_core::ir::{AtomicOp, BinaryOperator, Elem};
+use tracel_llvm::melior::{
+    dialect::{index, llvm},
+    ir::{
+        Attribute, Identifier,
+        attribute::{DenseI64ArrayAttribute, IntegerAttribute},
+        operation::{Operation, OperationBuilder},
+        r#type::IntegerType,
+    },
+};
+
+use crate::compiler::visitor::prelude::*;
+
+/// Mirror of `llvm::AtomicRMWInst::BinOp`, stored as an `i64` attribute by the LLVM dialect.
+#[derive(Clone, Copy)]
+enum AtomicBinOp {
+    Xchg = 0,
+    Add = 1,
+    Sub = 2,
+    And = 3,
+    Or = 5,
+    Xor = 6,
+    Max = 7,
+    Min = 8,
+    UMax = 9,
+    UMin = 10,
+    FAdd = 11,
+    FSub = 12,
+    FMax = 13,
+    FMin = 14,
+}
+
+/// `llvm::AtomicOrdering::Monotonic`, the equivalent of the relaxed ordering used by GPU targets.
+const ORDERING_MONOTONIC: i64 = 2;
+
+impl<'a> Visitor<'a> {
+    pub fn visit_atomic(&mut self, atomic: &AtomicOp, out: Variable) {
+        match atomic {
+            AtomicOp::Load(load) => {
+                let pointer = self.get_atomic_pointer(load.input);
+                let operation = OperationBuilder::new("llvm.load", self.location)
+                    .add_operands(&[pointer])
+                    .add_attributes(&self.atomic_access_attributes(load.input.elem()))
+                    .add_results(&[out.elem().to_type(self.context)])
+                    .build()
+                    .unwrap();
+                let value = self.append_operation_with_result(operation);
+                self.insert_variable(out, value);
+            }
+            AtomicOp::Store(store) => {
+                let pointer = self.get_atomic_pointer(out);
+                let value = self.get_variable(store.input);
+                let operation = OperationBuilder::new("llvm.store", self.location)
+                    .add_operands(&[value, pointer])
+                    .add_attributes(&self.atomic_access_attributes(out.elem()))
+                    .build()
+                    .unwrap();
+                self.block.append_operation(operation);
+            }
+            AtomicOp::Swap(swap) => {
+                self.visit_atomic_rmw(AtomicBinOp::Xchg, swap, out);
+            }
+            AtomicOp::Add(add) => {
+                let bin_op = match add.lhs.elem().is_float() {
+                    true => AtomicBinOp::FAdd,
+                    false => AtomicBinOp::Add,
+                };
+                self.visit_atomic_rmw(bin_op, add, out);
+            }
+            AtomicOp::Sub(sub) => {
+                let bin_op = match sub.lhs.elem().is_float() {
+                    true => AtomicBinOp::FSub,
+                    false => AtomicBinOp::Sub,
+                };
+                self.visit_atomic_rmw(bin_op, sub, out);
+            }
+            AtomicOp::Max(max) => {
+                let elem = max.lhs.elem();
+                let bin_op = if elem.is_float() {
+                    AtomicBinOp::FMax
+                } else if elem.is_signed_int() {
+                    AtomicBinOp::Max
+                } else {
+                    AtomicBinOp::UMax
+                };
+                self.visit_atomic_rmw(bin_op, max, out);
+            }
+            AtomicOp::Min(min) => {
+                let elem = min.lhs.elem();
+                let bin_op = if elem.is_float() {
+                    AtomicBinOp::FMin
+                } else if elem.is_signed_int() {
+                    AtomicBinOp::Min
+                } else {
+                    AtomicBinOp::UMin
+                };
+                self.visit_atomic_rmw(bin_op, min, out);
+            }
+            AtomicOp::And(and) => {
+                self.visit_atomic_rmw(AtomicBinOp::And, and, out);
+            }
+            AtomicOp::Or(or) => {
+                self.visit_atomic_rmw(AtomicBinOp::Or, or, out);
+            }
+            AtomicOp::Xor(xor) => {
+                self.visit_atomic_rmw(AtomicBinOp::Xor, xor, out);
+            }
+            AtomicOp::CompareAndSwap(cas) => {
+                assert!(
+                    !cas.input.elem().is_float(),
+                    "Compare and swap doesn't support float atomics"
+                );
+                let pointer = self.get_atomic_pointer(cas.input);
+                let cmp = self.get_variable(cas.cmp);
+                let val = self.get_variable(cas.val);
+                let value_type = out.elem().to_type(self.context);
+                let result_type = llvm::r#type::r#struct(
+                    self.context,
+                    &[value_type, IntegerType::new(self.context, 1).into()],
+                    false,
+                );
+                let ordering = self.integer_attribute(ORDERING_MONOTONIC);
+                let operation = OperationBuilder::new("llvm.cmpxchg", self.location)
+                    .add_operands(&[pointer, cmp, val])
+                    .add_attributes(&[
+                        (Identifier::new(self.context, "success_ordering"), ordering),
+                        (Identifier::new(self.context, "failure_ordering"), ordering),
+                    ])
+                    .add_results(&[result_type])
+                    .build()
+                    .unwrap();
+                let result = self.append_operation_with_result(operation);
+                // cmpxchg returns `{ old_value, success }`, only the old value is exposed.
+                let value = self.append_operation_with_result(llvm::extract_value(
+                    self.context,
+                    result,
+                    DenseI64ArrayAttribute::new(self.context, &[0]),
+                    value_type,
+                    self.location,
+                ));
+                self.insert_variable(out, value);
+            }
+        }
+    }
+
+    /// Get a pointer to the element referenced by an atomic variable.
+    ///
+    /// Indexing into an atomic array yields a pointer instead of a loaded value, so the atomic
+    /// operations can then act on the memory directly.
+    pub fn get_atomic_pointer(&self, variable: Variable) -> Value<'a, 'a> {
+        assert!(
+            !variable.item.is_vectorized(),
+            "Vectorized atomics are not supported on CPU"
+        );
+        match self.is_memory(variable) {
+            true => {
+                let memref = self.get_memory(variable);
+                let zero = self
+                    .block
+                    .const_int_from_type(self.context, self.location, 0, Type::index(self.context))
+                    .unwrap();
+                self.get_element_pointer(memref, zero, variable.elem())
+            }
+            false => self.get_variable(variable),
+        }
+    }
+
+    /// Compute the address of `memref[index]` as an LLVM pointer.
+    pub fn get_element_pointer(
+        &self,
+        memref: Value<'a, 'a>,
+        index: Value<'a, 'a>,
+        elem: Elem,
+    ) -> Value<'a, 'a> {
+        let integer_type = IntegerType::new(self.context, 64).into();
+        let pointer_type = llvm::r#type::pointer(self.context, 0);
+
+        // The CPU runtime always passes memrefs with a zero offset, so the aligned pointer is the
+        // start of the buffer.
+        let base = self.append_operation_with_result(
+            OperationBuilder::new("memref.extract_aligned_pointer_as_index", self.location)
+                .add_operands(&[memref])
+                .add_results(&[Type::index(self.context)])
+                .build()
+                .unwrap(),
+        );
+        let base =
+            self.append_operation_with_result(index::casts(base, integer_type, self.location));
+        let base = self.append_operation_with_result(
+            OperationBuilder::new("llvm.inttoptr", self.location)
+                .add_operands(&[base])
+                .add_results(&[pointer_type])
+                .build()
+                .unwrap(),
+        );
+        let index =
+            self.append_operation_with_result(index::casts(index, integer_type, self.location));
+        self.append_operation_with_result(llvm::get_element_ptr_dynamic(
+            self.context,
+            base,
+            &[index],
+            elem.to_type(self.context),
+            pointer_type,
+            self.location,
+        ))
+    }
+
+    fn visit_atomic_rmw(&mut self, bin_op: AtomicBinOp, op: &BinaryOperator, out: Variable) {
+        let pointer = self.get_atomic_pointer(op.lhs);
+        let value = self.get_variable(op.rhs);
+        let operation = self.atomic_rmw(bin_op, pointer, value, out.elem());
+        let result = self.append_operation_with_result(operation);
+        self.insert_variable(out, result);
+    }
+
+    fn atomic_rmw(
+        &self,
+        bin_op: AtomicBinOp,
+        pointer: Value<'a, 'a>,
+        value: Value<'a, 'a>,
+        elem: Elem,
+    ) -> Operation<'a> {
+        OperationBuilder::new("llvm.atomicrmw", self.location)
+            .add_operands(&[pointer, value])
+            .add_attributes(&[
+                (
+                    Identifier::new(self.context, "bin_op"),
+                    self.integer_attribute(bin_op as i64),
+                ),
+                (
+                    Identifier::new(self.context, "ordering"),
+                    self.integer_attribute(ORDERING_MONOTONIC),
+                ),
+            ])
+            .add_results(&[elem.to_type(self.context)])
+            .build()
+            .unwrap()
+    }
+
+    /// Atomic loads and stores need an explicit alignment in the LLVM dialect.
+    fn atomic_access_attributes(&self, elem: Elem) -> [(Identifier<'a>, Attribute<'a>); 2] {
+        [
+            (
+                Identifier::new(self.context, "ordering"),
+                self.integer_attribute(ORDERING_MONOTONIC),
+            ),
+            (
+                Identifier::new(self.context, "alignment"),
+                self.integer_attribute(elem.size() as i64),
+            ),
+        ]
+    }
+
+    fn integer_attribute(&self, value: i64) -> Attribute<'a> {
+        IntegerAttribute::new(IntegerType::new(self.context, 64).into(), value).into()
+    }
+}
@@ -0,0 +1,162 @@
+use cubecl_core::ir::{BarrierLevel, BarrierOps, VariableKind};
+use tracel_llvm::melior::{
+    dialect::{arith, func},
+    ir::{
+        Identifier,
+        attribute::{FlatSymbolRefAttribute, IntegerAttribute},
+        operation::OperationBuilder,
+        r#type::IntegerType,
+    },
+};
+
+use crate::compiler::visitor::prelude::*;
+
+impl<'a> Visitor<'a> {
+    /// Barriers are emulated with synchronous copies: every `memcpy_async` has completed by the
+    /// time it returns, so only the arrivals and waits of cube level barriers need to synchronize
+    /// units. Cooperative barriers synchronize the whole cube, while manual barriers complete
+    /// once they received an arrival per unit, like they are initialized on CUDA.
+    pub fn visit_barrier(&mut self, barrier_ops: &BarrierOps, out: Option<Variable>) {
+        match barrier_ops {
+            BarrierOps::Init { .. } => {}
+            BarrierOps::MemCopyAsync {
+                source,
+                source_length,
+                offset_source,
+                offset_out,
+                ..
+            } => {
+                let out = out.expect("Memcpy async should have an out");
+                let source_index = self.get_index(*offset_source, source.item);
+                let out_index = self.get_index(*offset_out, out.item);
+                let length = self.get_variable(*source_length);
+                self.copy_lines(*source, source_index, out, out_index, length);
+            }
+            BarrierOps::TmaLoad { .. } | BarrierOps::TmaLoadIm2col { .. } => {
+                panic!("{barrier_ops} is not supported on CPU.");
+            }
+            BarrierOps::ExpectTx { .. } => {}
+            BarrierOps::Arrive { barrier } => {
+                if let Some(id) = manual_barrier_id(barrier) {
+                    let count = self.const_u32(1);
+                    self.append_barrier_arrive(id, count);
+                }
+            }
+            BarrierOps::ArriveTx {
+                barrier,
+                arrive_count_update,
+                ..
+            } => {
+                if let Some(id) = manual_barrier_id(barrier) {
+                    let count = self.get_variable(*arrive_count_update);
+                    self.append_barrier_arrive(id, count);
+                }
+            }
+            BarrierOps::Wait { barrier } => match barrier_level(barrier) {
+                BarrierLevel::Unit => {}
+                BarrierLevel::CubeCoop(_) => self.append_sync_cube(),
+                BarrierLevel::CubeManual(_) => {
+                    let id = manual_barrier_id(barrier).unwrap();
+                    self.append_barrier_wait(id);
+                }
+            },
+            BarrierOps::ArriveAndWait { barrier } => match barrier_level(barrier) {
+                BarrierLevel::Unit => {}
+                BarrierLevel::CubeCoop(_) => self.append_sync_cube(),
+                BarrierLevel::CubeManual(_) => {
+                    let id = manual_barrier_id(barrier).unwrap();
+                    let count = self.const_u32(1);
+                    self.append_barrier_arrive(id, count);
+                    self.append_barrier_wait(id);
+                }
+            },
+        }
+    }
+
+    fn append_barrier_arrive(&mut self, id: u32, count: Value<'a, 'a>) {
+        let id = self.const_u32(id);
+        self.block.append_operation(func::call(
+            self.context,
+            FlatSymbolRefAttribute::new(self.context, "barrier_arrive"),
+            &[id, count],
+            &[],
+            self.location,
+        ));
+    }
+
+    fn append_barrier_wait(&mut self, id: u32) {
+        let id = self.const_u32(id);
+        self.block.append_operation(func::call(
+            self.context,
+            FlatSymbolRefAttribute::new(self.context, "barrier_wait"),
+            &[id],
+            &[],
+            self.location,
+        ));
+    }
+
+    fn const_u32(&self, value: u32) -> Value<'a, 'a> {
+        let integer_type = IntegerType::new(self.context, 32).into();
+        self.block
+            .const_int_from_type(self.context, self.location, value as i64, integer_type)
+            .unwrap()
+    }
+
+    /// Copy `length` lines from `source[source_index..]` to `out[out_index..]`, where both
+    /// indices are already scaled to scalar elements.
+    pub fn copy_lines(
+        &mut self,
+        source: Variable,
+        source_index: Value<'a, 'a>,
+        out: Variable,
+        out_index: Value<'a, 'a>,
+        length: Value<'a, 'a>,
+    ) {
+        let integer_type = IntegerType::new(self.context, 64).into();
+        let line_size = source.elem().size() * source.vectorization_factor() as usize;
+        let line_size = self
+            .block
+            .const_int_from_type(self.context, self.location, line_size as i64, integer_type)
+            .unwrap();
+        let length =
+            self.append_operation_with_result(arith::extui(length, integer_type, self.location));
+        let size = self.append_operation_with_result(arith::muli(length, line_size, self.location));
+
+        let source_memref = self.get_memory(source);
+        let source_pointer = self.get_element_pointer(source_memref, source_index, source.elem());
+        let out_memref = self.get_memory(out);
+        let out_pointer = self.get_element_pointer(out_memref, out_index, out.elem());
+
+        let is_volatile = IntegerAttribute::new(IntegerType::new(self.context, 1).into(), 0);
+        self.block.append_operation(
+            OperationBuilder::new("llvm.intr.memcpy", self.location)
+                .add_operands(&[out_pointer, source_pointer, size])
+                .add_attributes(&[(
+                    Identifier::new(self.context, "isVolatile"),
+                    is_volatile.into(),
+                )])
+                .build()
+                .unwrap(),
+        );
+    }
+}
+
+fn barrier_level(barrier: &Variable) -> BarrierLevel {
+    match barrier.kind {
+        VariableKind::Barrier { level, .. } => level,
+        _ => unreachable!("{barrier} should be a barrier"),
+    }
+}
+
+/// The id of `barrier` if it's a manual cube barrier, the only kind whose arrivals are tracked.
+fn manual_barrier_id(barrier: &Variable) -> Option<u32> {
+    match barrier.kind {
+        VariableKind::Barrier {
+            id,
+            level: BarrierLevel::CubeManual(_),
+            ..
+        } => Some(id),
+        VariableKind::Barrier { .. } => None,
+        _ => unreachable!("{barrier} should be a barrier"),
+    }
+}
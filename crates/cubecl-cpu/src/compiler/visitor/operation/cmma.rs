@@ -0,0 +1,388 @@
+use cubecl_core::ir::{Builtin, CoopMma, Elem, Matrix, MatrixIdent, MatrixLayout, VariableKind};
+use tracel_llvm::melior::{
+    dialect::{
+        arith::{self, CmpiPredicate},
+        memref,
+        ods::vector,
+        scf,
+    },
+    ir::{
+        Block, Region,
+        attribute::DenseI64ArrayAttribute,
+        r#type::{IntegerType, MemRefType},
+    },
+};
+
+use crate::compiler::visitor::prelude::*;
+
+impl<'a> Visitor<'a> {
+    /// Fragments are emulated in memory: the first unit of the plane holds each fragment whole,
+    /// in row major order, and executes the operations on it alone. Loads and stores wait for the
+    /// rest of the plane afterwards, like they are collective on GPUs, so the other units can
+    /// overwrite the memory that was loaded and read the values that were stored.
+    pub fn visit_cmma(&mut self, cmma: &CoopMma, out: Variable) {
+        match cmma {
+            CoopMma::Fill { value } => {
+                let matrix = matrix_of(out);
+                let memref = self.get_matrix(out);
+                let mut element = self.get_variable(*value);
+                if value.item.is_vectorized() {
+                    let first = DenseI64ArrayAttribute::new(self.context, &[0]).into();
+                    element = self.append_operation_with_result(vector::extract(
+                        self.context,
+                        element,
+                        &[],
+                        first,
+                        self.location,
+                    ));
+                }
+                let value = self.convert(element, value.elem(), matrix.elem);
+                let (rows, columns) = matrix_shape(&matrix);
+                self.append_if_plane_leader(|visitor| {
+                    visitor.append_for(rows * columns, |visitor, index| {
+                        visitor.block.append_operation(memref::store(
+                            value,
+                            memref,
+                            &[index],
+                            visitor.location,
+                        ));
+                    });
+                });
+            }
+            CoopMma::Load {
+                value,
+                stride,
+                offset,
+                layout,
+            } => {
+                let matrix = matrix_of(out);
+                let fragment = self.get_matrix(out);
+                let memory = self.get_memory(*value);
+                let offset = self.get_index(*offset, value.item);
+                let stride = self.get_index(*stride, stride.item);
+                let layout = layout.unwrap_or(matrix.layout);
+                let (rows, columns) = matrix_shape(&matrix);
+                self.append_if_plane_leader(|visitor| {
+                    visitor.append_for(rows, |visitor, row| {
+                        visitor.append_for(columns, |visitor, column| {
+                            let position =
+                                visitor.memory_position(offset, stride, layout, row, column);
+                            let element = visitor.append_operation_with_result(memref::load(
+                                memory,
+                                &[position],
+                                visitor.location,
+                            ));
+                            let element = visitor.convert(element, value.elem(), matrix.elem);
+                            let index = visitor.fragment_index(row, column, columns);
+                            visitor.block.append_operation(memref::store(
+                                element,
+                                fragment,
+                                &[index],
+                                visitor.location,
+                            ));
+                        });
+                    });
+                });
+                self.append_plane_sync();
+            }
+            CoopMma::Execute {
+                mat_a,
+                mat_b,
+                mat_c,
+            } => {
+                let matrix = matrix_of(out);
+                let (a, b, c, d) = (
+                    self.get_matrix(*mat_a),
+                    self.get_matrix(*mat_b),
+                    self.get_matrix(*mat_c),
+                    self.get_matrix(out),
+                );
+                let (elem_a, elem_b, elem_c) = (mat_a.elem(), mat_b.elem(), mat_c.elem());
+                let (m, n, k) = (matrix.m as u32, matrix.n as u32, matrix.k as u32);
+                self.append_if_plane_leader(|visitor| {
+                    visitor.append_for(m, |visitor, i| {
+                        visitor.append_for(n, |visitor, j| {
+                            let index = visitor.fragment_index(i, j, n);
+                            let accumulator = visitor.append_operation_with_result(memref::load(
+                                c,
+                                &[index],
+                                visitor.location,
+                            ));
+                            let accumulator = visitor.convert(accumulator, elem_c, matrix.elem);
+                            visitor.block.append_operation(memref::store(
+                                accumulator,
+                                d,
+                                &[index],
+                                visitor.location,
+                            ));
+                            visitor.append_for(k, |visitor, l| {
+                                let index_a = visitor.fragment_index(i, l, k);
+                                let lhs = visitor.append_operation_with_result(memref::load(
+                                    a,
+                                    &[index_a],
+                                    visitor.location,
+                                ));
+                                let lhs = visitor.convert(lhs, elem_a, matrix.elem);
+                                let index_b = visitor.fragment_index(l, j, n);
+                                let rhs = visitor.append_operation_with_result(memref::load(
+                                    b,
+                                    &[index_b],
+                                    visitor.location,
+                                ));
+                                let rhs = visitor.convert(rhs, elem_b, matrix.elem);
+                                let accumulator = visitor.append_operation_with_result(
+                                    memref::load(d, &[index], visitor.location),
+                                );
+                                let accumulator = if matrix.elem.is_int() {
+                                    let product = visitor.append_operation_with_result(
+                                        arith::muli(lhs, rhs, visitor.location),
+                                    );
+                                    visitor.append_operation_with_result(arith::addi(
+                                        accumulator,
+                                        product,
+                                        visitor.location,
+                                    ))
+                                } else {
+                                    let product = visitor.append_operation_with_result(
+                                        arith::mulf(lhs, rhs, visitor.location),
+                                    );
+                                    visitor.append_operation_with_result(arith::addf(
+                                        accumulator,
+                                        product,
+                                        visitor.location,
+                                    ))
+                                };
+                                visitor.block.append_operation(memref::store(
+                                    accumulator,
+                                    d,
+                                    &[index],
+                                    visitor.location,
+                                ));
+                            });
+                        });
+                    });
+                });
+            }
+            CoopMma::Store {
+                mat,
+                stride,
+                offset,
+                layout,
+            } => {
+                let matrix = matrix_of(*mat);
+                let fragment = self.get_matrix(*mat);
+                let memory = self.get_memory(out);
+                let offset = self.get_index(*offset, out.item);
+                let stride = self.get_index(*stride, stride.item);
+                let layout = *layout;
+                let (rows, columns) = matrix_shape(&matrix);
+                self.append_if_plane_leader(|visitor| {
+                    visitor.append_for(rows, |visitor, row| {
+                        visitor.append_for(columns, |visitor, column| {
+                            let index = visitor.fragment_index(row, column, columns);
+                            let element = visitor.append_operation_with_result(memref::load(
+                                fragment,
+                                &[index],
+                                visitor.location,
+                            ));
+                            let element = visitor.convert(element, matrix.elem, out.elem());
+                            let position =
+                                visitor.memory_position(offset, stride, layout, row, column);
+                            visitor.block.append_operation(memref::store(
+                                element,
+                                memory,
+                                &[position],
+                                visitor.location,
+                            ));
+                        });
+                    });
+                });
+                self.append_plane_sync();
+            }
+            CoopMma::Cast { input } => {
+                let matrix = matrix_of(out);
+                let source = self.get_matrix(*input);
+                let target = self.get_matrix(out);
+                let elem = input.elem();
+                let (rows, columns) = matrix_shape(&matrix);
+                self.append_if_plane_leader(|visitor| {
+                    visitor.append_for(rows * columns, |visitor, index| {
+                        let element = visitor.append_operation_with_result(memref::load(
+                            source,
+                            &[index],
+                            visitor.location,
+                        ));
+                        let element = visitor.convert(element, elem, matrix.elem);
+                        visitor.block.append_operation(memref::store(
+                            element,
+                            target,
+                            &[index],
+                            visitor.location,
+                        ));
+                    });
+                });
+            }
+        }
+    }
+
+    /// The memory holding the fragment `variable`. Fragments are allocated once at the start of
+    /// the kernel, so every operation on them is dominated by the allocation.
+    fn get_matrix(&mut self, variable: Variable) -> Value<'a, 'a> {
+        let VariableKind::Matrix { id, mat } = variable.kind else {
+            unreachable!("{variable} should be a matrix");
+        };
+        if let Some(memref) = self.variables.matrices.get(&id) {
+            return *memref;
+        }
+        let (rows, columns) = matrix_shape(&mat);
+        let memref_type = MemRefType::new(
+            mat.elem.to_type(self.context),
+            &[(rows * columns) as i64],
+            None,
+            None,
+        );
+        let memref = self
+            .function_block
+            .insert_operation(
+                0,
+                memref::alloca(self.context, memref_type, &[], &[], None, self.location),
+            )
+            .result(0)
+            .unwrap()
+            .into();
+        self.variables.matrices.insert(id, memref);
+        memref
+    }
+
+    /// Run `body` on the first unit of the plane only.
+    fn append_if_plane_leader(&mut self, body: impl FnOnce(&mut Self)) {
+        let lane = self.get_builtin(Builtin::UnitPosPlane);
+        let integer_type = IntegerType::new(self.context, 32).into();
+        let zero = self
+            .block
+            .const_int_from_type(self.context, self.location, 0, integer_type)
+            .unwrap();
+        let is_leader = self.append_operation_with_result(arith::cmpi(
+            self.context,
+            CmpiPredicate::Eq,
+            lane,
+            zero,
+            self.location,
+        ));
+        let then_region = self.build_region(&[], |visitor, _| body(visitor));
+        self.block.append_operation(scf::r#if(
+            is_leader,
+            &[],
+            then_region,
+            Region::new(),
+            self.location,
+        ));
+    }
+
+    /// Run `body` for every index in `0..end`.
+    fn append_for(&mut self, end: u32, body: impl FnOnce(&mut Self, Value<'a, 'a>)) {
+        let start = self.const_index(0);
+        let end = self.const_index(end as i64);
+        let step = self.const_index(1);
+        let region = self.build_region(&[Type::index(self.context)], |visitor, arguments| {
+            body(visitor, arguments[0])
+        });
+        self.block
+            .append_operation(scf::r#for(start, end, step, region, self.location));
+    }
+
+    /// Build a region of a single block with `arguments`, whose operations are emitted by `body`.
+    fn build_region(
+        &mut self,
+        arguments: &[Type<'a>],
+        body: impl FnOnce(&mut Self, &[Value<'a, 'a>]),
+    ) -> Region<'a> {
+        let region = Region::new();
+        let arguments: Vec<_> = arguments
+            .iter()
+            .map(|argument| (*argument, self.location))
+            .collect();
+        region.append_block(Block::new(&arguments));
+        let block = region.first_block().unwrap();
+        let arguments: Vec<Value<'a, 'a>> = (0..arguments.len())
+            .map(|index| block.argument(index).unwrap().into())
+            .collect();
+
+        let parent = std::mem::replace(&mut self.block, block);
+        body(self, &arguments);
+        self.block
+            .append_operation(scf::r#yield(&[], self.location));
+        self.block = parent;
+        region
+    }
+
+    fn const_index(&self, value: i64) -> Value<'a, 'a> {
+        self.block
+            .const_int_from_type(
+                self.context,
+                self.location,
+                value,
+                Type::index(self.context),
+            )
+            .unwrap()
+    }
+
+    /// Index of the element at `row` and `column` of a fragment with `columns` columns.
+    fn fragment_index(
+        &self,
+        row: Value<'a, 'a>,
+        column: Value<'a, 'a>,
+        columns: u32,
+    ) -> Value<'a, 'a> {
+        let columns = self.const_index(columns as i64);
+        let offset = self.append_operation_with_result(arith::muli(row, columns, self.location));
+        self.append_operation_with_result(arith::addi(offset, column, self.location))
+    }
+
+    /// Index in memory of the element at `row` and `column` of a fragment loaded or stored with
+    /// `layout`.
+    fn memory_position(
+        &self,
+        offset: Value<'a, 'a>,
+        stride: Value<'a, 'a>,
+        layout: MatrixLayout,
+        row: Value<'a, 'a>,
+        column: Value<'a, 'a>,
+    ) -> Value<'a, 'a> {
+        let (outer, inner) = match layout {
+            MatrixLayout::RowMajor => (row, column),
+            MatrixLayout::ColMajor => (column, row),
+            MatrixLayout::Undefined => panic!("The layout of the matrix should be defined"),
+        };
+        let position = self.append_operation_with_result(arith::muli(outer, stride, self.location));
+        let position =
+            self.append_operation_with_result(arith::addi(position, inner, self.location));
+        self.append_operation_with_result(arith::addi(offset, position, self.location))
+    }
+
+    fn convert(&self, value: Value<'a, 'a>, from: Elem, to: Elem) -> Value<'a, 'a> {
+        let target = to.to_type(self.context);
+        if from.is_int() == to.is_int() {
+            self.get_cast_same_type_category(from, to, target, value)
+        } else {
+            self.get_cast_different_type_category(from, to, target, value)
+        }
+    }
+}
+
+fn matrix_of(variable: Variable) -> Matrix {
+    match variable.kind {
+        VariableKind::Matrix { mat, .. } => mat,
+        _ => unreachable!("{variable} should be a matrix"),
+    }
+}
+
+/// Rows and columns of a fragment.
+fn matrix_shape(matrix: &Matrix) -> (u32, u32) {
+    let (m, n, k) = (matrix.m as u32, matrix.n as u32, matrix.k as u32);
+    match matrix.ident {
+        MatrixIdent::A => (m, k),
+        MatrixIdent::B => (k, n),
+        MatrixIdent::Accumulator => (m, n),
+    }
+}
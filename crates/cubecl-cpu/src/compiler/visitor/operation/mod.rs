@@ -1,8 +1,12 @@
 pub(super) mod arithmetic;
+pub(super) mod atomic;
+pub(super) mod barrier;
 pub(super) mod bitwise;
+pub(super) mod cmma;
 pub(super) mod comparison;
 pub(super) mod metadata;
 pub(super) mod operator;
+pub(super) mod plane;
 pub(super) mod synchronization;
 
 use cubecl_core::ir::{NonSemantic, Operation};
@@ -58,6 +62,9 @@ impl<'a> Visitor<'a> {
             Operation::Synchronization(synchronization) => {
                 self.visit_synchronization(synchronization);
             }
+            Operation::Barrier(barrier) => {
+                self.visit_barrier(barrier, None);
+            }
             operation => {
                 todo!(
                     "This operation ({}) is not implemented without an out",
@@ -69,14 +76,14 @@ impl<'a> Visitor<'a> {
 
     pub fn visit_operation_with_out(&mut self, operation: &Operation, out: Variable) {
         match operation {
-            Operation::Atomic(_atomic) => {
-                todo!("Atomic operation are not yet supported");
+            Operation::Atomic(atomic) => {
+                self.visit_atomic(atomic, out);
             }
             Operation::Arithmetic(arithmetic) => {
                 self.visit_arithmetic(arithmetic, out);
             }
-            Operation::Barrier(_barrier) => {
-                todo!("Barrier operation are not yet supported");
+            Operation::Barrier(barrier) => {
+                self.visit_barrier(barrier, Some(out));
             }
             Operation::Bitwise(bitwise) => {
                 self.visit_bitwise(bitwise, out);
@@ -94,7 +101,13 @@ impl<'a> Visitor<'a> {
             Operation::Operator(operator) => {
                 self.visit_operator_with_out(operator, out);
             }
-            Operation::CoopMma(_) | Operation::Plane(_) | Operation::Tma(_) => {
+            Operation::Plane(plane) => {
+                self.visit_plane(plane, out);
+            }
+            Operation::CoopMma(cmma) => {
+                self.visit_cmma(cmma, out);
+            }
+            Operation::Tma(_) => {
                 panic!("{operation} is not supported on CPU.");
             }
             Operation::Branch(_) => {
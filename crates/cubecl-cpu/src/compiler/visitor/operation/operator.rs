@@ -38,8 +38,32 @@ impl<'a> Visitor<'a> {
                     self.location,
                 ));
             }
-            Operator::CopyMemoryBulk(_copy_memory_bulk) => {
-                todo!("copy_memory_bulk is not implemented {}", operator)
+            Operator::CopyMemoryBulk(copy_memory_bulk) => {
+                let input = copy_memory_bulk.input;
+                let in_index = self.get_index(copy_memory_bulk.in_index, input.item);
+                let in_offset = self.get_index(copy_memory_bulk.offset_input, input.item);
+                let in_index = self.append_operation_with_result(arith::addi(
+                    in_index,
+                    in_offset,
+                    self.location,
+                ));
+                let out_index = self.get_index(copy_memory_bulk.out_index, out.item);
+                let out_offset = self.get_index(copy_memory_bulk.offset_out, out.item);
+                let out_index = self.append_operation_with_result(arith::addi(
+                    out_index,
+                    out_offset,
+                    self.location,
+                ));
+                let length = self.get_variable(copy_memory_bulk.len);
+                self.copy_lines(input, in_index, out, out_index, length);
+            }
+            Operator::Index(index) | Operator::UncheckedIndex(index)
+                if index.list.elem().is_atomic() =>
+            {
+                let memref = self.get_memory(index.list);
+                let index_value = self.get_index(index.index, out.item);
+                let pointer = self.get_element_pointer(memref, index_value, out.elem());
+                self.insert_variable(out, pointer);
             }
             Operator::Index(index) | Operator::UncheckedIndex(index) => {
                 let index_value = self.get_index(index.index, out.item);
@@ -180,7 +204,7 @@ impl<'a> Visitor<'a> {
         self.insert_variable(out, value);
     }
 
-    fn get_cast_different_type_category(
+    pub(super) fn get_cast_different_type_category(
         &self,
         to_cast: Elem,
         out: Elem,
@@ -220,7 +244,7 @@ impl<'a> Visitor<'a> {
         }
     }
 
-    fn get_cast_same_type_category(
+    pub(super) fn get_cast_same_type_category(
         &self,
         to_cast: Elem,
         out: Elem,
@@ -0,0 +1,414 @@
+use cubecl_core::ir::{BinaryOperator, Builtin, Item, Plane, UnaryOperator};
+use tracel_llvm::melior::{
+    dialect::{
+        arith::{self, CmpiPredicate},
+        func, llvm,
+        ods::vector,
+    },
+    ir::{attribute::FlatSymbolRefAttribute, operation::OperationBuilder, r#type::IntegerType},
+};
+
+use crate::{
+    compiler::visitor::prelude::*,
+    compute::plane::{PLANE_SIZE, PLANE_SLOT_SIZE},
+};
+
+#[derive(Clone, Copy)]
+enum PlaneReduce {
+    Sum,
+    Prod,
+    Min,
+    Max,
+    All,
+    Any,
+}
+
+/// Lanes combined by a reduction, relative to the current unit.
+#[derive(Clone, Copy)]
+enum PlaneLanes {
+    All,
+    Inclusive,
+    Exclusive,
+}
+
+impl<'a> Visitor<'a> {
+    /// Every unit of a plane runs on its own thread, so plane operations write the value of the
+    /// unit to memory, wait for the rest of the plane, then read the values of the other units.
+    /// See [`crate::compute::plane`] for the runtime side.
+    pub fn visit_plane(&mut self, plane: &Plane, out: Variable) {
+        match plane {
+            Plane::Elect => {
+                let mask = self.append_plane_sync();
+                let integer_type = IntegerType::new(self.context, 32).into();
+                let first = self.append_operation_with_result(llvm::intr_cttz(
+                    self.context,
+                    mask,
+                    false,
+                    integer_type,
+                    self.location,
+                ));
+                let lane = self.get_builtin(Builtin::UnitPosPlane);
+                let value = self.append_operation_with_result(arith::cmpi(
+                    self.context,
+                    CmpiPredicate::Eq,
+                    first,
+                    lane,
+                    self.location,
+                ));
+                self.insert_variable(out, value);
+            }
+            Plane::All(op) => self.visit_plane_reduce(op, out, PlaneReduce::All, PlaneLanes::All),
+            Plane::Any(op) => self.visit_plane_reduce(op, out, PlaneReduce::Any, PlaneLanes::All),
+            Plane::Sum(op) => self.visit_plane_reduce(op, out, PlaneReduce::Sum, PlaneLanes::All),
+            Plane::InclusiveSum(op) => {
+                self.visit_plane_reduce(op, out, PlaneReduce::Sum, PlaneLanes::Inclusive)
+            }
+            Plane::ExclusiveSum(op) => {
+                self.visit_plane_reduce(op, out, PlaneReduce::Sum, PlaneLanes::Exclusive)
+            }
+            Plane::Prod(op) => self.visit_plane_reduce(op, out, PlaneReduce::Prod, PlaneLanes::All),
+            Plane::InclusiveProd(op) => {
+                self.visit_plane_reduce(op, out, PlaneReduce::Prod, PlaneLanes::Inclusive)
+            }
+            Plane::ExclusiveProd(op) => {
+                self.visit_plane_reduce(op, out, PlaneReduce::Prod, PlaneLanes::Exclusive)
+            }
+            Plane::Min(op) => self.visit_plane_reduce(op, out, PlaneReduce::Min, PlaneLanes::All),
+            Plane::Max(op) => self.visit_plane_reduce(op, out, PlaneReduce::Max, PlaneLanes::All),
+            Plane::Ballot(op) => {
+                let value = self.get_variable(op.input);
+                let (mask, slots) = self.plane_exchange(value);
+                let integer_type = IntegerType::new(self.context, 32).into();
+                let zero = self
+                    .block
+                    .const_int_from_type(self.context, self.location, 0, integer_type)
+                    .unwrap();
+                let mut ballot = zero;
+                for lane in 0..PLANE_SIZE {
+                    let active = self.is_lane_active(mask, lane);
+                    let vote = self.load_lane(slots, lane, op.input.item);
+                    let vote =
+                        self.append_operation_with_result(arith::andi(active, vote, self.location));
+                    let bit = self
+                        .block
+                        .const_int_from_type(
+                            self.context,
+                            self.location,
+                            (1u32 << lane) as i32 as i64,
+                            integer_type,
+                        )
+                        .unwrap();
+                    let with_bit =
+                        self.append_operation_with_result(arith::ori(ballot, bit, self.location));
+                    ballot = self.append_operation_with_result(arith::select(
+                        vote,
+                        with_bit,
+                        ballot,
+                        self.location,
+                    ));
+                }
+                // Only the first word is used, a plane is never larger than 32 units on CPU.
+                let value = match out.item.is_vectorized() {
+                    true => {
+                        let mut elements = vec![zero; out.vectorization_factor() as usize];
+                        elements[0] = ballot;
+                        self.append_operation_with_result(vector::from_elements(
+                            self.context,
+                            out.item.to_type(self.context),
+                            &elements,
+                            self.location,
+                        ))
+                    }
+                    false => ballot,
+                };
+                self.insert_variable(out, value);
+            }
+            Plane::Broadcast(op) | Plane::Shuffle(op) => {
+                let source = self.get_variable(op.rhs);
+                self.visit_plane_shuffle(op, out, source);
+            }
+            Plane::ShuffleXor(op) => {
+                let lane = self.get_builtin(Builtin::UnitPosPlane);
+                let mask = self.get_variable(op.rhs);
+                let source =
+                    self.append_operation_with_result(arith::xori(lane, mask, self.location));
+                self.visit_plane_shuffle(op, out, source);
+            }
+            Plane::ShuffleUp(op) => {
+                let lane = self.get_builtin(Builtin::UnitPosPlane);
+                let delta = self.get_variable(op.rhs);
+                let source =
+                    self.append_operation_with_result(arith::subi(lane, delta, self.location));
+                self.visit_plane_shuffle(op, out, source);
+            }
+            Plane::ShuffleDown(op) => {
+                let lane = self.get_builtin(Builtin::UnitPosPlane);
+                let delta = self.get_variable(op.rhs);
+                let source =
+                    self.append_operation_with_result(arith::addi(lane, delta, self.location));
+                self.visit_plane_shuffle(op, out, source);
+            }
+        }
+    }
+
+    /// Read `op.lhs` from the unit at position `source`. Units reading outside of the plane get
+    /// their own value back.
+    fn visit_plane_shuffle(&mut self, op: &BinaryOperator, out: Variable, source: Value<'a, 'a>) {
+        let value = self.get_variable(op.lhs);
+        let (_, slots) = self.plane_exchange(value);
+        let lane = self.get_builtin(Builtin::UnitPosPlane);
+        let plane_size = self
+            .block
+            .const_int_from_type(
+                self.context,
+                self.location,
+                PLANE_SIZE as i64,
+                IntegerType::new(self.context, 32).into(),
+            )
+            .unwrap();
+        // Negative sources wrap around, so a single unsigned comparison checks both bounds.
+        let in_plane = self.append_operation_with_result(arith::cmpi(
+            self.context,
+            CmpiPredicate::Ult,
+            source,
+            plane_size,
+            self.location,
+        ));
+        let source =
+            self.append_operation_with_result(arith::select(in_plane, source, lane, self.location));
+
+        let integer_type = IntegerType::new(self.context, 64).into();
+        let source =
+            self.append_operation_with_result(arith::extui(source, integer_type, self.location));
+        let slot_size = self
+            .block
+            .const_int_from_type(
+                self.context,
+                self.location,
+                PLANE_SLOT_SIZE as i64,
+                integer_type,
+            )
+            .unwrap();
+        let offset =
+            self.append_operation_with_result(arith::muli(source, slot_size, self.location));
+        let value = self.load_slot(slots, offset, op.lhs.item);
+        self.insert_variable(out, value);
+    }
+
+    fn visit_plane_reduce(
+        &mut self,
+        op: &UnaryOperator,
+        out: Variable,
+        reduce: PlaneReduce,
+        lanes: PlaneLanes,
+    ) {
+        let item = op.input.item;
+        let value = self.get_variable(op.input);
+        let (mask, slots) = self.plane_exchange(value);
+        let current_lane = self.get_builtin(Builtin::UnitPosPlane);
+        let integer_type = IntegerType::new(self.context, 32).into();
+
+        let mut accumulator = self.plane_reduce_identity(reduce, item);
+        for lane in 0..PLANE_SIZE {
+            let mut included = self.is_lane_active(mask, lane);
+            let predicate = match lanes {
+                PlaneLanes::All => None,
+                PlaneLanes::Inclusive => Some(CmpiPredicate::Ule),
+                PlaneLanes::Exclusive => Some(CmpiPredicate::Ult),
+            };
+            if let Some(predicate) = predicate {
+                let lane = self
+                    .block
+                    .const_int_from_type(self.context, self.location, lane as i64, integer_type)
+                    .unwrap();
+                let before = self.append_operation_with_result(arith::cmpi(
+                    self.context,
+                    predicate,
+                    lane,
+                    current_lane,
+                    self.location,
+                ));
+                included =
+                    self.append_operation_with_result(arith::andi(included, before, self.location));
+            }
+            let value = self.load_lane(slots, lane, item);
+            let combined = self.plane_reduce_combine(reduce, item, accumulator, value);
+            accumulator = self.append_operation_with_result(arith::select(
+                included,
+                combined,
+                accumulator,
+                self.location,
+            ));
+        }
+        self.insert_variable(out, accumulator);
+    }
+
+    fn plane_reduce_identity(&self, reduce: PlaneReduce, item: Item) -> Value<'a, 'a> {
+        let elem = item.elem;
+        let bits = elem.size_bits() as u32;
+        match reduce {
+            PlaneReduce::Sum if elem.is_float() => self.create_float_constant_from_item(item, 0.0),
+            PlaneReduce::Prod if elem.is_float() => self.create_float_constant_from_item(item, 1.0),
+            PlaneReduce::Min if elem.is_float() => {
+                self.create_float_constant_from_item(item, f64::INFINITY)
+            }
+            PlaneReduce::Max if elem.is_float() => {
+                self.create_float_constant_from_item(item, f64::NEG_INFINITY)
+            }
+            PlaneReduce::Sum | PlaneReduce::Any => self.create_int_constant_from_item(item, 0),
+            PlaneReduce::Prod | PlaneReduce::All => self.create_int_constant_from_item(item, 1),
+            PlaneReduce::Min if elem.is_signed_int() => {
+                self.create_int_constant_from_item(item, (1i64 << (bits - 1)) - 1)
+            }
+            PlaneReduce::Min => self.create_int_constant_from_item(item, -1),
+            PlaneReduce::Max if elem.is_signed_int() => {
+                self.create_int_constant_from_item(item, -(1i64 << (bits - 1)))
+            }
+            PlaneReduce::Max => self.create_int_constant_from_item(item, 0),
+        }
+    }
+
+    fn plane_reduce_combine(
+        &self,
+        reduce: PlaneReduce,
+        item: Item,
+        lhs: Value<'a, 'a>,
+        rhs: Value<'a, 'a>,
+    ) -> Value<'a, 'a> {
+        let elem = item.elem;
+        let location = self.location;
+        match reduce {
+            PlaneReduce::Sum if elem.is_float() => {
+                self.append_operation_with_result(arith::addf(lhs, rhs, location))
+            }
+            PlaneReduce::Sum => self.append_operation_with_result(arith::addi(lhs, rhs, location)),
+            PlaneReduce::Prod if elem.is_float() => {
+                self.append_operation_with_result(arith::mulf(lhs, rhs, location))
+            }
+            PlaneReduce::Prod => self.append_operation_with_result(arith::muli(lhs, rhs, location)),
+            PlaneReduce::Min if elem.is_float() => {
+                self.append_operation_with_result(arith::minimumf(lhs, rhs, location))
+            }
+            PlaneReduce::Min if elem.is_signed_int() => {
+                self.append_operation_with_result(arith::minsi(lhs, rhs, location))
+            }
+            PlaneReduce::Min => self.append_operation_with_result(arith::minui(lhs, rhs, location)),
+            PlaneReduce::Max if elem.is_float() => {
+                self.append_operation_with_result(arith::maximumf(lhs, rhs, location))
+            }
+            PlaneReduce::Max if elem.is_signed_int() => {
+                self.append_operation_with_result(arith::maxsi(lhs, rhs, location))
+            }
+            PlaneReduce::Max => self.append_operation_with_result(arith::maxui(lhs, rhs, location)),
+            PlaneReduce::All => self.append_operation_with_result(arith::andi(lhs, rhs, location)),
+            PlaneReduce::Any => self.append_operation_with_result(arith::ori(lhs, rhs, location)),
+        }
+    }
+
+    /// Share `value` with the rest of the plane, returning the mask of the participating units
+    /// and a pointer to their values.
+    fn plane_exchange(&mut self, value: Value<'a, 'a>) -> (Value<'a, 'a>, Value<'a, 'a>) {
+        let slot = self.call_plane_function("plane_slot");
+        self.block.append_operation(
+            OperationBuilder::new("llvm.store", self.location)
+                .add_operands(&[value, slot])
+                .build()
+                .unwrap(),
+        );
+        let mask = self.append_plane_sync();
+        let slots = self.call_plane_function("plane_slots");
+        (mask, slots)
+    }
+
+    /// Wait for the rest of the plane, returning the mask of the units that reached this same
+    /// plane operation.
+    pub fn append_plane_sync(&mut self) -> Value<'a, 'a> {
+        let integer_type = IntegerType::new(self.context, 32).into();
+        let site = self
+            .block
+            .const_int_from_type(
+                self.context,
+                self.location,
+                self.plane_sync_counter as i64,
+                integer_type,
+            )
+            .unwrap();
+        self.plane_sync_counter += 1;
+        self.append_operation_with_result(func::call(
+            self.context,
+            FlatSymbolRefAttribute::new(self.context, "plane_sync"),
+            &[site],
+            &[integer_type],
+            self.location,
+        ))
+    }
+
+    fn call_plane_function(&self, name: &str) -> Value<'a, 'a> {
+        self.append_operation_with_result(func::call(
+            self.context,
+            FlatSymbolRefAttribute::new(self.context, name),
+            &[],
+            &[llvm::r#type::pointer(self.context, 0)],
+            self.location,
+        ))
+    }
+
+    fn is_lane_active(&self, mask: Value<'a, 'a>, lane: u32) -> Value<'a, 'a> {
+        let integer_type = IntegerType::new(self.context, 32).into();
+        let bit = self
+            .block
+            .const_int_from_type(
+                self.context,
+                self.location,
+                (1u32 << lane) as i32 as i64,
+                integer_type,
+            )
+            .unwrap();
+        let masked = self.append_operation_with_result(arith::andi(mask, bit, self.location));
+        let zero = self
+            .block
+            .const_int_from_type(self.context, self.location, 0, integer_type)
+            .unwrap();
+        self.append_operation_with_result(arith::cmpi(
+            self.context,
+            CmpiPredicate::Ne,
+            masked,
+            zero,
+            self.location,
+        ))
+    }
+
+    fn load_lane(&self, slots: Value<'a, 'a>, lane: u32, item: Item) -> Value<'a, 'a> {
+        let integer_type = IntegerType::new(self.context, 64).into();
+        let offset = self
+            .block
+            .const_int_from_type(
+                self.context,
+                self.location,
+                lane as i64 * PLANE_SLOT_SIZE as i64,
+                integer_type,
+            )
+            .unwrap();
+        self.load_slot(slots, offset, item)
+    }
+
+    /// Load a value of type `item` at `offset` bytes from `slots`.
+    fn load_slot(&self, slots: Value<'a, 'a>, offset: Value<'a, 'a>, item: Item) -> Value<'a, 'a> {
+        let pointer_type = llvm::r#type::pointer(self.context, 0);
+        let pointer = self.append_operation_with_result(llvm::get_element_ptr_dynamic(
+            self.context,
+            slots,
+            &[offset],
+            IntegerType::new(self.context, 8).into(),
+            pointer_type,
+            self.location,
+        ));
+        self.append_operation_with_result(
+            OperationBuilder::new("llvm.load", self.location)
+                .add_operands(&[pointer])
+                .add_results(&[item.to_type(self.context)])
+                .build()
+                .unwrap(),
+        )
+    }
+}
@@ -7,23 +7,32 @@ impl<'a> Visitor<'a> {
     pub fn visit_synchronization(&mut self, synchronization: &Synchronization) {
         match synchronization {
             Synchronization::SyncCube => {
-                let func_name = FlatSymbolRefAttribute::new(self.context, "sync_cube");
-                let cube_dim = self.args_manager.get(Builtin::CubeDim);
-                self.block.append_operation(func::call(
-                    self.context,
-                    func_name,
-                    &[cube_dim],
-                    &[],
-                    self.location,
-                ));
+                self.append_sync_cube();
+            }
+            Synchronization::SyncPlane => {
+                self.append_plane_sync();
             }
-            Synchronization::SyncPlane => {} //NOOP plane size is 1 on CPU
             Synchronization::SyncStorage => {
                 panic!("SyncStorage is not supported")
             }
             Synchronization::SyncProxyShared => {
                 panic!("SyncProxyShared is not supported")
             }
+            Synchronization::DeviceFence => {
+                panic!("DeviceFence is not supported")
+            }
         }
     }
+
+    pub fn append_sync_cube(&mut self) {
+        let func_name = FlatSymbolRefAttribute::new(self.context, "sync_cube");
+        let cube_dim = self.args_manager.get(Builtin::CubeDim);
+        self.block.append_operation(func::call(
+            self.context,
+            func_name,
+            &[cube_dim],
+            &[],
+            self.location,
+        ));
+    }
 }
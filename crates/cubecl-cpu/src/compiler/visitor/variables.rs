@@ -21,6 +21,7 @@ pub struct Variables<'a> {
     pub local: HashMap<u32, Value<'a, 'a>>,
     pub version: HashMap<(u32, u16), Value<'a, 'a>>,
     pub mutable: HashMap<u32, Value<'a, 'a>>,
+    pub matrices: HashMap<u32, Value<'a, 'a>>,
     pub global_constant: HashMap<u32, Item>,
 }
 
@@ -1,33 +1,17 @@
-use std::sync::{
-    atomic::{AtomicI32, Ordering},
-    mpsc,
+use std::{
+    sync::{Arc, mpsc},
+    thread,
 };
 
 use cubecl_core::ExecutionMode;
 
 use crate::compiler::{mlir_data::MlirData, mlir_engine::MlirEngine};
 
-// -1 variant indicate that the counter is not initialized
-static NB_CUBE_TO_SYNC: AtomicI32 = AtomicI32::new(-1);
-
-pub fn sync_cube(cube_dim: u32) {
-    if NB_CUBE_TO_SYNC.load(Ordering::Acquire) == -1 {
-        NB_CUBE_TO_SYNC.store((cube_dim as i64 - 1) as i32, Ordering::Release);
-    } else {
-        NB_CUBE_TO_SYNC.fetch_sub(-1, Ordering::Release);
-    }
-    loop {
-        let val = NB_CUBE_TO_SYNC.load(Ordering::Acquire);
-        if val == 0 {
-            NB_CUBE_TO_SYNC.store(-1, Ordering::Release);
-            break;
-        }
-        if val == -1 {
-            break;
-        }
-        std::hint::spin_loop();
-    }
-}
+use super::{
+    cube::CubeState,
+    plane::{PLANE_SIZE, PlaneState},
+    worker::MAX_STACK_SIZE,
+};
 
 pub enum Message {
     ComputeTask(ComputeTask),
@@ -43,6 +27,10 @@ pub struct ComputeTask {
 
 impl ComputeTask {
     pub fn compute(mut self) {
+        if self.mlir_engine.0.concurrent_units {
+            return self.compute_concurrent();
+        }
+
         self.mlir_data.push_builtin();
         for unit_pos in self.vec_unit_pos {
             self.mlir_data.builtin.set_unit_pos(unit_pos);
@@ -51,4 +39,33 @@ impl ComputeTask {
             }
         }
     }
+
+    /// Units of a cube need to execute concurrently to synchronize or to exchange values within
+    /// their plane, so each of them gets its own thread.
+    fn compute_concurrent(self) {
+        let cube = Arc::new(CubeState::new(self.vec_unit_pos.len() as u32));
+        thread::scope(|scope| {
+            for plane_units in self.vec_unit_pos.chunks(PLANE_SIZE as usize) {
+                let plane = Arc::new(PlaneState::new(plane_units.len() as u32));
+                for (lane, unit_pos) in plane_units.iter().enumerate() {
+                    let cube = cube.clone();
+                    let plane = plane.clone();
+                    let mut mlir_engine = self.mlir_engine.clone();
+                    let mut mlir_data = self.mlir_data.clone();
+                    thread::Builder::new()
+                        .stack_size(MAX_STACK_SIZE)
+                        .spawn_scoped(scope, move || {
+                            mlir_data.push_builtin();
+                            mlir_data.builtin.set_unit_pos(*unit_pos);
+                            cube.run_unit(|| {
+                                plane.run_unit(lane as u32, || unsafe {
+                                    mlir_engine.run_kernel(&mut mlir_data);
+                                })
+                            });
+                        })
+                        .unwrap();
+                }
+            }
+        });
+    }
 }
@@ -0,0 +1,159 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    sync::{Arc, Barrier, Condvar, Mutex},
+};
+
+use super::plane::plane_cube_end;
+
+/// State shared by the units of a cube.
+///
+/// Each unit of a cube runs on its own thread, and `sync_cube` waits for every unit still
+/// executing the current cube. Units that terminated early are no longer counted, so the others
+/// don't wait on them.
+///
+/// Manual barriers are rendezvous of their own: a phase completes once every unit of the cube
+/// arrived on it, whether or not the units wait, so they don't synchronize the whole cube.
+pub struct CubeState {
+    inner: Mutex<CubeInner>,
+    condvar: Condvar,
+    barrier: Barrier,
+    num_units: u32,
+}
+
+struct CubeInner {
+    /// Units that haven't left the current cube.
+    active: u32,
+    /// Units waiting on the current synchronization.
+    arrived: u32,
+    generation: u64,
+    /// Manual barriers of the current cube, by barrier id.
+    barriers: HashMap<u32, ManualBarrier>,
+}
+
+struct ManualBarrier {
+    /// Arrivals still expected to complete the current phase.
+    pending: u32,
+    phase: u64,
+}
+
+impl CubeInner {
+    fn complete(&mut self) {
+        self.arrived = 0;
+        self.generation += 1;
+    }
+}
+
+thread_local! {
+    static CUBE: RefCell<Option<Arc<CubeState>>> = const { RefCell::new(None) };
+    /// Phase of the last arrival of the calling unit on each manual barrier.
+    static BARRIER_TOKENS: RefCell<HashMap<u32, u64>> = RefCell::new(HashMap::new());
+}
+
+impl CubeState {
+    pub fn new(num_units: u32) -> Self {
+        assert!(num_units > 0);
+        Self {
+            inner: Mutex::new(CubeInner {
+                active: num_units,
+                arrived: 0,
+                generation: 0,
+                barriers: HashMap::new(),
+            }),
+            condvar: Condvar::new(),
+            barrier: Barrier::new(num_units as usize),
+            num_units,
+        }
+    }
+
+    /// Run `func` as a unit of this cube on the current thread.
+    pub fn run_unit(self: &Arc<Self>, func: impl FnOnce()) {
+        CUBE.with(|cube| *cube.borrow_mut() = Some(self.clone()));
+        func();
+        CUBE.with(|cube| *cube.borrow_mut() = None);
+    }
+}
+
+fn with_cube<R>(func: impl FnOnce(&CubeState) -> R) -> R {
+    CUBE.with(|cube| {
+        let cube = cube.borrow();
+        let state = cube
+            .as_ref()
+            .expect("Cube synchronizations should be executed inside a cube");
+        func(state)
+    })
+}
+
+/// Wait for every active unit of the cube.
+pub extern "C" fn sync_cube(_cube_dim: u32) {
+    with_cube(|state| {
+        let mut inner = state.inner.lock().unwrap();
+        let generation = inner.generation;
+        inner.arrived += 1;
+        if inner.arrived == inner.active {
+            inner.complete();
+            state.condvar.notify_all();
+        } else {
+            let _inner = state
+                .condvar
+                .wait_while(inner, |inner| inner.generation == generation)
+                .unwrap();
+        }
+    })
+}
+
+/// Arrive `count` times on the manual barrier `id`. The phase of the barrier completes once
+/// it received as many arrivals as there are units in the cube.
+pub extern "C" fn barrier_arrive(id: u32, count: u32) {
+    with_cube(|state| {
+        let mut inner = state.inner.lock().unwrap();
+        let barrier = inner.barriers.entry(id).or_insert(ManualBarrier {
+            pending: state.num_units,
+            phase: 0,
+        });
+        let phase = barrier.phase;
+        barrier.pending = barrier.pending.saturating_sub(count);
+        if barrier.pending == 0 {
+            barrier.pending = state.num_units;
+            barrier.phase += 1;
+            state.condvar.notify_all();
+        }
+        BARRIER_TOKENS.with(|tokens| tokens.borrow_mut().insert(id, phase));
+    })
+}
+
+/// Wait for the phase of the last arrival of the calling unit on the manual barrier `id` to
+/// complete.
+pub extern "C" fn barrier_wait(id: u32) {
+    with_cube(|state| {
+        let phase = BARRIER_TOKENS
+            .with(|tokens| tokens.borrow().get(&id).copied())
+            .expect("Units should arrive on a barrier before waiting on it");
+        let inner = state.inner.lock().unwrap();
+        let _inner = state
+            .condvar
+            .wait_while(inner, |inner| inner.barriers[&id].phase == phase)
+            .unwrap();
+    })
+}
+
+/// Mark the calling unit as done with the current cube, and wait for the rest of the cube
+/// before starting the next one.
+pub extern "C" fn cube_end() {
+    // The unit has to leave the cube before waiting on its plane, since the other units of the
+    // plane may be waiting on a cube synchronization.
+    with_cube(|state| {
+        let mut inner = state.inner.lock().unwrap();
+        inner.active -= 1;
+        if inner.active == 0 {
+            inner.active = state.num_units;
+            inner.barriers.clear();
+        } else if inner.arrived != 0 && inner.arrived == inner.active {
+            inner.complete();
+            state.condvar.notify_all();
+        }
+    });
+    BARRIER_TOKENS.with(|tokens| tokens.borrow_mut().clear());
+    plane_cube_end();
+    with_cube(|state| state.barrier.wait());
+}
@@ -1,4 +1,6 @@
 pub mod compute_task;
+pub mod cube;
+pub mod plane;
 pub mod scheduler;
 pub mod server;
 pub mod worker;
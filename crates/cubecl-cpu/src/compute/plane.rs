@@ -0,0 +1,157 @@
+use std::{
+    cell::RefCell,
+    sync::{Arc, Barrier, Condvar, Mutex},
+};
+
+/// Number of units in a plane on the CPU runtime.
+pub const PLANE_SIZE: u32 = 32;
+/// Bytes reserved for each unit to exchange a value, big enough for any line.
+pub const PLANE_SLOT_SIZE: usize = 128;
+
+#[repr(C, align(64))]
+#[derive(Clone, Copy)]
+struct Slot([u8; PLANE_SLOT_SIZE]);
+
+/// State shared by the units of a plane.
+///
+/// Each unit of a plane runs on its own thread, and plane operations are rendezvous where every
+/// unit still executing the current cube writes its value into a slot before reading the slots
+/// of the others. Once every unit reached a plane operation, the units are grouped by the plane
+/// operation they reached, so units that diverged only exchange values with the units that
+/// execute the same operation. Slots are double buffered so a unit can write the next value
+/// while slower units are still reading the previous ones.
+pub struct PlaneState {
+    inner: Mutex<PlaneInner>,
+    condvar: Condvar,
+    barrier: Barrier,
+    full_mask: u32,
+}
+
+struct PlaneInner {
+    /// Units that haven't left the current cube.
+    active: u32,
+    /// Units waiting on the current exchange.
+    arrived: u32,
+    generation: u64,
+    /// Plane operation each waiting unit reached.
+    sites: [u32; PLANE_SIZE as usize],
+    /// Units that took part in the exchange of each unit, for the last two exchanges.
+    participants: [[u32; PLANE_SIZE as usize]; 2],
+    slots: [Vec<Slot>; 2],
+}
+
+impl PlaneInner {
+    fn complete(&mut self) {
+        let (arrived, sites) = (self.arrived, self.sites);
+        let participants = &mut self.participants[(self.generation % 2) as usize];
+        for (lane, participants) in participants.iter_mut().enumerate() {
+            if arrived & (1 << lane) == 0 {
+                continue;
+            }
+            *participants = (0..PLANE_SIZE as usize)
+                .filter(|other| arrived & (1 << other) != 0 && sites[*other] == sites[lane])
+                .fold(0, |mask, other| mask | (1 << other));
+        }
+        self.arrived = 0;
+        self.generation += 1;
+    }
+}
+
+thread_local! {
+    static PLANE: RefCell<Option<(Arc<PlaneState>, u32)>> = const { RefCell::new(None) };
+}
+
+impl PlaneState {
+    pub fn new(num_units: u32) -> Self {
+        assert!(num_units > 0 && num_units <= PLANE_SIZE);
+        let full_mask = ((1u64 << num_units) - 1) as u32;
+        let slots = vec![Slot([0; PLANE_SLOT_SIZE]); PLANE_SIZE as usize];
+        Self {
+            inner: Mutex::new(PlaneInner {
+                active: full_mask,
+                arrived: 0,
+                generation: 0,
+                sites: [0; PLANE_SIZE as usize],
+                participants: [[0; PLANE_SIZE as usize]; 2],
+                slots: [slots.clone(), slots],
+            }),
+            condvar: Condvar::new(),
+            barrier: Barrier::new(num_units as usize),
+            full_mask,
+        }
+    }
+
+    /// Run `func` as the unit at position `lane` of this plane on the current thread.
+    pub fn run_unit(self: &Arc<Self>, lane: u32, func: impl FnOnce()) {
+        PLANE.with(|plane| *plane.borrow_mut() = Some((self.clone(), lane)));
+        func();
+        PLANE.with(|plane| *plane.borrow_mut() = None);
+    }
+}
+
+fn with_plane<R>(func: impl FnOnce(&PlaneState, u32) -> R) -> R {
+    PLANE.with(|plane| {
+        let plane = plane.borrow();
+        let (state, lane) = plane
+            .as_ref()
+            .expect("Plane operations should be executed inside a plane");
+        func(state, *lane)
+    })
+}
+
+/// Pointer to the slot of the calling unit for the next exchange.
+pub extern "C" fn plane_slot() -> *mut u8 {
+    with_plane(|state, lane| {
+        let mut inner = state.inner.lock().unwrap();
+        let buffer = (inner.generation % 2) as usize;
+        inner.slots[buffer][lane as usize].0.as_mut_ptr()
+    })
+}
+
+/// Wait for every active unit of the plane, then return the mask of the units that reached the
+/// same plane operation, identified by `site`.
+pub extern "C" fn plane_sync(site: u32) -> u32 {
+    with_plane(|state, lane| {
+        let mut inner = state.inner.lock().unwrap();
+        let generation = inner.generation;
+        inner.arrived |= 1 << lane;
+        inner.sites[lane as usize] = site;
+        if inner.arrived == inner.active {
+            inner.complete();
+            state.condvar.notify_all();
+        } else {
+            inner = state
+                .condvar
+                .wait_while(inner, |inner| inner.generation == generation)
+                .unwrap();
+        }
+        inner.participants[(generation % 2) as usize][lane as usize]
+    })
+}
+
+/// Pointer to the slots of the last completed exchange, laid out by unit position.
+pub extern "C" fn plane_slots() -> *const u8 {
+    with_plane(|state, _| {
+        let inner = state.inner.lock().unwrap();
+        let buffer = ((inner.generation + 1) % 2) as usize;
+        inner.slots[buffer].as_ptr() as *const u8
+    })
+}
+
+/// Mark the calling unit as done with the current cube, and wait for the rest of the plane
+/// before starting the next one.
+pub fn plane_cube_end() {
+    with_plane(|state, lane| {
+        {
+            let mut inner = state.inner.lock().unwrap();
+            inner.active &= !(1 << lane);
+            if inner.active == 0 {
+                inner.active = state.full_mask;
+            } else if inner.arrived != 0 && inner.arrived == inner.active {
+                inner.complete();
+                state.condvar.notify_all();
+            }
+        }
+        state.barrier.wait();
+    })
+}
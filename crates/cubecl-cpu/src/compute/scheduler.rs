@@ -1,7 +1,13 @@
+use std::collections::{HashMap, hash_map::Entry};
 use std::fmt::Debug;
-use std::{collections::HashMap, sync::mpsc};
+use std::sync::mpsc;
 
-use cubecl_core::{ExecutionMode, compute::CubeTask, prelude::CompiledKernel, server::Bindings};
+use cubecl_core::{
+    ExecutionMode,
+    compute::CubeTask,
+    prelude::{CompilationError, CompiledKernel},
+    server::Bindings,
+};
 use cubecl_runtime::{id::KernelId, memory_management::MemoryManagement, storage::BytesStorage};
 
 use crate::{
@@ -47,24 +53,23 @@ impl Scheduler {
         bindings: Bindings,
         kind: ExecutionMode,
         memory_management: &mut MemoryManagement<BytesStorage>,
-    ) {
-        let kernel = self
-            .compilation_cache
-            .entry(kernel.id())
-            .or_insert_with(|| {
-                kernel.compile(
-                    &mut Default::default(),
-                    &MlirCompilerOptions::default(),
-                    kind,
-                )
-            });
+    ) -> Result<(), CompilationError> {
+        let kernel = match self.compilation_cache.entry(kernel.id()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(kernel.compile(
+                &mut Default::default(),
+                &MlirCompilerOptions::default(),
+                kind,
+            )?),
+        };
 
         let cube_dim = kernel.cube_dim;
         let mut unit_pos_vec = Vec::with_capacity((cube_dim.x * cube_dim.y * cube_dim.z) as usize);
 
-        for unit_pos_x in 0..cube_dim.x {
+        // Units are ordered by `UNIT_POS` so consecutive units belong to the same plane.
+        for unit_pos_z in 0..cube_dim.z {
             for unit_pos_y in 0..cube_dim.y {
-                for unit_pos_z in 0..cube_dim.z {
+                for unit_pos_x in 0..cube_dim.x {
                     unit_pos_vec.push([unit_pos_x, unit_pos_y, unit_pos_z]);
                 }
             }
@@ -76,12 +81,17 @@ impl Scheduler {
         mlir_data.builtin.set_cube_dim(cube_dim);
         mlir_data.builtin.set_cube_count(cube_count);
 
+        // Units that have to run concurrently are all given to the same task, which runs each of
+        // them on its own thread.
+        let chunk_size = if mlir_engine.0.concurrent_units {
+            unit_pos_vec.len()
+        } else {
+            unit_pos_vec.len().div_ceil(self.workers.len())
+        };
+
         let (send, receive) = mpsc::channel();
         let mut msg_count = 0;
-        for (slice, worker) in unit_pos_vec
-            .chunks(unit_pos_vec.len().div_ceil(self.workers.len()))
-            .zip(self.workers.iter_mut())
-        {
+        for (slice, worker) in unit_pos_vec.chunks(chunk_size).zip(self.workers.iter_mut()) {
             let mlir_engine = mlir_engine.clone();
             let mlir_data = mlir_data.clone();
             let vec_unit_pos = slice.to_vec();
@@ -102,5 +112,7 @@ impl Scheduler {
                 break;
             }
         }
+
+        Ok(())
     }
 }
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use cubecl_common::profile::ProfileDuration;
 use cubecl_core::{
-    CubeCount, ExecutionMode, Feature, MemoryUsage,
+    CompilationError, CubeCount, ExecutionMode, Feature, MemoryUsage,
     compute::CubeTask,
     future::DynFut,
     server::{
@@ -11,8 +11,8 @@ use cubecl_core::{
 };
 use cubecl_runtime::{
     logging::ServerLogger,
-    memory_management::{MemoryManagement, offset_handles},
-    storage::{BindingResource, BytesStorage, ComputeStorage},
+    memory_management::{MemoryManagement, OutOfMemory, offset_handles},
+    storage::{BindingResource, BytesResource, BytesStorage, ComputeStorage},
     timestamp_profiler::TimestampProfiler,
 };
 
@@ -97,12 +97,12 @@ impl ComputeServer for CpuServer {
         self.ctx.memory_management.cleanup(true)
     }
 
-    fn create(&mut self, data: &[u8]) -> Handle {
-        let handle = self.empty(data.len());
+    fn try_create(&mut self, data: &[u8]) -> Result<Handle, OutOfMemory> {
+        let handle = self.try_empty(data.len())?;
         let binding = handle.clone().binding();
         self.copy_to_binding(binding, data);
 
-        handle
+        Ok(handle)
     }
 
     fn create_tensors(
@@ -121,9 +121,9 @@ impl ComputeServer for CpuServer {
         handles_strides
     }
 
-    fn empty(&mut self, size: usize) -> Handle {
-        let handle = self.ctx.memory_management.reserve(size as u64, None);
-        Handle::new(handle, None, None, size as u64)
+    fn try_empty(&mut self, size: usize) -> Result<Handle, OutOfMemory> {
+        let handle = self.ctx.memory_management.try_reserve(size as u64, None)?;
+        Ok(Handle::new(handle, None, None, size as u64))
     }
 
     fn empty_tensors(
@@ -150,6 +150,36 @@ impl ComputeServer for CpuServer {
         handles.into_iter().zip(strides).collect()
     }
 
+    fn copy(&mut self, src: Binding, dst: Binding) {
+        let src = self
+            .ctx
+            .memory_management
+            .get_resource(src.memory, src.offset_start, src.offset_end)
+            .expect("Failed to find resource");
+        // The handles can share an allocation, so the source can't be borrowed while the
+        // destination is written.
+        let data = src.read().to_vec();
+        let dst = self
+            .ctx
+            .memory_management
+            .get_resource(dst.memory, dst.offset_start, dst.offset_end)
+            .expect("Failed to find resource");
+
+        dst.write()[..data.len()].copy_from_slice(&data);
+    }
+
+    fn copy_from_peer(
+        &mut self,
+        src: BindingResource<BytesResource>,
+        size: usize,
+    ) -> Option<Handle> {
+        // Every server lives in host memory, so the bytes of a peer can be read directly.
+        let handle = self.empty(size);
+        self.copy_to_binding(handle.clone().binding(), &src.resource().read()[..size]);
+
+        Some(handle)
+    }
+
     unsafe fn execute(
         &mut self,
         kernel: Self::Kernel,
@@ -157,7 +187,7 @@ impl ComputeServer for CpuServer {
         bindings: Bindings,
         kind: ExecutionMode,
         _logger: Arc<ServerLogger>,
-    ) {
+    ) -> Result<(), CompilationError> {
         let cube_count = match count {
             CubeCount::Static(x, y, z) => [x, y, z],
             CubeCount::Dynamic(binding) => {
@@ -179,7 +209,7 @@ impl ComputeServer for CpuServer {
             bindings,
             kind,
             &mut self.ctx.memory_management,
-        );
+        )
     }
 
     fn flush(&mut self) {}
@@ -216,6 +246,18 @@ impl ComputeServer for CpuServer {
     fn allocation_mode(&mut self, mode: cubecl_runtime::memory_management::MemoryAllocationMode) {
         self.ctx.memory_management.mode(mode);
     }
+
+    fn memory_tracing(&mut self, enabled: bool) {
+        self.ctx.memory_management.tracing(enabled);
+    }
+
+    fn memory_trace_tag(&mut self, tag: Option<String>) {
+        self.ctx.memory_management.trace_tag(tag);
+    }
+
+    fn memory_trace(&mut self) -> cubecl_runtime::memory_management::MemoryTrace {
+        self.ctx.memory_management.trace()
+    }
 }
 
 impl CpuServer {
@@ -1,7 +1,9 @@
 use cubecl_common::profile::TimingMethod;
 use cubecl_core::{
-    CubeCount, CubeDim, MemoryConfiguration, Runtime, channel::MutexComputeChannel,
+    CubeCount, CubeDim, Feature, MemoryConfiguration, Runtime,
+    channel::MutexComputeChannel,
     client::ComputeClient,
+    ir::{Elem, FloatKind},
 };
 use cubecl_runtime::{
     ComputeRuntime, DeviceProperties,
@@ -13,7 +15,10 @@ use sysinfo::System;
 
 use crate::{
     compiler::{MlirCompiler, register_supported_types},
-    compute::server::{CpuContext, CpuServer},
+    compute::{
+        plane::PLANE_SIZE,
+        server::{CpuContext, CpuServer},
+    },
     device::CpuDevice,
 };
 
@@ -34,7 +39,7 @@ type Server = CpuServer;
 // TODO Investigate MSPC channel, it blocks, but may be better
 type Channel = MutexComputeChannel<Server>;
 
-fn create_client(options: RuntimeOptions) -> ComputeClient<Server, Channel> {
+pub(crate) fn create_client(options: RuntimeOptions) -> ComputeClient<Server, Channel> {
     let max_cube_dim = CubeDim::new(u32::MAX, u32::MAX, u32::MAX);
     let max_cube_count = CubeCount::Static(u32::MAX, u32::MAX, u32::MAX);
     let system = System::new_all();
@@ -44,8 +49,8 @@ fn create_client(options: RuntimeOptions) -> ComputeClient<Server, Channel> {
         .unwrap_or(system.total_memory()) as usize;
 
     let topology = HardwareProperties {
-        plane_size_min: u32::MAX,
-        plane_size_max: u32::MAX,
+        plane_size_min: PLANE_SIZE,
+        plane_size_max: PLANE_SIZE,
         max_bindings: u32::MAX,
         max_shared_memory_size,
         max_cube_count,
@@ -68,6 +73,29 @@ fn create_client(options: RuntimeOptions) -> ComputeClient<Server, Channel> {
     let mut device_props =
         DeviceProperties::new(&[], mem_properties, topology, TimingMethod::Device);
     register_supported_types(&mut device_props);
+    // Async copies are executed synchronously, which is a valid implementation of barriers.
+    device_props.register_feature(Feature::Barrier);
+    device_props.register_feature(Feature::Plane);
+    // Matrix fragments are emulated in memory, see `Visitor::visit_cmma`, so the common sizes of
+    // the GPU instructions are all supported.
+    let cmma_elems = [
+        (Elem::Float(FloatKind::F16), Elem::Float(FloatKind::F16)),
+        (Elem::Float(FloatKind::F16), Elem::Float(FloatKind::F32)),
+        (Elem::Float(FloatKind::BF16), Elem::Float(FloatKind::F32)),
+        (Elem::Float(FloatKind::F32), Elem::Float(FloatKind::F32)),
+    ];
+    for (ab, c) in cmma_elems {
+        for (m, n, k) in [(16, 16, 16), (32, 8, 16), (8, 32, 16), (16, 16, 8)] {
+            device_props.register_feature(Feature::Cmma {
+                a: ab,
+                b: ab,
+                c,
+                m,
+                n,
+                k,
+            });
+        }
+    }
 
     let ctx = CpuContext::new(memory_management);
     let server = CpuServer::new(ctx);
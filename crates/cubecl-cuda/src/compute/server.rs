@@ -21,7 +21,7 @@ use cubecl_core::{
     ir::FloatKind,
     server::{BindingWithMeta, Bindings, Handle, TensorMapBinding},
 };
-use cubecl_runtime::memory_management::MemoryUsage;
+use cubecl_runtime::memory_management::{MemoryUsage, OutOfMemory};
 use cubecl_runtime::storage::BindingResource;
 use cubecl_runtime::{
     memory_management::MemoryManagement,
@@ -195,8 +195,8 @@ impl ComputeServer for CudaServer {
         Box::pin(self.read_tensor_async(bindings))
     }
 
-    fn create(&mut self, data: &[u8]) -> server::Handle {
-        let handle = self.empty(data.len());
+    fn try_create(&mut self, data: &[u8]) -> Result<server::Handle, OutOfMemory> {
+        let handle = self.try_empty(data.len())?;
         let ctx = self.get_context();
 
         let binding = handle.clone().binding();
@@ -209,7 +209,7 @@ impl ComputeServer for CudaServer {
             cudarc::driver::result::memcpy_htod_async(resource.ptr, data, ctx.stream).unwrap();
         }
 
-        handle
+        Ok(handle)
     }
 
     fn create_tensors(
@@ -266,10 +266,10 @@ impl ComputeServer for CudaServer {
         handles_strides
     }
 
-    fn empty(&mut self, size: usize) -> server::Handle {
+    fn try_empty(&mut self, size: usize) -> Result<server::Handle, OutOfMemory> {
         let ctx = self.get_context();
-        let handle = ctx.memory_management.reserve(size as u64, None);
-        server::Handle::new(handle, None, None, size as u64)
+        let handle = ctx.memory_management.try_reserve(size as u64, None)?;
+        Ok(server::Handle::new(handle, None, None, size as u64))
     }
 
     fn empty_tensors(
@@ -311,6 +311,46 @@ impl ComputeServer for CudaServer {
         handles.into_iter().zip(strides).collect()
     }
 
+    fn copy(&mut self, src: server::Binding, dst: server::Binding) {
+        let ctx = self.get_context();
+        let src = find_resource(ctx, src);
+        let dst = find_resource(ctx, dst);
+        assert!(
+            dst.size() >= src.size(),
+            "The destination of a copy should be at least as big as the source"
+        );
+
+        unsafe {
+            cudarc::driver::result::memcpy_dtod_async(
+                dst.ptr,
+                src.ptr,
+                src.size() as usize,
+                ctx.stream,
+            )
+            .unwrap();
+        }
+    }
+
+    fn copy_from_peer(
+        &mut self,
+        src: BindingResource<CudaResource>,
+        size: usize,
+    ) -> Option<server::Handle> {
+        let handle = self.empty(size);
+        let ctx = self.get_context();
+        let dst = find_resource(ctx, handle.clone().binding());
+
+        // With unified addressing, the driver finds the device of each pointer and copies between
+        // them directly when peer access is possible.
+        unsafe {
+            cudarc::driver::sys::cuMemcpyAsync(dst.ptr, src.resource().ptr, size, ctx.stream)
+                .result()
+                .unwrap();
+        }
+
+        Some(handle)
+    }
+
     unsafe fn execute(
         &mut self,
         kernel: Self::Kernel,
@@ -318,7 +358,7 @@ impl ComputeServer for CudaServer {
         bindings: Bindings,
         mode: ExecutionMode,
         logger: Arc<ServerLogger>,
-    ) {
+    ) -> Result<(), CompilationError> {
         let mut kernel_id = kernel.id();
         kernel_id.mode(mode);
 
@@ -384,7 +424,7 @@ impl ComputeServer for CudaServer {
         let ctx = self.get_context();
 
         if !ctx.module_names.contains_key(&kernel_id) {
-            ctx.compile_kernel(&kernel_id, kernel, mode, logger);
+            ctx.compile_kernel(&kernel_id, kernel, mode, logger)?;
         }
 
         let tensor_maps: Vec<_> = bindings
@@ -505,6 +545,8 @@ impl ComputeServer for CudaServer {
                 false => ctx.timestamps.error(ProfileError::Unknown(err)),
             },
         }
+
+        Ok(())
     }
 
     fn flush(&mut self) {}
@@ -545,6 +587,18 @@ impl ComputeServer for CudaServer {
     fn allocation_mode(&mut self, mode: cubecl_runtime::memory_management::MemoryAllocationMode) {
         self.ctx.memory_management.mode(mode);
     }
+
+    fn memory_tracing(&mut self, enabled: bool) {
+        self.ctx.memory_management.tracing(enabled);
+    }
+
+    fn memory_trace_tag(&mut self, tag: Option<String>) {
+        self.ctx.memory_management.trace_tag(tag);
+    }
+
+    fn memory_trace(&mut self) -> cubecl_runtime::memory_management::MemoryTrace {
+        self.ctx.memory_management.trace()
+    }
 }
 
 fn find_resource(ctx: &mut CudaContext, binding: server::Binding) -> CudaResource {
@@ -605,7 +659,7 @@ impl CudaContext {
         kernel: Box<dyn CubeTask<CudaCompiler>>,
         mode: ExecutionMode,
         logger: Arc<ServerLogger>,
-    ) {
+    ) -> Result<(), CompilationError> {
         #[cfg(feature = "compilation-cache")]
         let name = if let Some(cache) = &self.ptx_cache {
             let name = kernel_id.stable_format();
@@ -623,7 +677,7 @@ impl CudaContext {
                     },
                     entry.shared_mem_bytes,
                 );
-                return;
+                return Ok(());
             }
             Some(name)
         } else {
@@ -633,7 +687,7 @@ impl CudaContext {
         log::trace!("Compiling kernel");
 
         let mut kernel_compiled =
-            kernel.compile(&mut Default::default(), &self.compilation_options, mode);
+            kernel.compile(&mut Default::default(), &self.compilation_options, mode)?;
 
         if logger.compilation_activated() {
             kernel_compiled.debug_info = Some(DebugInformation::new("cpp", kernel_id.clone()));
@@ -679,10 +733,12 @@ impl CudaContext {
                         message += format!("\n    {line}").as_str();
                     }
                 }
-                let source = kernel
-                    .compile(&mut Default::default(), &self.compilation_options, mode)
-                    .source;
-                panic!("{message}\n[Source]  \n{source}");
+                let source = &kernel_compiled.source;
+                return Err(CompilationError::new(
+                    "cuda",
+                    format!("{message}\n[Source]  \n{source}"),
+                )
+                .with_kernel(kernel.name()));
             };
             cudarc::nvrtc::result::get_ptx(program).unwrap()
         };
@@ -713,6 +769,8 @@ impl CudaContext {
             cube_dim,
             repr.shared_memory_size(),
         );
+
+        Ok(())
     }
 
     fn load_ptx(
@@ -34,6 +34,8 @@ mod tests {
     cubecl_std::testgen_tensor_identity!([f16, bf16, f32, u32]);
     cubecl_convolution::testgen_conv2d_accelerated!([f16: f16, bf16: bf16, f32: tf32]);
     cubecl_reduce::testgen_reduce!([f16, bf16, f32, f64]);
+    cubecl_reduce::testgen_reduce_axes!([f16, bf16, f32, f64]);
+    cubecl_scan::testgen_scan!([f16, bf16, f32, f64]);
     cubecl_random::testgen_random!();
     cubecl_reduce::testgen_shared_sum!([f16, bf16, f32, f64]);
 }
@@ -156,6 +156,7 @@ fn create_client<M: DialectWmmaCompiler<CudaDialect<M>>>(
     );
     register_supported_types(&mut device_props);
     device_props.register_feature(Feature::Type(Elem::Float(FloatKind::TF32)));
+    device_props.register_feature(Feature::DeviceFence);
     if arch_version >= 60 {
         device_props.register_feature(Feature::Type(Elem::AtomicFloat(FloatKind::F64)));
     }
@@ -14,8 +14,8 @@ use cubecl_core::prelude::*;
 use cubecl_core::{Feature, server::Bindings};
 use cubecl_hip_sys::{HIP_SUCCESS, get_hip_include_path, hiprtcResult_HIPRTC_SUCCESS};
 use cubecl_runtime::logging::ServerLogger;
-use cubecl_runtime::memory_management::MemoryUsage;
 use cubecl_runtime::memory_management::offset_handles;
+use cubecl_runtime::memory_management::{MemoryUsage, OutOfMemory};
 use cubecl_runtime::storage::BindingResource;
 use cubecl_runtime::timestamp_profiler::TimestampProfiler;
 use cubecl_runtime::{
@@ -159,12 +159,12 @@ impl ComputeServer for HipServer {
         ctx.memory_management.cleanup(true);
     }
 
-    fn create(&mut self, data: &[u8]) -> server::Handle {
-        let handle = self.empty(data.len());
+    fn try_create(&mut self, data: &[u8]) -> Result<server::Handle, OutOfMemory> {
+        let handle = self.try_empty(data.len())?;
 
         let binding = handle.clone().binding();
         self.copy_to_binding(binding, data);
-        handle
+        Ok(handle)
     }
 
     fn create_tensors(
@@ -183,10 +183,10 @@ impl ComputeServer for HipServer {
         handles_strides
     }
 
-    fn empty(&mut self, size: usize) -> server::Handle {
+    fn try_empty(&mut self, size: usize) -> Result<server::Handle, OutOfMemory> {
         let ctx = self.get_context();
-        let handle = ctx.memory_management.reserve(size as u64, None);
-        server::Handle::new(handle, None, None, size as u64)
+        let handle = ctx.memory_management.try_reserve(size as u64, None)?;
+        Ok(server::Handle::new(handle, None, None, size as u64))
     }
 
     fn empty_tensors(
@@ -212,6 +212,49 @@ impl ComputeServer for HipServer {
         handles.into_iter().zip(strides).collect()
     }
 
+    fn copy(&mut self, src: server::Binding, dst: server::Binding) {
+        let ctx = self.get_context();
+        let src = find_resource(ctx, src);
+        let dst = find_resource(ctx, dst);
+        assert!(
+            dst.size >= src.size,
+            "The destination of a copy should be at least as big as the source"
+        );
+
+        unsafe {
+            let status =
+                cubecl_hip_sys::hipMemcpyDtoDAsync(dst.ptr, src.ptr, src.size as usize, ctx.stream);
+            assert_eq!(
+                status, HIP_SUCCESS,
+                "Should copy data from device to device"
+            );
+        }
+    }
+
+    fn copy_from_peer(
+        &mut self,
+        src: BindingResource<HipResource>,
+        size: usize,
+    ) -> Option<server::Handle> {
+        let handle = self.empty(size);
+        let ctx = self.get_context();
+        let dst = find_resource(ctx, handle.clone().binding());
+
+        // The runtime finds the device of each pointer from its virtual address.
+        unsafe {
+            let status = cubecl_hip_sys::hipMemcpyAsync(
+                dst.ptr,
+                src.resource().ptr,
+                size,
+                cubecl_hip_sys::hipMemcpyKind_hipMemcpyDefault,
+                ctx.stream,
+            );
+            assert_eq!(status, HIP_SUCCESS, "Should copy data between devices");
+        }
+
+        Some(handle)
+    }
+
     unsafe fn execute(
         &mut self,
         kernel: Self::Kernel,
@@ -219,7 +262,7 @@ impl ComputeServer for HipServer {
         bindings: Bindings,
         mode: ExecutionMode,
         logger: Arc<ServerLogger>,
-    ) {
+    ) -> Result<(), CompilationError> {
         let mut kernel_id = kernel.id();
         kernel_id.mode(mode);
 
@@ -253,7 +296,7 @@ impl ComputeServer for HipServer {
         let ctx = self.get_context();
 
         if !ctx.module_names.contains_key(&kernel_id) {
-            ctx.compile_kernel(&kernel_id, kernel, mode, logger);
+            ctx.compile_kernel(&kernel_id, kernel, mode, logger)?;
         }
 
         let mut resources: Vec<_> = buffers.into_iter().map(|b| find_resource(ctx, b)).collect();
@@ -261,6 +304,8 @@ impl ComputeServer for HipServer {
         resources.extend(scalars.into_iter().map(|s| find_resource(ctx, s.binding())));
 
         ctx.execute_task(kernel_id, count, resources);
+
+        Ok(())
     }
 
     fn flush(&mut self) {}
@@ -293,6 +338,21 @@ impl ComputeServer for HipServer {
         let ctx = self.get_context();
         ctx.memory_management.mode(mode);
     }
+
+    fn memory_tracing(&mut self, enabled: bool) {
+        let ctx = self.get_context();
+        ctx.memory_management.tracing(enabled);
+    }
+
+    fn memory_trace_tag(&mut self, tag: Option<String>) {
+        let ctx = self.get_context();
+        ctx.memory_management.trace_tag(tag);
+    }
+
+    fn memory_trace(&mut self) -> cubecl_runtime::memory_management::MemoryTrace {
+        let ctx = self.get_context();
+        ctx.memory_management.trace()
+    }
 }
 
 fn find_resource(ctx: &mut HipContext, binding: server::Binding) -> HipResource {
@@ -347,7 +407,7 @@ impl HipContext {
         cube_kernel: Box<dyn CubeTask<HipCompiler>>,
         mode: ExecutionMode,
         logger: Arc<ServerLogger>,
-    ) {
+    ) -> Result<(), CompilationError> {
         #[cfg(feature = "compilation-cache")]
         let name = kernel_id.stable_format();
         #[cfg(feature = "compilation-cache")]
@@ -363,13 +423,13 @@ impl HipContext {
                     z: entry.cube_dim.2,
                 },
             );
-            return;
+            return Ok(());
         }
 
         // CubeCL compilation
         // jitc = just-in-time compiled
         let mut jitc_kernel =
-            cube_kernel.compile(&mut Default::default(), &self.compilation_options, mode);
+            cube_kernel.compile(&mut Default::default(), &self.compilation_options, mode)?;
 
         if logger.compilation_activated() {
             jitc_kernel.debug_info = Some(DebugInformation::new("cpp", kernel_id.clone()));
@@ -440,7 +500,11 @@ impl HipContext {
                 } else {
                     message += "\n No compilation logs found!";
                 }
-                panic!("{message}\n[Source]  \n{}", jitc_kernel.source);
+                return Err(CompilationError::new(
+                    "hip",
+                    format!("{message}\n[Source]  \n{}", jitc_kernel.source),
+                )
+                .with_kernel(cube_kernel.name()));
             }
             assert_eq!(
                 status, hiprtcResult_HIPRTC_SUCCESS,
@@ -487,6 +551,8 @@ impl HipContext {
             jitc_kernel.entrypoint_name,
             jitc_kernel.cube_dim,
         );
+
+        Ok(())
     }
 
     fn load_compiled_binary(
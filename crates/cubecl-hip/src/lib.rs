@@ -29,5 +29,7 @@ mod tests {
     cubecl_matmul::testgen_matmul_simple!([f16, f32]);
 
     cubecl_reduce::testgen_reduce!([f16, bf16, f32, f64]);
+    cubecl_reduce::testgen_reduce_axes!([f16, bf16, f32, f64]);
+    cubecl_scan::testgen_scan!([f16, bf16, f32, f64]);
     cubecl_reduce::testgen_shared_sum!([f32]);
 }
@@ -173,6 +173,7 @@ fn create_client<M: DialectWmmaCompiler<HipDialect<M>>>(
     device_props.register_feature(Feature::AtomicUInt(AtomicFeature::Add));
 
     device_props.register_feature(Feature::DynamicLineSize);
+    device_props.register_feature(Feature::DeviceFence);
 
     register_wmma_features(supported_wmma_combinations, &mut device_props);
 
@@ -0,0 +1,392 @@
+mod program;
+
+pub use program::*;
+
+use std::collections::HashMap;
+
+use cubecl_core::{
+    AtomicFeature, CompilationError, CompilationErrorRecorder, Compiler, ExecutionMode, Feature,
+    Metadata,
+    ir::{
+        BarrierLevel, BarrierOps, Branch, Elem, FloatKind, Id, Instruction, IntKind, NonSemantic,
+        Operation, OperationReflect, Processor, Scope, Synchronization, UIntKind, Variable,
+        VariableKind,
+    },
+    post_processing::checked_io::{CheckedIoProcessor, register_error_buffer},
+    prelude::KernelDefinition,
+};
+use cubecl_runtime::DeviceProperties;
+
+use crate::compute::value::{Scalar, coerce};
+
+/// Lowers kernels to [programs](Program) executed by the interpreter.
+#[derive(Clone, Debug, Default)]
+pub struct InterpreterCompiler {
+    program: Vec<Inst>,
+    const_arrays: HashMap<Id, Vec<Scalar>>,
+    /// For every enclosing loop, the target of `continue` and the jumps to patch with its exit.
+    loops: Vec<LoopFrame>,
+    mode: ExecutionMode,
+    error_buffer: Option<Variable>,
+    errors: CompilationErrorRecorder,
+}
+
+#[derive(Clone, Debug)]
+struct LoopFrame {
+    continue_target: Option<usize>,
+    continues: Vec<usize>,
+    breaks: Vec<usize>,
+}
+
+#[derive(Default, Debug)]
+pub struct InterpreterCompilerOptions {}
+
+impl Compiler for InterpreterCompiler {
+    type Representation = Program;
+
+    type CompilationOptions = InterpreterCompilerOptions;
+
+    fn compile(
+        &mut self,
+        mut kernel: KernelDefinition,
+        _compilation_options: &Self::CompilationOptions,
+        mode: ExecutionMode,
+    ) -> Result<Self::Representation, CompilationError> {
+        *self = Self {
+            mode,
+            error_buffer: register_error_buffer(&mut kernel, mode),
+            ..Default::default()
+        };
+
+        if !kernel.tensor_maps.is_empty() {
+            self.errors.unsupported("Tensor maps aren't supported");
+        }
+
+        let mut ext_meta_pos = Vec::new();
+        let mut num_ext = 0;
+        for binding in kernel.buffers.iter() {
+            ext_meta_pos.push(num_ext);
+            if binding.has_extended_meta {
+                num_ext += 1;
+            }
+        }
+
+        self.compile_scope(&mut kernel.body);
+        self.program.push(Inst::Return);
+
+        if let Some(err) = self.errors.take("interpreter") {
+            return Err(err);
+        }
+
+        Ok(Program {
+            name: kernel.options.kernel_name,
+            cube_dim: kernel.cube_dim,
+            buffers: kernel.buffers.iter().map(|binding| binding.item).collect(),
+            metadata: Metadata::new(kernel.buffers.len() as u32, num_ext),
+            ext_meta_pos,
+            const_arrays: core::mem::take(&mut self.const_arrays),
+            instructions: core::mem::take(&mut self.program),
+        })
+    }
+
+    fn elem_size(&self, elem: Elem) -> usize {
+        elem.size()
+    }
+
+    fn extension(&self) -> &'static str {
+        "txt"
+    }
+}
+
+impl InterpreterCompiler {
+    fn check_variable(&mut self, var: &Variable) {
+        match var.kind {
+            VariableKind::TensorMap(_) => self.errors.unsupported("Tensor maps aren't supported"),
+            VariableKind::Matrix { .. } => self
+                .errors
+                .unsupported("Cooperative matrices aren't supported"),
+            VariableKind::Pipeline { .. } => self.errors.unsupported("Pipelines aren't supported"),
+            _ => {}
+        }
+        if let Elem::Float(kind) | Elem::AtomicFloat(kind) = var.elem() {
+            if matches!(
+                kind,
+                FloatKind::E2M1
+                    | FloatKind::E2M3
+                    | FloatKind::E3M2
+                    | FloatKind::E4M3
+                    | FloatKind::E5M2
+                    | FloatKind::UE8M0
+            ) {
+                self.errors.unsupported(format!("{kind:?} isn't supported"));
+            }
+        }
+    }
+
+    fn compile_scope(&mut self, scope: &mut Scope) {
+        for (var, values) in scope.const_arrays.drain(..) {
+            let values = values
+                .into_iter()
+                .flat_map(|value| match value.as_const() {
+                    Some(constant) => coerce(
+                        smallvec::smallvec![Scalar::from_constant(constant)],
+                        var.item,
+                    ),
+                    None => unreachable!("Constant arrays should only contain constants"),
+                })
+                .collect();
+            self.const_arrays.insert(var.index().unwrap(), values);
+        }
+
+        let checked_io: Box<dyn Processor> =
+            Box::new(CheckedIoProcessor::new(self.mode, self.error_buffer));
+        let processing = scope.process([checked_io]);
+
+        for instruction in processing.instructions {
+            self.errors.set_operation(&instruction);
+            for var in instruction.operation.args().into_iter().flatten() {
+                self.check_variable(&var);
+            }
+            if let Some(out) = &instruction.out {
+                self.check_variable(out);
+            }
+            self.compile_instruction(instruction);
+        }
+    }
+
+    fn compile_instruction(&mut self, instruction: Instruction) {
+        let out = instruction.out;
+        match instruction.operation {
+            Operation::Branch(branch) => self.compile_branch(branch),
+            Operation::Synchronization(sync) => match sync {
+                Synchronization::SyncCube => self.program.push(Inst::SyncCube),
+                Synchronization::SyncPlane => self.program.push(Inst::SyncPlane),
+                // Memory is always coherent, units are executed one after the other.
+                Synchronization::SyncStorage
+                | Synchronization::SyncProxyShared
+                | Synchronization::DeviceFence => {}
+            },
+            Operation::Plane(op) => self.program.push(Inst::Plane {
+                op,
+                out: out.expect("Plane operations should have an out"),
+            }),
+            Operation::Barrier(op) => self.compile_barrier(op, out),
+            Operation::CoopMma(_) => self
+                .errors
+                .unsupported("Cooperative matrices aren't supported"),
+            Operation::Tma(_) => self.errors.unsupported("TMA isn't supported"),
+            Operation::NonSemantic(
+                NonSemantic::EnterDebugScope
+                | NonSemantic::ExitDebugScope
+                | NonSemantic::Comment { .. },
+            ) => {}
+            Operation::NonSemantic(op) => self.push_op(Op::NonSemantic(op), out),
+            Operation::Copy(var) => self.push_op(Op::Copy(var), out),
+            Operation::Arithmetic(op) => self.push_op(Op::Arithmetic(op), out),
+            Operation::Comparison(op) => self.push_op(Op::Comparison(op), out),
+            Operation::Bitwise(op) => self.push_op(Op::Bitwise(op), out),
+            Operation::Operator(op) => self.push_op(Op::Operator(op), out),
+            Operation::Atomic(op) => self.push_op(Op::Atomic(op), out),
+            Operation::Metadata(op) => self.push_op(Op::Metadata(op), out),
+        }
+    }
+
+    fn push_op(&mut self, op: Op, out: Option<Variable>) {
+        self.program.push(Inst::Op { op, out });
+    }
+
+    fn compile_barrier(&mut self, op: BarrierOps, out: Option<Variable>) {
+        match op {
+            // Async copies are executed synchronously, so only the waits of cube level barriers
+            // need to synchronize units.
+            BarrierOps::MemCopyAsync { .. } => self.push_op(Op::Barrier(op), out),
+            BarrierOps::TmaLoad { .. } | BarrierOps::TmaLoadIm2col { .. } => {
+                self.errors.unsupported("TMA isn't supported")
+            }
+            BarrierOps::Wait { barrier } | BarrierOps::ArriveAndWait { barrier } => {
+                let VariableKind::Barrier { level, .. } = barrier.kind else {
+                    unreachable!("{barrier} should be a barrier");
+                };
+                match level {
+                    BarrierLevel::Unit => {}
+                    BarrierLevel::CubeCoop(_) | BarrierLevel::CubeManual(_) => {
+                        self.program.push(Inst::SyncCube)
+                    }
+                }
+            }
+            BarrierOps::Init { .. }
+            | BarrierOps::Arrive { .. }
+            | BarrierOps::ArriveTx { .. }
+            | BarrierOps::ExpectTx { .. } => {}
+        }
+    }
+
+    fn compile_branch(&mut self, branch: Branch) {
+        match branch {
+            Branch::If(mut op) => {
+                let jump = self.push_placeholder();
+                self.compile_scope(&mut op.scope);
+                self.program[jump] = Inst::JumpIfNot {
+                    cond: op.cond,
+                    target: self.program.len(),
+                };
+            }
+            Branch::IfElse(mut op) => {
+                let jump_else = self.push_placeholder();
+                self.compile_scope(&mut op.scope_if);
+                let jump_end = self.push_placeholder();
+                self.program[jump_else] = Inst::JumpIfNot {
+                    cond: op.cond,
+                    target: self.program.len(),
+                };
+                self.compile_scope(&mut op.scope_else);
+                self.program[jump_end] = Inst::Jump(self.program.len());
+            }
+            Branch::Switch(mut op) => {
+                let switch = self.push_placeholder();
+                let mut jumps_end = Vec::new();
+                let mut cases = Vec::with_capacity(op.cases.len());
+
+                for (value, scope) in op.cases.iter_mut() {
+                    cases.push((*value, self.program.len()));
+                    self.compile_scope(scope);
+                    jumps_end.push(self.push_placeholder());
+                }
+                let default = self.program.len();
+                self.compile_scope(&mut op.scope_default);
+
+                let end = self.program.len();
+                for jump in jumps_end {
+                    self.program[jump] = Inst::Jump(end);
+                }
+                self.program[switch] = Inst::Switch {
+                    value: op.value,
+                    cases,
+                    default,
+                };
+            }
+            Branch::RangeLoop(mut op) => {
+                self.push_op(Op::Copy(op.start), Some(op.i));
+                let head = self.program.len();
+                let cond = self.push_placeholder();
+
+                self.loops.push(LoopFrame {
+                    continue_target: None,
+                    continues: Vec::new(),
+                    breaks: Vec::new(),
+                });
+                self.compile_scope(&mut op.scope);
+                let step = self.program.len();
+                self.program.push(Inst::RangeStep {
+                    i: op.i,
+                    step: op.step,
+                });
+                self.program.push(Inst::Jump(head));
+                let frame = self.loops.pop().unwrap();
+
+                let exit = self.program.len();
+                self.program[cond] = Inst::RangeCond {
+                    i: op.i,
+                    end: op.end,
+                    inclusive: op.inclusive,
+                    exit,
+                };
+                self.patch_loop(frame, step, exit);
+            }
+            Branch::Loop(mut op) => {
+                let head = self.program.len();
+                self.loops.push(LoopFrame {
+                    continue_target: Some(head),
+                    continues: Vec::new(),
+                    breaks: Vec::new(),
+                });
+                self.compile_scope(&mut op.scope);
+                self.program.push(Inst::Jump(head));
+                let frame = self.loops.pop().unwrap();
+                self.patch_loop(frame, head, self.program.len());
+            }
+            Branch::Return => self.program.push(Inst::Return),
+            Branch::Break => match self.loops.last_mut() {
+                Some(frame) => {
+                    let jump = self.program.len();
+                    frame.breaks.push(jump);
+                    self.program.push(Inst::Jump(usize::MAX));
+                }
+                None => self.errors.unsupported("Break outside of a loop"),
+            },
+            Branch::Continue => match self.loops.last_mut() {
+                Some(frame) => {
+                    let target = frame.continue_target;
+                    let jump = self.program.len();
+                    match target {
+                        Some(target) => self.program.push(Inst::Jump(target)),
+                        None => {
+                            frame.continues.push(jump);
+                            self.program.push(Inst::Jump(usize::MAX));
+                        }
+                    }
+                }
+                None => self.errors.unsupported("Continue outside of a loop"),
+            },
+        }
+    }
+
+    /// Push a jump that is patched once its target is known.
+    fn push_placeholder(&mut self) -> usize {
+        self.program.push(Inst::Jump(usize::MAX));
+        self.program.len() - 1
+    }
+
+    fn patch_loop(&mut self, frame: LoopFrame, continue_target: usize, exit: usize) {
+        for jump in frame.continues {
+            self.program[jump] = Inst::Jump(continue_target);
+        }
+        for jump in frame.breaks {
+            self.program[jump] = Inst::Jump(exit);
+        }
+    }
+}
+
+/// Register the features of the interpreter.
+pub fn register_features(props: &mut DeviceProperties<Feature>) {
+    for elem in [
+        Elem::Float(FloatKind::F16),
+        Elem::Float(FloatKind::BF16),
+        Elem::Float(FloatKind::Flex32),
+        Elem::Float(FloatKind::F32),
+        Elem::Float(FloatKind::F64),
+        Elem::Int(IntKind::I8),
+        Elem::Int(IntKind::I16),
+        Elem::Int(IntKind::I32),
+        Elem::Int(IntKind::I64),
+        Elem::UInt(UIntKind::U8),
+        Elem::UInt(UIntKind::U16),
+        Elem::UInt(UIntKind::U32),
+        Elem::UInt(UIntKind::U64),
+        Elem::AtomicFloat(FloatKind::F32),
+        Elem::AtomicFloat(FloatKind::F64),
+        Elem::AtomicInt(IntKind::I32),
+        Elem::AtomicInt(IntKind::I64),
+        Elem::AtomicUInt(UIntKind::U32),
+        Elem::AtomicUInt(UIntKind::U64),
+        Elem::Bool,
+    ] {
+        props.register_feature(Feature::Type(elem));
+    }
+
+    for feature in [
+        AtomicFeature::LoadStore,
+        AtomicFeature::Add,
+        AtomicFeature::MinMax,
+    ] {
+        props.register_feature(Feature::AtomicFloat(feature));
+        props.register_feature(Feature::AtomicInt(feature));
+        props.register_feature(Feature::AtomicUInt(feature));
+    }
+
+    props.register_feature(Feature::Plane);
+    props.register_feature(Feature::SyncPlane);
+    props.register_feature(Feature::Barrier);
+    props.register_feature(Feature::DynamicLineSize);
+    props.register_feature(Feature::DeviceFence);
+}
@@ -0,0 +1,153 @@
+use std::{collections::HashMap, fmt::Display};
+
+use cubecl_core::{
+    CubeDim, Metadata,
+    ir::{
+        self, Arithmetic, AtomicOp, BarrierOps, Bitwise, Comparison, Id, Instruction, Item,
+        NonSemantic, Operation, Operator, Plane, Variable,
+    },
+};
+
+use crate::compute::value::Scalar;
+
+/// A kernel lowered to a flat list of instructions, where structured control flow is replaced by
+/// jumps.
+#[derive(Debug, Clone)]
+pub struct Program {
+    pub name: String,
+    pub cube_dim: CubeDim,
+    /// The item of every buffer, indexed by binding position.
+    pub buffers: Vec<Item>,
+    /// Layout of the metadata binding.
+    pub metadata: Metadata,
+    /// The position of every buffer in the extended metadata.
+    pub ext_meta_pos: Vec<u32>,
+    /// The elements of every constant array.
+    pub const_arrays: HashMap<Id, Vec<Scalar>>,
+    pub instructions: Vec<Inst>,
+}
+
+/// An operation that doesn't affect control flow.
+#[derive(Debug, Clone)]
+pub enum Op {
+    Copy(Variable),
+    Arithmetic(Arithmetic),
+    Comparison(Comparison),
+    Bitwise(Bitwise),
+    Operator(Operator),
+    Atomic(AtomicOp),
+    Metadata(ir::Metadata),
+    /// Only async copies, which are executed synchronously.
+    Barrier(BarrierOps),
+    /// Only prints.
+    NonSemantic(NonSemantic),
+}
+
+impl From<Op> for Operation {
+    fn from(op: Op) -> Self {
+        match op {
+            Op::Copy(var) => Operation::Copy(var),
+            Op::Arithmetic(op) => Operation::Arithmetic(op),
+            Op::Comparison(op) => Operation::Comparison(op),
+            Op::Bitwise(op) => Operation::Bitwise(op),
+            Op::Operator(op) => Operation::Operator(op),
+            Op::Atomic(op) => Operation::Atomic(op),
+            Op::Metadata(op) => Operation::Metadata(op),
+            Op::Barrier(op) => Operation::Barrier(op),
+            Op::NonSemantic(op) => Operation::NonSemantic(op),
+        }
+    }
+}
+
+/// A single step of a [program](Program).
+#[derive(Debug, Clone)]
+pub enum Inst {
+    Op {
+        op: Op,
+        out: Option<Variable>,
+    },
+    Jump(usize),
+    JumpIfNot {
+        cond: Variable,
+        target: usize,
+    },
+    Switch {
+        value: Variable,
+        cases: Vec<(Variable, usize)>,
+        default: usize,
+    },
+    /// Exit the loop at `exit` if `i` reached `end`.
+    RangeCond {
+        i: Variable,
+        end: Variable,
+        inclusive: bool,
+        exit: usize,
+    },
+    RangeStep {
+        i: Variable,
+        step: Option<Variable>,
+    },
+    Return,
+    SyncCube,
+    SyncPlane,
+    /// A collective operation, executed by every active unit of the plane at once.
+    Plane {
+        op: Plane,
+        out: Variable,
+    },
+}
+
+impl Display for Program {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "kernel {} cube_dim {:?}", self.name, self.cube_dim)?;
+        for (id, values) in self.const_arrays.iter() {
+            writeln!(f, "const array {id}: {values:?}")?;
+        }
+        for (pc, inst) in self.instructions.iter().enumerate() {
+            write!(f, "{pc:>5}: ")?;
+            match inst {
+                Inst::Op { op, out } => {
+                    let instruction = Instruction {
+                        out: *out,
+                        source_loc: None,
+                        operation: op.clone().into(),
+                    };
+                    writeln!(f, "{instruction}")?
+                }
+                Inst::Jump(target) => writeln!(f, "jump {target}")?,
+                Inst::JumpIfNot { cond, target } => writeln!(f, "jump_if_not({cond}) {target}")?,
+                Inst::Switch {
+                    value,
+                    cases,
+                    default,
+                } => {
+                    write!(f, "switch({value})")?;
+                    for (case, target) in cases {
+                        write!(f, " {case} => {target},")?;
+                    }
+                    writeln!(f, " _ => {default}")?
+                }
+                Inst::RangeCond {
+                    i,
+                    end,
+                    inclusive,
+                    exit,
+                } => {
+                    let cmp = if *inclusive { "<=" } else { "<" };
+                    writeln!(f, "if !({i} {cmp} {end}) jump {exit}")?
+                }
+                Inst::RangeStep {
+                    i,
+                    step: Some(step),
+                } => writeln!(f, "{i} += {step}")?,
+                Inst::RangeStep { i, step: None } => writeln!(f, "{i} += 1")?,
+                Inst::Return => writeln!(f, "return")?,
+                Inst::SyncCube => writeln!(f, "sync_cube")?,
+                Inst::SyncPlane => writeln!(f, "sync_plane")?,
+                // Plane operations already end with a new line.
+                Inst::Plane { op, out } => write!(f, "{out} = {op}")?,
+            }
+        }
+        Ok(())
+    }
+}
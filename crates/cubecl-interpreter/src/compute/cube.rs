@@ -0,0 +1,128 @@
+use crate::compiler::{Inst, Program};
+
+use super::{
+    plane::{PLANE_SIZE, execute_plane},
+    unit::{Env, Globals, SharedMemories, Unit, UnitState},
+};
+
+/// Execute every cube of a launch, one after the other.
+pub fn execute(program: &Program, globals: &Globals) {
+    let [count_x, count_y, count_z] = globals.cube_count;
+
+    for z in 0..count_z {
+        for y in 0..count_y {
+            for x in 0..count_x {
+                let env = Env {
+                    program,
+                    globals,
+                    cube_pos: [x, y, z],
+                };
+                execute_cube(&env);
+            }
+        }
+    }
+}
+
+/// Execute the units of a cube until they are all done.
+///
+/// Planes run one at a time, and the units of a plane execute in lockstep, one instruction each in
+/// lane order, until they finish or block on a synchronization point. Once no unit can make
+/// progress, blocked units are released in a fixed order, which makes every execution
+/// deterministic:
+///
+/// 1. Plane operations, once no unit of the plane is running. Units at the same instruction form
+///    the active set of the operation.
+/// 2. Plane synchronizations.
+/// 3. Cube synchronizations, once every unit that isn't done reached one.
+fn execute_cube(env: &Env) {
+    let dim = env.program.cube_dim;
+    let mut shared = SharedMemories::new();
+    let mut units = Vec::with_capacity(dim.num_elems() as usize);
+
+    // Units are ordered by `UNIT_POS` so consecutive units belong to the same plane.
+    for z in 0..dim.z {
+        for y in 0..dim.y {
+            for x in 0..dim.x {
+                units.push(Unit::new([x, y, z], units.len() as u32));
+            }
+        }
+    }
+
+    loop {
+        for plane in units.chunks_mut(PLANE_SIZE as usize) {
+            while plane.iter().any(|unit| unit.state == UnitState::Running) {
+                for unit in plane.iter_mut() {
+                    unit.step(env, &mut shared);
+                }
+            }
+        }
+
+        if units.iter().all(|unit| unit.state == UnitState::Done) {
+            return;
+        }
+        if release_plane_operations(&mut units, env) {
+            continue;
+        }
+
+        let mut released = false;
+        for unit in units.iter_mut() {
+            if unit.state == UnitState::SyncPlane {
+                unit.state = UnitState::Running;
+                released = true;
+            }
+        }
+        if released {
+            continue;
+        }
+
+        if units
+            .iter()
+            .all(|unit| matches!(unit.state, UnitState::SyncCube | UnitState::Done))
+        {
+            for unit in units.iter_mut() {
+                if unit.state == UnitState::SyncCube {
+                    unit.state = UnitState::Running;
+                }
+            }
+            continue;
+        }
+
+        panic!(
+            "Kernel {} deadlocked in cube {:?}: {:?}",
+            env.program.name,
+            env.cube_pos,
+            units
+                .iter()
+                .map(|unit| (unit.index, unit.state, unit.pc))
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+/// Execute the pending plane operations, returns whether any unit was released.
+fn release_plane_operations(units: &mut [Unit], env: &Env) -> bool {
+    let mut released = false;
+
+    for plane in units.chunks_mut(PLANE_SIZE as usize) {
+        let Some(pc) = plane
+            .iter()
+            .filter(|unit| unit.state == UnitState::Plane)
+            .map(|unit| unit.pc)
+            .min()
+        else {
+            continue;
+        };
+        let Inst::Plane { op, out } = &env.program.instructions[pc] else {
+            unreachable!("Units wait on plane operations");
+        };
+
+        let mut group = plane
+            .iter_mut()
+            .filter(|unit| unit.state == UnitState::Plane && unit.pc == pc)
+            .collect::<Vec<_>>();
+        execute_plane(op, out, &mut group, env);
+        released = true;
+    }
+
+    released
+}
@@ -0,0 +1,6 @@
+pub mod cube;
+pub mod ops;
+pub mod plane;
+pub mod server;
+pub mod unit;
+pub mod value;
@@ -0,0 +1,353 @@
+//! Evaluation of the pure operations, component by component.
+//!
+//! Operands are converted to the element the operation is computed in, then every component is
+//! computed in the widest representation of its kind and normalized back to that element.
+
+use core::cmp::Ordering;
+
+use cubecl_core::ir::{Arithmetic, Bitwise, Comparison, Elem, Item, Variable};
+
+use super::value::{Line, Scalar, coerce, value_elem};
+
+/// Apply `func` to every component of the operands, broadcasting scalars.
+pub fn binary(lhs: Line, rhs: Line, elem: Elem, func: impl Fn(Scalar, Scalar) -> Scalar) -> Line {
+    let len = lhs.len().max(rhs.len());
+    let component = |line: &Line, i: usize| line[if line.len() == 1 { 0 } else { i }].cast(elem);
+    (0..len)
+        .map(|i| func(component(&lhs, i), component(&rhs, i)).cast(elem))
+        .collect()
+}
+
+/// Apply `func` to every component of the input.
+pub fn unary(input: Line, elem: Elem, func: impl Fn(Scalar) -> Scalar) -> Line {
+    input
+        .into_iter()
+        .map(|value| func(value.cast(elem)).cast(elem))
+        .collect()
+}
+
+fn numeric2(
+    lhs: Scalar,
+    rhs: Scalar,
+    float: fn(f64, f64) -> f64,
+    int: fn(i64, i64) -> i64,
+    uint: fn(u64, u64) -> u64,
+) -> Scalar {
+    match (lhs, rhs) {
+        (Scalar::Float(lhs), Scalar::Float(rhs)) => Scalar::Float(float(lhs, rhs)),
+        (Scalar::Int(lhs), Scalar::Int(rhs)) => Scalar::Int(int(lhs, rhs)),
+        (Scalar::UInt(lhs), Scalar::UInt(rhs)) => Scalar::UInt(uint(lhs, rhs)),
+        (Scalar::Bool(lhs), Scalar::Bool(rhs)) => Scalar::Bool(uint(lhs as u64, rhs as u64) != 0),
+        (lhs, rhs) => unreachable!("Operands {lhs:?} and {rhs:?} should have the same element"),
+    }
+}
+
+fn numeric1(
+    input: Scalar,
+    float: fn(f64) -> f64,
+    int: fn(i64) -> i64,
+    uint: fn(u64) -> u64,
+) -> Scalar {
+    match input {
+        Scalar::Float(val) => Scalar::Float(float(val)),
+        Scalar::Int(val) => Scalar::Int(int(val)),
+        Scalar::UInt(val) => Scalar::UInt(uint(val)),
+        Scalar::Bool(val) => Scalar::Bool(uint(val as u64) != 0),
+    }
+}
+
+fn float1(input: Line, elem: Elem, func: fn(f64) -> f64) -> Line {
+    unary(input, elem, |val| Scalar::Float(func(val.as_f64())))
+}
+
+fn float2(lhs: Line, rhs: Line, elem: Elem, func: fn(f64, f64) -> f64) -> Line {
+    binary(lhs, rhs, elem, |lhs, rhs| {
+        Scalar::Float(func(lhs.as_f64(), rhs.as_f64()))
+    })
+}
+
+fn int_div(lhs: i64, rhs: i64) -> i64 {
+    if rhs == 0 { 0 } else { lhs.wrapping_div(rhs) }
+}
+
+fn int_rem(lhs: i64, rhs: i64) -> i64 {
+    if rhs == 0 { 0 } else { lhs.wrapping_rem(rhs) }
+}
+
+fn uint_div(lhs: u64, rhs: u64) -> u64 {
+    lhs.checked_div(rhs).unwrap_or(0)
+}
+
+fn uint_rem(lhs: u64, rhs: u64) -> u64 {
+    lhs.checked_rem(rhs).unwrap_or(0)
+}
+
+/// Evaluate an arithmetic operation producing a value of `out`.
+pub fn arithmetic(op: &Arithmetic, out: Item, read: impl Fn(&Variable) -> Line) -> Line {
+    let elem = value_elem(out.elem);
+
+    match op {
+        Arithmetic::Add(op) => binary(read(&op.lhs), read(&op.rhs), elem, add),
+        Arithmetic::Sub(op) => binary(read(&op.lhs), read(&op.rhs), elem, sub),
+        Arithmetic::Mul(op) => binary(read(&op.lhs), read(&op.rhs), elem, mul),
+        Arithmetic::Div(op) => binary(read(&op.lhs), read(&op.rhs), elem, |lhs, rhs| {
+            numeric2(lhs, rhs, |a, b| a / b, int_div, uint_div)
+        }),
+        Arithmetic::Modulo(op) => binary(read(&op.lhs), read(&op.rhs), elem, |lhs, rhs| {
+            numeric2(lhs, rhs, |a, b| a % b, int_rem, uint_rem)
+        }),
+        Arithmetic::Remainder(op) => binary(read(&op.lhs), read(&op.rhs), elem, |lhs, rhs| {
+            numeric2(
+                lhs,
+                rhs,
+                |a, b| a - b * (a / b).floor(),
+                |a, b| {
+                    let rem = int_rem(a, b);
+                    if rem != 0 && (rem < 0) != (b < 0) {
+                        rem + b
+                    } else {
+                        rem
+                    }
+                },
+                uint_rem,
+            )
+        }),
+        Arithmetic::Max(op) => binary(read(&op.lhs), read(&op.rhs), elem, max),
+        Arithmetic::Min(op) => binary(read(&op.lhs), read(&op.rhs), elem, min),
+        Arithmetic::MulHi(op) => binary(read(&op.lhs), read(&op.rhs), elem, |lhs, rhs| {
+            let bits = elem.size_bits() as u32;
+            match (lhs, rhs) {
+                (Scalar::Int(a), Scalar::Int(b)) => {
+                    Scalar::Int(((a as i128 * b as i128) >> bits) as i64)
+                }
+                (Scalar::UInt(a), Scalar::UInt(b)) => {
+                    Scalar::UInt(((a as u128 * b as u128) >> bits) as u64)
+                }
+                (lhs, rhs) => unreachable!("mul_hi isn't defined for {lhs:?} and {rhs:?}"),
+            }
+        }),
+        Arithmetic::Powf(op) => float2(read(&op.lhs), read(&op.rhs), elem, f64::powf),
+        Arithmetic::ArcTan2(op) => float2(read(&op.lhs), read(&op.rhs), elem, f64::atan2),
+        Arithmetic::Hypot(op) => float2(read(&op.lhs), read(&op.rhs), elem, f64::hypot),
+        Arithmetic::Fma(op) => {
+            let a = read(&op.a);
+            let b = read(&op.b);
+            let c = read(&op.c);
+            let len = a.len().max(b.len()).max(c.len());
+            let component = |line: &Line, i: usize| line[if line.len() == 1 { 0 } else { i }];
+            (0..len)
+                .map(|i| {
+                    let (a, b, c) = (component(&a, i), component(&b, i), component(&c, i));
+                    Scalar::Float(a.as_f64().mul_add(b.as_f64(), c.as_f64())).cast(elem)
+                })
+                .collect()
+        }
+        Arithmetic::Clamp(op) => {
+            let input = read(&op.input);
+            let lower = read(&op.min_value);
+            let upper = read(&op.max_value);
+            binary(binary(input, lower, elem, max), upper, elem, min)
+        }
+        Arithmetic::Dot(op) => {
+            let products = binary(read(&op.lhs), read(&op.rhs), elem, mul);
+            let sum = products
+                .into_iter()
+                .reduce(|acc, val| add(acc, val).cast(elem));
+            smallvec::smallvec![sum.unwrap_or(Scalar::zero(elem))]
+        }
+        Arithmetic::Magnitude(op) => {
+            let input = read(&op.input);
+            let sum: f64 = input.iter().map(|val| val.as_f64() * val.as_f64()).sum();
+            smallvec::smallvec![Scalar::Float(sum.sqrt()).cast(elem)]
+        }
+        Arithmetic::Normalize(op) => {
+            let input = read(&op.input);
+            let sum: f64 = input.iter().map(|val| val.as_f64() * val.as_f64()).sum();
+            let norm = sum.sqrt();
+            unary(input, elem, |val| Scalar::Float(val.as_f64() / norm))
+        }
+        Arithmetic::Abs(op) => unary(read(&op.input), elem, |val| {
+            numeric1(val, f64::abs, i64::wrapping_abs, |a| a)
+        }),
+        Arithmetic::Neg(op) => unary(read(&op.input), elem, |val| {
+            numeric1(val, |a| -a, i64::wrapping_neg, u64::wrapping_neg)
+        }),
+        Arithmetic::Exp(op) => float1(read(&op.input), elem, f64::exp),
+        Arithmetic::Exp2(op) => float1(read(&op.input), elem, f64::exp2),
+        Arithmetic::Log(op) => float1(read(&op.input), elem, f64::ln),
+        Arithmetic::Log1p(op) => float1(read(&op.input), elem, f64::ln_1p),
+        Arithmetic::Log2(op) => float1(read(&op.input), elem, f64::log2),
+        Arithmetic::Log10(op) => float1(read(&op.input), elem, f64::log10),
+        Arithmetic::Cos(op) => float1(read(&op.input), elem, f64::cos),
+        Arithmetic::Sin(op) => float1(read(&op.input), elem, f64::sin),
+        Arithmetic::Tan(op) => float1(read(&op.input), elem, f64::tan),
+        Arithmetic::Tanh(op) => float1(read(&op.input), elem, f64::tanh),
+        Arithmetic::Sinh(op) => float1(read(&op.input), elem, f64::sinh),
+        Arithmetic::Cosh(op) => float1(read(&op.input), elem, f64::cosh),
+        Arithmetic::ArcCos(op) => float1(read(&op.input), elem, f64::acos),
+        Arithmetic::ArcSin(op) => float1(read(&op.input), elem, f64::asin),
+        Arithmetic::ArcTan(op) => float1(read(&op.input), elem, f64::atan),
+        Arithmetic::ArcSinh(op) => float1(read(&op.input), elem, f64::asinh),
+        Arithmetic::ArcCosh(op) => float1(read(&op.input), elem, f64::acosh),
+        Arithmetic::ArcTanh(op) => float1(read(&op.input), elem, f64::atanh),
+        Arithmetic::Sqrt(op) => float1(read(&op.input), elem, f64::sqrt),
+        Arithmetic::InverseSqrt(op) => float1(read(&op.input), elem, |a| 1.0 / a.sqrt()),
+        Arithmetic::Round(op) => float1(read(&op.input), elem, f64::round_ties_even),
+        Arithmetic::Floor(op) => float1(read(&op.input), elem, f64::floor),
+        Arithmetic::Ceil(op) => float1(read(&op.input), elem, f64::ceil),
+        Arithmetic::Erf(op) => float1(read(&op.input), elem, erf),
+        Arithmetic::Recip(op) => float1(read(&op.input), elem, |a| 1.0 / a),
+    }
+}
+
+/// Evaluate a comparison, in the element of the left hand side.
+pub fn comparison(op: &Comparison, read: impl Fn(&Variable) -> Line) -> Line {
+    let (op, check): (_, fn(Ordering) -> bool) = match op {
+        Comparison::Lower(op) => (op, Ordering::is_lt),
+        Comparison::LowerEqual(op) => (op, Ordering::is_le),
+        Comparison::Equal(op) => (op, Ordering::is_eq),
+        Comparison::NotEqual(op) => (op, Ordering::is_ne),
+        Comparison::GreaterEqual(op) => (op, Ordering::is_ge),
+        Comparison::Greater(op) => (op, Ordering::is_gt),
+    };
+    // Every comparison with NaN is false, except `!=`.
+    let unordered = check(Ordering::Less) && check(Ordering::Greater);
+    let elem = value_elem(op.lhs.elem());
+
+    let lhs = read(&op.lhs);
+    let rhs = read(&op.rhs);
+    let len = lhs.len().max(rhs.len());
+    let component = |line: &Line, i: usize| line[if line.len() == 1 { 0 } else { i }].cast(elem);
+
+    (0..len)
+        .map(|i| {
+            let ordering = compare(component(&lhs, i), component(&rhs, i));
+            Scalar::Bool(ordering.map(check).unwrap_or(unordered))
+        })
+        .collect()
+}
+
+fn bits(value: Scalar, width: u32) -> u64 {
+    let mask = if width == 64 {
+        u64::MAX
+    } else {
+        (1u64 << width) - 1
+    };
+    value.as_u64() & mask
+}
+
+/// Evaluate a bitwise operation producing a value of `out`.
+pub fn bitwise(op: &Bitwise, out: Item, read: impl Fn(&Variable) -> Line) -> Line {
+    let elem = value_elem(out.elem);
+    let width = elem.size_bits() as u32;
+
+    let logic = |lhs: Line, rhs: Line, func: fn(u64, u64) -> u64| {
+        binary(lhs, rhs, elem, move |lhs, rhs| {
+            Scalar::UInt(func(lhs.as_u64(), rhs.as_u64()))
+        })
+    };
+    // Count operations are defined on the bits of the input, but produce an `u32`.
+    let count = |input: &Variable, func: &dyn Fn(u64, u32) -> u64| {
+        let width = value_elem(input.elem()).size_bits() as u32;
+        let input = read(input);
+        coerce(
+            input
+                .into_iter()
+                .map(|val| Scalar::UInt(func(bits(val, width), width)))
+                .collect(),
+            Item::vectorized(elem, out.vectorization),
+        )
+    };
+
+    match op {
+        Bitwise::BitwiseAnd(op) => logic(read(&op.lhs), read(&op.rhs), |a, b| a & b),
+        Bitwise::BitwiseOr(op) => logic(read(&op.lhs), read(&op.rhs), |a, b| a | b),
+        Bitwise::BitwiseXor(op) => logic(read(&op.lhs), read(&op.rhs), |a, b| a ^ b),
+        Bitwise::ShiftLeft(op) => binary(read(&op.lhs), read(&op.rhs), elem, |lhs, rhs| {
+            let shift = (rhs.as_u64() % width as u64) as u32;
+            match lhs {
+                Scalar::Int(val) => Scalar::Int(val.wrapping_shl(shift)),
+                other => Scalar::UInt(other.as_u64().wrapping_shl(shift)),
+            }
+        }),
+        Bitwise::ShiftRight(op) => binary(read(&op.lhs), read(&op.rhs), elem, |lhs, rhs| {
+            let shift = (rhs.as_u64() % width as u64) as u32;
+            match lhs {
+                Scalar::Int(val) => Scalar::Int(val.wrapping_shr(shift)),
+                other => Scalar::UInt(other.as_u64().wrapping_shr(shift)),
+            }
+        }),
+        Bitwise::BitwiseNot(op) => unary(read(&op.input), elem, |val| Scalar::UInt(!val.as_u64())),
+        Bitwise::CountOnes(op) => count(&op.input, &|val, _| val.count_ones() as u64),
+        Bitwise::ReverseBits(op) => unary(read(&op.input), elem, |val| {
+            Scalar::UInt(bits(val, width).reverse_bits() >> (64 - width))
+        }),
+        Bitwise::LeadingZeros(op) => count(&op.input, &|val, width| {
+            (val.leading_zeros() - (64 - width)) as u64
+        }),
+        Bitwise::FindFirstSet(op) => count(&op.input, &|val, _| match val {
+            0 => 0,
+            val => val.trailing_zeros() as u64 + 1,
+        }),
+    }
+}
+
+/// The item of a list indexed with the given line size, where `0` is the line size of the list.
+pub fn item_with_line_size(item: Item, line_size: u32) -> Item {
+    match line_size {
+        0 => item,
+        size => Item::vectorized(item.elem, core::num::NonZero::new(size as u8)),
+    }
+}
+
+/// Error function, using the approximation of Numerical Recipes (fractional error below 1.2e-7).
+fn erf(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))));
+    let erfc = t * poly.exp();
+    if x >= 0.0 { 1.0 - erfc } else { erfc - 1.0 }
+}
+
+/// Add two scalars of the same element.
+pub fn add(lhs: Scalar, rhs: Scalar) -> Scalar {
+    numeric2(lhs, rhs, |a, b| a + b, i64::wrapping_add, u64::wrapping_add)
+}
+
+/// Subtract two scalars of the same element.
+pub fn sub(lhs: Scalar, rhs: Scalar) -> Scalar {
+    numeric2(lhs, rhs, |a, b| a - b, i64::wrapping_sub, u64::wrapping_sub)
+}
+
+/// Multiply two scalars of the same element.
+pub fn mul(lhs: Scalar, rhs: Scalar) -> Scalar {
+    numeric2(lhs, rhs, |a, b| a * b, i64::wrapping_mul, u64::wrapping_mul)
+}
+
+/// The minimum of two scalars of the same element.
+pub fn min(lhs: Scalar, rhs: Scalar) -> Scalar {
+    numeric2(lhs, rhs, f64::min, i64::min, u64::min)
+}
+
+/// The maximum of two scalars of the same element.
+pub fn max(lhs: Scalar, rhs: Scalar) -> Scalar {
+    numeric2(lhs, rhs, f64::max, i64::max, u64::max)
+}
+
+/// Compare two scalars of the same element, `None` if either is NaN.
+pub fn compare(lhs: Scalar, rhs: Scalar) -> Option<core::cmp::Ordering> {
+    match (lhs, rhs) {
+        (Scalar::Float(a), Scalar::Float(b)) => a.partial_cmp(&b),
+        (Scalar::Int(a), Scalar::Int(b)) => Some(a.cmp(&b)),
+        (Scalar::UInt(a), Scalar::UInt(b)) => Some(a.cmp(&b)),
+        (Scalar::Bool(a), Scalar::Bool(b)) => Some(a.cmp(&b)),
+        (lhs, rhs) => unreachable!("Can't compare {lhs:?} and {rhs:?}"),
+    }
+}
@@ -0,0 +1,155 @@
+use cubecl_core::ir::{Elem, Plane, Variable};
+
+use super::{
+    ops,
+    unit::{Env, Unit, UnitState},
+    value::{Line, Scalar, Value, value_elem},
+};
+
+pub const PLANE_SIZE: u32 = 32;
+
+/// Execute a plane operation for every unit of `group`, which must be ordered by lane.
+pub fn execute_plane(op: &Plane, out: &Variable, group: &mut [&mut Unit], env: &Env) {
+    let elem = value_elem(out.elem());
+    let count = group.len();
+    let lanes = group
+        .iter()
+        .map(|unit| unit.index % PLANE_SIZE)
+        .collect::<Vec<_>>();
+    let read = |group: &[&mut Unit], var: &Variable| {
+        group
+            .iter()
+            .map(|unit| unit.read_line(var, env))
+            .collect::<Vec<_>>()
+    };
+    // The value of the unit at lane `src`, or the own value of the unit when `src` is inactive.
+    let select = |values: &[Line], own: usize, src: Option<u32>| {
+        src.and_then(|src| lanes.iter().position(|lane| *lane == src))
+            .map(|pos| values[pos].clone())
+            .unwrap_or_else(|| values[own].clone())
+    };
+
+    let results: Vec<Line> = match op {
+        // The group is ordered by lane, so the first unit has the lowest active lane.
+        Plane::Elect => (0..count)
+            .map(|i| smallvec::smallvec![Scalar::Bool(i == 0)])
+            .collect(),
+        Plane::All(op) => vec![
+            reduce(read(group, &op.input), Elem::Bool, |a, b| {
+                Scalar::Bool(a.as_bool() && b.as_bool())
+            });
+            count
+        ],
+        Plane::Any(op) => vec![
+            reduce(read(group, &op.input), Elem::Bool, |a, b| {
+                Scalar::Bool(a.as_bool() || b.as_bool())
+            });
+            count
+        ],
+        Plane::Ballot(op) => {
+            let mask = read(group, &op.input)
+                .iter()
+                .zip(lanes.iter())
+                .filter(|(value, _)| value[0].as_bool())
+                .fold(0u64, |mask, (_, lane)| mask | (1 << lane));
+            let mut ballot = Line::new();
+            ballot.push(Scalar::UInt(mask));
+            ballot.resize(4, Scalar::UInt(0));
+            vec![ballot; count]
+        }
+        Plane::Broadcast(op) | Plane::Shuffle(op) => {
+            let values = read(group, &op.lhs);
+            let src = read(group, &op.rhs);
+            (0..count)
+                .map(|i| select(&values, i, Some(src[i][0].as_u64() as u32)))
+                .collect()
+        }
+        Plane::ShuffleXor(op) => {
+            let values = read(group, &op.lhs);
+            let mask = read(group, &op.rhs);
+            (0..count)
+                .map(|i| select(&values, i, Some(lanes[i] ^ mask[i][0].as_u64() as u32)))
+                .collect()
+        }
+        Plane::ShuffleUp(op) => {
+            let values = read(group, &op.lhs);
+            let delta = read(group, &op.rhs);
+            (0..count)
+                .map(|i| {
+                    let src = lanes[i].checked_sub(delta[i][0].as_u64() as u32);
+                    select(&values, i, src)
+                })
+                .collect()
+        }
+        Plane::ShuffleDown(op) => {
+            let values = read(group, &op.lhs);
+            let delta = read(group, &op.rhs);
+            (0..count)
+                .map(|i| {
+                    let src = lanes[i] + delta[i][0].as_u64() as u32;
+                    select(&values, i, (src < PLANE_SIZE).then_some(src))
+                })
+                .collect()
+        }
+        Plane::Sum(op) => vec![reduce(read(group, &op.input), elem, ops::add); count],
+        Plane::Prod(op) => vec![reduce(read(group, &op.input), elem, ops::mul); count],
+        Plane::Min(op) => vec![reduce(read(group, &op.input), elem, ops::min); count],
+        Plane::Max(op) => vec![reduce(read(group, &op.input), elem, ops::max); count],
+        Plane::InclusiveSum(op) => scan(read(group, &op.input), elem, None, ops::add),
+        Plane::InclusiveProd(op) => scan(read(group, &op.input), elem, None, ops::mul),
+        Plane::ExclusiveSum(op) => scan(
+            read(group, &op.input),
+            elem,
+            Some(Scalar::UInt(0)),
+            ops::add,
+        ),
+        Plane::ExclusiveProd(op) => scan(
+            read(group, &op.input),
+            elem,
+            Some(Scalar::UInt(1)),
+            ops::mul,
+        ),
+    };
+
+    for (unit, value) in group.iter_mut().zip(results) {
+        unit.write(out, Value::Line(value));
+        unit.pc += 1;
+        unit.state = UnitState::Running;
+    }
+}
+
+/// Combine the values of every lane, component by component.
+fn reduce(values: Vec<Line>, elem: Elem, func: fn(Scalar, Scalar) -> Scalar) -> Line {
+    values
+        .into_iter()
+        .reduce(|acc, value| ops::binary(acc, value, elem, func))
+        .expect("A plane has at least one active unit")
+}
+
+/// Prefix scan over the lanes, exclusive when an identity is provided.
+fn scan(
+    values: Vec<Line>,
+    elem: Elem,
+    identity: Option<Scalar>,
+    func: fn(Scalar, Scalar) -> Scalar,
+) -> Vec<Line> {
+    let mut acc: Option<Line> = None;
+    let mut results = Vec::with_capacity(values.len());
+
+    for value in values {
+        let previous = acc.clone();
+        let next = match acc {
+            Some(acc) => ops::binary(acc, value.clone(), elem, func),
+            None => ops::unary(value.clone(), elem, |it| it),
+        };
+        results.push(match identity {
+            Some(identity) => {
+                previous.unwrap_or_else(|| smallvec::smallvec![identity.cast(elem); value.len()])
+            }
+            None => next.clone(),
+        });
+        acc = Some(next);
+    }
+
+    results
+}
@@ -0,0 +1,715 @@
+use std::collections::{BTreeMap, HashMap};
+
+use cubecl_core::{
+    ir::{
+        self, AtomicOp, BarrierOps, Builtin, Elem, Id, NonSemantic, Operator, Variable,
+        VariableKind,
+    },
+    server::ScalarBinding,
+};
+use cubecl_runtime::storage::BytesResource;
+
+use crate::compiler::{Inst, Op, Program};
+
+use super::{
+    ops,
+    plane::PLANE_SIZE,
+    value::{Line, MemoryId, Pointer, Scalar, Value, coerce, line_size, value_elem},
+};
+
+/// Everything bound to a kernel launch.
+pub struct Globals {
+    pub buffers: Vec<BytesResource>,
+    pub metadata: Vec<u32>,
+    pub scalars: BTreeMap<Elem, ScalarBinding>,
+    pub cube_count: [u32; 3],
+}
+
+/// Everything shared by the units of a cube.
+pub struct Env<'a> {
+    pub program: &'a Program,
+    pub globals: &'a Globals,
+    pub cube_pos: [u32; 3],
+}
+
+/// Shared memories of a cube, allocated on first use.
+pub type SharedMemories = HashMap<Id, Vec<Scalar>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitState {
+    Running,
+    /// Waiting for every unit of the cube.
+    SyncCube,
+    /// Waiting for every unit of the plane.
+    SyncPlane,
+    /// Waiting for the other units of the plane to reach a plane operation.
+    Plane,
+    Done,
+}
+
+/// A single unit, with its own registers and local arrays.
+#[derive(Debug)]
+pub struct Unit {
+    pub pos: [u32; 3],
+    /// The position of the unit in the cube, `UNIT_POS`.
+    pub index: u32,
+    pub pc: usize,
+    pub state: UnitState,
+    registers: Vec<Option<Value>>,
+    local_arrays: HashMap<Id, Vec<Scalar>>,
+}
+
+impl Unit {
+    pub fn new(pos: [u32; 3], index: u32) -> Self {
+        Self {
+            pos,
+            index,
+            pc: 0,
+            state: UnitState::Running,
+            registers: Vec::new(),
+            local_arrays: HashMap::new(),
+        }
+    }
+
+    /// Execute the next instruction, unless the unit is done or waits for other units.
+    pub fn step(&mut self, env: &Env, shared: &mut SharedMemories) {
+        if self.state == UnitState::Running {
+            match &env.program.instructions[self.pc] {
+                Inst::Op { op, out } => {
+                    self.execute(op, *out, env, shared);
+                    self.pc += 1;
+                }
+                Inst::Jump(target) => self.pc = *target,
+                Inst::JumpIfNot { cond, target } => match self.read_scalar(cond, env).as_bool() {
+                    true => self.pc += 1,
+                    false => self.pc = *target,
+                },
+                Inst::Switch {
+                    value,
+                    cases,
+                    default,
+                } => {
+                    let elem = value_elem(value.elem());
+                    let value = self.read_scalar(value, env);
+                    self.pc = cases
+                        .iter()
+                        .find(|(case, _)| self.read_scalar(case, env).cast(elem) == value)
+                        .map(|(_, target)| *target)
+                        .unwrap_or(*default);
+                }
+                Inst::RangeCond {
+                    i,
+                    end,
+                    inclusive,
+                    exit,
+                } => {
+                    let elem = value_elem(i.elem());
+                    let ordering = ops::compare(
+                        self.read_scalar(i, env),
+                        self.read_scalar(end, env).cast(elem),
+                    );
+                    let keep_going = match inclusive {
+                        true => ordering.is_some_and(|it| it.is_le()),
+                        false => ordering.is_some_and(|it| it.is_lt()),
+                    };
+                    self.pc = if keep_going { self.pc + 1 } else { *exit };
+                }
+                Inst::RangeStep { i, step } => {
+                    let elem = value_elem(i.elem());
+                    let step = match step {
+                        Some(step) => self.read_scalar(step, env).cast(elem),
+                        None => Scalar::UInt(1).cast(elem),
+                    };
+                    let next = ops::add(self.read_scalar(i, env), step).cast(elem);
+                    self.write(i, Value::Line(smallvec::smallvec![next]));
+                    self.pc += 1;
+                }
+                Inst::Return => self.state = UnitState::Done,
+                Inst::SyncCube => {
+                    self.state = UnitState::SyncCube;
+                    self.pc += 1;
+                }
+                Inst::SyncPlane => {
+                    self.state = UnitState::SyncPlane;
+                    self.pc += 1;
+                }
+                // The plane operation is executed by the scheduler once every unit reached it.
+                Inst::Plane { .. } => self.state = UnitState::Plane,
+            }
+        }
+    }
+
+    /// Read the value of a variable.
+    pub fn read(&self, var: &Variable, env: &Env) -> Value {
+        match var.kind {
+            VariableKind::LocalMut { id }
+            | VariableKind::LocalConst { id }
+            | VariableKind::Versioned { id, .. } => self
+                .registers
+                .get(id as usize)
+                .cloned()
+                .flatten()
+                .unwrap_or_else(|| Value::zeros(var.item)),
+            VariableKind::ConstantScalar(value) => Value::Line(coerce(
+                smallvec::smallvec![Scalar::from_constant(value)],
+                var.item,
+            )),
+            VariableKind::GlobalScalar(id) => {
+                let elem = var.elem();
+                let size = elem.size();
+                let binding = &env.globals.scalars[&elem];
+                let value = Scalar::read(&binding.data()[id as usize * size..], elem);
+                Value::Line(smallvec::smallvec![value])
+            }
+            VariableKind::Builtin(builtin) => Value::Line(coerce(
+                smallvec::smallvec![Scalar::UInt(self.builtin(builtin, env) as u64)],
+                var.item,
+            )),
+            _ => panic!("{var} can't be read as a value"),
+        }
+    }
+
+    pub fn read_line(&self, var: &Variable, env: &Env) -> Line {
+        self.read(var, env).line()
+    }
+
+    /// The first component of a variable.
+    pub fn read_scalar(&self, var: &Variable, env: &Env) -> Scalar {
+        self.read_line(var, env)[0]
+    }
+
+    /// Assign a value to a local variable.
+    pub fn write(&mut self, var: &Variable, value: Value) {
+        let id = match var.kind {
+            VariableKind::LocalMut { id }
+            | VariableKind::LocalConst { id }
+            | VariableKind::Versioned { id, .. } => id as usize,
+            _ => panic!("Can't assign to {var}"),
+        };
+        if self.registers.len() <= id {
+            self.registers.resize(id + 1, None);
+        }
+        self.registers[id] = Some(value.coerce(var.item));
+    }
+
+    fn builtin(&self, builtin: Builtin, env: &Env) -> u32 {
+        let [unit_x, unit_y, unit_z] = self.pos;
+        let [cube_x, cube_y, cube_z] = env.cube_pos;
+        let dim = env.program.cube_dim;
+        let [count_x, count_y, count_z] = env.globals.cube_count;
+        let abs_x = cube_x * dim.x + unit_x;
+        let abs_y = cube_y * dim.y + unit_y;
+        let abs_z = cube_z * dim.z + unit_z;
+
+        match builtin {
+            Builtin::UnitPos => self.index,
+            Builtin::UnitPosX => unit_x,
+            Builtin::UnitPosY => unit_y,
+            Builtin::UnitPosZ => unit_z,
+            Builtin::CubePosCluster
+            | Builtin::CubePosClusterX
+            | Builtin::CubePosClusterY
+            | Builtin::CubePosClusterZ => 0,
+            Builtin::CubePos => cube_x + cube_y * count_x + cube_z * count_x * count_y,
+            Builtin::CubePosX => cube_x,
+            Builtin::CubePosY => cube_y,
+            Builtin::CubePosZ => cube_z,
+            Builtin::CubeDim => dim.num_elems(),
+            Builtin::CubeDimX => dim.x,
+            Builtin::CubeDimY => dim.y,
+            Builtin::CubeDimZ => dim.z,
+            Builtin::CubeClusterDim
+            | Builtin::CubeClusterDimX
+            | Builtin::CubeClusterDimY
+            | Builtin::CubeClusterDimZ => 1,
+            Builtin::CubeCount => count_x * count_y * count_z,
+            Builtin::CubeCountX => count_x,
+            Builtin::CubeCountY => count_y,
+            Builtin::CubeCountZ => count_z,
+            Builtin::PlaneDim => PLANE_SIZE,
+            Builtin::UnitPosPlane => self.index % PLANE_SIZE,
+            Builtin::AbsolutePos => {
+                let size_x = count_x * dim.x;
+                let size_y = count_y * dim.y;
+                abs_x + abs_y * size_x + abs_z * size_x * size_y
+            }
+            Builtin::AbsolutePosX => abs_x,
+            Builtin::AbsolutePosY => abs_y,
+            Builtin::AbsolutePosZ => abs_z,
+        }
+    }
+
+    fn execute(&mut self, op: &Op, out: Option<Variable>, env: &Env, shared: &mut SharedMemories) {
+        let out_var = || out.expect("Operation should have an out");
+
+        match op {
+            Op::Copy(input) => {
+                let value = self.read(input, env);
+                self.write(&out_var(), value);
+            }
+            Op::Arithmetic(op) => {
+                let out = out_var();
+                let value = ops::arithmetic(op, out.item, |var| self.read_line(var, env));
+                self.write(&out, Value::Line(value));
+            }
+            Op::Comparison(op) => {
+                let value = ops::comparison(op, |var| self.read_line(var, env));
+                self.write(&out_var(), Value::Line(value));
+            }
+            Op::Bitwise(op) => {
+                let out = out_var();
+                let value = ops::bitwise(op, out.item, |var| self.read_line(var, env));
+                self.write(&out, Value::Line(value));
+            }
+            Op::Operator(op) => self.execute_operator(op, out_var(), env, shared),
+            Op::Atomic(op) => self.execute_atomic(op, out_var(), env, shared),
+            Op::Metadata(op) => {
+                let out = out_var();
+                let value = self.metadata(op, env);
+                let value = coerce(smallvec::smallvec![Scalar::UInt(value as u64)], out.item);
+                self.write(&out, Value::Line(value));
+            }
+            Op::Barrier(BarrierOps::MemCopyAsync {
+                source,
+                source_length,
+                offset_source,
+                offset_out,
+                ..
+            }) => {
+                let out = out_var();
+                let length = self.read_scalar(source_length, env).as_usize();
+                let source_offset = self.read_scalar(offset_source, env).as_usize();
+                let out_offset = self.read_scalar(offset_out, env).as_usize();
+                for i in 0..length {
+                    let line = self.load_line(source, source_offset + i, 0, env, shared);
+                    self.store_line(&out, out_offset + i, 0, line, env, shared);
+                }
+            }
+            Op::NonSemantic(NonSemantic::Print {
+                format_string,
+                args,
+            }) => {
+                let args = args.iter().map(|arg| self.read_line(arg, env)).collect();
+                println!("{}", format_print(format_string, args));
+            }
+            Op::Barrier(op) => unreachable!("{op} should be lowered at compilation"),
+            Op::NonSemantic(op) => unreachable!("{op} should be lowered at compilation"),
+        }
+    }
+
+    fn execute_operator(
+        &mut self,
+        op: &Operator,
+        out: Variable,
+        env: &Env,
+        shared: &mut SharedMemories,
+    ) {
+        match op {
+            Operator::Index(op) | Operator::UncheckedIndex(op) => {
+                let index = self.read_scalar(&op.index, env).as_usize();
+                let value = match memory_of(&op.list) {
+                    Some(memory) if op.list.elem().is_atomic() => {
+                        self.allocate(&op.list, shared);
+                        Value::Pointer(Pointer {
+                            memory,
+                            offset: index.saturating_mul(line_size(op.list.item)),
+                            elem: op.list.elem(),
+                        })
+                    }
+                    Some(_) => {
+                        Value::Line(self.load_line(&op.list, index, op.line_size, env, shared))
+                    }
+                    // Indexing a line reads one of its components.
+                    None => {
+                        let line = self.read_line(&op.list, env);
+                        let value = line
+                            .get(index)
+                            .copied()
+                            .unwrap_or(Scalar::zero(op.list.elem()));
+                        Value::Line(smallvec::smallvec![value])
+                    }
+                };
+                self.write(&out, value);
+            }
+            Operator::IndexAssign(op) | Operator::UncheckedIndexAssign(op) => {
+                let index = self.read_scalar(&op.index, env).as_usize();
+                let value = self.read_line(&op.value, env);
+                match memory_of(&out) {
+                    Some(_) => self.store_line(&out, index, op.line_size, value, env, shared),
+                    None => {
+                        let mut line = coerce(self.read_line(&out, env), out.item);
+                        if let Some(component) = line.get_mut(index) {
+                            *component = value[0].cast(out.elem());
+                        }
+                        self.write(&out, Value::Line(line));
+                    }
+                }
+            }
+            Operator::CopyMemory(op) => {
+                let in_index = self.read_scalar(&op.in_index, env).as_usize();
+                let out_index = self.read_scalar(&op.out_index, env).as_usize();
+                let line = self.load_line(&op.input, in_index, 0, env, shared);
+                self.store_line(&out, out_index, 0, line, env, shared);
+            }
+            Operator::CopyMemoryBulk(op) => {
+                let in_index = self.read_scalar(&op.in_index, env).as_usize();
+                let out_index = self.read_scalar(&op.out_index, env).as_usize();
+                let len = self.read_scalar(&op.len, env).as_usize();
+                for i in 0..len {
+                    let line = self.load_line(&op.input, in_index + i, 0, env, shared);
+                    self.store_line(&out, out_index + i, 0, line, env, shared);
+                }
+            }
+            Operator::InitLine(op) => {
+                let line = op
+                    .inputs
+                    .iter()
+                    .map(|input| self.read_scalar(input, env))
+                    .collect();
+                self.write(&out, Value::Line(line));
+            }
+            Operator::And(op) => {
+                let value = ops::binary(
+                    self.read_line(&op.lhs, env),
+                    self.read_line(&op.rhs, env),
+                    Elem::Bool,
+                    |lhs, rhs| Scalar::Bool(lhs.as_bool() && rhs.as_bool()),
+                );
+                self.write(&out, Value::Line(value));
+            }
+            Operator::Or(op) => {
+                let value = ops::binary(
+                    self.read_line(&op.lhs, env),
+                    self.read_line(&op.rhs, env),
+                    Elem::Bool,
+                    |lhs, rhs| Scalar::Bool(lhs.as_bool() || rhs.as_bool()),
+                );
+                self.write(&out, Value::Line(value));
+            }
+            Operator::Not(op) => {
+                let value = ops::unary(self.read_line(&op.input, env), Elem::Bool, |val| {
+                    Scalar::Bool(!val.as_bool())
+                });
+                self.write(&out, Value::Line(value));
+            }
+            Operator::Cast(op) => {
+                let value = self.read(&op.input, env);
+                self.write(&out, value);
+            }
+            Operator::Reinterpret(op) => {
+                let input_elem = value_elem(op.input.elem());
+                let out_elem = value_elem(out.elem());
+                let line = self.read_line(&op.input, env);
+
+                let mut bytes = vec![0; line.len() * input_elem.size()];
+                for (value, bytes) in line.iter().zip(bytes.chunks_mut(input_elem.size())) {
+                    value.write(bytes, input_elem);
+                }
+                let value = bytes
+                    .chunks(out_elem.size())
+                    .map(|bytes| Scalar::read(bytes, out_elem))
+                    .collect();
+                self.write(&out, Value::Line(value));
+            }
+            Operator::Select(op) => {
+                let cond = self.read_line(&op.cond, env);
+                let then = coerce(self.read_line(&op.then, env), out.item);
+                let or_else = coerce(self.read_line(&op.or_else, env), out.item);
+                let value = then
+                    .into_iter()
+                    .zip(or_else)
+                    .enumerate()
+                    .map(|(i, (then, or_else))| {
+                        match cond[if cond.len() == 1 { 0 } else { i }].as_bool() {
+                            true => then,
+                            false => or_else,
+                        }
+                    })
+                    .collect();
+                self.write(&out, Value::Line(value));
+            }
+        }
+    }
+
+    fn execute_atomic(
+        &mut self,
+        op: &AtomicOp,
+        out: Variable,
+        env: &Env,
+        shared: &mut SharedMemories,
+    ) {
+        let modify = |unit: &mut Self,
+                      shared: &mut SharedMemories,
+                      pointer: &Variable,
+                      value: &Variable,
+                      func: fn(Scalar, Scalar) -> Scalar| {
+            let pointer = unit.read(pointer, env).pointer();
+            let elem = value_elem(pointer.elem);
+            let value = unit.read_scalar(value, env).cast(elem);
+            let old = unit.load(pointer, env, shared);
+            unit.store(pointer, func(old, value).cast(elem), env, shared);
+            unit.write(&out, Value::Line(smallvec::smallvec![old]));
+        };
+
+        match op {
+            AtomicOp::Load(op) => {
+                let pointer = self.read(&op.input, env).pointer();
+                let value = self.load(pointer, env, shared);
+                self.write(&out, Value::Line(smallvec::smallvec![value]));
+            }
+            AtomicOp::Store(op) => {
+                let pointer = self.read(&out, env).pointer();
+                let value = self.read_scalar(&op.input, env);
+                self.store(pointer, value, env, shared);
+            }
+            AtomicOp::Swap(op) => modify(self, shared, &op.lhs, &op.rhs, |_, new| new),
+            AtomicOp::Add(op) => modify(self, shared, &op.lhs, &op.rhs, ops::add),
+            AtomicOp::Sub(op) => modify(self, shared, &op.lhs, &op.rhs, ops::sub),
+            AtomicOp::Max(op) => modify(self, shared, &op.lhs, &op.rhs, ops::max),
+            AtomicOp::Min(op) => modify(self, shared, &op.lhs, &op.rhs, ops::min),
+            AtomicOp::And(op) => modify(self, shared, &op.lhs, &op.rhs, |old, val| {
+                Scalar::UInt(old.as_u64() & val.as_u64())
+            }),
+            AtomicOp::Or(op) => modify(self, shared, &op.lhs, &op.rhs, |old, val| {
+                Scalar::UInt(old.as_u64() | val.as_u64())
+            }),
+            AtomicOp::Xor(op) => modify(self, shared, &op.lhs, &op.rhs, |old, val| {
+                Scalar::UInt(old.as_u64() ^ val.as_u64())
+            }),
+            AtomicOp::CompareAndSwap(op) => {
+                let pointer = self.read(&op.input, env).pointer();
+                let elem = value_elem(pointer.elem);
+                let cmp = self.read_scalar(&op.cmp, env).cast(elem);
+                let val = self.read_scalar(&op.val, env).cast(elem);
+                let old = self.load(pointer, env, shared);
+                if old == cmp {
+                    self.store(pointer, val, env, shared);
+                }
+                self.write(&out, Value::Line(smallvec::smallvec![old]));
+            }
+        }
+    }
+
+    fn metadata(&self, op: &ir::Metadata, env: &Env) -> u32 {
+        let program = env.program;
+        let data = &env.globals.metadata;
+        let ext_pos = |var: &Variable| program.ext_meta_pos[var.index().unwrap() as usize];
+        let static_length = |var: &Variable| match var.kind {
+            VariableKind::LocalArray { length, .. }
+            | VariableKind::SharedMemory { length, .. }
+            | VariableKind::ConstantArray { length, .. } => length,
+            _ => line_size(var.item) as u32,
+        };
+
+        match op {
+            ir::Metadata::Rank { var } => data[program.metadata.rank_index(ext_pos(var)) as usize],
+            ir::Metadata::Stride { dim, var } => {
+                let offset = data[program.metadata.stride_offset_index(ext_pos(var)) as usize];
+                data[offset as usize + self.read_scalar(dim, env).as_usize()]
+            }
+            ir::Metadata::Shape { dim, var } => {
+                let offset = data[program.metadata.shape_offset_index(ext_pos(var)) as usize];
+                data[offset as usize + self.read_scalar(dim, env).as_usize()]
+            }
+            ir::Metadata::Length { var } => match var.kind {
+                VariableKind::GlobalInputArray(id) | VariableKind::GlobalOutputArray(id) => {
+                    data[program.metadata.len_index(id) as usize]
+                }
+                _ => static_length(var),
+            },
+            ir::Metadata::BufferLength { var } => match var.kind {
+                VariableKind::GlobalInputArray(id) | VariableKind::GlobalOutputArray(id) => {
+                    data[program.metadata.buffer_len_index(id) as usize]
+                }
+                _ => static_length(var),
+            },
+        }
+    }
+
+    /// Allocate the local or shared memory of a list, zero initialized.
+    fn allocate(&mut self, list: &Variable, shared: &mut SharedMemories) {
+        let (memories, id, length) = match list.kind {
+            VariableKind::SharedMemory { id, length, .. } => (shared, id, length),
+            VariableKind::LocalArray { id, length } => (&mut self.local_arrays, id, length),
+            _ => return,
+        };
+        memories.entry(id).or_insert_with(|| {
+            vec![Scalar::zero(list.elem()); length as usize * line_size(list.item)]
+        });
+    }
+
+    /// Read the line at `index` of a list, reinterpreted with `line_size` components.
+    fn load_line(
+        &mut self,
+        list: &Variable,
+        index: usize,
+        line_size_override: u32,
+        env: &Env,
+        shared: &mut SharedMemories,
+    ) -> Line {
+        let Some(memory) = memory_of(list) else {
+            return self.read_line(list, env);
+        };
+        self.allocate(list, shared);
+
+        let size = line_size(ops::item_with_line_size(list.item, line_size_override));
+        let start = index.saturating_mul(size);
+        (0..size)
+            .map(|i| {
+                let pointer = Pointer {
+                    memory,
+                    offset: start.saturating_add(i),
+                    elem: list.elem(),
+                };
+                self.load(pointer, env, shared)
+            })
+            .collect()
+    }
+
+    /// Write the line at `index` of a list, reinterpreted with `line_size` components.
+    fn store_line(
+        &mut self,
+        list: &Variable,
+        index: usize,
+        line_size_override: u32,
+        value: Line,
+        env: &Env,
+        shared: &mut SharedMemories,
+    ) {
+        let Some(memory) = memory_of(list) else {
+            return self.write(list, Value::Line(value));
+        };
+        self.allocate(list, shared);
+
+        let item = ops::item_with_line_size(list.item, line_size_override);
+        let value = coerce(value, item);
+        let start = index.saturating_mul(line_size(item));
+        for (i, value) in value.into_iter().enumerate() {
+            let pointer = Pointer {
+                memory,
+                offset: start.saturating_add(i),
+                elem: list.elem(),
+            };
+            self.store(pointer, value, env, shared);
+        }
+    }
+
+    /// Read a single element, out-of-bounds reads return zero.
+    fn load(&self, pointer: Pointer, env: &Env, shared: &SharedMemories) -> Scalar {
+        let zero = Scalar::zero(pointer.elem);
+        match pointer.memory {
+            MemoryId::Global(id) => {
+                let bytes = env.globals.buffers[id as usize].read();
+                let size = pointer.elem.size();
+                match pointer.offset.checked_mul(size) {
+                    Some(start) if start + size <= bytes.len() => {
+                        Scalar::read(&bytes[start..], pointer.elem)
+                    }
+                    _ => zero,
+                }
+            }
+            MemoryId::Shared(id) => shared[&id].get(pointer.offset).copied().unwrap_or(zero),
+            MemoryId::Local(id) => self.local_arrays[&id]
+                .get(pointer.offset)
+                .copied()
+                .unwrap_or(zero),
+            MemoryId::Const(id) => env.program.const_arrays[&id]
+                .get(pointer.offset)
+                .copied()
+                .unwrap_or(zero),
+        }
+    }
+
+    /// Write a single element, out-of-bounds writes are dropped.
+    fn store(&mut self, pointer: Pointer, value: Scalar, env: &Env, shared: &mut SharedMemories) {
+        let value = value.cast(pointer.elem);
+        let element = match pointer.memory {
+            MemoryId::Global(id) => {
+                let bytes = env.globals.buffers[id as usize].write();
+                let size = pointer.elem.size();
+                if let Some(start) = pointer.offset.checked_mul(size) {
+                    if start + size <= bytes.len() {
+                        value.write(&mut bytes[start..], pointer.elem);
+                    }
+                }
+                return;
+            }
+            MemoryId::Shared(id) => shared.get_mut(&id).unwrap().get_mut(pointer.offset),
+            MemoryId::Local(id) => self
+                .local_arrays
+                .get_mut(&id)
+                .unwrap()
+                .get_mut(pointer.offset),
+            MemoryId::Const(_) => unreachable!("Constant arrays are read only"),
+        };
+        if let Some(element) = element {
+            *element = value;
+        }
+    }
+}
+
+/// The memory a list lives in, `None` for variables that aren't lists.
+fn memory_of(var: &Variable) -> Option<MemoryId> {
+    match var.kind {
+        VariableKind::GlobalInputArray(id) | VariableKind::GlobalOutputArray(id) => {
+            Some(MemoryId::Global(id))
+        }
+        VariableKind::SharedMemory { id, .. } => Some(MemoryId::Shared(id)),
+        VariableKind::LocalArray { id, .. } => Some(MemoryId::Local(id)),
+        VariableKind::ConstantArray { id, .. } => Some(MemoryId::Const(id)),
+        _ => None,
+    }
+}
+
+/// Substitute the C style conversion specifiers of `format` with the arguments.
+fn format_print(format: &str, args: Vec<Line>) -> String {
+    let mut output = String::new();
+    let mut args = args.into_iter();
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            output.push(c);
+            continue;
+        }
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            output.push('%');
+            continue;
+        }
+
+        // Flags, width, precision and length modifiers come before the conversion.
+        let mut spec = String::new();
+        for c in chars.by_ref() {
+            spec.push(c);
+            if c.is_ascii_alphabetic() && !matches!(c, 'h' | 'l' | 'z' | 'j' | 't' | 'L') {
+                break;
+            }
+        }
+        let precision = spec
+            .split_once('.')
+            .and_then(|(_, rest)| rest.trim_end_matches(char::is_alphabetic).parse().ok());
+
+        match args.next() {
+            Some(line) => {
+                let components = line
+                    .iter()
+                    .map(|value| match (value, precision) {
+                        (Scalar::Float(val), Some(precision)) => format!("{val:.precision$}"),
+                        (Scalar::Float(val), None) => format!("{val}"),
+                        (Scalar::Int(val), _) => format!("{val}"),
+                        (Scalar::UInt(val), _) => format!("{val}"),
+                        (Scalar::Bool(val), _) => format!("{}", *val as u8),
+                    })
+                    .collect::<Vec<_>>();
+                output.push_str(&components.join(", "));
+            }
+            None => {
+                output.push('%');
+                output.push_str(&spec);
+            }
+        }
+    }
+
+    output
+}
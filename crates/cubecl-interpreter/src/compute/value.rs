@@ -0,0 +1,277 @@
+use cubecl_core::ir::{ConstantScalarValue, Elem, FloatKind, IntKind, Item, UIntKind};
+use half::{bf16, f16};
+use smallvec::SmallVec;
+
+/// A single component of a value, wide enough to hold any supported element.
+///
+/// Values are always normalized to the element they were produced for: floats are rounded to the
+/// precision of their kind, and integers wrap to the width of their kind.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scalar {
+    Float(f64),
+    Int(i64),
+    UInt(u64),
+    Bool(bool),
+}
+
+/// The components of a line, a scalar is a line of a single component.
+pub type Line = SmallVec<[Scalar; 4]>;
+
+/// Memory that can be addressed by a [pointer](Pointer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemoryId {
+    Global(u32),
+    Shared(u32),
+    Local(u32),
+    Const(u32),
+}
+
+/// A reference to a single element, only used by atomics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pointer {
+    pub memory: MemoryId,
+    /// Offset in elements.
+    pub offset: usize,
+    pub elem: Elem,
+}
+
+/// The content of a register.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Line(Line),
+    Pointer(Pointer),
+}
+
+impl Value {
+    /// A line filled with zeros for the given item.
+    pub fn zeros(item: Item) -> Self {
+        Value::Line(smallvec::smallvec![Scalar::zero(item.elem); line_size(item)])
+    }
+
+    pub fn line(self) -> Line {
+        match self {
+            Value::Line(line) => line,
+            Value::Pointer(pointer) => panic!("Expected a line, got a pointer to {pointer:?}"),
+        }
+    }
+
+    pub fn pointer(self) -> Pointer {
+        match self {
+            Value::Pointer(pointer) => pointer,
+            Value::Line(line) => panic!("Expected a pointer, got {line:?}"),
+        }
+    }
+
+    /// Convert the value to the given item, broadcasting scalars to the line size of the item.
+    pub fn coerce(self, item: Item) -> Self {
+        match self {
+            Value::Line(line) => Value::Line(coerce(line, item)),
+            pointer => pointer,
+        }
+    }
+}
+
+/// The number of components of an item.
+pub fn line_size(item: Item) -> usize {
+    item.vectorization.map(|it| it.get() as usize).unwrap_or(1)
+}
+
+/// Convert every component to the elem of the item, broadcasting scalars to the line size.
+pub fn coerce(line: Line, item: Item) -> Line {
+    let size = line_size(item);
+    if line.len() == 1 && size > 1 {
+        let value = line[0].cast(item.elem);
+        return smallvec::smallvec![value; size];
+    }
+    line.into_iter()
+        .map(|value| value.cast(item.elem))
+        .collect()
+}
+
+/// The element stored by an atomic, or the element itself.
+pub fn value_elem(elem: Elem) -> Elem {
+    match elem {
+        Elem::AtomicFloat(kind) => Elem::Float(kind),
+        Elem::AtomicInt(kind) => Elem::Int(kind),
+        Elem::AtomicUInt(kind) => Elem::UInt(kind),
+        elem => elem,
+    }
+}
+
+impl Scalar {
+    pub fn zero(elem: Elem) -> Self {
+        match value_elem(elem) {
+            Elem::Float(_) => Scalar::Float(0.0),
+            Elem::Int(_) => Scalar::Int(0),
+            Elem::UInt(_) => Scalar::UInt(0),
+            Elem::Bool => Scalar::Bool(false),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn from_constant(value: ConstantScalarValue) -> Self {
+        match value {
+            ConstantScalarValue::Int(val, kind) => Scalar::Int(val).cast(Elem::Int(kind)),
+            ConstantScalarValue::Float(val, kind) => Scalar::Float(val).cast(Elem::Float(kind)),
+            ConstantScalarValue::UInt(val, kind) => Scalar::UInt(val).cast(Elem::UInt(kind)),
+            ConstantScalarValue::Bool(val) => Scalar::Bool(val),
+        }
+    }
+
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Scalar::Float(val) => val,
+            Scalar::Int(val) => val as f64,
+            Scalar::UInt(val) => val as f64,
+            Scalar::Bool(val) => val as u8 as f64,
+        }
+    }
+
+    pub fn as_i64(self) -> i64 {
+        match self {
+            Scalar::Float(val) => val as i64,
+            Scalar::Int(val) => val,
+            Scalar::UInt(val) => val as i64,
+            Scalar::Bool(val) => val as i64,
+        }
+    }
+
+    pub fn as_u64(self) -> u64 {
+        match self {
+            Scalar::Float(val) => val as u64,
+            Scalar::Int(val) => val as u64,
+            Scalar::UInt(val) => val,
+            Scalar::Bool(val) => val as u64,
+        }
+    }
+
+    pub fn as_bool(self) -> bool {
+        match self {
+            Scalar::Float(val) => val != 0.0,
+            Scalar::Int(val) => val != 0,
+            Scalar::UInt(val) => val != 0,
+            Scalar::Bool(val) => val,
+        }
+    }
+
+    pub fn as_usize(self) -> usize {
+        match self {
+            Scalar::Int(val) if val < 0 => usize::MAX,
+            other => other.as_u64() as usize,
+        }
+    }
+
+    /// Convert the scalar to the given element, rounding floats and wrapping integers.
+    ///
+    /// Float to integer conversions saturate like they do on GPUs.
+    pub fn cast(self, elem: Elem) -> Self {
+        match value_elem(elem) {
+            Elem::Float(kind) => {
+                let val = self.as_f64();
+                Scalar::Float(match kind {
+                    FloatKind::F16 => f16::from_f64(val).to_f64(),
+                    FloatKind::BF16 => bf16::from_f64(val).to_f64(),
+                    FloatKind::F32 | FloatKind::Flex32 | FloatKind::TF32 => val as f32 as f64,
+                    FloatKind::F64 => val,
+                    kind => unreachable!("{kind:?} is rejected at compilation"),
+                })
+            }
+            Elem::Int(kind) => Scalar::Int(match self {
+                Scalar::Float(val) => match kind {
+                    IntKind::I8 => val as i8 as i64,
+                    IntKind::I16 => val as i16 as i64,
+                    IntKind::I32 => val as i32 as i64,
+                    IntKind::I64 => val as i64,
+                },
+                other => {
+                    let val = other.as_i64();
+                    match kind {
+                        IntKind::I8 => val as i8 as i64,
+                        IntKind::I16 => val as i16 as i64,
+                        IntKind::I32 => val as i32 as i64,
+                        IntKind::I64 => val,
+                    }
+                }
+            }),
+            Elem::UInt(kind) => Scalar::UInt(match self {
+                Scalar::Float(val) => match kind {
+                    UIntKind::U8 => val as u8 as u64,
+                    UIntKind::U16 => val as u16 as u64,
+                    UIntKind::U32 => val as u32 as u64,
+                    UIntKind::U64 => val as u64,
+                },
+                other => {
+                    let val = other.as_u64();
+                    match kind {
+                        UIntKind::U8 => val as u8 as u64,
+                        UIntKind::U16 => val as u16 as u64,
+                        UIntKind::U32 => val as u32 as u64,
+                        UIntKind::U64 => val,
+                    }
+                }
+            }),
+            Elem::Bool => Scalar::Bool(self.as_bool()),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Decode a scalar from its in-memory representation.
+    pub fn read(bytes: &[u8], elem: Elem) -> Self {
+        match value_elem(elem) {
+            Elem::Float(kind) => Scalar::Float(match kind {
+                FloatKind::F16 => f16::from_ne_bytes([bytes[0], bytes[1]]).to_f64(),
+                FloatKind::BF16 => bf16::from_ne_bytes([bytes[0], bytes[1]]).to_f64(),
+                FloatKind::F32 | FloatKind::Flex32 | FloatKind::TF32 => {
+                    f32::from_ne_bytes(bytes[..4].try_into().unwrap()) as f64
+                }
+                FloatKind::F64 => f64::from_ne_bytes(bytes[..8].try_into().unwrap()),
+                kind => unreachable!("{kind:?} is rejected at compilation"),
+            }),
+            Elem::Int(kind) => Scalar::Int(match kind {
+                IntKind::I8 => bytes[0] as i8 as i64,
+                IntKind::I16 => i16::from_ne_bytes(bytes[..2].try_into().unwrap()) as i64,
+                IntKind::I32 => i32::from_ne_bytes(bytes[..4].try_into().unwrap()) as i64,
+                IntKind::I64 => i64::from_ne_bytes(bytes[..8].try_into().unwrap()),
+            }),
+            Elem::UInt(kind) => Scalar::UInt(match kind {
+                UIntKind::U8 => bytes[0] as u64,
+                UIntKind::U16 => u16::from_ne_bytes(bytes[..2].try_into().unwrap()) as u64,
+                UIntKind::U32 => u32::from_ne_bytes(bytes[..4].try_into().unwrap()) as u64,
+                UIntKind::U64 => u64::from_ne_bytes(bytes[..8].try_into().unwrap()),
+            }),
+            Elem::Bool => Scalar::Bool(bytes[0] != 0),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Encode the scalar into its in-memory representation.
+    pub fn write(self, bytes: &mut [u8], elem: Elem) {
+        let value = self.cast(elem);
+        match value_elem(elem) {
+            Elem::Float(kind) => {
+                let val = value.as_f64();
+                match kind {
+                    FloatKind::F16 => bytes[..2].copy_from_slice(&f16::from_f64(val).to_ne_bytes()),
+                    FloatKind::BF16 => {
+                        bytes[..2].copy_from_slice(&bf16::from_f64(val).to_ne_bytes())
+                    }
+                    FloatKind::F32 | FloatKind::Flex32 | FloatKind::TF32 => {
+                        bytes[..4].copy_from_slice(&(val as f32).to_ne_bytes())
+                    }
+                    FloatKind::F64 => bytes[..8].copy_from_slice(&val.to_ne_bytes()),
+                    kind => unreachable!("{kind:?} is rejected at compilation"),
+                }
+            }
+            Elem::Int(_) | Elem::UInt(_) => {
+                let size = elem.size();
+                let val = value.as_u64().to_ne_bytes();
+                #[cfg(target_endian = "little")]
+                bytes[..size].copy_from_slice(&val[..size]);
+                #[cfg(target_endian = "big")]
+                bytes[..size].copy_from_slice(&val[8 - size..]);
+            }
+            Elem::Bool => bytes[0] = value.as_bool() as u8,
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+#[derive(new, Clone, PartialEq, Eq, Default, Hash, Debug)]
+pub struct InterpreterDevice;
@@ -0,0 +1,112 @@
+use cubecl_common::profile::TimingMethod;
+use cubecl_core::{
+    CubeCount, CubeDim, MemoryConfiguration, Runtime, channel::MutexComputeChannel,
+    client::ComputeClient,
+};
+use cubecl_runtime::{
+    ComputeRuntime, DeviceProperties,
+    id::DeviceId,
+    memory_management::{HardwareProperties, MemoryDeviceProperties, MemoryManagement},
+    storage::BytesStorage,
+};
+
+use crate::{
+    compiler::{InterpreterCompiler, register_features},
+    compute::{
+        plane::PLANE_SIZE,
+        server::{InterpreterContext, InterpreterServer},
+    },
+    device::InterpreterDevice,
+};
+
+#[derive(Default)]
+pub struct RuntimeOptions {
+    /// Configures the memory management.
+    pub memory_config: MemoryConfiguration,
+}
+
+/// Runtime that interprets kernels directly from their IR.
+///
+/// Cubes and their units are executed one after the other on the calling thread, so results are
+/// deterministic. It's meant as a reference to test other runtimes and compiler passes against,
+/// not for performance.
+#[derive(Debug)]
+pub struct InterpreterRuntime;
+
+static RUNTIME: ComputeRuntime<InterpreterDevice, Server, Channel> = ComputeRuntime::new();
+
+type Server = InterpreterServer;
+type Channel = MutexComputeChannel<Server>;
+
+/// Limits are only there to keep the memory of a single launch reasonable.
+const MAX_UNITS_PER_CUBE: u32 = 1024;
+const MAX_SHARED_MEMORY_SIZE: usize = 1024 * 1024;
+const MAX_PAGE_SIZE: u64 = 1024 * 1024 * 1024;
+
+pub(crate) fn create_client(options: RuntimeOptions) -> ComputeClient<Server, Channel> {
+    let topology = HardwareProperties {
+        plane_size_min: PLANE_SIZE,
+        plane_size_max: PLANE_SIZE,
+        max_bindings: u32::MAX,
+        max_shared_memory_size: MAX_SHARED_MEMORY_SIZE,
+        max_cube_count: CubeCount::Static(u32::MAX, u32::MAX, u32::MAX),
+        max_units_per_cube: MAX_UNITS_PER_CUBE,
+        max_cube_dim: CubeDim::new(MAX_UNITS_PER_CUBE, MAX_UNITS_PER_CUBE, 64),
+        num_streaming_multiprocessors: None,
+        num_tensor_cores: None,
+        min_tensor_cores_dim: None,
+    };
+    let storage = BytesStorage::default();
+
+    const ALIGNMENT: u64 = 4;
+    let mem_properties = MemoryDeviceProperties {
+        max_page_size: MAX_PAGE_SIZE,
+        alignment: ALIGNMENT,
+    };
+
+    let memory_management =
+        MemoryManagement::from_configuration(storage, &mem_properties, options.memory_config);
+    let mut device_props =
+        DeviceProperties::new(&[], mem_properties, topology, TimingMethod::Device);
+    register_features(&mut device_props);
+
+    let ctx = InterpreterContext::new(memory_management);
+    let server = InterpreterServer::new(ctx);
+    ComputeClient::new(MutexComputeChannel::new(server), device_props, ())
+}
+
+impl Runtime for InterpreterRuntime {
+    type Compiler = InterpreterCompiler;
+    type Server = InterpreterServer;
+
+    type Channel = Channel;
+    type Device = InterpreterDevice;
+
+    fn client(device: &Self::Device) -> ComputeClient<Self::Server, Self::Channel> {
+        RUNTIME.client(device, move || create_client(RuntimeOptions::default()))
+    }
+
+    fn name(_client: &ComputeClient<Self::Server, Self::Channel>) -> &'static str {
+        "interpreter"
+    }
+
+    fn supported_line_sizes() -> &'static [u8] {
+        &[8, 4, 2, 1]
+    }
+
+    fn max_cube_count() -> (u32, u32, u32) {
+        (u32::MAX, u32::MAX, u32::MAX)
+    }
+
+    fn device_id(_device: &Self::Device) -> DeviceId {
+        DeviceId::new(0, 0)
+    }
+
+    fn can_read_tensor(_shape: &[usize], _strides: &[usize]) -> bool {
+        true
+    }
+
+    fn device_count() -> usize {
+        1
+    }
+}
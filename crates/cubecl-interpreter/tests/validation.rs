@@ -0,0 +1,45 @@
+use cubecl_common::future::block_on;
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+use cubecl_interpreter::InterpreterRuntime;
+use cubecl_runtime::config::GlobalConfig;
+
+#[cube(launch)]
+fn write_shifted(output: &mut Array<u32>) {
+    output[UNIT_POS + 2] = UNIT_POS;
+}
+
+// The global configuration can only be set once, so this is the only test of this binary.
+#[test]
+fn out_of_bounds_accesses_are_reported_after_sync() {
+    let mut config = GlobalConfig::default();
+    config.compilation.validation = true;
+    GlobalConfig::set(config);
+
+    let client = InterpreterRuntime::client(&Default::default());
+    let handle = client.create(u32::as_bytes(&[0, 0, 0, 0]));
+
+    write_shifted::launch::<InterpreterRuntime>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(4, 1, 1),
+        unsafe { ArrayArg::from_raw_parts::<u32>(&handle, 4, 1) },
+    )
+    .unwrap();
+    block_on(client.sync());
+
+    let mut records = client.take_out_of_bounds_records();
+    records.sort_by_key(|record| record.index);
+
+    let accesses = records
+        .iter()
+        .map(|record| (record.buffer, record.index, record.length))
+        .collect::<Vec<_>>();
+    assert_eq!(accesses, vec![(0, 4, 4), (0, 5, 4)]);
+    assert!(records[0].kernel_name.contains("write_shifted"));
+
+    // The in-bounds writes still happen, and the records are only reported once.
+    let actual = client.read_one(handle.binding());
+    assert_eq!(u32::from_bytes(&actual), &[0, 0, 0, 1]);
+    assert!(client.take_out_of_bounds_records().is_empty());
+}
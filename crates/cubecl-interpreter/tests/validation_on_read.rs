@@ -0,0 +1,42 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+use cubecl_interpreter::InterpreterRuntime;
+use cubecl_runtime::config::GlobalConfig;
+
+#[cube(launch)]
+fn read_shifted(input: &Array<u32>, output: &mut Array<u32>) {
+    output[UNIT_POS] = input[UNIT_POS + 3];
+}
+
+// The global configuration can only be set once, so this is the only test of this binary.
+#[test]
+fn out_of_bounds_accesses_are_reported_after_read() {
+    let mut config = GlobalConfig::default();
+    config.compilation.validation = true;
+    GlobalConfig::set(config);
+
+    let client = InterpreterRuntime::client(&Default::default());
+    let input = client.create(u32::as_bytes(&[1, 2, 3, 4]));
+    let output = client.empty(4 * size_of::<u32>());
+
+    read_shifted::launch::<InterpreterRuntime>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(2, 1, 1),
+        unsafe { ArrayArg::from_raw_parts::<u32>(&input, 4, 1) },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&output, 4, 1) },
+    )
+    .unwrap();
+
+    // Reading the output is enough to report the accesses, without an explicit sync.
+    let actual = client.read_one(output.binding());
+    assert_eq!(&u32::from_bytes(&actual)[..2], &[4, 0]);
+
+    let records = client.take_out_of_bounds_records();
+    let accesses = records
+        .iter()
+        .map(|record| (record.buffer, record.index, record.length))
+        .collect::<Vec<_>>();
+    assert_eq!(accesses, vec![(0, 4, 4)]);
+    assert!(records[0].kernel_name.contains("read_shifted"));
+}
@@ -18,13 +18,28 @@ pub enum Arithmetic {
     Div(BinaryOperator),
     Abs(UnaryOperator),
     Exp(UnaryOperator),
+    Exp2(UnaryOperator),
     Log(UnaryOperator),
     Log1p(UnaryOperator),
+    Log2(UnaryOperator),
+    Log10(UnaryOperator),
     Cos(UnaryOperator),
     Sin(UnaryOperator),
+    Tan(UnaryOperator),
     Tanh(UnaryOperator),
+    Sinh(UnaryOperator),
+    Cosh(UnaryOperator),
+    ArcCos(UnaryOperator),
+    ArcSin(UnaryOperator),
+    ArcTan(UnaryOperator),
+    ArcSinh(UnaryOperator),
+    ArcCosh(UnaryOperator),
+    ArcTanh(UnaryOperator),
+    ArcTan2(BinaryOperator),
     Powf(BinaryOperator),
+    Hypot(BinaryOperator),
     Sqrt(UnaryOperator),
+    InverseSqrt(UnaryOperator),
     Round(UnaryOperator),
     Floor(UnaryOperator),
     Ceil(UnaryOperator),
@@ -56,13 +71,28 @@ impl Display for Arithmetic {
             Arithmetic::Div(op) => write!(f, "{} / {}", op.lhs, op.rhs),
             Arithmetic::Abs(op) => write!(f, "{}.abs()", op.input),
             Arithmetic::Exp(op) => write!(f, "{}.exp()", op.input),
+            Arithmetic::Exp2(op) => write!(f, "{}.exp2()", op.input),
             Arithmetic::Log(op) => write!(f, "{}.log()", op.input),
             Arithmetic::Log1p(op) => write!(f, "{}.log_1p()", op.input),
+            Arithmetic::Log2(op) => write!(f, "{}.log2()", op.input),
+            Arithmetic::Log10(op) => write!(f, "{}.log10()", op.input),
             Arithmetic::Cos(op) => write!(f, "{}.cos()", op.input),
             Arithmetic::Sin(op) => write!(f, "{}.sin()", op.input),
+            Arithmetic::Tan(op) => write!(f, "{}.tan()", op.input),
             Arithmetic::Tanh(op) => write!(f, "{}.tanh()", op.input),
+            Arithmetic::Sinh(op) => write!(f, "{}.sinh()", op.input),
+            Arithmetic::Cosh(op) => write!(f, "{}.cosh()", op.input),
+            Arithmetic::ArcCos(op) => write!(f, "{}.acos()", op.input),
+            Arithmetic::ArcSin(op) => write!(f, "{}.asin()", op.input),
+            Arithmetic::ArcTan(op) => write!(f, "{}.atan()", op.input),
+            Arithmetic::ArcSinh(op) => write!(f, "{}.asinh()", op.input),
+            Arithmetic::ArcCosh(op) => write!(f, "{}.acosh()", op.input),
+            Arithmetic::ArcTanh(op) => write!(f, "{}.atanh()", op.input),
+            Arithmetic::ArcTan2(op) => write!(f, "{}.atan2({})", op.lhs, op.rhs),
             Arithmetic::Powf(op) => write!(f, "{}.pow({})", op.lhs, op.rhs),
+            Arithmetic::Hypot(op) => write!(f, "{}.hypot({})", op.lhs, op.rhs),
             Arithmetic::Sqrt(op) => write!(f, "{}.sqrt()", op.input),
+            Arithmetic::InverseSqrt(op) => write!(f, "{}.inverse_sqrt()", op.input),
             Arithmetic::Round(op) => write!(f, "{}.round()", op.input),
             Arithmetic::Floor(op) => write!(f, "{}.floor()", op.input),
             Arithmetic::Ceil(op) => write!(f, "{}.ceil()", op.input),
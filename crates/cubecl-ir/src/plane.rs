@@ -18,6 +18,10 @@ pub enum Plane {
     Any(UnaryOperator),
     Ballot(UnaryOperator),
     Broadcast(BinaryOperator),
+    Shuffle(BinaryOperator),
+    ShuffleXor(BinaryOperator),
+    ShuffleUp(BinaryOperator),
+    ShuffleDown(BinaryOperator),
     Sum(UnaryOperator),
     InclusiveSum(UnaryOperator),
     ExclusiveSum(UnaryOperator),
@@ -38,6 +42,10 @@ impl Display for Plane {
             Plane::Broadcast(op) => {
                 writeln!(f, "plane_broadcast({}, {})", op.lhs, op.rhs)
             }
+            Plane::Shuffle(op) => writeln!(f, "plane_shuffle({}, {})", op.lhs, op.rhs),
+            Plane::ShuffleXor(op) => writeln!(f, "plane_shuffle_xor({}, {})", op.lhs, op.rhs),
+            Plane::ShuffleUp(op) => writeln!(f, "plane_shuffle_up({}, {})", op.lhs, op.rhs),
+            Plane::ShuffleDown(op) => writeln!(f, "plane_shuffle_down({}, {})", op.lhs, op.rhs),
             Plane::Sum(op) => writeln!(f, "plane_sum({})", op.input),
             Plane::InclusiveSum(op) => writeln!(f, "plane_inclusive_sum({})", op.input),
             Plane::ExclusiveSum(op) => writeln!(f, "plane_exclusive_sum({})", op.input),
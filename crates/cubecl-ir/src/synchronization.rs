@@ -16,6 +16,8 @@ pub enum Synchronization {
     /// Sync CTA proxy.
     /// Experimental, CUDA only, SM 9.0+ only
     SyncProxyShared,
+    /// Order the storage accesses of a unit for all the units of the kernel, without waiting.
+    DeviceFence,
 }
 
 impl Display for Synchronization {
@@ -25,6 +27,7 @@ impl Display for Synchronization {
             Synchronization::SyncStorage => write!(f, "sync_storage()"),
             Synchronization::SyncProxyShared => write!(f, "sync_proxy_shared()"),
             Synchronization::SyncPlane => write!(f, "sync_plane()"),
+            Synchronization::DeviceFence => write!(f, "device_fence()"),
         }
     }
 }
@@ -84,7 +84,7 @@ pub enum Expression {
         ident: Ident,
         args: Vec<Expression>,
     },
-    Continue(Span),
+    Continue,
     Return(Span),
     ForLoop {
         range: Box<Expression>,
@@ -191,7 +191,7 @@ impl Expression {
             Expression::FunctionCall { .. } => None,
             Expression::Break => None,
             Expression::Cast { to, .. } => Some(to.clone()),
-            Expression::Continue { .. } => None,
+            Expression::Continue => None,
             Expression::Return { .. } => None,
             Expression::ForLoop { .. } => None,
             Expression::FieldAccess { .. } => None,
@@ -270,7 +270,10 @@ impl Expression {
                 let path = frontend_path();
                 quote![#path::branch::break_expand(scope);]
             }
-            Expression::Continue(span) => error!(*span, "Continue not supported yet"),
+            Expression::Continue => {
+                let path = frontend_path();
+                quote![#path::branch::continue_expand(scope);]
+            }
             Expression::Return(span) => error!(
                 *span,
                 "Return not supported yet. Consider using the terminate!() macro instead."
@@ -50,6 +50,7 @@ impl Launch {
             let compute_client = prelude_type("ComputeClient");
             let cube_count = prelude_type("CubeCount");
             let cube_dim = prelude_type("CubeDim");
+            let compilation_error = prelude_type("CompilationError");
 
             let kernel_doc = format!(
                 "Launch the kernel [{}()] on the given runtime",
@@ -67,9 +68,9 @@ impl Launch {
                     __cube_count: #cube_count,
                     __cube_dim: #cube_dim,
                     #(#args),*
-                ) -> () {
+                ) -> Result<(), #compilation_error> {
                     #body
-                    launcher.launch(__cube_count, __kernel, __client);
+                    launcher.launch(__cube_count, __kernel, __client)
                 }
             }
         } else {
@@ -82,6 +83,7 @@ impl Launch {
             let compute_client = prelude_type("ComputeClient");
             let cube_count = prelude_type("CubeCount");
             let cube_dim = prelude_type("CubeDim");
+            let compilation_error = prelude_type("CompilationError");
 
             let kernel_doc = format!(
                 "Launch the kernel [{}()] on the given runtime",
@@ -99,9 +101,9 @@ impl Launch {
                     __cube_count: #cube_count,
                     __cube_dim: #cube_dim,
                     #(#args),*
-                ) -> () {
+                ) -> Result<(), #compilation_error> {
                     #body
-                    launcher.launch_unchecked(__cube_count, __kernel, __client);
+                    launcher.launch_unchecked(__cube_count, __kernel, __client)
                 }
             }
         } else {
@@ -167,7 +167,7 @@ impl Expression {
             Expr::Const(block) => Expression::Verbatim {
                 tokens: quote![#block],
             },
-            Expr::Continue(cont) => Expression::Continue(cont.span()),
+            Expr::Continue(_) => Expression::Continue,
             Expr::Return(ret) => Expression::Return(ret.span()),
             Expr::ForLoop(for_loop) => expand_for_loop(for_loop, context)?,
             Expr::Loop(loop_expr) => expand_loop(loop_expr, context)?,
@@ -3,7 +3,14 @@ use cubecl_core::{Runtime, client::ComputeClient, prelude::TensorHandleRef};
 use cubecl_std::tensor::TensorHandle;
 
 use crate::{
-    components::{MatmulSetupError, tile::accelerated::AcceleratedMatmul},
+    components::{
+        FormattedConfigError, MatmulSetupError,
+        global::{
+            args::{ConcreteInputsFactory, ConcreteOutputRef, MatmulArgs},
+            epilogue::{Epilogue, EpilogueRef},
+        },
+        tile::accelerated::AcceleratedMatmul,
+    },
     kernels::layered::{
         Selection,
         double_buffering::DoubleBufferingArgs,
@@ -11,6 +18,8 @@ use crate::{
         ordered_double_buffering::OrderedSelectionArgs,
         simple::SimpleArgs,
         simple_unit::SimpleUnitSelectionArgs,
+        split_k::{SplitKAlgorithm, SplitKSelectionArgs},
+        stream_k::{StreamKAlgorithm, StreamKSelectionArgs},
     },
 };
 
@@ -52,7 +61,16 @@ pub enum Strategy {
     SimpleUnit(Selection<SimpleUnitSelectionArgs>),
     DoubleUnit(Selection<DoubleUnitSelectionArgs>),
     OrderedDoubleBuffering(Selection<OrderedSelectionArgs>),
+    /// Splits K in chunks computed by different cubes, for problems with a small output and a
+    /// large K. Falls back to unit matmuls if the inferred accelerated matmul is unavailable
+    SplitK(Selection<SplitKSelectionArgs<SimpleArgs>>),
+    /// Distributes the K iterations of all output tiles evenly across a persistent grid of cubes.
+    /// Falls back to unit matmuls if the inferred accelerated matmul is unavailable
+    StreamK(Selection<StreamKSelectionArgs<SimpleArgs>>),
     Naive,
+    /// Fuses an [epilogue](Epilogue) in the write of the output of the inner strategy.
+    /// The naive and TMA strategies don't support epilogues
+    Fused(Box<Strategy>, Epilogue),
     #[default]
     /// Tries using a Simple matmul, then a SimpleUnit if the former failed
     Auto,
@@ -115,15 +133,50 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
     rhs_scale: &Option<TensorHandleRef<R>>,
     out: &TensorHandleRef<R>,
 ) -> Result<(), MatmulSetupError> {
+    match strategy {
+        Strategy::Fused(strategy, epilogue) => {
+            let out = EpilogueRef::<R, MP::EO>::new(out, epilogue);
+            out.validate()?;
+            launch_ref_with_output::<R, MP, _>(
+                strategy, client, lhs, lhs_scale, rhs, rhs_scale, &out,
+            )
+        }
+        _ => launch_ref_with_output::<R, MP, _>(
+            strategy, client, lhs, lhs_scale, rhs, rhs_scale, out,
+        ),
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn launch_ref_with_output<R: Runtime, MP: MatmulPrecision, O>(
+    strategy: &Strategy,
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<R>,
+    lhs_scale: &Option<TensorHandleRef<R>>,
+    rhs: &TensorHandleRef<R>,
+    rhs_scale: &Option<TensorHandleRef<R>>,
+    out: &O,
+) -> Result<(), MatmulSetupError>
+where
+    O: ConcreteOutputRef<R>,
+    <O::Args as MatmulArgs>::Input<MP::EI>: ConcreteInputsFactory,
+{
+    let unsupported_epilogue = || {
+        let strategy = strategy.clone();
+        Err(MatmulSetupError::InvalidConfig(FormattedConfigError::new(
+            move || format!("Strategy {strategy:?} doesn't support epilogues"),
+        )))
+    };
+
     match strategy {
         Strategy::Simple(loading_strategy, selection) => match loading_strategy {
             SyncLoadingStrategy::Cyclic => {
-                layered::launch_ref::<R, MP, SimpleAlgorithm<AcceleratedMatmul>>(
+                layered::launch_ref_with_output::<R, MP, SimpleAlgorithm<AcceleratedMatmul>, _>(
                     client, lhs, lhs_scale, rhs, rhs_scale, out, selection,
                 )
             }
             SyncLoadingStrategy::Strided => {
-                layered::launch_ref::<
+                layered::launch_ref_with_output::<
                     R,
                     MP,
                     SimpleAlgorithm<
@@ -131,9 +184,10 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
                         sync_full_strided::SyncFullStridedLoading,
                         sync_full_strided::SyncFullStridedLoading,
                     >,
+                    _,
                 >(client, lhs, lhs_scale, rhs, rhs_scale, out, selection)
             }
-            SyncLoadingStrategy::Tilewise => layered::launch_ref::<
+            SyncLoadingStrategy::Tilewise => layered::launch_ref_with_output::<
                 R,
                 MP,
                 SimpleAlgorithm<
@@ -141,6 +195,7 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
                     sync_full_tilewise::SyncFullTilewiseLoading<ColMajorTilingOrder>,
                     sync_full_tilewise::SyncFullTilewiseLoading<RowMajorTilingOrder>,
                 >,
+                _,
             >(
                 client,
                 lhs,
@@ -152,13 +207,14 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
             ),
         },
         Strategy::SimpleBarrier(loading_strategy) => match loading_strategy {
-            AsyncLoadingStrategy::Cooperative => layered::launch_ref::<
+            AsyncLoadingStrategy::Cooperative => layered::launch_ref_with_output::<
                 R,
                 MP,
                 SimpleBarrierAlgorithm<
                     AcceleratedMatmul,
                     async_full_cooperative::AsyncFullCooperativeLoading,
                 >,
+                _,
             >(
                 client,
                 lhs,
@@ -168,13 +224,14 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
                 out,
                 &Default::default(),
             ),
-            AsyncLoadingStrategy::Cyclic => layered::launch_ref::<
+            AsyncLoadingStrategy::Cyclic => layered::launch_ref_with_output::<
                 R,
                 MP,
                 SimpleBarrierAlgorithm<
                     AcceleratedMatmul,
                     async_full_cyclic::AsyncFullCyclicLoading<ColMajorTilingOrder>,
                 >,
+                _,
             >(
                 client,
                 lhs,
@@ -184,13 +241,14 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
                 out,
                 &Default::default(),
             ),
-            AsyncLoadingStrategy::MaximizeSliceLength => layered::launch_ref::<
+            AsyncLoadingStrategy::MaximizeSliceLength => layered::launch_ref_with_output::<
                 R,
                 MP,
                 SimpleBarrierAlgorithm<
                     AcceleratedMatmul,
                     async_full_maximize_slice_length::AsyncFullMaximizeSliceLengthLoading,
                 >,
+                _,
             >(
                 client,
                 lhs,
@@ -200,13 +258,14 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
                 out,
                 &Default::default(),
             ),
-            AsyncLoadingStrategy::MaximizeUnitCount => layered::launch_ref::<
+            AsyncLoadingStrategy::MaximizeUnitCount => layered::launch_ref_with_output::<
                 R,
                 MP,
                 SimpleBarrierAlgorithm<
                     AcceleratedMatmul,
                     async_full_maximize_unit_count::AsyncFullMaximizeUnitCountLoading,
                 >,
+                _,
             >(
                 client,
                 lhs,
@@ -217,13 +276,17 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
                 &Default::default(),
             ),
             AsyncLoadingStrategy::Tma => {
+                if out.has_epilogue() {
+                    return unsupported_epilogue();
+                }
+
                 layered::matmul_cmma_tma_ref_no_check::<R, MP, SimpleTmaAlgorithm<AcceleratedMatmul>>(
                     client,
                     lhs,
                     lhs_scale,
                     rhs,
                     rhs_scale,
-                    out,
+                    out.tensor(),
                     (false, false),
                     &Default::default(),
                 )
@@ -231,50 +294,127 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
         },
         Strategy::DoubleBuffering(loading_strategy, selection) => match loading_strategy {
             SyncPartialLoadingStrategy::Cyclic => {
-                layered::launch_ref::<R, MP, CyclicDoubleBufferingAlgorithm<AcceleratedMatmul>>(
-                    client, lhs, lhs_scale, rhs, rhs_scale, out, selection,
-                )
+                layered::launch_ref_with_output::<
+                    R,
+                    MP,
+                    CyclicDoubleBufferingAlgorithm<AcceleratedMatmul>,
+                    _,
+                >(client, lhs, lhs_scale, rhs, rhs_scale, out, selection)
             }
             SyncPartialLoadingStrategy::Tilewise => {
-                layered::launch_ref::<R, MP, TilewiseDoubleBufferingAlgorithm<AcceleratedMatmul>>(
-                    client, lhs, lhs_scale, rhs, rhs_scale, out, selection,
-                )
+                layered::launch_ref_with_output::<
+                    R,
+                    MP,
+                    TilewiseDoubleBufferingAlgorithm<AcceleratedMatmul>,
+                    _,
+                >(client, lhs, lhs_scale, rhs, rhs_scale, out, selection)
             }
             SyncPartialLoadingStrategy::Hybrid => {
-                layered::launch_ref::<R, MP, HybridDoubleBufferingAlgorithm<AcceleratedMatmul>>(
-                    client, lhs, lhs_scale, rhs, rhs_scale, out, selection,
-                )
+                layered::launch_ref_with_output::<
+                    R,
+                    MP,
+                    HybridDoubleBufferingAlgorithm<AcceleratedMatmul>,
+                    _,
+                >(client, lhs, lhs_scale, rhs, rhs_scale, out, selection)
             }
         },
         Strategy::OrderedDoubleBuffering(selection) => {
-            layered::launch_ref::<R, MP, OrderedDoubleBufferingAlgorithm<AcceleratedMatmul>>(
+            layered::launch_ref_with_output::<
+                R,
+                MP,
+                OrderedDoubleBufferingAlgorithm<AcceleratedMatmul>,
+                _,
+            >(client, lhs, lhs_scale, rhs, rhs_scale, out, selection)
+        }
+        Strategy::SimpleUnit(selection) => {
+            layered::launch_ref_with_output::<R, MP, SimpleUnitAlgorithm, _>(
                 client, lhs, lhs_scale, rhs, rhs_scale, out, selection,
             )
         }
-        Strategy::SimpleUnit(selection) => layered::launch_ref::<R, MP, SimpleUnitAlgorithm>(
-            client, lhs, lhs_scale, rhs, rhs_scale, out, selection,
-        ),
-        Strategy::DoubleUnit(selection) => layered::launch_ref::<R, MP, DoubleUnitAlgorithm>(
-            client, lhs, lhs_scale, rhs, rhs_scale, out, selection,
-        ),
+        Strategy::DoubleUnit(selection) => {
+            layered::launch_ref_with_output::<R, MP, DoubleUnitAlgorithm, _>(
+                client, lhs, lhs_scale, rhs, rhs_scale, out, selection,
+            )
+        }
+        Strategy::SplitK(selection) => {
+            let result = layered::launch_ref_with_output::<
+                R,
+                MP,
+                SplitKAlgorithm<SimpleAlgorithm<AcceleratedMatmul>>,
+                _,
+            >(client, lhs, lhs_scale, rhs, rhs_scale, out, selection);
+
+            match (result, selection) {
+                (Err(MatmulSetupError::Unavailable(_)), Selection::Inferred(args)) => {
+                    layered::launch_ref_with_output::<R, MP, SplitKAlgorithm<SimpleUnitAlgorithm>, _>(
+                        client,
+                        lhs,
+                        lhs_scale,
+                        rhs,
+                        rhs_scale,
+                        out,
+                        &Selection::Inferred(SplitKSelectionArgs {
+                            num_splits: args.num_splits,
+                            inner: Default::default(),
+                        }),
+                    )
+                }
+                (result, _) => result,
+            }
+        }
+        Strategy::StreamK(selection) => {
+            let result = layered::launch_ref_with_output::<
+                R,
+                MP,
+                StreamKAlgorithm<SimpleAlgorithm<AcceleratedMatmul>>,
+                _,
+            >(client, lhs, lhs_scale, rhs, rhs_scale, out, selection);
+
+            match (result, selection) {
+                (Err(MatmulSetupError::Unavailable(_)), Selection::Inferred(args)) => {
+                    layered::launch_ref_with_output::<R, MP, StreamKAlgorithm<SimpleUnitAlgorithm>, _>(
+                        client,
+                        lhs,
+                        lhs_scale,
+                        rhs,
+                        rhs_scale,
+                        out,
+                        &Selection::Inferred(StreamKSelectionArgs {
+                            num_cubes: args.num_cubes,
+                            inner: Default::default(),
+                        }),
+                    )
+                }
+                (result, _) => result,
+            }
+        }
         Strategy::Naive => {
+            if out.has_epilogue() {
+                return unsupported_epilogue();
+            }
+
             // TODO Implement naive with EI and EO
-            naive::launch_ref::<R, MP::EI>(client, lhs, rhs, out)?;
+            naive::launch_ref::<R, MP::EI>(client, lhs, rhs, out.tensor())?;
             Ok(())
         }
+        Strategy::Fused(..) => Err(MatmulSetupError::InvalidConfig(FormattedConfigError::new(
+            || "Epilogues can't be nested".to_string(),
+        ))),
         Strategy::Auto => {
-            if let Err(err) = layered::launch_ref::<R, MP, SimpleAlgorithm<AcceleratedMatmul>>(
-                client,
-                lhs,
-                lhs_scale,
-                rhs,
-                rhs_scale,
-                out,
-                &Default::default(),
-            ) {
+            if let Err(err) =
+                layered::launch_ref_with_output::<R, MP, SimpleAlgorithm<AcceleratedMatmul>, _>(
+                    client,
+                    lhs,
+                    lhs_scale,
+                    rhs,
+                    rhs_scale,
+                    out,
+                    &Default::default(),
+                )
+            {
                 match err {
                     MatmulSetupError::Unavailable(_) => {
-                        layered::launch_ref::<R, MP, SimpleUnitAlgorithm>(
+                        layered::launch_ref_with_output::<R, MP, SimpleUnitAlgorithm, _>(
                             client,
                             lhs,
                             lhs_scale,
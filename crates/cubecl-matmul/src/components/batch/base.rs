@@ -1,7 +1,7 @@
 use crate::components::{
     AvailableLineSizes, InputRuntimeArg, MatmulLineSizes, MatmulPrecision, MatmulProblem,
     MatmulSelection, MatmulSpec, OutputRuntimeArg, TilingScheme,
-    batch::{CubeCountInput, CubeCountInputArgs, HypercubeConfig},
+    batch::{CubeCountInput, CubeCountInputArgs, CubeCountPlan, HypercubeConfig},
     error::MatmulSetupError,
     global::{self, GlobalConfig as _, Quantization},
 };
@@ -44,7 +44,31 @@ pub trait BatchMatmulFamily: 'static + Send + Sync {
         output: OutputRuntimeArg<'a, MS, R>,
         cube_count_input: CubeCountInputArgs<'a, R>,
         config: Self::Config,
-    );
+    ) -> Result<(), CompilationError>;
+
+    /// Makes the [CubeCountPlan] to launch the matmul with.
+    ///
+    /// By default, follows the [HypercubeConfig].
+    fn cube_count_plan(
+        config: &Self::Config,
+        problem: &MatmulProblem,
+        max_cube_count: CubeCount,
+    ) -> CubeCountPlan {
+        config
+            .hypercube_config()
+            .cube_count_plan(problem, max_cube_count)
+    }
+
+    /// Number of partial results computed for each output element.
+    ///
+    /// When specified, the matmul doesn't write to the output but to a zero-initialized
+    /// workspace of shape `[num_partials, ..batches, m, n]` in the accumulator precision,
+    /// which is then summed into the output by a second kernel.
+    ///
+    /// By default, the matmul writes directly to the output.
+    fn num_partials(_config: &Self::Config, _problem: &MatmulProblem) -> Option<u32> {
+        None
+    }
 
     /// Filters out line sizes that are incompatible with this matmul family.
     ///
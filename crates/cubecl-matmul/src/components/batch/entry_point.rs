@@ -68,3 +68,38 @@ pub(crate) fn matmul<
         );
     };
 }
+
+#[cube(launch_unchecked)]
+/// Sums the partial results of a matmul into the output.
+///
+/// The partials are a contiguous tensor of shape `[num_partials, ..batches, m, n]`,
+/// see [num_partials](BatchMatmulFamily::num_partials).
+pub(crate) fn reduce_partials<Args: MatmulArgs, EI: Numeric, EA: Numeric, EO: Numeric>(
+    inputs: &Input<Args, EI>,
+    output: &mut Output<Args, EO>,
+    partials: &Tensor<Line<EA>>,
+) {
+    let line_size = partials.line_size();
+    let num_lines = partials.stride(0) / line_size;
+
+    if ABSOLUTE_POS >= num_lines {
+        terminate!()
+    }
+
+    let mut state = Args::init_state(inputs, output);
+    let mut out = TensorOutput::<EI, EO, Args>::new(&mut state);
+    let out = VirtualTensor::<EO, ReadWrite>::new::<TensorOutput<EI, EO, Args>>(&mut out);
+
+    let mut acc = partials[ABSOLUTE_POS];
+    for slot in 1..partials.shape(0) {
+        acc += partials[slot * num_lines + ABSOLUTE_POS];
+    }
+
+    let index = ABSOLUTE_POS * line_size;
+    let mut offset = 0;
+    for axis in 0..out.rank() {
+        offset += index / partials.stride(axis + 1) % out.shape(axis) * out.stride(axis);
+    }
+
+    out.write(offset / line_size, Line::cast_from(acc));
+}
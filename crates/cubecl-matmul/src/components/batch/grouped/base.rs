@@ -0,0 +1,139 @@
+use cubecl_core::prelude::*;
+
+use crate::components::{
+    FormattedConfigError, MatmulIdent, MatmulLineSizes, MatmulPrecision, MatmulProblem,
+    MatmulSelection, MatmulSetupError, MatrixLayout, TilingScheme,
+    global::{GlobalConfig, GlobalMatmulFamily},
+};
+
+#[derive(Clone, Debug)]
+/// Description of a grouped matmul, where the rows of `lhs` and `out` are partitioned in
+/// contiguous groups, each multiplied by the `rhs` matrix of its group:
+///
+/// `out[offsets[g]..offsets[g + 1]] = lhs[offsets[g]..offsets[g + 1]] @ rhs[g]`
+///
+/// Only the total number of rows is known on the host, the group offsets may live on the device.
+pub struct GroupedMatmulProblem {
+    /// Total number of rows of `lhs` and `out`, over all groups
+    pub m: usize,
+    /// Number of columns of `rhs` and `out`
+    pub n: usize,
+    /// Shared dimension
+    pub k: usize,
+    /// Number of groups, which is also the number of `rhs` matrices
+    pub num_groups: usize,
+    /// Memory layout of `lhs`
+    pub lhs_layout: MatrixLayout,
+    /// Memory layout of `rhs`
+    pub rhs_layout: MatrixLayout,
+}
+
+impl GroupedMatmulProblem {
+    /// The problem of a single group of average size, used to select the matmul configuration.
+    pub fn representative_problem(&self) -> MatmulProblem {
+        MatmulProblem {
+            m: (self.m / self.num_groups.max(1)).max(1),
+            n: self.n,
+            k: self.k,
+            lhs_batches: vec![1],
+            rhs_batches: vec![1],
+            lhs_layout: self.lhs_layout,
+            rhs_layout: self.rhs_layout,
+        }
+    }
+
+    /// Cubes to launch for the given tiling scheme.
+    ///
+    /// Cubes are laid out along x for rows and y for columns. The number of row tiles of all
+    /// groups is bounded, since each group wastes at most one partially filled tile.
+    pub fn cube_count(&self, tiling_scheme: &TilingScheme) -> CubeCount {
+        let m_tiles =
+            (self.m as u32).div_ceil(tiling_scheme.elements_in_stage_m()) + self.num_groups as u32;
+        let n_tiles = (self.n as u32).div_ceil(tiling_scheme.elements_in_stage_n());
+
+        CubeCount::Static(m_tiles, n_tiles, 1)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+/// Configuration for the grouped batch matmul
+pub struct GroupedBatchConfig<G: GlobalConfig> {
+    global_config: G,
+}
+
+impl<G: GlobalConfig> GroupedBatchConfig<G> {
+    /// Create a new config for grouped batch matmul
+    pub fn new(global_config: G) -> Self {
+        Self { global_config }
+    }
+
+    /// Underlying global matmul config
+    pub fn global_config(&self) -> G {
+        self.global_config
+    }
+
+    /// Returns the [TilingScheme]
+    pub fn tiling_scheme(&self) -> TilingScheme {
+        self.global_config.tiling_scheme()
+    }
+
+    /// Returns the [CubeDim]
+    pub fn cube_dim(&self) -> CubeDim {
+        self.global_config.cube_dim()
+    }
+
+    /// Returns the line sizes for Lhs, Rhs and output
+    pub fn line_sizes(&self) -> MatmulLineSizes {
+        MatmulLineSizes {
+            lhs: self.global_config.global_line_size(MatmulIdent::Lhs) as u8,
+            rhs: self.global_config.global_line_size(MatmulIdent::Rhs) as u8,
+            out: self.global_config.global_line_size(MatmulIdent::Out) as u8,
+        }
+    }
+}
+
+/// Constructs the configuration of a grouped matmul computing each tile with the global matmul
+/// `GMM`.
+///
+/// This function may return an error if the configuration cannot be supported on the current
+/// runtime, or if:
+/// - the matmul is quantized
+/// - global partitions contain more than one stage
+pub fn setup_grouped<GMM: GlobalMatmulFamily, MP: MatmulPrecision, R: Runtime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    problem: &GroupedMatmulProblem,
+    selection: &MatmulSelection,
+    line_sizes: &MatmulLineSizes,
+) -> Result<GroupedBatchConfig<GMM::Config>, MatmulSetupError> {
+    if MP::QUANTIZED || selection.quantized {
+        return Err(MatmulSetupError::InvalidConfig(Box::new(
+            "Grouped matmul is not supported for quantized matmuls",
+        )));
+    }
+
+    let global_partition_size = selection.tiling_scheme.global_partition_size;
+    if global_partition_size.m != 1
+        || global_partition_size.n != 1
+        || global_partition_size.batches != 1
+    {
+        return Err(MatmulSetupError::InvalidConfig(FormattedConfigError::new(
+            move || {
+                format!(
+                    "Grouped matmul requires a global partition size of 1, got {global_partition_size:?}"
+                )
+            },
+        )));
+    }
+
+    // Group sizes are arbitrary, so bounds must be checked along M: the global matmul is set up
+    // for a number of rows that isn't a multiple of the stage size.
+    let stage_m = selection.tiling_scheme.elements_in_stage_m() as usize;
+    let setup_problem = MatmulProblem {
+        m: stage_m + 1,
+        ..problem.representative_problem()
+    };
+
+    let global_config = GMM::setup::<MP, R>(client, &setup_problem, selection, line_sizes)?;
+
+    Ok(GroupedBatchConfig::new(global_config))
+}
@@ -0,0 +1,110 @@
+use crate::components::MatmulPrecision;
+use crate::components::batch::grouped::base::GroupedBatchConfig;
+use crate::components::batch::grouped::view::GroupRows;
+use crate::components::global::{GlobalConfig as _, GlobalMatmul, GlobalMatmulFamily};
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+use cubecl_std::tensor::r#virtual::{ReadWrite, VirtualTensor};
+use cubecl_std::{CubeOption, div_ceil};
+
+#[cube(launch_unchecked)]
+/// Launches the grouped matmul kernel
+pub(crate) fn grouped_matmul<
+    EI: Numeric,
+    ES: Numeric,
+    EA: Numeric,
+    EO: Numeric,
+    GMMF: GlobalMatmulFamily,
+>(
+    lhs: &Tensor<Line<EI>>,
+    rhs: &Tensor<Line<EI>>,
+    group_offsets: &Tensor<u32>,
+    out: &mut Tensor<Line<EO>>,
+    #[comptime] config: GroupedBatchConfig<GMMF::Config>,
+) {
+    execute::<(EI, ES, EA, EO), GMMF::Matmul<(EI, ES, EA, EO)>>(
+        lhs,
+        rhs,
+        group_offsets,
+        out,
+        config.global_config(),
+    );
+}
+
+#[cube]
+/// Computes the output tile of the cube.
+///
+/// The row tiles of all groups are laid out one group after the other along x, and the column
+/// tiles along y. Cubes past the last row tile of the last group don't do anything.
+fn execute<MP: MatmulPrecision, GMM: GlobalMatmul<MP>>(
+    lhs: &Tensor<Line<MP::EI>>,
+    rhs: &Tensor<Line<MP::EI>>,
+    group_offsets: &Tensor<u32>,
+    out: &mut Tensor<Line<MP::EO>>,
+    #[comptime] config: GMM::Config,
+) {
+    let tiling_scheme = config.tiling_scheme();
+    let stage_m = tiling_scheme.elements_in_stage_m();
+    let m_tile = CUBE_POS_X;
+    let num_groups = group_offsets.len() - 1;
+
+    let mut group = 0u32.runtime();
+    let mut group_first_tile = 0u32.runtime();
+    loop {
+        if group >= num_groups {
+            terminate!();
+        }
+
+        let num_tiles = div_ceil(group_offsets[group + 1] - group_offsets[group], stage_m);
+        if m_tile < group_first_tile + num_tiles {
+            break;
+        }
+
+        group_first_tile += num_tiles;
+        group += 1;
+    }
+
+    let row_start = group_offsets[group];
+    let num_rows = group_offsets[group + 1] - row_start;
+    let m_offset = (m_tile - group_first_tile) * stage_m;
+    let n_offset = CUBE_POS_Y * tiling_scheme.elements_in_stage_n();
+    let k = lhs.shape(lhs.rank() - 1);
+
+    let lhs_batch_offset = row_start * lhs.stride(lhs.rank() - 2);
+    let rhs_batch_offset = group * rhs.stride(0);
+    let out_batch_offset = row_start * out.stride(out.rank() - 2);
+
+    let lhs = GroupRows::<MP::EI>::new(lhs, num_rows);
+    let rhs = VirtualTensor::<MP::EI>::new::<Tensor<Line<MP::EI>>>(rhs);
+    let mut out = GroupRows::<MP::EO>::new(out, num_rows);
+
+    let lhs = VirtualTensor::<MP::EI>::new::<GroupRows<MP::EI>>(&lhs);
+    let out = VirtualTensor::<MP::EO, ReadWrite>::new::<GroupRows<MP::EO>>(&mut out);
+
+    let mut acc = GMM::init_accumulator(config);
+
+    GMM::execute(
+        GMM::init_lhs_loader(
+            lhs,
+            m_offset,
+            0,
+            group,
+            lhs_batch_offset,
+            CubeOption::new_None(),
+            config,
+        ),
+        GMM::init_rhs_loader(
+            rhs,
+            0,
+            n_offset,
+            group,
+            rhs_batch_offset,
+            CubeOption::new_None(),
+            config,
+        ),
+        GMM::init_writer(out, m_offset, n_offset, group, out_batch_offset),
+        &mut acc,
+        (0, k),
+        config,
+    );
+}
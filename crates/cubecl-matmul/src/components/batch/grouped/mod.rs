@@ -0,0 +1,7 @@
+mod base;
+mod matmul;
+mod view;
+
+pub use base::*;
+pub(crate) use matmul::grouped_matmul;
+pub use view::{GroupRows, GroupRowsExpand};
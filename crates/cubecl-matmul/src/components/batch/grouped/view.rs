@@ -0,0 +1,140 @@
+use std::marker::PhantomData;
+
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+use cubecl_core::unexpanded;
+use cubecl_std::tensor::r#virtual::{VirtualTensorOperations, VirtualTensorOperationsExpand};
+
+/// The rows of a matrix belonging to one group, seen as a matrix of their own.
+///
+/// Only the number of rows differs from the underlying matrix, so that bounds are checked
+/// against the group. Rows are addressed relative to the first row of the group by giving its
+/// offset as batch offset to the global matmul.
+///
+/// Can't be turned into a tensor map.
+pub struct GroupRows<E: Numeric> {
+    _e: PhantomData<E>,
+}
+
+/// Expand type for [GroupRows].
+pub struct GroupRowsExpand<E: Numeric> {
+    tensor: ExpandElementTyped<Tensor<Line<E>>>,
+    num_rows: ExpandElementTyped<u32>,
+}
+
+impl<E: Numeric> GroupRows<E> {
+    /// Create a view over the first `num_rows` rows of the given matrix.
+    pub fn new(_tensor: &Tensor<Line<E>>, _num_rows: u32) -> Self {
+        unexpanded!()
+    }
+
+    /// Expand function of [Self::new].
+    pub fn __expand_new(
+        _scope: &mut Scope,
+        tensor: ExpandElementTyped<Tensor<Line<E>>>,
+        num_rows: ExpandElementTyped<u32>,
+    ) -> GroupRowsExpand<E> {
+        GroupRowsExpand { tensor, num_rows }
+    }
+}
+
+#[cube]
+fn group_shape<E: Numeric>(tensor: &Tensor<Line<E>>, num_rows: u32, axis: u32) -> u32 {
+    select(axis == tensor.rank() - 2, num_rows, tensor.shape(axis))
+}
+
+impl<E: Numeric> VirtualTensorOperations<E> for GroupRows<E> {}
+
+impl<E: Numeric> VirtualTensorOperationsExpand<E> for GroupRowsExpand<E> {
+    fn __expand_read_method(
+        &self,
+        scope: &mut Scope,
+        index: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<Line<E>> {
+        self.tensor
+            .clone()
+            .__expand_index_unchecked_method(scope, index)
+    }
+
+    fn __expand_read_window_method(
+        &self,
+        context: &mut Scope,
+        start: ExpandElementTyped<u32>,
+        end: ExpandElementTyped<u32>,
+    ) -> SliceExpand<Line<E>, ReadOnly> {
+        self.tensor
+            .clone()
+            .__expand_slice_method(context, start, end)
+    }
+
+    fn __expand_write_method(
+        &self,
+        scope: &mut Scope,
+        index: ExpandElementTyped<u32>,
+        value: ExpandElementTyped<Line<E>>,
+    ) {
+        self.tensor
+            .clone()
+            .__expand_index_assign_unchecked_method(scope, index, value)
+    }
+
+    fn __expand_shape_method(
+        &self,
+        scope: &mut Scope,
+        axis: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<u32> {
+        group_shape::expand::<E>(scope, self.tensor.clone(), self.num_rows.clone(), axis)
+    }
+
+    fn __expand_stride_method(
+        &self,
+        scope: &mut Scope,
+        axis: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<u32> {
+        self.tensor.clone().__expand_stride_method(scope, axis)
+    }
+
+    fn __expand_rank_method(&self, scope: &mut Scope) -> ExpandElementTyped<u32> {
+        self.tensor.clone().__expand_rank_method(scope)
+    }
+
+    fn __expand_len_method(&self, scope: &mut Scope) -> ExpandElementTyped<u32> {
+        self.tensor.clone().__expand_len_method(scope)
+    }
+
+    fn __expand_buffer_len_method(&self, scope: &mut Scope) -> ExpandElementTyped<u32> {
+        self.tensor.clone().__expand_buffer_len_method(scope)
+    }
+
+    fn __expand_as_tensor_map_method(
+        &self,
+        _scope: &mut Scope,
+    ) -> ExpandElementTyped<TensorMap<E>> {
+        unimplemented!("GroupRows can't be turned into a tensor map");
+    }
+}
+
+mod __group_rows {
+    use super::*;
+
+    impl<E: Numeric> CubeType for GroupRows<E> {
+        type ExpandType = GroupRowsExpand<E>;
+    }
+
+    impl<E: Numeric> Clone for GroupRowsExpand<E> {
+        fn clone(&self) -> Self {
+            Self {
+                tensor: self.tensor.clone(),
+                num_rows: self.num_rows.clone(),
+            }
+        }
+    }
+
+    impl<E: Numeric> IntoMut for GroupRowsExpand<E> {
+        fn into_mut(self, _scope: &mut Scope) -> Self {
+            self
+        }
+    }
+
+    impl<E: Numeric> CubeDebug for GroupRowsExpand<E> {}
+}
@@ -2,7 +2,16 @@
 
 mod base;
 mod entry_point;
+mod grouped;
+mod partial;
 mod partitioned_matmul;
+mod split_k;
+mod stream_k;
 
 pub use base::*;
+pub(crate) use entry_point::reduce_partials;
+pub use grouped::*;
+pub use partial::KPartitioningSelection;
 pub use partitioned_matmul::*;
+pub use split_k::*;
+pub use stream_k::*;
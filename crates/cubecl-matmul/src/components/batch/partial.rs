@@ -0,0 +1,140 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+use cubecl_std::{
+    CubeOption,
+    tensor::r#virtual::{ReadWrite, VirtualTensor},
+};
+
+use crate::{
+    components::{
+        FormattedConfigError, MatmulPrecision, MatmulSelection, MatmulSetupError, TilingScheme,
+        global::{self, Quantization},
+    },
+    kernels::layered::NUM_SM_APPROX,
+};
+
+#[derive(Default, Copy, Clone, Debug)]
+/// How the K dimension is partitioned across cubes by the
+/// [Split-K](crate::components::batch::SplitKBatchMatmulFamily) and
+/// [Stream-K](crate::components::batch::StreamKBatchMatmulFamily) batch matmuls.
+///
+/// Ignored by the other batch matmuls.
+pub struct KPartitioningSelection {
+    /// Number of chunks K is split into by Split-K.
+    ///
+    /// If not specified, enough splits to occupy the device are used.
+    pub num_splits: Option<u32>,
+    /// Number of persistent cubes launched by Stream-K.
+    ///
+    /// If not specified, one cube per SM is used.
+    pub num_cubes: Option<u32>,
+}
+
+/// Granularity at which K is partitioned.
+///
+/// Double-buffered global matmuls compute stages in pairs and only check bounds against the
+/// whole K dimension, so every K range other than the last must cover an even number of stages.
+pub(crate) fn k_iteration_size(tiling_scheme: &TilingScheme) -> u32 {
+    2 * tiling_scheme.elements_in_stage_k()
+}
+
+/// Number of cubes needed to occupy the device.
+pub(crate) fn default_num_cubes<R: Runtime>(client: &ComputeClient<R::Server, R::Channel>) -> u32 {
+    client
+        .properties()
+        .hardware
+        .num_streaming_multiprocessors
+        .unwrap_or(NUM_SM_APPROX)
+}
+
+/// Returns an error if the selection can't be used by a batch matmul partitioning K:
+/// - quantized matmuls are not supported
+/// - global partitions must contain a single stage
+pub(crate) fn validate_k_partitioning<MP: MatmulPrecision>(
+    selection: &MatmulSelection,
+) -> Result<(), MatmulSetupError> {
+    if MP::QUANTIZED || selection.quantized {
+        return Err(MatmulSetupError::InvalidConfig(Box::new(
+            "Partitioning K is not supported for quantized matmuls",
+        )));
+    }
+
+    let global_partition_size = selection.tiling_scheme.global_partition_size;
+    if global_partition_size.m != 1
+        || global_partition_size.n != 1
+        || global_partition_size.batches != 1
+    {
+        return Err(MatmulSetupError::InvalidConfig(FormattedConfigError::new(
+            move || {
+                format!(
+                    "Partitioning K requires a global partition size of 1, got {global_partition_size:?}"
+                )
+            },
+        )));
+    }
+
+    Ok(())
+}
+
+#[cube]
+/// Execute the global matmul of the nth batch over `k_range`, writing its result in the given
+/// slot of the partials workspace.
+///
+/// The workspace is a contiguous tensor of shape `[num_slots, ..batches, m, n]`.
+pub(crate) fn execute_partial_global_matmul<MP: MatmulPrecision, GMM: global::GlobalMatmul<MP>>(
+    lhs: VirtualTensor<MP::EI>,
+    rhs: VirtualTensor<MP::EI>,
+    partials: VirtualTensor<MP::EO, ReadWrite>,
+    m_offset: u32,
+    n_offset: u32,
+    nth_batch: u32,
+    slot: u32,
+    acc: &mut GMM::Accumulator,
+    k_range: (u32, u32),
+    quantization: CubeOption<Quantization<MP>>,
+    #[comptime] config: GMM::Config,
+) {
+    let rank = lhs.rank();
+    let partials_rank = partials.rank();
+
+    let batch_out =
+        nth_batch * partials.stride(partials_rank - 2) * partials.shape(partials_rank - 2);
+    let mut batch_lhs = 0u32.runtime();
+    let mut batch_rhs = 0u32.runtime();
+    for axis in 0..rank - 2 {
+        let tmp = batch_out / partials.stride(axis + 1);
+        batch_lhs += tmp % lhs.shape(axis) * lhs.stride(axis);
+        batch_rhs += tmp % rhs.shape(axis) * rhs.stride(axis);
+    }
+
+    GMM::execute(
+        GMM::init_lhs_loader(
+            lhs,
+            m_offset,
+            k_range.0,
+            nth_batch,
+            batch_lhs,
+            quantization,
+            config,
+        ),
+        GMM::init_rhs_loader(
+            rhs,
+            k_range.0,
+            n_offset,
+            nth_batch,
+            batch_rhs,
+            quantization,
+            config,
+        ),
+        GMM::init_writer(
+            partials,
+            m_offset,
+            n_offset,
+            nth_batch,
+            slot * partials.stride(0) + batch_out,
+        ),
+        acc,
+        k_range,
+        config,
+    );
+}
@@ -4,7 +4,9 @@ mod global_order;
 mod sm_allocation;
 
 pub use base::{HypercubeConfig, HypercubeSelection};
-pub use cube_count_plan::{CubeCountInput, CubeCountInputArgs, CubeCountPlanSelection};
+pub use cube_count_plan::{
+    CubeCountInput, CubeCountInputArgs, CubeCountPlan, CubeCountPlanConfig, CubeCountPlanSelection,
+};
 pub use global_order::GlobalOrderSelection;
 pub use sm_allocation::SmAllocation;
 
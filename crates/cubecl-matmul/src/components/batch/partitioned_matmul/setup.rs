@@ -50,7 +50,7 @@ impl<GMM: GlobalMatmulFamily, S: GlobalPartitionMatmul> BatchMatmulFamily
         output: OutputRuntimeArg<'a, MS, R>,
         cube_count_input: CubeCountInputArgs<'a, R>,
         config: Self::Config,
-    ) {
+    ) -> Result<(), CompilationError> {
         unsafe {
             matmul::launch_unchecked::<Args<MS>, EI<MS>, ES<MS>, EA<MS>, EO<MS>, Self, R>(
                 client,
@@ -60,7 +60,7 @@ impl<GMM: GlobalMatmulFamily, S: GlobalPartitionMatmul> BatchMatmulFamily
                 output,
                 cube_count_input,
                 config,
-            );
+            )
         }
     }
 }
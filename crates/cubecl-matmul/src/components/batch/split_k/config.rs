@@ -0,0 +1,77 @@
+use cubecl_core::CubeDim;
+
+use crate::components::{
+    MatmulIdent, MatmulLineSizes, MatmulProblem, MatmulSetupError,
+    batch::{BatchConfig, HypercubeConfig, partial::k_iteration_size},
+    global::GlobalConfig,
+};
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+/// Configuration for Split-K batch matmul
+pub struct SplitKBatchConfig<G: GlobalConfig> {
+    global_config: G,
+    hypercube_config: HypercubeConfig,
+    num_splits: u32,
+}
+
+impl<G: GlobalConfig> BatchConfig for SplitKBatchConfig<G> {
+    type GlobalConfig = G;
+
+    fn global_config(&self) -> Self::GlobalConfig {
+        self.global_config
+    }
+
+    fn quantized(&self) -> bool {
+        self.global_config().quantized()
+    }
+
+    fn cube_dim(&self) -> CubeDim {
+        self.global_config.cube_dim()
+    }
+
+    fn line_sizes(&self) -> MatmulLineSizes {
+        MatmulLineSizes {
+            lhs: self.global_config.global_line_size(MatmulIdent::Lhs) as u8,
+            rhs: self.global_config.global_line_size(MatmulIdent::Rhs) as u8,
+            out: self.global_config.global_line_size(MatmulIdent::Out) as u8,
+        }
+    }
+
+    fn hypercube_config(&self) -> HypercubeConfig {
+        self.hypercube_config
+    }
+
+    fn can_yield_extra_cubes(&self) -> bool {
+        self.hypercube_config
+            .cube_count_plan_config
+            .can_yield_extra_cubes()
+    }
+}
+
+impl<G: GlobalConfig> SplitKBatchConfig<G> {
+    /// Create a new config for Split-K batch matmul
+    pub fn new(global_config: G, hypercube_config: HypercubeConfig, num_splits: u32) -> Self {
+        Self {
+            global_config,
+            hypercube_config,
+            num_splits,
+        }
+    }
+
+    /// Number of chunks K is split into, each computed by a different cube
+    pub fn num_splits(&self) -> u32 {
+        self.num_splits
+    }
+
+    /// Granularity at which K is split
+    pub fn k_iteration_size(&self) -> u32 {
+        k_iteration_size(&self.global_config.tiling_scheme())
+    }
+
+    /// May return an error if:
+    /// - hypercube config is invalid
+    pub fn validate(self, problem: &MatmulProblem) -> Result<Self, MatmulSetupError> {
+        self.hypercube_config.validate(problem)?;
+        Ok(self)
+    }
+}
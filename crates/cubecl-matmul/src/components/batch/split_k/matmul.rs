@@ -0,0 +1,70 @@
+use std::marker::PhantomData;
+
+use crate::components::MatmulPrecision;
+use crate::components::batch::partial::execute_partial_global_matmul;
+use crate::components::batch::split_k::config::SplitKBatchConfig;
+use crate::components::batch::{BatchConfig as _, BatchMatmul, CubeCountInput};
+use crate::components::global::Quantization;
+use crate::components::global::{self, GlobalMatmul};
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+use cubecl_std::tensor::r#virtual::{ReadWrite, VirtualTensor};
+use cubecl_std::{CubeOption, div_ceil};
+
+/// Executes matrix multiplication at the batch level,
+/// splitting the K dimension in chunks computed by different cubes.
+///
+/// Each cube computes the matmul of one stage of the output over one chunk of K,
+/// and writes its partial result to the slot of its chunk in the partials workspace.
+pub struct SplitKBatchMatmul<MP: MatmulPrecision, GMM: global::GlobalMatmul<MP>> {
+    _mp: PhantomData<MP>,
+    _gmm: PhantomData<GMM>,
+}
+
+#[cube]
+impl<MP: MatmulPrecision, GMM: GlobalMatmul<MP>> BatchMatmul<MP> for SplitKBatchMatmul<MP, GMM> {
+    type Config = SplitKBatchConfig<GMM::Config>;
+
+    fn execute(
+        lhs: VirtualTensor<MP::EI>,
+        rhs: VirtualTensor<MP::EI>,
+        out: VirtualTensor<MP::EO, ReadWrite>,
+        quantization: CubeOption<Quantization<MP>>,
+        cube_count_args: CubeCountInput,
+        #[comptime] config: Self::Config,
+    ) {
+        let problem_k = lhs.shape(lhs.rank() - 1);
+        let num_splits = config.num_splits();
+        let k_iteration_size = config.k_iteration_size();
+
+        let tiling_scheme = config.tiling_scheme();
+        let (m_index, n_index, batch_index) =
+            cube_count_args.cube_pos_to_tensor_pos(config.hypercube_config().global_order);
+
+        // The batch dimension of the hypercube enumerates the splits of every batch
+        let split = batch_index % num_splits;
+        let nth_batch = batch_index / num_splits;
+
+        let num_k_iterations = div_ceil(problem_k, k_iteration_size);
+        let split_size = div_ceil(num_k_iterations, num_splits) * k_iteration_size;
+        let k_start = split * split_size;
+        let k_range = (k_start, Min::min(k_start + split_size, problem_k));
+
+        let global_config = config.global_config();
+        let mut acc = GMM::init_accumulator(global_config);
+
+        execute_partial_global_matmul::<MP, GMM>(
+            lhs,
+            rhs,
+            out,
+            m_index * tiling_scheme.elements_in_stage_m(),
+            n_index * tiling_scheme.elements_in_stage_n(),
+            nth_batch,
+            split,
+            &mut acc,
+            k_range,
+            quantization,
+            global_config,
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod config;
+mod matmul;
+mod setup;
+
+pub use setup::SplitKBatchMatmulFamily;
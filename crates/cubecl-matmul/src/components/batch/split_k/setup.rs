@@ -0,0 +1,114 @@
+use std::marker::PhantomData;
+
+use crate::components::batch::entry_point::matmul;
+use crate::components::batch::partial::{
+    default_num_cubes, k_iteration_size, validate_k_partitioning,
+};
+use crate::components::batch::split_k::config::SplitKBatchConfig;
+use crate::components::batch::split_k::matmul::SplitKBatchMatmul;
+use crate::components::batch::{
+    BatchConfig as _, BatchMatmulFamily, CubeCountInputArgs, CubeCountPlan,
+};
+use crate::components::global::GlobalMatmulFamily;
+use crate::components::{
+    Args, EA, EI, EO, ES, InputRuntimeArg, MatmulPrecision, MatmulProblem, MatmulSelection,
+    MatmulSpec, OutputRuntimeArg,
+};
+use crate::components::{MatmulLineSizes, MatmulSetupError};
+use cubecl_core::prelude::*;
+
+/// Split-K batch matmul family for any precision
+pub struct SplitKBatchMatmulFamily<GMM: GlobalMatmulFamily> {
+    _gmm: PhantomData<GMM>,
+}
+
+impl<GMM: GlobalMatmulFamily> BatchMatmulFamily for SplitKBatchMatmulFamily<GMM> {
+    type Matmul<MP: MatmulPrecision> = SplitKBatchMatmul<MP, GMM::Matmul<MP>>;
+    type Config = SplitKBatchConfig<GMM::Config>;
+
+    fn setup<MP: MatmulPrecision, R: Runtime>(
+        client: &ComputeClient<R::Server, R::Channel>,
+        problem: &MatmulProblem,
+        selection: &MatmulSelection,
+        line_sizes: &MatmulLineSizes,
+    ) -> Result<Self::Config, MatmulSetupError> {
+        validate_k_partitioning::<MP>(selection)?;
+
+        let global_config = GMM::setup::<MP, R>(client, problem, selection, line_sizes)?;
+
+        let cube_span = selection.hypercube_selection.cube_span;
+        let num_k_iterations = Ord::max(
+            (problem.k as u32).div_ceil(k_iteration_size(&selection.tiling_scheme)),
+            1,
+        );
+        let num_splits = selection
+            .k_partitioning
+            .num_splits
+            .unwrap_or_else(|| {
+                let num_tiles = (problem.m as u32).div_ceil(cube_span.m)
+                    * (problem.n as u32).div_ceil(cube_span.n)
+                    * problem.num_batches() as u32;
+                default_num_cubes::<R>(client).div_ceil(num_tiles)
+            })
+            .clamp(1, num_k_iterations);
+        // Fewer splits may cover K with the same split size, which would leave the last ones empty
+        let num_splits = num_k_iterations.div_ceil(num_k_iterations.div_ceil(num_splits));
+
+        SplitKBatchConfig::new(
+            global_config,
+            selection.hypercube_selection.to_hypercube_config(
+                &split_problem(problem, num_splits),
+                client.properties().hardware.max_cube_count.clone(),
+            ),
+            num_splits,
+        )
+        .validate(problem)
+    }
+
+    fn cube_count_plan(
+        config: &Self::Config,
+        problem: &MatmulProblem,
+        max_cube_count: CubeCount,
+    ) -> CubeCountPlan {
+        config
+            .hypercube_config()
+            .cube_count_plan(&split_problem(problem, config.num_splits()), max_cube_count)
+    }
+
+    fn num_partials(config: &Self::Config, _problem: &MatmulProblem) -> Option<u32> {
+        Some(config.num_splits())
+    }
+
+    unsafe fn launch_unchecked<'a, MS: MatmulSpec, R: Runtime>(
+        client: &ComputeClient<<R as Runtime>::Server, <R as Runtime>::Channel>,
+        cube_dim: CubeDim,
+        cube_count: CubeCount,
+        input: InputRuntimeArg<'a, MS, R>,
+        output: OutputRuntimeArg<'a, MS, R>,
+        cube_count_input: CubeCountInputArgs<'a, R>,
+        config: Self::Config,
+    ) -> Result<(), CompilationError> {
+        unsafe {
+            matmul::launch_unchecked::<Args<MS>, EI<MS>, ES<MS>, EA<MS>, EO<MS>, Self, R>(
+                client,
+                cube_count,
+                cube_dim,
+                input,
+                output,
+                cube_count_input,
+                config,
+            )
+        }
+    }
+}
+
+/// The problem seen by the hypercube, where every split of a batch is a batch of its own.
+fn split_problem(problem: &MatmulProblem, num_splits: u32) -> MatmulProblem {
+    let num_batches = problem.num_batches() * num_splits as usize;
+
+    MatmulProblem {
+        lhs_batches: vec![num_batches],
+        rhs_batches: vec![num_batches],
+        ..problem.clone()
+    }
+}
@@ -0,0 +1,123 @@
+use cubecl_core::CubeDim;
+
+use crate::components::{
+    FormattedConfigError, MatmulIdent, MatmulLineSizes, MatmulProblem, MatmulSetupError,
+    batch::{BatchConfig, HypercubeConfig, partial::k_iteration_size},
+    global::GlobalConfig,
+};
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+/// Configuration for Stream-K batch matmul
+pub struct StreamKBatchConfig<G: GlobalConfig> {
+    global_config: G,
+    hypercube_config: HypercubeConfig,
+    num_cubes: u32,
+}
+
+impl<G: GlobalConfig> BatchConfig for StreamKBatchConfig<G> {
+    type GlobalConfig = G;
+
+    fn global_config(&self) -> Self::GlobalConfig {
+        self.global_config
+    }
+
+    fn quantized(&self) -> bool {
+        self.global_config().quantized()
+    }
+
+    fn cube_dim(&self) -> CubeDim {
+        self.global_config.cube_dim()
+    }
+
+    fn line_sizes(&self) -> MatmulLineSizes {
+        MatmulLineSizes {
+            lhs: self.global_config.global_line_size(MatmulIdent::Lhs) as u8,
+            rhs: self.global_config.global_line_size(MatmulIdent::Rhs) as u8,
+            out: self.global_config.global_line_size(MatmulIdent::Out) as u8,
+        }
+    }
+
+    fn hypercube_config(&self) -> HypercubeConfig {
+        self.hypercube_config
+    }
+
+    fn can_yield_extra_cubes(&self) -> bool {
+        false
+    }
+}
+
+impl<G: GlobalConfig> StreamKBatchConfig<G> {
+    /// Create a new config for Stream-K batch matmul
+    pub fn new(global_config: G, hypercube_config: HypercubeConfig, num_cubes: u32) -> Self {
+        Self {
+            global_config,
+            hypercube_config,
+            num_cubes,
+        }
+    }
+
+    /// Granularity at which K is partitioned
+    pub fn k_iteration_size(&self) -> u32 {
+        k_iteration_size(&self.global_config.tiling_scheme())
+    }
+
+    /// Number of output tiles and number of K iterations per tile
+    pub fn num_iterations(&self, problem: &MatmulProblem) -> (u64, u64) {
+        let tiling_scheme = self.global_config.tiling_scheme();
+        let num_tiles = (problem.m as u64).div_ceil(tiling_scheme.elements_in_stage_m() as u64)
+            * (problem.n as u64).div_ceil(tiling_scheme.elements_in_stage_n() as u64)
+            * problem.num_batches() as u64;
+        let iterations_per_tile = (problem.k as u64).div_ceil(self.k_iteration_size() as u64);
+
+        (num_tiles, iterations_per_tile)
+    }
+
+    /// Number of persistent cubes to launch, never more than there are iterations
+    pub fn grid_size(&self, problem: &MatmulProblem) -> u32 {
+        let (num_tiles, iterations_per_tile) = self.num_iterations(problem);
+
+        (self.num_cubes as u64)
+            .min(num_tiles * iterations_per_tile)
+            .max(1) as u32
+    }
+
+    /// Maximal number of cubes contributing to the same output tile
+    pub fn num_partials(&self, problem: &MatmulProblem) -> u32 {
+        let (num_tiles, iterations_per_tile) = self.num_iterations(problem);
+        let num_iterations = num_tiles * iterations_per_tile;
+
+        if num_iterations == 0 {
+            return 1;
+        }
+
+        let grid_size = self.grid_size(problem) as u64;
+        let owner = |iteration: u64| ((iteration + 1) * grid_size - 1) / num_iterations;
+
+        (0..num_tiles)
+            .map(|tile| {
+                let first = tile * iterations_per_tile;
+                owner(first + iterations_per_tile - 1) - owner(first) + 1
+            })
+            .max()
+            .unwrap_or(1) as u32
+    }
+
+    /// May return an error if:
+    /// - the iterations can't be indexed in 32 bits
+    pub fn validate(self, problem: &MatmulProblem) -> Result<Self, MatmulSetupError> {
+        let (num_tiles, iterations_per_tile) = self.num_iterations(problem);
+        let num_iterations = num_tiles * iterations_per_tile;
+
+        if (num_iterations + 1) * self.grid_size(problem) as u64 > u32::MAX as u64 {
+            return Err(MatmulSetupError::InvalidConfig(FormattedConfigError::new(
+                move || {
+                    format!(
+                        "Too many iterations ({num_iterations:?}) to be distributed with Stream-K"
+                    )
+                },
+            )));
+        }
+
+        Ok(self)
+    }
+}
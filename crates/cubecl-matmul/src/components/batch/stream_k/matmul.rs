@@ -0,0 +1,93 @@
+use std::marker::PhantomData;
+
+use crate::components::MatmulPrecision;
+use crate::components::batch::partial::execute_partial_global_matmul;
+use crate::components::batch::stream_k::config::StreamKBatchConfig;
+use crate::components::batch::{BatchConfig as _, BatchMatmul, CubeCountInput};
+use crate::components::global::Quantization;
+use crate::components::global::{self, GlobalMatmul};
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+use cubecl_std::tensor::r#virtual::{ReadWrite, VirtualTensor};
+use cubecl_std::{CubeOption, div_ceil};
+
+/// Executes matrix multiplication at the batch level,
+/// distributing K iterations evenly across a persistent grid of cubes.
+///
+/// The iterations of all output tiles are laid out one tile after the other, and each cube
+/// computes a contiguous range of them. When a range covers part of a tile, the partial result
+/// is written to the slot given by the rank of the cube among those contributing to the tile.
+pub struct StreamKBatchMatmul<MP: MatmulPrecision, GMM: global::GlobalMatmul<MP>> {
+    _mp: PhantomData<MP>,
+    _gmm: PhantomData<GMM>,
+}
+
+#[cube]
+impl<MP: MatmulPrecision, GMM: GlobalMatmul<MP>> BatchMatmul<MP> for StreamKBatchMatmul<MP, GMM> {
+    type Config = StreamKBatchConfig<GMM::Config>;
+
+    fn execute(
+        lhs: VirtualTensor<MP::EI>,
+        rhs: VirtualTensor<MP::EI>,
+        out: VirtualTensor<MP::EO, ReadWrite>,
+        quantization: CubeOption<Quantization<MP>>,
+        _cube_count_args: CubeCountInput,
+        #[comptime] config: Self::Config,
+    ) {
+        let problem_k = lhs.shape(lhs.rank() - 1);
+        let out_rank = out.rank();
+        let problem_m = out.shape(out_rank - 2);
+        let problem_n = out.shape(out_rank - 1);
+        let num_batches = out.stride(0) / (problem_m * problem_n);
+
+        let tiling_scheme = config.tiling_scheme();
+        let k_iteration_size = config.k_iteration_size();
+        let stage_m = tiling_scheme.elements_in_stage_m();
+        let stage_n = tiling_scheme.elements_in_stage_n();
+
+        let m_tiles = div_ceil(problem_m, stage_m);
+        let n_tiles = div_ceil(problem_n, stage_n);
+        let iterations_per_tile = div_ceil(problem_k, k_iteration_size);
+        let num_iterations = m_tiles * n_tiles * num_batches * iterations_per_tile;
+
+        let start = CUBE_POS * num_iterations / CUBE_COUNT;
+        let end = (CUBE_POS + 1) * num_iterations / CUBE_COUNT;
+
+        let global_config = config.global_config();
+        let mut acc = GMM::init_accumulator(global_config);
+
+        let mut iteration = start;
+        while iteration < end {
+            let tile = iteration / iterations_per_tile;
+            let tile_start = tile * iterations_per_tile;
+            let segment_end = Min::min(end, tile_start + iterations_per_tile);
+
+            // Last cube whose range starts at or before the first iteration of the tile
+            let first_cube = ((tile_start + 1) * CUBE_COUNT - 1) / num_iterations;
+
+            let nth_batch = tile / (m_tiles * n_tiles);
+            let tile_in_batch = tile % (m_tiles * n_tiles);
+
+            let k_range = (
+                (iteration - tile_start) * k_iteration_size,
+                Min::min((segment_end - tile_start) * k_iteration_size, problem_k),
+            );
+
+            execute_partial_global_matmul::<MP, GMM>(
+                lhs,
+                rhs,
+                out,
+                (tile_in_batch / n_tiles) * stage_m,
+                (tile_in_batch % n_tiles) * stage_n,
+                nth_batch,
+                CUBE_POS - first_cube,
+                &mut acc,
+                k_range,
+                quantization,
+                global_config,
+            );
+
+            iteration = segment_end;
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::marker::PhantomData;
+
+use crate::components::batch::entry_point::matmul;
+use crate::components::batch::partial::{default_num_cubes, validate_k_partitioning};
+use crate::components::batch::stream_k::config::StreamKBatchConfig;
+use crate::components::batch::stream_k::matmul::StreamKBatchMatmul;
+use crate::components::batch::{
+    BatchMatmulFamily, CubeCountInputArgs, CubeCountPlan, CubeCountPlanConfig, HypercubeConfig,
+};
+use crate::components::global::GlobalMatmulFamily;
+use crate::components::{
+    Args, EA, EI, EO, ES, InputRuntimeArg, MatmulPrecision, MatmulProblem, MatmulSelection,
+    MatmulSpec, OutputRuntimeArg,
+};
+use crate::components::{MatmulLineSizes, MatmulSetupError};
+use cubecl_core::prelude::*;
+
+/// Stream-K batch matmul family for any precision
+pub struct StreamKBatchMatmulFamily<GMM: GlobalMatmulFamily> {
+    _gmm: PhantomData<GMM>,
+}
+
+impl<GMM: GlobalMatmulFamily> BatchMatmulFamily for StreamKBatchMatmulFamily<GMM> {
+    type Matmul<MP: MatmulPrecision> = StreamKBatchMatmul<MP, GMM::Matmul<MP>>;
+    type Config = StreamKBatchConfig<GMM::Config>;
+
+    fn setup<MP: MatmulPrecision, R: Runtime>(
+        client: &ComputeClient<R::Server, R::Channel>,
+        problem: &MatmulProblem,
+        selection: &MatmulSelection,
+        line_sizes: &MatmulLineSizes,
+    ) -> Result<Self::Config, MatmulSetupError> {
+        validate_k_partitioning::<MP>(selection)?;
+
+        let global_config = GMM::setup::<MP, R>(client, problem, selection, line_sizes)?;
+
+        // The persistent grid is always launched flattened
+        let hypercube_config = HypercubeConfig {
+            cube_span: selection.hypercube_selection.cube_span,
+            global_order: selection.hypercube_selection.global_order,
+            cube_count_plan_config: CubeCountPlanConfig::Flattened,
+        };
+        let num_cubes = Ord::max(
+            selection
+                .k_partitioning
+                .num_cubes
+                .unwrap_or_else(|| default_num_cubes::<R>(client)),
+            1,
+        );
+
+        StreamKBatchConfig::new(global_config, hypercube_config, num_cubes).validate(problem)
+    }
+
+    fn cube_count_plan(
+        config: &Self::Config,
+        problem: &MatmulProblem,
+        _max_cube_count: CubeCount,
+    ) -> CubeCountPlan {
+        CubeCountPlan::Flattened {
+            m_cubes: config.grid_size(problem),
+            n_cubes: 1,
+            batch_cubes: 1,
+        }
+    }
+
+    fn num_partials(config: &Self::Config, problem: &MatmulProblem) -> Option<u32> {
+        Some(config.num_partials(problem))
+    }
+
+    unsafe fn launch_unchecked<'a, MS: MatmulSpec, R: Runtime>(
+        client: &ComputeClient<<R as Runtime>::Server, <R as Runtime>::Channel>,
+        cube_dim: CubeDim,
+        cube_count: CubeCount,
+        input: InputRuntimeArg<'a, MS, R>,
+        output: OutputRuntimeArg<'a, MS, R>,
+        cube_count_input: CubeCountInputArgs<'a, R>,
+        config: Self::Config,
+    ) -> Result<(), CompilationError> {
+        unsafe {
+            matmul::launch_unchecked::<Args<MS>, EI<MS>, ES<MS>, EA<MS>, EO<MS>, Self, R>(
+                client,
+                cube_count,
+                cube_dim,
+                input,
+                output,
+                cube_count_input,
+                config,
+            )
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use cubecl_core::{CubeCount, CubeDim, LineSizeError, ir::Elem};
+use cubecl_core::{
+    CompilationError, CubeCount, CubeDim, LineSizeError, ir::Elem, tune::AutotuneError,
+};
 use std::fmt::{Debug, Display};
 
 use crate::components::TileSize;
@@ -13,6 +15,9 @@ pub enum MatmulSetupError {
 
     /// No compatible line size could be found for the given constraints.
     LineSize(LineSizeError),
+
+    /// The matmul kernel couldn't be compiled for the current runtime.
+    Compilation(CompilationError),
 }
 
 /// A specific feature required for matmul is not available in the current runtime or hardware.
@@ -63,6 +68,22 @@ impl From<LineSizeError> for MatmulSetupError {
     }
 }
 
+impl From<CompilationError> for MatmulSetupError {
+    fn from(value: CompilationError) -> Self {
+        Self::Compilation(value)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<AutotuneError> for MatmulSetupError {
+    fn into(self) -> AutotuneError {
+        match self {
+            MatmulSetupError::Compilation(err) => AutotuneError::Compilation(err),
+            err => AutotuneError::Unknown(format!("{err:?}")),
+        }
+    }
+}
+
 impl Display for MatmulSetupError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{self:?}")
@@ -91,6 +112,12 @@ impl Debug for MatmulSetupError {
                     "Unable to launch matmul because could not find supported line size: {err:?}"
                 )
             }
+            MatmulSetupError::Compilation(err) => {
+                writeln!(
+                    f,
+                    "Unable to launch matmul because it failed to compile: {err}"
+                )
+            }
         }
     }
 }
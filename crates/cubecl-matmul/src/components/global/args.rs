@@ -7,7 +7,7 @@ use cubecl_std::{
     tensor::r#virtual::{VirtualTensorOperations, VirtualTensorOperationsExpand},
 };
 
-use super::Quantization;
+use super::{OperandQuantization, Quantization};
 use crate::components::{self, MatmulLineSizes, MatmulPrecision, MatmulProblem, MatmulSelection};
 
 /// Create the input runtime arguments for a matmul kernel that works on concrete inputs and
@@ -35,6 +35,29 @@ pub trait ConcreteOutputFactory: LaunchArg {
     ) -> Self::RuntimeArg<'a, R>;
 }
 
+/// Output of a matmul kernel that works on concrete inputs, along with the [MatmulArgs] used to
+/// write it.
+pub trait ConcreteOutputRef<R: Runtime> {
+    /// Arguments of the kernel writing to this output.
+    type Args: MatmulArgs;
+
+    /// The tensor the matmul result is written to.
+    fn tensor(&self) -> &TensorHandleRef<'_, R>;
+
+    /// Whether elementwise operations are fused in the write of the output.
+    fn has_epilogue(&self) -> bool {
+        false
+    }
+
+    /// Create the output runtime argument.
+    fn create<'a, EO: Numeric>(
+        &'a self,
+        selection: &MatmulSelection,
+        problem: &MatmulProblem,
+        line_sizes: &MatmulLineSizes,
+    ) -> <<Self::Args as MatmulArgs>::Output<EO> as LaunchArg>::RuntimeArg<'a, R>;
+}
+
 #[cube]
 /// Arguments for the matrix multiplication algorithm.
 pub trait MatmulArgs: Send + Sync + 'static + Clone {
@@ -481,6 +504,23 @@ impl<EG: Numeric> ConcreteOutputFactory for Tensor<Line<EG>> {
     }
 }
 
+impl<R: Runtime> ConcreteOutputRef<R> for TensorHandleRef<'_, R> {
+    type Args = TensorArgs;
+
+    fn tensor(&self) -> &TensorHandleRef<'_, R> {
+        self
+    }
+
+    fn create<'a, EO: Numeric>(
+        &'a self,
+        selection: &MatmulSelection,
+        problem: &MatmulProblem,
+        line_sizes: &MatmulLineSizes,
+    ) -> TensorArg<'a, R> {
+        <Tensor<Line<EO>> as ConcreteOutputFactory>::create(self, selection, problem, line_sizes)
+    }
+}
+
 #[cube]
 impl MatmulArgs for TensorArgs {
     type Output<EO: Numeric> = Tensor<Line<EO>>;
@@ -489,8 +529,8 @@ impl MatmulArgs for TensorArgs {
         *const Tensor<Line<EI>>,
         *const Tensor<Line<EI>>,
         *mut Tensor<Line<EO>>,
-        CubeOption<f32>,
-        CubeOption<f32>,
+        CubeOption<*const Tensor<f32>>,
+        CubeOption<*const Tensor<f32>>,
     );
 
     fn init_state<EI: Numeric, EO: Numeric>(
@@ -498,11 +538,11 @@ impl MatmulArgs for TensorArgs {
         output: &mut Self::Output<EO>,
     ) -> Self::State<EI, EO> {
         let lhs_scale = match &input.lhs_scale {
-            CubeOption::Some(scale) => CubeOption::new_Some(scale[0]),
+            CubeOption::Some(scale) => CubeOption::<*const Tensor<f32>>::new_Some(scale),
             CubeOption::None => CubeOption::new_None(),
         };
         let rhs_scale = match &input.rhs_scale {
-            CubeOption::Some(scale) => CubeOption::new_Some(scale[0]),
+            CubeOption::Some(scale) => CubeOption::<*const Tensor<f32>>::new_Some(scale),
             CubeOption::None => CubeOption::new_None(),
         };
         (&input.lhs, &input.rhs, output, lhs_scale, rhs_scale)
@@ -620,17 +660,17 @@ impl MatmulArgs for TensorArgs {
     }
 
     fn quantization<MP: MatmulPrecision>(state: &Self::State<MP::EI, MP::EO>) -> Quantization<MP> {
-        // TODO Currently, this assume that the scaling is always the last value in the buffer.
+        // TODO Currently, this assume that the scaling is always the first value in the buffer.
         //      Also, in burn the scaling is presently fix to f32, hence the extra conversions.
 
         let (_, _, _, scaling_lhs, scaling_rhs) = *state;
         let scaling_lhs = scaling_lhs.unwrap();
         let scaling_rhs = scaling_rhs.unwrap();
 
-        Quantization::<MP> {
-            scaling_lhs: MP::ES::cast_from(scaling_lhs),
-            scaling_rhs: MP::ES::cast_from(scaling_rhs),
-        }
+        Quantization::<MP>::new(
+            OperandQuantization::per_tensor(scaling_lhs),
+            OperandQuantization::per_tensor(scaling_rhs),
+        )
     }
 }
 
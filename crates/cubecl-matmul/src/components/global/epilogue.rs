@@ -0,0 +1,475 @@
+use std::marker::PhantomData;
+
+use cubecl::prelude::*;
+use cubecl_core::{self as cubecl, server::Handle};
+use cubecl_std::{CubeOption, CubeOptionExpand, tensor::TensorHandle};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    OperandQuantization, Quantization,
+    args::{ConcreteOutputRef, MatmulArgs, TensorInputs},
+};
+use crate::components::{
+    FormattedConfigError, MatmulLineSizes, MatmulPrecision, MatmulProblem, MatmulSelection,
+    MatmulSetupError,
+};
+
+/// Elementwise operations fused in the write of the matmul output.
+///
+/// Every output element is computed in `f32` as
+/// `activation(alpha * acc + bias) + beta * residual`, then cast to the element type of the
+/// output tensor.
+#[derive(Clone, Debug)]
+pub struct Epilogue {
+    /// Scaling of the matmul result.
+    pub alpha: f32,
+    /// Scaling of the residual tensor, ignored without one.
+    pub beta: f32,
+    /// Bias added to the scaled result.
+    pub bias: Option<EpilogueTensor>,
+    /// How the bias is broadcast over the output, ignored without one.
+    pub bias_layout: BiasLayout,
+    /// Activation applied after the bias.
+    pub activation: Activation,
+    /// Tensor added to the result of the activation.
+    pub residual: Option<EpilogueTensor>,
+}
+
+impl Default for Epilogue {
+    fn default() -> Self {
+        Self {
+            alpha: 1.0,
+            beta: 1.0,
+            bias: None,
+            bias_layout: BiasLayout::default(),
+            activation: Activation::default(),
+            residual: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Tensor read by an [epilogue](Epilogue), of the same element type as the output.
+///
+/// The bias and residual tensors are shared by every batch.
+pub struct EpilogueTensor {
+    pub handle: Handle,
+    pub shape: Vec<usize>,
+    pub strides: Vec<usize>,
+}
+
+impl<R: Runtime, E: CubePrimitive> From<TensorHandle<R, E>> for EpilogueTensor {
+    fn from(tensor: TensorHandle<R, E>) -> Self {
+        Self {
+            handle: tensor.handle,
+            shape: tensor.shape,
+            strides: tensor.strides,
+        }
+    }
+}
+
+impl EpilogueTensor {
+    fn as_tensor_arg<R: Runtime, EC: Numeric>(&self, line_size: u8) -> TensorArg<'_, R> {
+        unsafe {
+            TensorArg::from_raw_parts::<EC>(&self.handle, &self.strides, &self.shape, line_size)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
+/// Activation function of an [epilogue](Epilogue).
+pub enum Activation {
+    #[default]
+    Identity,
+    Relu,
+    /// The exact GELU, computed with the error function.
+    Gelu,
+    Silu,
+    Sigmoid,
+    Tanh,
+}
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
+/// How the bias of an [epilogue](Epilogue) is broadcast over the output.
+pub enum BiasLayout {
+    /// A bias of shape `[m]`, with one value per row.
+    PerRow,
+    /// A bias of shape `[n]`, with one value per column.
+    #[default]
+    PerColumn,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+/// Comptime part of an [epilogue](Epilogue).
+pub struct EpilogueConfig {
+    pub activation: Activation,
+    pub bias_layout: BiasLayout,
+}
+
+/// Output of a matmul written through an [epilogue](Epilogue).
+///
+/// The output and the residual must be contiguous and have the same shape.
+pub(crate) struct EpilogueRef<'a, R: Runtime, EC: Numeric> {
+    out: &'a TensorHandleRef<'a, R>,
+    epilogue: &'a Epilogue,
+    config: EpilogueConfig,
+    _output: PhantomData<EC>,
+}
+
+impl<'a, R: Runtime, EC: Numeric> EpilogueRef<'a, R, EC> {
+    pub(crate) fn new(out: &'a TensorHandleRef<'a, R>, epilogue: &'a Epilogue) -> Self {
+        Self {
+            out,
+            epilogue,
+            config: EpilogueConfig {
+                activation: epilogue.activation,
+                bias_layout: epilogue.bias_layout,
+            },
+            _output: PhantomData,
+        }
+    }
+
+    /// Check that the bias and the residual match the output.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn validate(&self) -> Result<(), MatmulSetupError> {
+        let invalid = |reason: String| {
+            Err(MatmulSetupError::InvalidConfig(FormattedConfigError::new(
+                move || reason.clone(),
+            )))
+        };
+
+        let rank = self.out.shape.len();
+        if !is_contiguous(self.out.shape, self.out.strides) {
+            return invalid("The output of an epilogue must be contiguous".to_string());
+        }
+
+        if let Some(bias) = &self.epilogue.bias {
+            let bias_layout = self.epilogue.bias_layout;
+            let expected = match bias_layout {
+                BiasLayout::PerRow => self.out.shape[rank - 2],
+                BiasLayout::PerColumn => self.out.shape[rank - 1],
+            };
+            if bias.shape != [expected] || bias.strides != [1] {
+                return invalid(format!(
+                    "Expected a contiguous bias of shape [{expected}] for a {bias_layout:?} bias, got shape {:?} and strides {:?}",
+                    bias.shape, bias.strides
+                ));
+            }
+        }
+
+        if let Some(residual) = &self.epilogue.residual {
+            if residual.shape != self.out.shape || residual.strides != self.out.strides {
+                return invalid(format!(
+                    "Expected a residual with the shape {:?} and strides {:?} of the output, got shape {:?} and strides {:?}",
+                    self.out.shape, self.out.strides, residual.shape, residual.strides
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_contiguous(shape: &[usize], strides: &[usize]) -> bool {
+    let mut expected = 1;
+    for (shape, stride) in shape.iter().zip(strides).rev() {
+        if *shape != 1 && *stride != expected {
+            return false;
+        }
+        expected *= shape;
+    }
+    true
+}
+
+impl<R: Runtime, EC: Numeric> ConcreteOutputRef<R> for EpilogueRef<'_, R, EC> {
+    type Args = EpilogueArgs<EC>;
+
+    fn tensor(&self) -> &TensorHandleRef<'_, R> {
+        self.out
+    }
+
+    fn has_epilogue(&self) -> bool {
+        true
+    }
+
+    fn create<'a, EO: Numeric>(
+        &'a self,
+        _selection: &MatmulSelection,
+        _problem: &MatmulProblem,
+        line_sizes: &MatmulLineSizes,
+    ) -> EpilogueOutputLaunch<'a, EC, R> {
+        let epilogue = self.epilogue;
+        let bias_line_size = match epilogue.bias_layout {
+            BiasLayout::PerRow => 1,
+            BiasLayout::PerColumn => line_sizes.out,
+        };
+
+        EpilogueOutputLaunch::new(
+            self.out.as_tensor_arg(line_sizes.out),
+            epilogue
+                .bias
+                .as_ref()
+                .map(|it| it.as_tensor_arg::<R, EC>(bias_line_size))
+                .into(),
+            epilogue
+                .residual
+                .as_ref()
+                .map(|it| it.as_tensor_arg::<R, EC>(line_sizes.out))
+                .into(),
+            ScalarArg::new(epilogue.alpha),
+            ScalarArg::new(epilogue.beta),
+            &self.config,
+        )
+    }
+}
+
+#[derive(Clone)]
+/// Type implementing [MatmulArgs] where the output is written through an [epilogue](Epilogue),
+/// to a tensor of element type `EC`.
+pub struct EpilogueArgs<EC: Numeric> {
+    _output: PhantomData<EC>,
+}
+
+#[derive(CubeLaunch, CubeType)]
+/// Output representation for [EpilogueArgs] implementing [MatmulArgs].
+pub struct EpilogueOutput<EC: Numeric> {
+    /// The output tensor.
+    pub out: Tensor<Line<EC>>,
+    pub bias: CubeOption<Tensor<Line<EC>>>,
+    pub residual: CubeOption<Tensor<Line<EC>>>,
+    pub alpha: f32,
+    pub beta: f32,
+    #[cube(comptime)]
+    pub config: EpilogueConfig,
+}
+
+#[derive(CubeType)]
+/// State of [EpilogueArgs].
+pub struct EpilogueState<EI: Numeric, EC: Numeric> {
+    lhs: *const Tensor<Line<EI>>,
+    rhs: *const Tensor<Line<EI>>,
+    out: *mut Tensor<Line<EC>>,
+    bias: CubeOption<*const Tensor<Line<EC>>>,
+    residual: CubeOption<*const Tensor<Line<EC>>>,
+    lhs_scale: CubeOption<*const Tensor<f32>>,
+    rhs_scale: CubeOption<*const Tensor<f32>>,
+    alpha: f32,
+    beta: f32,
+    #[cube(comptime)]
+    config: EpilogueConfig,
+}
+
+#[cube]
+impl<EC: Numeric> MatmulArgs for EpilogueArgs<EC> {
+    type Output<EO: Numeric> = EpilogueOutput<EC>;
+    type Input<EI: Numeric> = TensorInputs<EI>;
+    type State<EI: Numeric, EO: Numeric> = EpilogueState<EI, EC>;
+
+    fn init_state<EI: Numeric, EO: Numeric>(
+        input: &Self::Input<EI>,
+        output: &mut Self::Output<EO>,
+    ) -> Self::State<EI, EO> {
+        let lhs_scale = match &input.lhs_scale {
+            CubeOption::Some(scale) => CubeOption::<*const Tensor<f32>>::new_Some(scale),
+            CubeOption::None => CubeOption::new_None(),
+        };
+        let rhs_scale = match &input.rhs_scale {
+            CubeOption::Some(scale) => CubeOption::<*const Tensor<f32>>::new_Some(scale),
+            CubeOption::None => CubeOption::new_None(),
+        };
+        let bias = match &output.bias {
+            CubeOption::Some(bias) => CubeOption::<*const Tensor<Line<EC>>>::new_Some(bias),
+            CubeOption::None => CubeOption::new_None(),
+        };
+        let residual = match &output.residual {
+            CubeOption::Some(residual) => CubeOption::<*const Tensor<Line<EC>>>::new_Some(residual),
+            CubeOption::None => CubeOption::new_None(),
+        };
+
+        EpilogueState::<EI, EC> {
+            lhs: &input.lhs,
+            rhs: &input.rhs,
+            out: &mut output.out,
+            bias,
+            residual,
+            lhs_scale,
+            rhs_scale,
+            alpha: output.alpha,
+            beta: output.beta,
+            config: comptime!(output.config),
+        }
+    }
+
+    fn read_lhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        coordinate: u32,
+    ) -> Line<EI> {
+        unsafe { (*state.lhs)[coordinate] }
+    }
+
+    fn read_rhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        coordinate: u32,
+    ) -> Line<EI> {
+        unsafe { (*state.rhs)[coordinate] }
+    }
+
+    fn read_window_lhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        start: u32,
+        end: u32,
+    ) -> Slice<Line<EI>> {
+        unsafe { (*state.lhs).slice(start, end) }
+    }
+
+    fn read_window_rhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        start: u32,
+        end: u32,
+    ) -> Slice<Line<EI>> {
+        unsafe { (*state.rhs).slice(start, end) }
+    }
+
+    fn as_tensor_map_lhs<EI: Numeric, EO: Numeric>(_state: &Self::State<EI, EO>) -> TensorMap<EI> {
+        comptime!(unimplemented!("Can't use `EpilogueArgs` as `TensorMap`"));
+        #[allow(unreachable_code)]
+        TensorMap::dummy()
+    }
+
+    fn as_tensor_map_rhs<EI: Numeric, EO: Numeric>(_state: &Self::State<EI, EO>) -> TensorMap<EI> {
+        comptime!(unimplemented!("Can't use `EpilogueArgs` as `TensorMap`"));
+        #[allow(unreachable_code)]
+        TensorMap::dummy()
+    }
+
+    fn shape_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.lhs).shape(dim) }
+    }
+
+    fn shape_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.rhs).shape(dim) }
+    }
+
+    fn shape_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.out).shape(dim) }
+    }
+
+    fn stride_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.lhs).stride(dim) }
+    }
+
+    fn stride_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.rhs).stride(dim) }
+    }
+
+    fn stride_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.out).stride(dim) }
+    }
+
+    fn write_out<EI: Numeric, EO: Numeric>(
+        state: &mut Self::State<EI, EO>,
+        coordinate: u32,
+        value: Line<EO>,
+    ) {
+        let line_size = value.size();
+        let mut value = Line::empty(line_size).fill(state.alpha) * Line::<f32>::cast_from(value);
+
+        match state.bias {
+            CubeOption::Some(bias) => {
+                let out = unsafe { &*state.out };
+                let rank = out.rank();
+                // The output is contiguous, so a line never spans two rows.
+                let position = coordinate * line_size;
+
+                value += match comptime!(state.config.bias_layout) {
+                    BiasLayout::PerRow => {
+                        let row = (position / out.stride(rank - 2)) % out.shape(rank - 2);
+                        let bias = unsafe { (*bias)[row] };
+                        Line::empty(line_size).fill(f32::cast_from(bias[0]))
+                    }
+                    BiasLayout::PerColumn => {
+                        let col = position % out.shape(rank - 1);
+                        Line::<f32>::cast_from(unsafe { (*bias)[col / line_size] })
+                    }
+                };
+            }
+            CubeOption::None => {}
+        }
+
+        value = activation(value, state.config.activation);
+
+        match state.residual {
+            CubeOption::Some(residual) => {
+                let residual = Line::<f32>::cast_from(unsafe { (*residual)[coordinate] });
+                value += Line::empty(line_size).fill(state.beta) * residual;
+            }
+            CubeOption::None => {}
+        }
+
+        unsafe { (*state.out)[coordinate] = Line::cast_from(value) }
+    }
+
+    fn rank_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.lhs).rank() }
+    }
+
+    fn rank_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.rhs).rank() }
+    }
+
+    fn rank_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.out).rank() }
+    }
+
+    fn len_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.lhs).len() }
+    }
+
+    fn len_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.rhs).len() }
+    }
+
+    fn len_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.out).len() }
+    }
+
+    fn buffer_len_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.lhs).buffer_len() }
+    }
+
+    fn buffer_len_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.rhs).buffer_len() }
+    }
+
+    fn buffer_len_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.out).buffer_len() }
+    }
+
+    fn quantization<MP: MatmulPrecision>(state: &Self::State<MP::EI, MP::EO>) -> Quantization<MP> {
+        Quantization::<MP>::new(
+            OperandQuantization::per_tensor(state.lhs_scale.unwrap()),
+            OperandQuantization::per_tensor(state.rhs_scale.unwrap()),
+        )
+    }
+}
+
+#[cube]
+/// Apply the activation function to every element of the line.
+pub fn activation(value: Line<f32>, #[comptime] activation: Activation) -> Line<f32> {
+    let line_size = value.size();
+    let zero = Line::empty(line_size).fill(0.0);
+    let one = Line::empty(line_size).fill(1.0);
+
+    match comptime!(activation) {
+        Activation::Identity => value,
+        Activation::Relu => Max::max(value, zero),
+        Activation::Gelu => {
+            let half = Line::empty(line_size).fill(0.5);
+            let scale = Line::empty(line_size).fill(core::f32::consts::FRAC_1_SQRT_2);
+            half * value * (one + Erf::erf(value * scale))
+        }
+        Activation::Silu => value / (one + Exp::exp(zero - value)),
+        Activation::Sigmoid => one / (one + Exp::exp(zero - value)),
+        Activation::Tanh => Tanh::tanh(value),
+    }
+}
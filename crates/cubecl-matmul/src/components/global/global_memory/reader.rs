@@ -221,6 +221,20 @@ impl<EG: Numeric> TensorReader<EG> {
         #[comptime] ident: MatmulIdent,
         #[comptime] config: G,
     ) -> Line<EG> {
+        let view = self.coordinates_in_tile::<G>(tile_x, tile_y, position, ident, config);
+        self.load_coalesced::<G>(view, ident, config)
+    }
+
+    /// Coordinates (row, col) in the matrix of the line read by
+    /// [load_coalesced_in_tile](Self::load_coalesced_in_tile).
+    pub fn coordinates_in_tile<G: global::GlobalConfig>(
+        &self,
+        tile_x: u32,
+        tile_y: u32,
+        position: u32,
+        #[comptime] ident: MatmulIdent,
+        #[comptime] config: G,
+    ) -> (u32, u32) {
         let tile_size_x = config.tiling_scheme().elements_in_tile_row(ident);
         let tile_size_y = config.tiling_scheme().elements_in_tile_col(ident);
 
@@ -232,7 +246,10 @@ impl<EG: Numeric> TensorReader<EG> {
             MatrixLayout::ColMajor => (position % tile_size_x, position / tile_size_x),
         };
 
-        self.load_coalesced::<G>((load_x + view_tile_x, load_y + view_tile_y), ident, config)
+        (
+            load_x + view_tile_x + self.x_offset.read(),
+            load_y + view_tile_y + self.y_offset.read(),
+        )
     }
 
     /// Reads data from the tensor view at the specified index within the whole view,
@@ -251,27 +268,37 @@ impl<EG: Numeric> TensorReader<EG> {
         #[comptime] ident: MatmulIdent,
         #[comptime] config: G,
     ) -> Line<EG> {
+        let view = self.coordinates_in_stage::<G>(position, ident, config);
+        self.load_coalesced::<G>(view, ident, config)
+    }
+
+    /// Coordinates (row, col) in the matrix of the line read by
+    /// [load_coalesced_in_stage](Self::load_coalesced_in_stage).
+    pub fn coordinates_in_stage<G: global::GlobalConfig>(
+        &self,
+        position: u32,
+        #[comptime] ident: MatmulIdent,
+        #[comptime] config: G,
+    ) -> (u32, u32) {
         let stage_shape_x = config.tiling_scheme().elements_in_stage_row(ident);
         let stage_shape_y = config.tiling_scheme().elements_in_stage_col(ident);
 
-        let load_offsets = match config.matrix_layout(ident) {
+        let (load_x, load_y) = match config.matrix_layout(ident) {
             MatrixLayout::RowMajor => (position / stage_shape_y, position % stage_shape_y),
             MatrixLayout::ColMajor => (position % stage_shape_x, position / stage_shape_x),
         };
 
-        self.load_coalesced::<G>(load_offsets, ident, config)
+        (load_x + self.x_offset.read(), load_y + self.y_offset.read())
     }
 
     fn load_coalesced<G: global::GlobalConfig>(
         &self,
-        load_offsets: (u32, u32),
+        view: (u32, u32),
         #[comptime] ident: MatmulIdent,
         #[comptime] config: G,
     ) -> Line<EG> {
         let line_size = config.global_line_size(ident);
-
-        let view_x = load_offsets.0 + self.x_offset.read();
-        let view_y = load_offsets.1 + self.y_offset.read();
+        let (view_x, view_y) = view;
 
         let read_pos =
             (view_x * self.stride_x + view_y * self.stride_y + self.batch_offset) / line_size;
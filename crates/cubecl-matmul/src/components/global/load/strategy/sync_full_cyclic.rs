@@ -184,7 +184,18 @@ pub(crate) fn load_and_store_line<MP: MatmulPrecision, TO: TilingOrder, G: Globa
     );
 
     stage.as_slice_mut(job.line_size)[unit_position / job.line_size] = match quantization {
-        CubeOption::Some(quantization) => quantization.dequantize(line_read, job.ident),
+        CubeOption::Some(quantization) => quantization.dequantize(
+            line_read,
+            tensor_reader.coordinates_in_tile::<G>(
+                tile_x,
+                tile_y,
+                pos_within_tile,
+                job.ident,
+                config,
+            ),
+            job.ident,
+            comptime!(config.matrix_layout(job.ident)),
+        ),
         CubeOption::None => Line::cast_from(line_read),
     };
 }
@@ -108,7 +108,16 @@ impl<MP: MatmulPrecision> LoadingJob<MP, StridedTilingLayout> for SyncFullStride
         );
 
         stage.as_slice_mut(this.line_size)[unit_position] = match quantization {
-            CubeOption::Some(quantization) => quantization.dequantize(line_read, this.ident),
+            CubeOption::Some(quantization) => quantization.dequantize(
+                line_read,
+                tensor_reader.coordinates_in_stage::<G>(
+                    unit_position * this.line_size,
+                    this.ident,
+                    config,
+                ),
+                this.ident,
+                comptime!(config.matrix_layout(this.ident)),
+            ),
             CubeOption::None => Line::cast_from(line_read),
         }
     }
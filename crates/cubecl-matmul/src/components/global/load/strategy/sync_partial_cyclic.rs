@@ -252,7 +252,18 @@ pub(crate) fn load_and_store_line<MP: MatmulPrecision, TO: TilingOrder, G: Globa
         .slice_mut(tile_start, tile_end);
 
     tile_slice[pos_within_tile / line_size] = match quantization {
-        CubeOption::Some(quantization) => quantization.dequantize(line_read, job.ident),
+        CubeOption::Some(quantization) => quantization.dequantize(
+            line_read,
+            tensor_reader.coordinates_in_tile::<G>(
+                tile_x,
+                tile_y,
+                pos_within_tile,
+                job.ident,
+                config,
+            ),
+            job.ident,
+            comptime!(config.matrix_layout(job.ident)),
+        ),
         CubeOption::None => Line::cast_from(line_read),
     }
 }
@@ -247,7 +247,18 @@ impl SyncPartialTilewiseJob {
         let offset = line_index_within_tile + num_lines_to_skip_global;
 
         stage.as_slice_mut(this.line_size)[offset] = match quantization {
-            CubeOption::Some(quantization) => quantization.dequantize(line_read, this.ident),
+            CubeOption::Some(quantization) => quantization.dequantize(
+                line_read,
+                tensor_reader.coordinates_in_tile::<G>(
+                    tile.0,
+                    tile.1,
+                    line_index_within_tile * this.line_size,
+                    this.ident,
+                    config,
+                ),
+                this.ident,
+                comptime!(config.matrix_layout(this.ident)),
+            ),
             CubeOption::None => Line::cast_from(line_read),
         };
     }
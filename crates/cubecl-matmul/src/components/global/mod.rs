@@ -2,6 +2,7 @@
 //! Handles memory movement, bound checks, plane specialization.
 
 pub mod args;
+pub mod epilogue;
 pub mod global_memory;
 pub mod load;
 pub mod multi_stage;
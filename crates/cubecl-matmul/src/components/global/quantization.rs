@@ -1,24 +1,503 @@
+use std::marker::PhantomData;
+
 use cubecl::prelude::*;
 use cubecl_core as cubecl;
+use cubecl_std::{CubeOption, CubeOptionExpand};
+use serde::{Deserialize, Serialize};
 
-use crate::components::{MatmulIdent, MatmulPrecision};
+use super::args::MatmulArgs;
+use crate::components::{MatmulIdent, MatmulPrecision, MatrixLayout};
 
-/// Store the quantization meta-parameters.
-/// For now, we only support symmetric quantization,
-/// thus we only store the scaling.
+/// Store the quantization meta-parameters of both inputs.
+///
+/// Values are dequantized as `(value - zero_point) * scale` when they are loaded into the stage
+/// memory.
 #[derive(CubeType, Clone, Copy)]
 pub struct Quantization<MP: MatmulPrecision> {
-    pub scaling_lhs: MP::ES,
-    pub scaling_rhs: MP::ES,
+    pub lhs: OperandQuantization,
+    pub rhs: OperandQuantization,
+    #[cube(comptime)]
+    _precision: PhantomData<MP>,
 }
 
 #[cube]
 impl<MP: MatmulPrecision> Quantization<MP> {
-    pub fn dequantize(&self, line: Line<MP::EI>, #[comptime] ident: MatmulIdent) -> Line<MP::ES> {
+    pub fn new(lhs: OperandQuantization, rhs: OperandQuantization) -> Quantization<MP> {
+        Quantization::<MP> {
+            lhs,
+            rhs,
+            _precision: PhantomData,
+        }
+    }
+
+    /// Dequantize a line read from global memory, whose first element is at the given
+    /// `(row, col)` position of the matrix.
+    pub fn dequantize(
+        &self,
+        line: Line<MP::EI>,
+        position: (u32, u32),
+        #[comptime] ident: MatmulIdent,
+        #[comptime] layout: MatrixLayout,
+    ) -> Line<MP::ES> {
         match ident {
-            MatmulIdent::Lhs => Line::<MP::ES>::new(self.scaling_lhs) * Line::cast_from(line),
-            MatmulIdent::Rhs => Line::<MP::ES>::new(self.scaling_rhs) * Line::cast_from(line),
+            MatmulIdent::Lhs => self
+                .lhs
+                .dequantize::<MP::EI, MP::ES>(line, position, layout),
+            MatmulIdent::Rhs => self
+                .rhs
+                .dequantize::<MP::EI, MP::ES>(line, position, layout),
             MatmulIdent::Out => comptime!(unreachable!()),
         }
     }
 }
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
+/// How the quantized values of an input are stored.
+pub enum QuantizedStorage {
+    /// One value per element.
+    #[default]
+    Int8,
+    /// Two signed 4-bit values per byte along the contiguous dimension, the low nibble first.
+    PackedInt4,
+}
+
+#[derive(CubeType, Clone, Copy)]
+/// Quantization parameters of one input, with one scale per block of
+/// `block_rows x block_cols` elements.
+///
+/// Positions are given within a matrix, scales and zero points being shared by all batches.
+pub struct OperandQuantization {
+    pub scales: *const Tensor<f32>,
+    pub zero_points: CubeOption<*const Tensor<f32>>,
+    /// Number of rows of the matrix, elements past it are out-of-bounds
+    pub rows: u32,
+    /// Number of columns of the matrix, elements past it are out-of-bounds
+    pub cols: u32,
+    pub block_rows: u32,
+    pub block_cols: u32,
+    /// Stride between the scales of consecutive row blocks
+    pub stride_row: u32,
+    /// Stride between the scales of consecutive column blocks
+    pub stride_col: u32,
+    #[cube(comptime)]
+    pub storage: QuantizedStorage,
+}
+
+#[cube]
+impl OperandQuantization {
+    /// Symmetric quantization with a single scale for the whole tensor, the first element of
+    /// `scales`.
+    pub fn per_tensor(scales: *const Tensor<f32>) -> OperandQuantization {
+        OperandQuantization {
+            scales,
+            zero_points: CubeOption::new_None(),
+            rows: u32::MAX,
+            cols: u32::MAX,
+            block_rows: u32::MAX,
+            block_cols: u32::MAX,
+            stride_row: 0,
+            stride_col: 0,
+            storage: QuantizedStorage::Int8,
+        }
+    }
+
+    /// Dequantize a line whose first element is at the given `(row, col)` position, the line
+    /// going along the contiguous dimension of the `layout`.
+    ///
+    /// Out-of-bounds elements are dequantized to zero.
+    pub fn dequantize<EI: Numeric, ES: Numeric>(
+        &self,
+        line: Line<EI>,
+        position: (u32, u32),
+        #[comptime] layout: MatrixLayout,
+    ) -> Line<ES> {
+        let values = match comptime!(self.storage) {
+            QuantizedStorage::Int8 => Line::<f32>::cast_from(line),
+            QuantizedStorage::PackedInt4 => unpack_int4::<EI>(line),
+        };
+        let line_size = values.size();
+        let (row, col) = position;
+
+        let mut scales = Line::<f32>::empty(line_size);
+        let mut zero_points = Line::<f32>::empty(line_size).fill(0.0);
+
+        #[unroll]
+        for i in 0..line_size {
+            let row = match comptime!(layout) {
+                MatrixLayout::RowMajor => row,
+                MatrixLayout::ColMajor => row + i,
+            };
+            let col = match comptime!(layout) {
+                MatrixLayout::RowMajor => col + i,
+                MatrixLayout::ColMajor => col,
+            };
+
+            let in_bounds = row < self.rows && col < self.cols;
+            let index = select(
+                in_bounds,
+                (row / self.block_rows) * self.stride_row
+                    + (col / self.block_cols) * self.stride_col,
+                0,
+            );
+
+            // Out-of-bounds values are read as zeros, a null scale keeps them so with a zero point
+            scales[i] = select(in_bounds, unsafe { (*self.scales)[index] }, 0.0);
+
+            match self.zero_points {
+                CubeOption::Some(zero_points_tensor) => {
+                    zero_points[i] = unsafe { (*zero_points_tensor)[index] };
+                }
+                CubeOption::None => {}
+            }
+        }
+
+        Line::cast_from((values - zero_points) * scales)
+    }
+}
+
+#[cube]
+/// Unpack a line of bytes holding two signed 4-bit values each, the low nibble first, into a line
+/// of twice the size.
+pub fn unpack_int4<EI: Numeric>(line: Line<EI>) -> Line<f32> {
+    let line_size = line.size();
+    let mut values = Line::<f32>::empty(comptime!(line_size * 2));
+
+    #[unroll]
+    for i in 0..line_size {
+        let byte = i32::cast_from(line[i]);
+        // Arithmetic shift, which keeps the sign of the high nibble
+        let high = byte >> 4;
+        let low = byte - high * 16;
+
+        values[2 * i] = f32::cast_from(select(low >= 8, low - 16, low));
+        values[2 * i + 1] = f32::cast_from(high);
+    }
+
+    values
+}
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
+/// Comptime part of the inputs of [QuantizedArgs].
+pub struct QuantizationConfig {
+    pub lhs: QuantizedStorage,
+    pub rhs: QuantizedStorage,
+}
+
+/// A quantized input of a matmul, along with its quantization parameters.
+///
+/// For an input of `rows x cols` elements, `scales` (and `zero_points` if the quantization is
+/// asymmetric) is a `f32` tensor of shape `[rows / block_rows, cols / block_cols]`, shared by all
+/// batches. For example:
+/// - `[1, 1]` for per-tensor scaling;
+/// - `[m, 1]` for per-row scaling of `lhs`, `[1, n]` for per-column scaling of `rhs`;
+/// - `[m, k / 32]` for `lhs` and `[k / 32, n]` for `rhs` with blocks of 32 elements along `k`.
+///
+/// Packed int4 values are stored in an `i8` tensor with the shape and strides of its bytes, the
+/// contiguous dimension holding two values per byte.
+pub struct QuantizedTensorRef<'a, R: Runtime> {
+    pub values: &'a TensorHandleRef<'a, R>,
+    pub scales: &'a TensorHandleRef<'a, R>,
+    pub zero_points: Option<&'a TensorHandleRef<'a, R>>,
+    pub storage: QuantizedStorage,
+}
+
+impl<R: Runtime> QuantizedTensorRef<'_, R> {
+    /// Axis of the values along which the elements are contiguous.
+    pub fn contiguous_axis(&self) -> usize {
+        let rank = self.values.strides.len();
+        match self.values.strides[rank - 1] {
+            1 => rank - 1,
+            _ => rank - 2,
+        }
+    }
+
+    /// Shape of the matrices, in number of quantized values.
+    pub fn logical_shape(&self) -> Vec<usize> {
+        let mut shape = self.values.shape.to_vec();
+        if let QuantizedStorage::PackedInt4 = self.storage {
+            shape[self.contiguous_axis()] *= 2;
+        }
+        shape
+    }
+
+    /// Strides of the matrices, in number of quantized values.
+    pub fn logical_strides(&self) -> Vec<usize> {
+        match self.storage {
+            QuantizedStorage::Int8 => self.values.strides.to_vec(),
+            QuantizedStorage::PackedInt4 => {
+                let contiguous_axis = self.contiguous_axis();
+                self.values
+                    .strides
+                    .iter()
+                    .enumerate()
+                    .map(|(axis, stride)| match axis == contiguous_axis {
+                        true => *stride,
+                        false => stride * 2,
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+/// Type implementing [MatmulArgs] where both inputs are quantized with per-tensor, per-channel or
+/// per-block scales, and optional zero points.
+pub struct QuantizedArgs;
+
+#[derive(CubeLaunch, CubeType)]
+/// Input representation for [QuantizedArgs] implementing [MatmulArgs].
+pub struct QuantizedInputs<EG: Numeric> {
+    /// The lhs tensor.
+    pub lhs: Tensor<Line<EG>>,
+    pub lhs_scales: Tensor<f32>,
+    pub lhs_zero_points: CubeOption<Tensor<f32>>,
+    /// The rhs tensor.
+    pub rhs: Tensor<Line<EG>>,
+    pub rhs_scales: Tensor<f32>,
+    pub rhs_zero_points: CubeOption<Tensor<f32>>,
+    #[cube(comptime)]
+    pub config: QuantizationConfig,
+}
+
+#[derive(CubeType)]
+/// State of [QuantizedArgs].
+pub struct QuantizedState<EI: Numeric, EO: Numeric> {
+    lhs: *const Tensor<Line<EI>>,
+    lhs_scales: *const Tensor<f32>,
+    lhs_zero_points: CubeOption<*const Tensor<f32>>,
+    rhs: *const Tensor<Line<EI>>,
+    rhs_scales: *const Tensor<f32>,
+    rhs_zero_points: CubeOption<*const Tensor<f32>>,
+    out: *mut Tensor<Line<EO>>,
+    #[cube(comptime)]
+    config: QuantizationConfig,
+}
+
+#[cube]
+impl MatmulArgs for QuantizedArgs {
+    type Output<EO: Numeric> = Tensor<Line<EO>>;
+    type Input<EI: Numeric> = QuantizedInputs<EI>;
+    type State<EI: Numeric, EO: Numeric> = QuantizedState<EI, EO>;
+
+    fn init_state<EI: Numeric, EO: Numeric>(
+        input: &Self::Input<EI>,
+        output: &mut Self::Output<EO>,
+    ) -> Self::State<EI, EO> {
+        let lhs_zero_points = match &input.lhs_zero_points {
+            CubeOption::Some(zero_points) => {
+                CubeOption::<*const Tensor<f32>>::new_Some(zero_points)
+            }
+            CubeOption::None => CubeOption::new_None(),
+        };
+        let rhs_zero_points = match &input.rhs_zero_points {
+            CubeOption::Some(zero_points) => {
+                CubeOption::<*const Tensor<f32>>::new_Some(zero_points)
+            }
+            CubeOption::None => CubeOption::new_None(),
+        };
+
+        QuantizedState::<EI, EO> {
+            lhs: &input.lhs,
+            lhs_scales: &input.lhs_scales,
+            lhs_zero_points,
+            rhs: &input.rhs,
+            rhs_scales: &input.rhs_scales,
+            rhs_zero_points,
+            out: output,
+            config: comptime!(input.config),
+        }
+    }
+
+    // Packed lines hold two values per element, so the line of logical index `coordinate`
+    // is the packed line at the same index.
+    fn read_lhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        coordinate: u32,
+    ) -> Line<EI> {
+        unsafe { (*state.lhs)[coordinate] }
+    }
+
+    fn read_rhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        coordinate: u32,
+    ) -> Line<EI> {
+        unsafe { (*state.rhs)[coordinate] }
+    }
+
+    fn read_window_lhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        start: u32,
+        end: u32,
+    ) -> Slice<Line<EI>> {
+        unsafe { (*state.lhs).slice(start, end) }
+    }
+
+    fn read_window_rhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        start: u32,
+        end: u32,
+    ) -> Slice<Line<EI>> {
+        unsafe { (*state.rhs).slice(start, end) }
+    }
+
+    fn as_tensor_map_lhs<EI: Numeric, EO: Numeric>(_state: &Self::State<EI, EO>) -> TensorMap<EI> {
+        comptime!(unimplemented!("Can't use `QuantizedArgs` as `TensorMap`"));
+        #[allow(unreachable_code)]
+        TensorMap::dummy()
+    }
+
+    fn as_tensor_map_rhs<EI: Numeric, EO: Numeric>(_state: &Self::State<EI, EO>) -> TensorMap<EI> {
+        comptime!(unimplemented!("Can't use `QuantizedArgs` as `TensorMap`"));
+        #[allow(unreachable_code)]
+        TensorMap::dummy()
+    }
+
+    fn shape_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        logical_shape::<EI>(unsafe { &*state.lhs }, dim, state.config.lhs)
+    }
+
+    fn shape_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        logical_shape::<EI>(unsafe { &*state.rhs }, dim, state.config.rhs)
+    }
+
+    fn shape_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.out).shape(dim) }
+    }
+
+    fn stride_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        logical_stride::<EI>(unsafe { &*state.lhs }, dim, state.config.lhs)
+    }
+
+    fn stride_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        logical_stride::<EI>(unsafe { &*state.rhs }, dim, state.config.rhs)
+    }
+
+    fn stride_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.out).stride(dim) }
+    }
+
+    fn write_out<EI: Numeric, EO: Numeric>(
+        state: &mut Self::State<EI, EO>,
+        coordinate: u32,
+        value: Line<EO>,
+    ) {
+        unsafe { (*state.out)[coordinate] = value }
+    }
+
+    fn rank_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.lhs).rank() }
+    }
+
+    fn rank_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.rhs).rank() }
+    }
+
+    fn rank_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.out).rank() }
+    }
+
+    fn len_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.lhs).len() }
+    }
+
+    fn len_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.rhs).len() }
+    }
+
+    fn len_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.out).len() }
+    }
+
+    fn buffer_len_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.lhs).buffer_len() }
+    }
+
+    fn buffer_len_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.rhs).buffer_len() }
+    }
+
+    fn buffer_len_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.out).buffer_len() }
+    }
+
+    fn quantization<MP: MatmulPrecision>(state: &Self::State<MP::EI, MP::EO>) -> Quantization<MP> {
+        Quantization::<MP>::new(
+            operand_quantization::<MP::EI>(
+                unsafe { &*state.lhs },
+                unsafe { &*state.lhs_scales },
+                state.lhs_zero_points,
+                state.config.lhs,
+            ),
+            operand_quantization::<MP::EI>(
+                unsafe { &*state.rhs },
+                unsafe { &*state.rhs_scales },
+                state.rhs_zero_points,
+                state.config.rhs,
+            ),
+        )
+    }
+}
+
+#[cube]
+/// Axis along which the elements of the tensor are contiguous.
+fn contiguous_axis<EI: Numeric>(tensor: &Tensor<Line<EI>>) -> u32 {
+    let rank = tensor.rank();
+    select(tensor.stride(rank - 1) == 1, rank - 1, rank - 2)
+}
+
+#[cube]
+/// Shape of the tensor in number of quantized values.
+fn logical_shape<EI: Numeric>(
+    tensor: &Tensor<Line<EI>>,
+    axis: u32,
+    #[comptime] storage: QuantizedStorage,
+) -> u32 {
+    match comptime!(storage) {
+        QuantizedStorage::Int8 => tensor.shape(axis),
+        QuantizedStorage::PackedInt4 => {
+            let shape = tensor.shape(axis);
+            select(axis == contiguous_axis::<EI>(tensor), shape * 2, shape)
+        }
+    }
+}
+
+#[cube]
+/// Stride of the tensor in number of quantized values.
+fn logical_stride<EI: Numeric>(
+    tensor: &Tensor<Line<EI>>,
+    axis: u32,
+    #[comptime] storage: QuantizedStorage,
+) -> u32 {
+    match comptime!(storage) {
+        QuantizedStorage::Int8 => tensor.stride(axis),
+        QuantizedStorage::PackedInt4 => {
+            let stride = tensor.stride(axis);
+            select(axis == contiguous_axis::<EI>(tensor), stride, stride * 2)
+        }
+    }
+}
+
+#[cube]
+fn operand_quantization<EI: Numeric>(
+    tensor: &Tensor<Line<EI>>,
+    scales: &Tensor<f32>,
+    zero_points: CubeOption<*const Tensor<f32>>,
+    #[comptime] storage: QuantizedStorage,
+) -> OperandQuantization {
+    let rank = tensor.rank();
+    let rows = logical_shape::<EI>(tensor, rank - 2, storage);
+    let cols = logical_shape::<EI>(tensor, rank - 1, storage);
+
+    OperandQuantization {
+        scales,
+        zero_points,
+        rows,
+        cols,
+        block_rows: rows / scales.shape(0),
+        block_cols: cols / scales.shape(1),
+        stride_row: scales.stride(0),
+        stride_col: scales.stride(1),
+        storage,
+    }
+}
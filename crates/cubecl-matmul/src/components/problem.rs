@@ -26,12 +26,15 @@ pub struct MatmulProblem {
 impl MatmulProblem {
     /// Returns the batch dimensions of the output
     fn output_batch_dims(&self) -> Vec<usize> {
-        self.lhs_batches
+        let mut dims: Vec<usize> = self
+            .lhs_batches
             .iter()
             .rev()
             .zip(self.rhs_batches.iter().rev())
             .map(|(&dim_lhs, &dim_rhs)| std::cmp::max(dim_lhs, dim_rhs))
-            .collect()
+            .collect();
+        dims.reverse();
+        dims
     }
 
     /// Returns the total number of batches of the output
@@ -40,7 +43,6 @@ impl MatmulProblem {
     }
 
     /// Returns the shape of the identified tensor, inferred by the problem definition
-    #[allow(unused)]
     pub(crate) fn shape(&self, ident: MatmulIdent) -> Vec<usize> {
         match ident {
             MatmulIdent::Lhs => self
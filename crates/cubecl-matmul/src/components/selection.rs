@@ -1,6 +1,6 @@
 use crate::components::{
     TilingScheme,
-    batch::HypercubeSelection,
+    batch::{HypercubeSelection, KPartitioningSelection},
     global::{LoadSpecializationConfig, load::LoaderMode},
     stage::PartitionBuffering,
 };
@@ -15,6 +15,7 @@ pub struct MatmulSelection {
     pub loader_mode: LoaderMode,
     pub load_specialization_config: LoadSpecializationConfig,
     pub hypercube_selection: HypercubeSelection,
+    pub k_partitioning: KPartitioningSelection,
 }
 
 impl MatmulSelection {
@@ -36,6 +37,7 @@ pub struct MatmulSelectionBuilder {
     loading_precompute_strategy: LoadingPrecomputeStrategy,
     loader_mode: LoaderMode,
     load_specialization_config: LoadSpecializationConfig,
+    k_partitioning: KPartitioningSelection,
 }
 
 impl MatmulSelectionBuilder {
@@ -49,6 +51,7 @@ impl MatmulSelectionBuilder {
             loading_precompute_strategy: LoadingPrecomputeStrategy::default(),
             loader_mode: LoaderMode::default(),
             load_specialization_config: LoadSpecializationConfig::default(),
+            k_partitioning: KPartitioningSelection::default(),
         }
     }
 
@@ -98,6 +101,11 @@ impl MatmulSelectionBuilder {
         self
     }
 
+    pub fn k_partitioning(mut self, k_partitioning: KPartitioningSelection) -> Self {
+        self.k_partitioning = k_partitioning;
+        self
+    }
+
     pub fn build(self) -> MatmulSelection {
         MatmulSelection {
             plane_dim: self.plane_dim.unwrap(),
@@ -108,6 +116,7 @@ impl MatmulSelectionBuilder {
             loading_precompute_strategy: self.loading_precompute_strategy,
             loader_mode: self.loader_mode,
             load_specialization_config: self.load_specialization_config,
+            k_partitioning: self.k_partitioning,
         }
     }
 }
@@ -7,5 +7,7 @@ pub mod simple;
 pub mod simple_barrier;
 pub mod simple_tma;
 pub mod simple_unit;
+pub mod split_k;
+pub mod stream_k;
 
 pub use base::Algorithm;
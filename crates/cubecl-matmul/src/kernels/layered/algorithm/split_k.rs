@@ -0,0 +1,59 @@
+use cubecl_core::{Runtime, client::ComputeClient, ir::Elem};
+
+use std::marker::PhantomData;
+
+use crate::components::{
+    AvailableLineSizes, MatmulProblem, MatmulSelection, batch::SplitKBatchMatmulFamily,
+};
+
+use super::Algorithm;
+
+/// Algorithm `A` with K split in chunks computed by different cubes,
+/// whose partial results are summed in a second pass
+pub struct SplitKAlgorithm<A: Algorithm> {
+    pub _a: PhantomData<A>,
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct SplitKSelectionArgs<S> {
+    /// Number of chunks K is split into, inferred from the problem if not specified
+    pub num_splits: Option<u32>,
+    /// Selection arguments of the underlying algorithm
+    pub inner: S,
+}
+
+impl<A: Algorithm> Algorithm for SplitKAlgorithm<A> {
+    type SelectionArgs = SplitKSelectionArgs<A::SelectionArgs>;
+    type TileMatmul = A::TileMatmul;
+    type StageMatmul = A::StageMatmul;
+    type GlobalMatmul = A::GlobalMatmul;
+    type BatchMatmul = SplitKBatchMatmulFamily<Self::GlobalMatmul>;
+
+    fn selection<R: Runtime>(
+        client: &ComputeClient<R::Server, R::Channel>,
+        problem: &MatmulProblem,
+        plane_dim: u32,
+        elem_stage: Elem,
+        elem_acc: Elem,
+        args: &Self::SelectionArgs,
+    ) -> MatmulSelection {
+        let mut selection = A::selection::<R>(
+            client,
+            problem,
+            plane_dim,
+            elem_stage,
+            elem_acc,
+            &args.inner,
+        );
+        selection.k_partitioning.num_splits = args.num_splits;
+        selection
+    }
+
+    fn filter_line_sizes(available_line_sizes: AvailableLineSizes) -> AvailableLineSizes {
+        A::filter_line_sizes(available_line_sizes)
+    }
+
+    fn select_plane_dim<R: Runtime>(client: &ComputeClient<R::Server, R::Channel>) -> u32 {
+        A::select_plane_dim::<R>(client)
+    }
+}
@@ -0,0 +1,59 @@
+use cubecl_core::{Runtime, client::ComputeClient, ir::Elem};
+
+use std::marker::PhantomData;
+
+use crate::components::{
+    AvailableLineSizes, MatmulProblem, MatmulSelection, batch::StreamKBatchMatmulFamily,
+};
+
+use super::Algorithm;
+
+/// Algorithm `A` with the K iterations of all output tiles evenly distributed across a
+/// persistent grid of cubes, whose partial results are summed in a second pass
+pub struct StreamKAlgorithm<A: Algorithm> {
+    pub _a: PhantomData<A>,
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct StreamKSelectionArgs<S> {
+    /// Number of persistent cubes, one per SM if not specified
+    pub num_cubes: Option<u32>,
+    /// Selection arguments of the underlying algorithm
+    pub inner: S,
+}
+
+impl<A: Algorithm> Algorithm for StreamKAlgorithm<A> {
+    type SelectionArgs = StreamKSelectionArgs<A::SelectionArgs>;
+    type TileMatmul = A::TileMatmul;
+    type StageMatmul = A::StageMatmul;
+    type GlobalMatmul = A::GlobalMatmul;
+    type BatchMatmul = StreamKBatchMatmulFamily<Self::GlobalMatmul>;
+
+    fn selection<R: Runtime>(
+        client: &ComputeClient<R::Server, R::Channel>,
+        problem: &MatmulProblem,
+        plane_dim: u32,
+        elem_stage: Elem,
+        elem_acc: Elem,
+        args: &Self::SelectionArgs,
+    ) -> MatmulSelection {
+        let mut selection = A::selection::<R>(
+            client,
+            problem,
+            plane_dim,
+            elem_stage,
+            elem_acc,
+            &args.inner,
+        );
+        selection.k_partitioning.num_cubes = args.num_cubes;
+        selection
+    }
+
+    fn filter_line_sizes(available_line_sizes: AvailableLineSizes) -> AvailableLineSizes {
+        A::filter_line_sizes(available_line_sizes)
+    }
+
+    fn select_plane_dim<R: Runtime>(client: &ComputeClient<R::Server, R::Channel>) -> u32 {
+        A::select_plane_dim::<R>(client)
+    }
+}
@@ -1,13 +1,22 @@
-use crate::components::batch::{BatchMatmulFamily, CubeCountInputArgs};
+use crate::components::batch::{
+    BatchConfig, BatchMatmulFamily, CubeCountInputArgs, CubeCountPlan, reduce_partials,
+};
+use crate::components::global::args::{
+    ConcreteInputsFactory, ConcreteOutputRef, MatmulArgs, TensorArgs, TensorInputs, TensorMapArgs,
+};
+use crate::components::tile::TileMatmulFamily;
 use crate::components::{
-    AvailableLineSizes, InputRuntimeArg, MatmulAvailabilityError, MatmulLineSizes, MatmulPrecision,
-    MatmulProblem, MatmulSelection, MatmulSetupError, MatmulSpec, MatrixLayout, OutputRuntimeArg,
-    ReplaceES,
+    Args, AvailableLineSizes, EA, EI, EO, ES, InputRuntimeArg, MatmulAvailabilityError,
+    MatmulIdent, MatmulLineSizes, MatmulPrecision, MatmulProblem, MatmulSelection,
+    MatmulSetupError, MatmulSpec, MatrixLayout, OutputRuntimeArg, ReplaceES,
+};
+use crate::kernels::layered::selector::{
+    launch_kernel_concrete, launch_kernel_concrete_with_output,
 };
-use crate::components::{global::args::TensorMapArgs, tile::TileMatmulFamily};
-use crate::kernels::layered::selector::launch_kernel_concrete;
 use core::any::TypeId;
-use cubecl_core::{Feature, prelude::*, try_tensor_line_size_parallel};
+use cubecl_core::{
+    Feature, calculate_cube_count_elemwise, prelude::*, try_tensor_line_size_parallel,
+};
 use cubecl_core::{Runtime, client::ComputeClient, frontend::TensorHandleRef};
 use cubecl_std::tensor::{
     MatrixBatchLayout, TensorHandle, into_contiguous_pitched, matrix_batch_layout,
@@ -86,6 +95,27 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
     out: &TensorHandleRef<'_, R>,
     selection: &Selection<A::SelectionArgs>,
 ) -> Result<(), MatmulSetupError> {
+    launch_ref_with_output::<R, MP, A, _>(client, lhs, lhs_scale, rhs, rhs_scale, out, selection)
+}
+
+/// Launch a matrix multiplication kernel writing to the given [output](ConcreteOutputRef).
+///
+/// Cmma will be used if available and enabled,
+/// otherwise it will fall back on a non-cmma implementation
+#[allow(clippy::result_large_err)]
+pub fn launch_ref_with_output<R: Runtime, MP: MatmulPrecision, A: Algorithm, O>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    lhs_scale: &Option<TensorHandleRef<'_, R>>,
+    rhs: &TensorHandleRef<'_, R>,
+    rhs_scale: &Option<TensorHandleRef<'_, R>>,
+    out: &O,
+    selection: &Selection<A::SelectionArgs>,
+) -> Result<(), MatmulSetupError>
+where
+    O: ConcreteOutputRef<R>,
+    <O::Args as MatmulArgs>::Input<MP::EI>: ConcreteInputsFactory,
+{
     let check_layout = |tensor: &TensorHandleRef<'_, R>| match matrix_batch_layout(tensor.strides) {
         MatrixBatchLayout::Contiguous => (false, false),
         MatrixBatchLayout::MildlyPermuted {
@@ -99,7 +129,7 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
     let (rhs_make_contiguous, rhs_transposed) = check_layout(rhs);
 
     match (lhs_make_contiguous, rhs_make_contiguous) {
-        (false, false) => launch_inner_ref::<R, MP, A>(
+        (false, false) => launch_inner_ref::<R, MP, A, O>(
             client,
             lhs,
             lhs_scale,
@@ -109,7 +139,7 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
             (lhs_transposed, rhs_transposed),
             selection,
         ),
-        (false, true) => launch_inner_ref::<R, MP, A>(
+        (false, true) => launch_inner_ref::<R, MP, A, O>(
             client,
             lhs,
             lhs_scale,
@@ -119,7 +149,7 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
             (lhs_transposed, rhs_transposed),
             selection,
         ),
-        (true, false) => launch_inner_ref::<R, MP, A>(
+        (true, false) => launch_inner_ref::<R, MP, A, O>(
             client,
             &into_contiguous_pitched::<R, MP::EI>(client, lhs).as_ref(),
             lhs_scale,
@@ -129,7 +159,7 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
             (lhs_transposed, rhs_transposed),
             selection,
         ),
-        (true, true) => launch_inner_ref::<R, MP, A>(
+        (true, true) => launch_inner_ref::<R, MP, A, O>(
             client,
             &into_contiguous_pitched::<R, MP::EI>(client, lhs).as_ref(),
             lhs_scale,
@@ -143,16 +173,21 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
 }
 
 #[allow(clippy::result_large_err, clippy::too_many_arguments)]
-fn launch_inner_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
+fn launch_inner_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm, O>(
     client: &ComputeClient<R::Server, R::Channel>,
     lhs: &TensorHandleRef<'_, R>,
     lhs_scale: &Option<TensorHandleRef<'_, R>>,
     rhs: &TensorHandleRef<'_, R>,
     rhs_scale: &Option<TensorHandleRef<'_, R>>,
-    out: &TensorHandleRef<'_, R>,
+    output: &O,
     transposed: (bool, bool),
     selection: &Selection<A::SelectionArgs>,
-) -> Result<(), MatmulSetupError> {
+) -> Result<(), MatmulSetupError>
+where
+    O: ConcreteOutputRef<R>,
+    <O::Args as MatmulArgs>::Input<MP::EI>: ConcreteInputsFactory,
+{
+    let out = output.tensor();
     let rank = lhs.strides.len();
     let ei_elem = MP::EI::as_elem_native().expect("To be a native type");
     let eo_elem = MP::EO::as_elem_native().expect("To be a native type");
@@ -222,34 +257,40 @@ fn launch_inner_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
 
     let plane_dim = fix_plane_dim(A::select_plane_dim::<R>(client));
 
-    launch_inner_ref_fix_dtype::<R, MP, A>(
-        client, lhs, lhs_scale, rhs, rhs_scale, out, problem, line_sizes, plane_dim, selection,
+    launch_inner_ref_fix_dtype::<R, MP, A, O>(
+        client, lhs, lhs_scale, rhs, rhs_scale, output, problem, line_sizes, plane_dim, selection,
     )
 }
 
 #[allow(clippy::result_large_err, clippy::too_many_arguments)]
-fn launch_inner_ref_fix_dtype<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
+fn launch_inner_ref_fix_dtype<R: Runtime, MP: MatmulPrecision, A: Algorithm, O>(
     client: &ComputeClient<R::Server, R::Channel>,
     lhs: &TensorHandleRef<'_, R>,
     lhs_scale: &Option<TensorHandleRef<'_, R>>,
     rhs: &TensorHandleRef<'_, R>,
     rhs_scale: &Option<TensorHandleRef<'_, R>>,
-    out: &TensorHandleRef<'_, R>,
+    output: &O,
     problem: MatmulProblem,
     line_sizes: MatmulLineSizes,
     plane_dim: u32,
     selection: &Selection<A::SelectionArgs>,
-) -> Result<(), MatmulSetupError> {
+) -> Result<(), MatmulSetupError>
+where
+    O: ConcreteOutputRef<R>,
+    <O::Args as MatmulArgs>::Input<MP::EI>: ConcreteInputsFactory,
+{
     if <A::TileMatmul as TileMatmulFamily>::requires_accelerator()
         && TypeId::of::<MP::ES>() == TypeId::of::<f32>()
         && tf32::is_supported(client)
     {
-        launch_kernel_concrete::<ReplaceES<MP, tf32>, R, A>(
-            client, lhs, lhs_scale, rhs, rhs_scale, out, problem, line_sizes, plane_dim, selection,
+        launch_kernel_concrete_with_output::<(ReplaceES<MP, tf32>, O::Args), R, A, O>(
+            client, lhs, lhs_scale, rhs, rhs_scale, output, problem, line_sizes, plane_dim,
+            selection,
         )
     } else {
-        launch_kernel_concrete::<MP, R, A>(
-            client, lhs, lhs_scale, rhs, rhs_scale, out, problem, line_sizes, plane_dim, selection,
+        launch_kernel_concrete_with_output::<(MP, O::Args), R, A, O>(
+            client, lhs, lhs_scale, rhs, rhs_scale, output, problem, line_sizes, plane_dim,
+            selection,
         )
     }
 }
@@ -348,8 +389,77 @@ pub fn launch_with_config<'a, MS: MatmulSpec, R: Runtime, A: Algorithm>(
             output,
             cube_count_input,
             config,
-        );
+        )?;
     };
 
     Ok(())
 }
+
+/// Allocate the zero-initialized workspace of a matmul writing
+/// [partial results](BatchMatmulFamily::num_partials).
+pub fn partials_workspace<MS: MatmulSpec, R: Runtime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    problem: &MatmulProblem,
+    num_partials: u32,
+) -> TensorHandle<R, EA<MS>> {
+    let mut shape = problem.shape(MatmulIdent::Out);
+    shape.insert(0, num_partials as usize);
+    TensorHandle::zeros(client, shape)
+}
+
+/// Launch a matrix multiplication kernel writing [partial results](BatchMatmulFamily::num_partials)
+/// to the [workspace](partials_workspace), then the kernel summing them into the output.
+///
+/// The partial results are computed from `lhs` and `rhs`, while `input` and `output` are the
+/// arguments used to write the output.
+#[allow(clippy::too_many_arguments, clippy::result_large_err)]
+pub fn launch_with_partials<'a, MS: MatmulSpec, R: Runtime, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &'a TensorHandleRef<'a, R>,
+    lhs_scale: &'a Option<TensorHandleRef<'a, R>>,
+    rhs: &'a TensorHandleRef<'a, R>,
+    rhs_scale: &'a Option<TensorHandleRef<'a, R>>,
+    input: InputRuntimeArg<'a, MS, R>,
+    output: OutputRuntimeArg<'a, MS, R>,
+    partials: &'a TensorHandle<R, EA<MS>>,
+    selection: &MatmulSelection,
+    problem: &MatmulProblem,
+    cube_count_plan: CubeCountPlan,
+    config: <A::BatchMatmul as BatchMatmulFamily>::Config,
+) -> Result<(), MatmulSetupError> {
+    let line_sizes = config.line_sizes();
+
+    launch_with_config::<((EI<MS>, ES<MS>, EA<MS>, EA<MS>), TensorArgs), R, A>(
+        client,
+        config.cube_dim(),
+        cube_count_plan.resolve(),
+        TensorInputs::create(
+            lhs,
+            lhs_scale,
+            rhs,
+            rhs_scale,
+            selection,
+            problem,
+            &line_sizes,
+        ),
+        partials.as_arg(line_sizes.out),
+        cube_count_plan.as_args(),
+        config,
+    )?;
+
+    let num_lines = partials.strides[0] / line_sizes.out as usize;
+    let cube_dim = CubeDim::default();
+
+    unsafe {
+        reduce_partials::launch_unchecked::<Args<MS>, EI<MS>, EA<MS>, EO<MS>, R>(
+            client,
+            calculate_cube_count_elemwise(num_lines, cube_dim),
+            cube_dim,
+            input,
+            output,
+            partials.as_arg(line_sizes.out),
+        )?;
+    }
+
+    Ok(())
+}
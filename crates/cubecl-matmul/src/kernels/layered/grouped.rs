@@ -0,0 +1,207 @@
+use core::any::TypeId;
+
+use cubecl_core::{Feature, Runtime, client::ComputeClient, frontend::TensorHandleRef, prelude::*};
+use cubecl_std::tensor::{
+    MatrixBatchLayout, TensorHandle, into_contiguous_pitched, matrix_batch_layout,
+};
+
+use crate::components::batch::{GroupedMatmulProblem, grouped_matmul, setup_grouped};
+use crate::components::tile::TileMatmulFamily;
+use crate::components::{
+    AvailableLineSizes, FormattedConfigError, MatmulAvailabilityError, MatmulPrecision,
+    MatmulSetupError, MatrixLayout, ReplaceES,
+};
+use crate::kernels::layered::{Algorithm, Selection};
+
+/// Computes the group offsets of consecutive groups of the given sizes, to be used with
+/// [launch_grouped_ref].
+pub fn group_offsets(group_sizes: &[usize]) -> Vec<u32> {
+    core::iter::once(0)
+        .chain(group_sizes.iter().scan(0, |offset, size| {
+            *offset += *size as u32;
+            Some(*offset)
+        }))
+        .collect()
+}
+
+/// Launch a grouped matrix multiplication, with the group sizes known on the host.
+///
+/// See [launch_grouped_ref].
+#[allow(clippy::result_large_err)]
+pub fn launch_grouped_with_sizes<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    group_sizes: &[usize],
+    out: &TensorHandleRef<'_, R>,
+    selection: &Selection<A::SelectionArgs>,
+) -> Result<(), MatmulSetupError> {
+    let offsets = group_offsets(group_sizes);
+    let group_offsets = TensorHandle::<R, u32>::new_contiguous(
+        vec![offsets.len()],
+        client.create(u32::as_bytes(&offsets)),
+    );
+
+    launch_grouped_ref::<R, MP, A>(client, lhs, rhs, &group_offsets.as_ref(), out, selection)
+}
+
+/// Launch a grouped matrix multiplication, as used by mixture-of-experts layers.
+///
+/// The rows of `lhs` (`[m, k]`) and `out` (`[m, n]`) are partitioned in contiguous groups, and
+/// `rhs` (`[num_groups, k, n]`) holds one matrix per group. `group_offsets` is a `u32` tensor of
+/// `num_groups + 1` elements, where the rows of group `g` are
+/// `group_offsets[g]..group_offsets[g + 1]`, and may be computed on the device.
+///
+/// All groups are computed in a single launch, reusing the global matmul of the algorithm `A`.
+/// Algorithms relying on tensor maps aren't supported.
+#[allow(clippy::result_large_err)]
+pub fn launch_grouped_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    group_offsets: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+    selection: &Selection<A::SelectionArgs>,
+) -> Result<(), MatmulSetupError> {
+    if lhs.shape.len() != 2 || rhs.shape.len() != 3 || out.shape.len() != 2 {
+        return Err(MatmulSetupError::InvalidConfig(Box::new(
+            "Grouped matmul expects lhs of rank 2, rhs of rank 3 and out of rank 2",
+        )));
+    }
+
+    let num_groups = rhs.shape[0];
+    if group_offsets.shape != [num_groups + 1] {
+        return Err(MatmulSetupError::InvalidConfig(FormattedConfigError::new(
+            move || {
+                format!(
+                    "Expected {} group offsets for {num_groups} groups",
+                    num_groups + 1
+                )
+            },
+        )));
+    }
+
+    let lhs_contiguous;
+    let lhs = match matrix_batch_layout(lhs.strides) {
+        MatrixBatchLayout::HighlyPermuted => {
+            lhs_contiguous = into_contiguous_pitched::<R, MP::EI>(client, lhs);
+            &lhs_contiguous.as_ref()
+        }
+        _ => lhs,
+    };
+    let rhs_contiguous;
+    let rhs = match matrix_batch_layout(rhs.strides) {
+        MatrixBatchLayout::HighlyPermuted => {
+            rhs_contiguous = into_contiguous_pitched::<R, MP::EI>(client, rhs);
+            &rhs_contiguous.as_ref()
+        }
+        _ => rhs,
+    };
+
+    let layout = |tensor: &TensorHandleRef<'_, R>| match matrix_batch_layout(tensor.strides) {
+        MatrixBatchLayout::MildlyPermuted {
+            transposed: true, ..
+        } => MatrixLayout::ColMajor,
+        _ => MatrixLayout::RowMajor,
+    };
+
+    let problem = GroupedMatmulProblem {
+        m: lhs.shape[0],
+        n: rhs.shape[2],
+        k: lhs.shape[1],
+        num_groups,
+        lhs_layout: layout(lhs),
+        rhs_layout: layout(rhs),
+    };
+
+    if <A::TileMatmul as TileMatmulFamily>::requires_accelerator()
+        && TypeId::of::<MP::ES>() == TypeId::of::<f32>()
+        && tf32::is_supported(client)
+    {
+        launch_grouped_inner::<R, ReplaceES<MP, tf32>, A>(
+            client,
+            lhs,
+            rhs,
+            group_offsets,
+            out,
+            &problem,
+            selection,
+        )
+    } else {
+        launch_grouped_inner::<R, MP, A>(client, lhs, rhs, group_offsets, out, &problem, selection)
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn launch_grouped_inner<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    group_offsets: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+    problem: &GroupedMatmulProblem,
+    selection: &Selection<A::SelectionArgs>,
+) -> Result<(), MatmulSetupError> {
+    let ei_elem = MP::EI::as_elem_native_unchecked();
+    let eo_elem = MP::EO::as_elem_native_unchecked();
+
+    if !client.properties().feature_enabled(Feature::Type(ei_elem))
+        || !client.properties().feature_enabled(Feature::Type(eo_elem))
+    {
+        return Err(MatmulSetupError::Unavailable(
+            MatmulAvailabilityError::TypesUnavailable {
+                input: ei_elem,
+                output: eo_elem,
+            },
+        ));
+    }
+
+    let line_sizes = AvailableLineSizes::from_elem_types::<R>(&ei_elem, &eo_elem);
+    let line_sizes = A::filter_line_sizes(line_sizes);
+    // Groups may start at any row, so lines can't span rows
+    let line_sizes = match problem.lhs_layout {
+        MatrixLayout::RowMajor => {
+            line_sizes.filter_lhs_with_tensor(lhs.strides, lhs.shape, problem.lhs_layout)
+        }
+        MatrixLayout::ColMajor => line_sizes.filter_lhs(|line_size| *line_size == 1),
+    };
+    let line_sizes = line_sizes
+        .filter_rhs_with_tensor(rhs.strides, rhs.shape, problem.rhs_layout)
+        .filter_out_with_tensor(out.strides, out.shape)
+        .pick_max()?;
+
+    let plane_dim = match A::select_plane_dim::<R>(client) {
+        0 => 32,
+        plane_dim => plane_dim,
+    };
+
+    let selection = match selection {
+        Selection::Forced(selection) => selection.clone(),
+        Selection::Inferred(args) => A::selection::<R>(
+            client,
+            &problem.representative_problem(),
+            plane_dim,
+            MP::ES::as_elem_native_unchecked(),
+            MP::EA::as_elem_native_unchecked(),
+            args,
+        ),
+    };
+
+    let config = setup_grouped::<A::GlobalMatmul, MP, R>(client, problem, &selection, &line_sizes)?;
+    let line_sizes = config.line_sizes();
+
+    unsafe {
+        grouped_matmul::launch_unchecked::<MP::EI, MP::ES, MP::EA, MP::EO, A::GlobalMatmul, R>(
+            client,
+            problem.cube_count(&selection.tiling_scheme),
+            config.cube_dim(),
+            lhs.as_tensor_arg(line_sizes.lhs),
+            rhs.as_tensor_arg(line_sizes.rhs),
+            group_offsets.as_tensor_arg(1),
+            out.as_tensor_arg(line_sizes.out),
+            config,
+        )?;
+    }
+
+    Ok(())
+}
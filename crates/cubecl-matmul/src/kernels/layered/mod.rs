@@ -1,10 +1,17 @@
 mod algorithm;
 mod base;
+mod grouped;
+mod quantized;
 mod selector;
 
 pub use algorithm::*;
-pub use base::{Selection, launch, launch_ref, launch_with_config, matmul_cmma_tma_ref_no_check};
+pub use base::{
+    Selection, launch, launch_ref, launch_ref_with_output, launch_with_config,
+    launch_with_partials, matmul_cmma_tma_ref_no_check, partials_workspace,
+};
+pub use grouped::{group_offsets, launch_grouped_ref, launch_grouped_with_sizes};
+pub use quantized::launch_quantized_ref;
 pub use selector::{
     NUM_SM_APPROX, NUM_TENSOR_CORES_APPROX, TileSizeSelection, find_instruction_size,
-    launch_kernel_concrete, launch_kernel_virtual,
+    launch_kernel_concrete, launch_kernel_concrete_with_output, launch_kernel_virtual,
 };
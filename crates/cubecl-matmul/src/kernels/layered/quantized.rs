@@ -0,0 +1,211 @@
+use core::any::TypeId;
+
+use cubecl_core::{Feature, Runtime, client::ComputeClient, frontend::TensorHandleRef, prelude::*};
+use cubecl_std::tensor::{MatrixBatchLayout, matrix_batch_layout};
+
+use crate::components::batch::{BatchConfig, BatchMatmulFamily};
+use crate::components::global::{
+    QuantizationConfig, QuantizedArgs, QuantizedInputsLaunch, QuantizedStorage, QuantizedTensorRef,
+};
+use crate::components::{
+    AvailableLineSizes, FormattedConfigError, MatmulAvailabilityError, MatmulPrecision,
+    MatmulProblem, MatmulSetupError, MatrixLayout, Quantized,
+};
+use crate::kernels::layered::{Algorithm, Selection, launch_with_config};
+
+/// Launch a matrix multiplication of quantized inputs.
+///
+/// Both inputs are dequantized with their [scales and zero points](QuantizedTensorRef) as they
+/// are loaded into the stage memory, then multiplied in the stage precision of `MP`. The element
+/// type of the quantized values is `MP::EI`, which must be `i8` for packed int4 inputs.
+///
+/// Only algorithms loading their inputs synchronously are supported, and inputs can't be highly
+/// permuted.
+#[allow(clippy::result_large_err)]
+pub fn launch_quantized_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &QuantizedTensorRef<'_, R>,
+    rhs: &QuantizedTensorRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+    selection: &Selection<A::SelectionArgs>,
+) -> Result<(), MatmulSetupError> {
+    let invalid = |reason: String| {
+        Err(MatmulSetupError::InvalidConfig(FormattedConfigError::new(
+            move || reason.clone(),
+        )))
+    };
+
+    let ei_elem = MP::EI::as_elem_native_unchecked();
+    let eo_elem = MP::EO::as_elem_native_unchecked();
+
+    if !client.properties().feature_enabled(Feature::Type(ei_elem))
+        || !client.properties().feature_enabled(Feature::Type(eo_elem))
+    {
+        return Err(MatmulSetupError::Unavailable(
+            MatmulAvailabilityError::TypesUnavailable {
+                input: ei_elem,
+                output: eo_elem,
+            },
+        ));
+    }
+
+    if !client
+        .properties()
+        .feature_enabled(Feature::DynamicLineSize)
+    {
+        return Err(MatmulSetupError::Unavailable(
+            MatmulAvailabilityError::DynamicLineSizeUnavailable,
+        ));
+    }
+
+    for (name, operand) in [("lhs", lhs), ("rhs", rhs)] {
+        if let MatrixBatchLayout::HighlyPermuted = matrix_batch_layout(operand.values.strides) {
+            return invalid(format!(
+                "The {name} of a quantized matmul can't be highly permuted"
+            ));
+        }
+
+        if operand.storage == QuantizedStorage::PackedInt4
+            && TypeId::of::<MP::EI>() != TypeId::of::<i8>()
+        {
+            return invalid(format!(
+                "Packed int4 values of the {name} must be stored as i8"
+            ));
+        }
+
+        let shape = operand.logical_shape();
+        let rank = shape.len();
+        let (rows, cols) = (shape[rank - 2], shape[rank - 1]);
+
+        if operand.scales.shape.len() != 2
+            || operand.scales.shape[0] == 0
+            || operand.scales.shape[1] == 0
+            || rows % operand.scales.shape[0] != 0
+            || cols % operand.scales.shape[1] != 0
+        {
+            return invalid(format!(
+                "The scales of the {name} of shape {:?} don't divide its {rows}x{cols} matrices in blocks",
+                operand.scales.shape
+            ));
+        }
+
+        if let Some(zero_points) = operand.zero_points {
+            if zero_points.shape != operand.scales.shape {
+                return invalid(format!(
+                    "The zero points of the {name} must have the shape {:?} of its scales, got {:?}",
+                    operand.scales.shape, zero_points.shape
+                ));
+            }
+        }
+    }
+
+    let lhs_shape = lhs.logical_shape();
+    let rhs_shape = rhs.logical_shape();
+    let lhs_strides = lhs.logical_strides();
+    let rhs_strides = rhs.logical_strides();
+    let rank = lhs_shape.len();
+
+    let layout = |strides: &[usize]| match matrix_batch_layout(strides) {
+        MatrixBatchLayout::MildlyPermuted {
+            transposed: true, ..
+        } => MatrixLayout::ColMajor,
+        _ => MatrixLayout::RowMajor,
+    };
+
+    let problem = MatmulProblem {
+        m: lhs_shape[rank - 2],
+        n: rhs_shape[rank - 1],
+        k: lhs_shape[rank - 1],
+        lhs_batches: lhs_shape[..rank - 2].to_vec(),
+        rhs_batches: rhs_shape[..rank - 2].to_vec(),
+        lhs_layout: layout(&lhs_strides),
+        rhs_layout: layout(&rhs_strides),
+    };
+
+    // A line of packed values holds half as many bytes
+    let fits_storage = |line_size: &u8, storage: QuantizedStorage| match storage {
+        QuantizedStorage::Int8 => true,
+        QuantizedStorage::PackedInt4 => line_size % 2 == 0,
+    };
+
+    let line_sizes = AvailableLineSizes::from_elem_types::<R>(&ei_elem, &eo_elem);
+    let line_sizes = A::filter_line_sizes(line_sizes)
+        .filter_lhs_with_tensor(&lhs_strides, &lhs_shape, problem.lhs_layout)
+        .filter_rhs_with_tensor(&rhs_strides, &rhs_shape, problem.rhs_layout)
+        .filter_out_with_tensor(out.strides, out.shape)
+        .filter_lhs(|line_size| fits_storage(line_size, lhs.storage))
+        .filter_rhs(|line_size| fits_storage(line_size, rhs.storage))
+        .pick_max()?;
+
+    let plane_dim = match A::select_plane_dim::<R>(client) {
+        0 => 32,
+        plane_dim => plane_dim,
+    };
+
+    let mut selection = match selection {
+        Selection::Forced(selection) => selection.clone(),
+        Selection::Inferred(args) => A::selection::<R>(
+            client,
+            &problem,
+            plane_dim,
+            MP::ES::as_elem_native_unchecked(),
+            MP::EA::as_elem_native_unchecked(),
+            args,
+        ),
+    };
+    selection.quantized = true;
+
+    type Precision<MP> = (
+        <MP as MatmulPrecision>::EI,
+        <MP as MatmulPrecision>::ES,
+        <MP as MatmulPrecision>::EA,
+        <MP as MatmulPrecision>::EO,
+        Quantized,
+    );
+
+    let config = A::setup::<Precision<MP>, R>(client, &problem, &selection, &line_sizes)?;
+
+    if A::BatchMatmul::num_partials(&config, &problem).is_some() {
+        return Err(MatmulSetupError::InvalidConfig(Box::new(
+            "Quantized matmuls writing partial results are not supported",
+        )));
+    }
+
+    let cube_count_plan = A::BatchMatmul::cube_count_plan(
+        &config,
+        &problem,
+        client.properties().hardware.max_cube_count.clone(),
+    );
+    let line_sizes = config.line_sizes();
+
+    let values_line_size = |line_size: u8, storage: QuantizedStorage| match storage {
+        QuantizedStorage::Int8 => line_size,
+        QuantizedStorage::PackedInt4 => line_size / 2,
+    };
+
+    let quantization_config = QuantizationConfig {
+        lhs: lhs.storage,
+        rhs: rhs.storage,
+    };
+    let input = QuantizedInputsLaunch::new(
+        lhs.values
+            .as_tensor_arg(values_line_size(line_sizes.lhs, lhs.storage)),
+        lhs.scales.as_tensor_arg(1),
+        lhs.zero_points.map(|it| it.as_tensor_arg(1)).into(),
+        rhs.values
+            .as_tensor_arg(values_line_size(line_sizes.rhs, rhs.storage)),
+        rhs.scales.as_tensor_arg(1),
+        rhs.zero_points.map(|it| it.as_tensor_arg(1)).into(),
+        &quantization_config,
+    );
+
+    launch_with_config::<(Precision<MP>, QuantizedArgs), R, A>(
+        client,
+        config.cube_dim(),
+        cube_count_plan.resolve(),
+        input,
+        out.as_tensor_arg(line_sizes.out),
+        cube_count_plan.as_args(),
+        config,
+    )
+}
@@ -1,16 +1,19 @@
-use crate::components::batch::BatchConfig;
+use crate::components::batch::{BatchConfig, BatchMatmulFamily, CubeCountPlan};
 use crate::components::{
-    InputArg, MatmulProblem, MatmulSpec, OutputArg,
-    global::args::{ConcreteInputsFactory, ConcreteOutputFactory},
+    Args, EA, EO, InputArg, MatmulProblem, MatmulSelection, MatmulSpec, OutputArg,
+    global::args::{ConcreteInputsFactory, ConcreteOutputFactory, ConcreteOutputRef},
 };
 use crate::components::{
     InputRuntimeArg, MatmulLineSizes, MatmulPrecision, MatmulSetupError, OutputRuntimeArg,
 };
 use crate::kernels::layered::base::Selection;
-use crate::kernels::layered::{Algorithm, launch_with_config};
+use crate::kernels::layered::{
+    Algorithm, launch_with_config, launch_with_partials, partials_workspace,
+};
 use cubecl_core::frontend::CubePrimitive;
 use cubecl_core::prelude::TensorHandleRef;
 use cubecl_core::{Runtime, client::ComputeClient};
+use cubecl_std::tensor::TensorHandle;
 
 /// Select which kernel to launch for the given Algorithm.
 ///
@@ -32,40 +35,170 @@ where
     InputArg<MS>: ConcreteInputsFactory,
     OutputArg<MS>: ConcreteOutputFactory,
 {
+    let (selection, config, cube_count_plan, partials) =
+        select_concrete::<MS, R, A>(client, &problem, line_sizes, plane_dim, selection)?;
+
+    let line_sizes = config.line_sizes();
+    let input = <InputArg<MS> as ConcreteInputsFactory>::create(
+        lhs,
+        lhs_scale,
+        rhs,
+        rhs_scale,
+        &selection,
+        &problem,
+        &line_sizes,
+    );
+    let output =
+        <OutputArg<MS> as ConcreteOutputFactory>::create(out, &selection, &problem, &line_sizes);
+
+    launch_concrete::<MS, R, A>(
+        client,
+        lhs,
+        lhs_scale,
+        rhs,
+        rhs_scale,
+        input,
+        output,
+        partials.as_ref(),
+        &selection,
+        &problem,
+        cube_count_plan,
+        config,
+    )
+}
+
+/// Select which kernel to launch for the given Algorithm.
+///
+/// Only works for concrete tensor inputs, the output argument is created from `output` once the
+/// line sizes are known.
+#[allow(clippy::result_large_err, clippy::too_many_arguments)]
+pub fn launch_kernel_concrete_with_output<MS: MatmulSpec, R: Runtime, A: Algorithm, O>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    lhs_scale: &Option<TensorHandleRef<'_, R>>,
+    rhs: &TensorHandleRef<'_, R>,
+    rhs_scale: &Option<TensorHandleRef<'_, R>>,
+    output: &O,
+    problem: MatmulProblem,
+    line_sizes: MatmulLineSizes,
+    plane_dim: u32,
+    selection: &Selection<A::SelectionArgs>,
+) -> Result<(), MatmulSetupError>
+where
+    InputArg<MS>: ConcreteInputsFactory,
+    O: ConcreteOutputRef<R, Args = Args<MS>>,
+{
+    let (selection, config, cube_count_plan, partials) =
+        select_concrete::<MS, R, A>(client, &problem, line_sizes, plane_dim, selection)?;
+
+    let line_sizes = config.line_sizes();
+    let input = <InputArg<MS> as ConcreteInputsFactory>::create(
+        lhs,
+        lhs_scale,
+        rhs,
+        rhs_scale,
+        &selection,
+        &problem,
+        &line_sizes,
+    );
+    let output = output.create::<EO<MS>>(&selection, &problem, &line_sizes);
+
+    launch_concrete::<MS, R, A>(
+        client,
+        lhs,
+        lhs_scale,
+        rhs,
+        rhs_scale,
+        input,
+        output,
+        partials.as_ref(),
+        &selection,
+        &problem,
+        cube_count_plan,
+        config,
+    )
+}
+
+type ConcreteSelection<R, MS, A> = (
+    MatmulSelection,
+    <<A as Algorithm>::BatchMatmul as BatchMatmulFamily>::Config,
+    CubeCountPlan,
+    Option<TensorHandle<R, EA<MS>>>,
+);
+
+/// Select the configuration of the kernel, and allocate the workspace of its partial results if
+/// it writes any.
+///
+/// The workspace is allocated before the launch arguments, which borrow it.
+#[allow(clippy::result_large_err)]
+fn select_concrete<MS: MatmulSpec, R: Runtime, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    problem: &MatmulProblem,
+    line_sizes: MatmulLineSizes,
+    plane_dim: u32,
+    selection: &Selection<A::SelectionArgs>,
+) -> Result<ConcreteSelection<R, MS, A>, MatmulSetupError> {
     let elem_stage = <MS::Precision as MatmulPrecision>::ES::as_elem_native_unchecked();
     let elem_acc = <MS::Precision as MatmulPrecision>::EA::as_elem_native_unchecked();
 
     let selection = match selection {
         Selection::Forced(selection) => selection.clone(),
         Selection::Inferred(args) => {
-            A::selection::<R>(client, &problem, plane_dim, elem_stage, elem_acc, args)
+            A::selection::<R>(client, problem, plane_dim, elem_stage, elem_acc, args)
         }
     };
-    let config = A::setup::<MS::Precision, R>(client, &problem, &selection, &line_sizes)?;
-    let cube_count_plan = config.hypercube_config().cube_count_plan(
-        &problem,
+    let config = A::setup::<MS::Precision, R>(client, problem, &selection, &line_sizes)?;
+    let cube_count_plan = A::BatchMatmul::cube_count_plan(
+        &config,
+        problem,
         client.properties().hardware.max_cube_count.clone(),
     );
+    let partials = A::BatchMatmul::num_partials(&config, problem)
+        .map(|num_partials| partials_workspace::<MS, R>(client, problem, num_partials));
 
-    let line_sizes = config.line_sizes();
+    Ok((selection, config, cube_count_plan, partials))
+}
 
-    launch_with_config::<MS, R, A>(
-        client,
-        config.cube_dim(),
-        cube_count_plan.resolve(),
-        <InputArg<MS> as ConcreteInputsFactory>::create(
+#[allow(clippy::result_large_err, clippy::too_many_arguments)]
+fn launch_concrete<'a, MS: MatmulSpec, R: Runtime, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &'a TensorHandleRef<'a, R>,
+    lhs_scale: &'a Option<TensorHandleRef<'a, R>>,
+    rhs: &'a TensorHandleRef<'a, R>,
+    rhs_scale: &'a Option<TensorHandleRef<'a, R>>,
+    input: InputRuntimeArg<'a, MS, R>,
+    output: OutputRuntimeArg<'a, MS, R>,
+    partials: Option<&'a TensorHandle<R, EA<MS>>>,
+    selection: &MatmulSelection,
+    problem: &MatmulProblem,
+    cube_count_plan: CubeCountPlan,
+    config: <A::BatchMatmul as BatchMatmulFamily>::Config,
+) -> Result<(), MatmulSetupError> {
+    match partials {
+        Some(partials) => launch_with_partials::<MS, R, A>(
+            client,
             lhs,
             lhs_scale,
             rhs,
             rhs_scale,
-            &selection,
-            &problem,
-            &line_sizes,
+            input,
+            output,
+            partials,
+            selection,
+            problem,
+            cube_count_plan,
+            config,
         ),
-        <OutputArg<MS> as ConcreteOutputFactory>::create(out, &selection, &problem, &line_sizes),
-        cube_count_plan.as_args(),
-        config,
-    )
+        None => launch_with_config::<MS, R, A>(
+            client,
+            config.cube_dim(),
+            cube_count_plan.resolve(),
+            input,
+            output,
+            cube_count_plan.as_args(),
+            config,
+        ),
+    }
 }
 
 /// Select which kernel to launch for the given Algorithm.
@@ -89,7 +222,14 @@ pub fn launch_kernel_virtual<'a, MS: MatmulSpec, R: Runtime, A: Algorithm>(
     };
     let config = A::setup::<MS::Precision, R>(client, &problem, &selection, &line_sizes)?;
 
-    let cube_count_plan = config.hypercube_config().cube_count_plan(
+    if A::BatchMatmul::num_partials(&config, &problem).is_some() {
+        return Err(MatmulSetupError::InvalidConfig(Box::new(
+            "Matmuls writing partial results are only supported with concrete inputs and output",
+        )));
+    }
+
+    let cube_count_plan = A::BatchMatmul::cube_count_plan(
+        &config,
         &problem,
         client.properties().hardware.max_cube_count.clone(),
     );
@@ -168,7 +168,7 @@ pub fn launch<R: Runtime, E: Numeric>(
             rhs.as_arg(vectorization_factor),
             out.as_tensor_arg(1),
             Some(ndims as u32 - 2),
-        );
+        )?;
     };
 
     Ok(())
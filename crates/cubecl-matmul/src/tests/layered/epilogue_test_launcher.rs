@@ -0,0 +1,328 @@
+use std::marker::PhantomData;
+
+use cubecl_core::CubeElement;
+use cubecl_core::prelude::*;
+
+use crate::components::AvailableLineSizes;
+use crate::components::MatmulIdent;
+use crate::components::MatmulProblem;
+use crate::components::MatmulSelection;
+use crate::components::batch::BatchConfig;
+use crate::components::batch::BatchMatmulFamily;
+use crate::components::global::args::{ConcreteInputsFactory, ConcreteOutputRef, TensorInputs};
+use crate::components::global::epilogue::{
+    Activation, BiasLayout, Epilogue, EpilogueArgs, EpilogueRef, EpilogueTensor,
+};
+use crate::kernels::layered::{Algorithm, launch_with_partials, partials_workspace};
+use crate::tests::test_utils::{
+    CastInto, Sample, TestPrecision, assert_equals_approx, matmul_cpu_reference, matmul_epsilon,
+};
+
+use super::matmul_test_launcher::tensor_raw_parts;
+
+/// Test the correctness of the specified Matmul with fused epilogues on the given device,
+/// against a naive CPU implementation over the given problem
+pub fn test_matmul_epilogue<A, P, R>(
+    client: ComputeClient<R::Server, R::Channel>,
+    problem: MatmulProblem,
+    selection: MatmulSelection,
+) where
+    A: Algorithm,
+    P: TestPrecision<EA = f32>,
+    P::EG: Float + CastInto<f32>,
+    f32: CastInto<P::EG>,
+    R: Runtime,
+{
+    let epilogues = [
+        (
+            Epilogue {
+                alpha: 0.5,
+                beta: -1.5,
+                bias_layout: BiasLayout::PerColumn,
+                activation: Activation::Gelu,
+                ..Default::default()
+            },
+            true,
+            true,
+        ),
+        (
+            Epilogue {
+                alpha: 2.0,
+                beta: 1.0,
+                bias_layout: BiasLayout::PerRow,
+                activation: Activation::Relu,
+                ..Default::default()
+            },
+            true,
+            false,
+        ),
+        (
+            Epilogue {
+                alpha: 1.0,
+                beta: 0.5,
+                bias_layout: BiasLayout::PerColumn,
+                activation: Activation::Silu,
+                ..Default::default()
+            },
+            false,
+            true,
+        ),
+    ];
+
+    for (epilogue, with_bias, with_residual) in epilogues {
+        test_epilogue::<A, P, R>(
+            &client,
+            &problem,
+            &selection,
+            &epilogue,
+            with_bias,
+            with_residual,
+        );
+    }
+}
+
+fn test_epilogue<A, P, R>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    problem: &MatmulProblem,
+    selection: &MatmulSelection,
+    epilogue: &Epilogue,
+    with_bias: bool,
+    with_residual: bool,
+) where
+    A: Algorithm,
+    P: TestPrecision<EA = f32>,
+    P::EG: Float + CastInto<f32>,
+    f32: CastInto<P::EG>,
+    R: Runtime,
+{
+    let env = std::env::var("MATMUL_TEST_MODE");
+
+    let panic_on_launch_err = match env {
+        Ok(val) => match val.as_str() {
+            "panic" => true,
+            "skip" => false,
+            _ => false,
+        },
+        Err(_) => false,
+    };
+    let lhs = tensor_raw_parts::<P, R>(client, problem, MatmulIdent::Lhs);
+    let rhs = tensor_raw_parts::<P, R>(client, problem, MatmulIdent::Rhs);
+    let out = tensor_raw_parts::<P, R>(client, problem, MatmulIdent::Out);
+
+    let bias_shape = match epilogue.bias_layout {
+        BiasLayout::PerRow => vec![problem.m],
+        BiasLayout::PerColumn => vec![problem.n],
+    };
+    let bias = with_bias.then(|| P::EG::sample::<R>(client, &bias_shape, 4321));
+    let residual = with_residual.then(|| P::EG::sample::<R>(client, &out.shape, 8765));
+
+    let elem_size = size_of::<P::EG>();
+    let out_handle = TensorHandleRef {
+        handle: &out.handle,
+        strides: &out.strides,
+        shape: &out.shape,
+        elem_size,
+        runtime: PhantomData,
+    };
+    let epilogue = Epilogue {
+        bias: bias.clone().map(EpilogueTensor::from),
+        residual: residual.clone().map(EpilogueTensor::from),
+        ..epilogue.clone()
+    };
+    let output = EpilogueRef::<R, P::EG>::new(&out_handle, &epilogue);
+    output.validate().unwrap();
+
+    let line_sizes = AvailableLineSizes::from_elem_types::<R>(
+        &P::EG::as_elem_native_unchecked(),
+        &P::EG::as_elem_native_unchecked(),
+    );
+    let line_sizes = A::filter_line_sizes(line_sizes);
+    let line_sizes = line_sizes
+        .filter_lhs_with_tensor(&lhs.strides, &lhs.shape, problem.lhs_layout)
+        .filter_rhs_with_tensor(&rhs.strides, &rhs.shape, problem.rhs_layout)
+        .filter_out_with_tensor(&out.strides, &out.shape)
+        .pick_max()
+        .unwrap();
+
+    let config = match A::setup::<(P::EG, P::ES, P::EA, P::EG), R>(
+        client,
+        problem,
+        selection,
+        &line_sizes,
+    ) {
+        Ok(config) => config,
+        Err(err) => {
+            let msg = format!("Can't launch the test: {err}");
+            if panic_on_launch_err {
+                panic!("{msg}");
+            } else {
+                println!("{msg}");
+                return;
+            }
+        }
+    };
+
+    let line_sizes = config.line_sizes();
+    let lhs_handle = TensorHandleRef {
+        handle: &lhs.handle,
+        strides: &lhs.strides,
+        shape: &lhs.shape,
+        elem_size,
+        runtime: PhantomData,
+    };
+    let rhs_handle = TensorHandleRef {
+        handle: &rhs.handle,
+        strides: &rhs.strides,
+        shape: &rhs.shape,
+        elem_size,
+        runtime: PhantomData,
+    };
+
+    let partials = A::BatchMatmul::num_partials(&config, problem).map(|num_partials| {
+        partials_workspace::<(P::EG, P::ES, P::EA, P::EG), R>(client, problem, num_partials)
+    });
+
+    let inputs = TensorInputs::create(
+        &lhs_handle,
+        &None,
+        &rhs_handle,
+        &None,
+        selection,
+        problem,
+        &line_sizes,
+    );
+    let cube_count_plan = A::BatchMatmul::cube_count_plan(
+        &config,
+        problem,
+        client.properties().hardware.max_cube_count.clone(),
+    );
+    let output = output.create::<P::EG>(selection, problem, &line_sizes);
+
+    match &partials {
+        Some(partials) => {
+            launch_with_partials::<((P::EG, P::ES, P::EA, P::EG), EpilogueArgs<P::EG>), R, A>(
+                client,
+                &lhs_handle,
+                &None,
+                &rhs_handle,
+                &None,
+                inputs,
+                output,
+                partials,
+                selection,
+                problem,
+                cube_count_plan,
+                config,
+            )
+            .unwrap()
+        }
+        None => unsafe {
+            A::BatchMatmul::launch_unchecked::<
+                ((P::EG, P::ES, P::EA, P::EG), EpilogueArgs<P::EG>),
+                R,
+            >(
+                client,
+                config.cube_dim(),
+                cube_count_plan.resolve(),
+                inputs,
+                output,
+                cube_count_plan.as_args(),
+                config,
+            )
+            .unwrap();
+        },
+    }
+
+    let read = |tensor: &Option<cubecl_std::tensor::TensorHandle<R, P::EG>>| {
+        tensor.as_ref().map(|it| {
+            let data = client.read_one(it.handle.clone().binding());
+            P::EG::from_bytes(&data)
+                .iter()
+                .map(|x| (*x).cast_into())
+                .collect::<Vec<f32>>()
+        })
+    };
+
+    let acc = matmul_cpu_reference::<P>(
+        &lhs.original_data.unwrap(),
+        &rhs.original_data.unwrap(),
+        problem,
+    );
+    let expected = epilogue_cpu_reference(
+        &acc,
+        problem,
+        &epilogue,
+        read(&bias).as_deref(),
+        read(&residual).as_deref(),
+    )
+    .into_iter()
+    .map(|x| x.cast_into())
+    .collect::<Vec<P::EG>>();
+
+    if let Err(e) = assert_equals_approx::<R, P::EG>(
+        client,
+        out.handle,
+        &out.shape,
+        &out.strides,
+        &expected,
+        matmul_epsilon::<R, P::EG, P::ES>(client),
+    ) {
+        panic!("{epilogue:?}: {e}");
+    }
+}
+
+/// Applies the epilogue to the contiguous matmul result.
+fn epilogue_cpu_reference(
+    acc: &[f32],
+    problem: &MatmulProblem,
+    epilogue: &Epilogue,
+    bias: Option<&[f32]>,
+    residual: Option<&[f32]>,
+) -> Vec<f32> {
+    acc.iter()
+        .enumerate()
+        .map(|(i, acc)| {
+            let row = (i / problem.n) % problem.m;
+            let col = i % problem.n;
+
+            let mut value = epilogue.alpha * acc;
+            if let Some(bias) = bias {
+                value += match epilogue.bias_layout {
+                    BiasLayout::PerRow => bias[row],
+                    BiasLayout::PerColumn => bias[col],
+                };
+            }
+
+            value = match epilogue.activation {
+                Activation::Identity => value,
+                Activation::Relu => value.max(0.0),
+                Activation::Gelu => 0.5 * value * (1.0 + erf(value / core::f32::consts::SQRT_2)),
+                Activation::Silu => value / (1.0 + (-value).exp()),
+                Activation::Sigmoid => 1.0 / (1.0 + (-value).exp()),
+                Activation::Tanh => value.tanh(),
+            };
+
+            if let Some(residual) = residual {
+                value += epilogue.beta * residual[i];
+            }
+
+            value
+        })
+        .collect()
+}
+
+/// Error function, using the approximation of Numerical Recipes (fractional error below 1.2e-7).
+fn erf(x: f32) -> f32 {
+    let z = x.abs() as f64;
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))));
+    let erfc = (t * poly.exp()) as f32;
+    if x >= 0.0 { 1.0 - erfc } else { erfc - 1.0 }
+}
@@ -0,0 +1,133 @@
+use std::marker::PhantomData;
+
+use cubecl_core::CubeElement;
+use cubecl_core::prelude::*;
+
+use crate::components::{MatmulIdent, MatmulProblem, MatrixLayout};
+use crate::kernels::layered::{Algorithm, Selection, launch_grouped_with_sizes};
+use crate::tests::test_utils::{
+    CastInto, Sample, TestPrecision, assert_equals_approx, matmul_cpu_reference, matmul_epsilon,
+};
+
+use super::matmul_test_launcher::tensor_raw_parts;
+
+/// Test the correctness of the grouped matmul of the specified algorithm on the given device,
+/// against a naive CPU implementation of each group
+pub fn test_grouped_matmul_algorithm<A, P, R>(
+    client: ComputeClient<R::Server, R::Channel>,
+    group_sizes: &[usize],
+    n: usize,
+    k: usize,
+    rhs_layout: MatrixLayout,
+) where
+    A: Algorithm,
+    P: TestPrecision,
+    P::EG: Float,
+    R: Runtime,
+{
+    let env = std::env::var("MATMUL_TEST_MODE");
+
+    let panic_on_launch_err = match env {
+        Ok(val) => match val.as_str() {
+            "panic" => true,
+            "skip" => false,
+            _ => false,
+        },
+        Err(_) => false,
+    };
+
+    let num_groups = group_sizes.len();
+    let m = group_sizes.iter().sum::<usize>();
+
+    let lhs = P::EG::sample::<R>(&client, &[m, k], 1234);
+    let lhs_data = P::EG::from_bytes(&client.read_one(lhs.handle.clone().binding())).to_owned();
+
+    // The rhs of all groups, seen as the batches of a regular matmul
+    let rhs_problem = MatmulProblem {
+        m,
+        n,
+        k,
+        lhs_batches: vec![num_groups],
+        rhs_batches: vec![num_groups],
+        lhs_layout: MatrixLayout::RowMajor,
+        rhs_layout,
+    };
+    let rhs = tensor_raw_parts::<P, R>(&client, &rhs_problem, MatmulIdent::Rhs);
+    let rhs_data = rhs.original_data.unwrap();
+
+    let out_shape = [m, n];
+    let out_strides = [n, 1];
+    let out = client.empty(m * n * size_of::<P::EG>());
+
+    let elem_size = size_of::<P::EG>();
+    let rhs_handle = TensorHandleRef {
+        handle: &rhs.handle,
+        strides: &rhs.strides,
+        shape: &rhs.shape,
+        elem_size,
+        runtime: PhantomData,
+    };
+    let out_handle = TensorHandleRef {
+        handle: &out,
+        strides: &out_strides,
+        shape: &out_shape,
+        elem_size,
+        runtime: PhantomData,
+    };
+
+    if let Err(err) = launch_grouped_with_sizes::<R, P::MP, A>(
+        &client,
+        &lhs.as_ref(),
+        &rhs_handle,
+        group_sizes,
+        &out_handle,
+        &Selection::default(),
+    ) {
+        let msg = format!("Can't launch the test: {err}");
+        if panic_on_launch_err {
+            panic!("{msg}");
+        } else {
+            println!("{msg}");
+            return;
+        }
+    }
+
+    let mut expected = Vec::with_capacity(m * n);
+    let mut row_start = 0;
+    for (group, &num_rows) in group_sizes.iter().enumerate() {
+        // Empty groups don't contribute any row
+        if num_rows == 0 {
+            continue;
+        }
+
+        let problem = MatmulProblem {
+            m: num_rows,
+            n,
+            k,
+            lhs_batches: vec![1],
+            rhs_batches: vec![1],
+            lhs_layout: MatrixLayout::RowMajor,
+            rhs_layout: MatrixLayout::RowMajor,
+        };
+        let group_lhs = &lhs_data[row_start * k..(row_start + num_rows) * k];
+        let group_rhs = &rhs_data[group * k * n..(group + 1) * k * n];
+
+        expected.extend(
+            matmul_cpu_reference::<P>(group_lhs, group_rhs, &problem)
+                .into_iter()
+                .map(|x| x.cast_into()),
+        );
+        row_start += num_rows;
+    }
+
+    if let Err(e) = assert_equals_approx::<R, P::EG>(
+        &client,
+        out,
+        &out_shape,
+        &out_strides,
+        &expected,
+        matmul_epsilon::<R, P::EG, P::ES>(&client),
+    ) {
+        panic!("Groups {group_sizes:?}: {e}");
+    }
+}
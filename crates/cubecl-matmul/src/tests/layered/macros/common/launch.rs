@@ -11,6 +11,17 @@ macro_rules! testgen_matmul_launch {
                 client, $problem, $selection,
             );
         }
+
+        #[cfg(feature = "matmul_tests_epilogue")]
+        #[test]
+        pub fn test_epilogue() {
+            let client = TestRuntime::client(&Default::default());
+            $crate::tests::layered::epilogue_test_launcher::test_matmul_epilogue::<
+                $algorithm,
+                $precision,
+                TestRuntime,
+            >(client, $problem, $selection);
+        }
     };
 
     (Unit, $algorithm: ty, $precision: ty, $selection: expr, $problem: expr) => {
@@ -24,6 +35,17 @@ macro_rules! testgen_matmul_launch {
                 client, $problem, $selection,
             );
         }
+
+        #[cfg(feature = "matmul_tests_epilogue")]
+        #[test]
+        pub fn test_epilogue() {
+            let client = TestRuntime::client(&Default::default());
+            $crate::tests::layered::epilogue_test_launcher::test_matmul_epilogue::<
+                $algorithm,
+                $precision,
+                TestRuntime,
+            >(client, $problem, $selection);
+        }
     };
 
     (Tma, $algorithm: ty, $precision: ty, $selection: expr, $problem: expr) => {
@@ -61,5 +61,25 @@ macro_rules! testgen_matmul_problem_size {
                 }
             );
         }
+
+        #[cfg(feature = "matmul_tests_alt_shapes")]
+        mod g16x32x1024 {
+            use super::*;
+            $crate::testgen_matmul_launch!(
+                $kind,
+                $algorithm,
+                $precision,
+                $selection,
+                MatmulProblem {
+                    m: 16,
+                    n: 32,
+                    k: 1024,
+                    lhs_batches: vec![2],
+                    rhs_batches: vec![2],
+                    lhs_layout: $layouts.0,
+                    rhs_layout: $layouts.1,
+                }
+            );
+        }
     };
 }
@@ -0,0 +1,43 @@
+#[macro_export]
+macro_rules! testgen_matmul_grouped {
+    ($algorithm: ty, $precision: ty) => {
+        use $crate::components::MatrixLayout;
+        use $crate::tests::layered::grouped_test_launcher::test_grouped_matmul_algorithm;
+
+        #[test]
+        pub fn single_group() {
+            let client = TestRuntime::client(&Default::default());
+            test_grouped_matmul_algorithm::<$algorithm, $precision, TestRuntime>(
+                client,
+                &[32],
+                32,
+                32,
+                MatrixLayout::RowMajor,
+            );
+        }
+
+        #[test]
+        pub fn uneven_groups() {
+            let client = TestRuntime::client(&Default::default());
+            test_grouped_matmul_algorithm::<$algorithm, $precision, TestRuntime>(
+                client,
+                &[5, 0, 33, 16, 1],
+                24,
+                40,
+                MatrixLayout::RowMajor,
+            );
+        }
+
+        #[test]
+        pub fn uneven_groups_rhs_col_major() {
+            let client = TestRuntime::client(&Default::default());
+            test_grouped_matmul_algorithm::<$algorithm, $precision, TestRuntime>(
+                client,
+                &[17, 3, 0, 40],
+                32,
+                24,
+                MatrixLayout::ColMajor,
+            );
+        }
+    };
+}
@@ -1,4 +1,6 @@
 pub mod common;
+pub mod grouped;
 pub mod plane_accelerated;
+pub mod quantized;
 pub mod tma;
 pub mod unit;
@@ -5,7 +5,9 @@ macro_rules! testgen_matmul_plane_accelerated_algorithm {
             simple::SimpleAlgorithm,
             simple_barrier::SimpleBarrierAlgorithm,
             double_buffering::{CyclicDoubleBufferingAlgorithm, TilewiseDoubleBufferingAlgorithm, HybridDoubleBufferingAlgorithm},
-            ordered_double_buffering::OrderedDoubleBufferingAlgorithm
+            ordered_double_buffering::OrderedDoubleBufferingAlgorithm,
+            split_k::SplitKAlgorithm,
+            stream_k::StreamKAlgorithm
         };
         use $crate::components::global::load::{
             async_full_cyclic,
@@ -96,5 +98,33 @@ macro_rules! testgen_matmul_plane_accelerated_algorithm {
 
             $crate::testgen_matmul_accelerated_precision!(OrderedDoubleBufferingAlgorithm<TMM>);
         }
+
+        #[cfg(feature = "matmul_tests_split_k")]
+        mod split_k {
+            use super::*;
+
+            $crate::testgen_matmul_accelerated_precision!(SplitKAlgorithm<SimpleAlgorithm<TMM>>);
+        }
+
+        #[cfg(feature = "matmul_tests_stream_k")]
+        mod stream_k {
+            use super::*;
+
+            $crate::testgen_matmul_accelerated_precision!(StreamKAlgorithm<CyclicDoubleBufferingAlgorithm<TMM>>);
+        }
+
+        #[cfg(feature = "matmul_tests_grouped")]
+        mod grouped {
+            use super::*;
+
+            $crate::testgen_matmul_grouped!(SimpleAlgorithm<TMM>, (f32, f32));
+        }
+
+        #[cfg(feature = "matmul_tests_quantized")]
+        mod quantized {
+            use super::*;
+
+            $crate::testgen_matmul_quantized!(SimpleAlgorithm<TMM>, half::f16);
+        }
     };
 }
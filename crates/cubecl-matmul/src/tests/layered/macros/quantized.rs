@@ -0,0 +1,91 @@
+#[macro_export]
+macro_rules! testgen_matmul_quantized {
+    ($algorithm: ty, $es: ty) => {
+        use $crate::components::MatrixLayout;
+        use $crate::components::global::QuantizedStorage;
+        use $crate::tests::layered::quantized_test_launcher::{
+            QuantizedOperand, ScaleGranularity, test_quantized_matmul_algorithm,
+        };
+
+        #[test]
+        pub fn per_tensor_symmetric() {
+            let client = TestRuntime::client(&Default::default());
+            test_quantized_matmul_algorithm::<$algorithm, $es, TestRuntime>(
+                client,
+                (32, 32, 64),
+                QuantizedOperand::default(),
+                QuantizedOperand::default(),
+            );
+        }
+
+        #[test]
+        pub fn per_channel_asymmetric() {
+            let client = TestRuntime::client(&Default::default());
+            let operand = QuantizedOperand {
+                granularity: ScaleGranularity::PerChannel,
+                asymmetric: true,
+                ..Default::default()
+            };
+            test_quantized_matmul_algorithm::<$algorithm, $es, TestRuntime>(
+                client,
+                (32, 32, 64),
+                operand,
+                operand,
+            );
+        }
+
+        #[test]
+        pub fn block_32_symmetric() {
+            let client = TestRuntime::client(&Default::default());
+            let operand = QuantizedOperand {
+                granularity: ScaleGranularity::BlockK(32),
+                ..Default::default()
+            };
+            test_quantized_matmul_algorithm::<$algorithm, $es, TestRuntime>(
+                client,
+                (32, 32, 128),
+                operand,
+                operand,
+            );
+        }
+
+        #[test]
+        pub fn packed_int4_rhs_col_major_block_32_asymmetric() {
+            let client = TestRuntime::client(&Default::default());
+            test_quantized_matmul_algorithm::<$algorithm, $es, TestRuntime>(
+                client,
+                (32, 32, 64),
+                QuantizedOperand {
+                    granularity: ScaleGranularity::PerChannel,
+                    ..Default::default()
+                },
+                QuantizedOperand {
+                    layout: MatrixLayout::ColMajor,
+                    granularity: ScaleGranularity::BlockK(32),
+                    asymmetric: true,
+                    storage: QuantizedStorage::PackedInt4,
+                },
+            );
+        }
+
+        #[test]
+        pub fn packed_int4_lhs_out_of_bounds() {
+            let client = TestRuntime::client(&Default::default());
+            let operand = QuantizedOperand {
+                granularity: ScaleGranularity::BlockK(32),
+                asymmetric: true,
+                storage: QuantizedStorage::PackedInt4,
+                ..Default::default()
+            };
+            test_quantized_matmul_algorithm::<$algorithm, $es, TestRuntime>(
+                client,
+                (40, 24, 96),
+                operand,
+                QuantizedOperand {
+                    asymmetric: true,
+                    ..Default::default()
+                },
+            );
+        }
+    };
+}
@@ -3,6 +3,8 @@ macro_rules! testgen_matmul_unit_algorithm {
     () => {
         use $crate::kernels::layered::double_unit::DoubleUnitAlgorithm;
         use $crate::kernels::layered::simple_unit::SimpleUnitAlgorithm;
+        use $crate::kernels::layered::split_k::SplitKAlgorithm;
+        use $crate::kernels::layered::stream_k::StreamKAlgorithm;
 
         #[cfg(feature = "matmul_tests_simple")]
         mod simple {
@@ -17,5 +19,33 @@ macro_rules! testgen_matmul_unit_algorithm {
 
             $crate::testgen_matmul_unit_precision!(DoubleUnitAlgorithm);
         }
+
+        #[cfg(feature = "matmul_tests_split_k")]
+        mod split_k {
+            use super::*;
+
+            $crate::testgen_matmul_unit_precision!(SplitKAlgorithm<SimpleUnitAlgorithm>);
+        }
+
+        #[cfg(feature = "matmul_tests_stream_k")]
+        mod stream_k {
+            use super::*;
+
+            $crate::testgen_matmul_unit_precision!(StreamKAlgorithm<DoubleUnitAlgorithm>);
+        }
+
+        #[cfg(feature = "matmul_tests_grouped")]
+        mod grouped {
+            use super::*;
+
+            $crate::testgen_matmul_grouped!(SimpleUnitAlgorithm, (f32, f32));
+        }
+
+        #[cfg(feature = "matmul_tests_quantized")]
+        mod quantized {
+            use super::*;
+
+            $crate::testgen_matmul_quantized!(SimpleUnitAlgorithm, f32);
+        }
     };
 }
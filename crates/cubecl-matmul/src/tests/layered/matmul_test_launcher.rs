@@ -1,3 +1,5 @@
+use std::marker::PhantomData;
+
 use cubecl_core::prelude::*;
 use cubecl_core::{CubeElement, server};
 
@@ -6,7 +8,7 @@ use crate::components::batch::{BatchConfig, BatchMatmulFamily};
 use crate::components::global::args::TensorInputsLaunch;
 use crate::components::{AvailableLineSizes, MatmulIdent};
 use crate::components::{MatmulProblem, MatmulSelection};
-use crate::kernels::layered::Algorithm;
+use crate::kernels::layered::{Algorithm, launch_with_partials, partials_workspace};
 use crate::tests::test_utils::Sample;
 use crate::tests::test_utils::TestPrecision;
 
@@ -75,47 +77,89 @@ pub fn test_matmul_algorithm<A, P, R>(
         }
     };
 
-    let cube_count_plan = config.hypercube_config().cube_count_plan(
+    let cube_count_plan = A::BatchMatmul::cube_count_plan(
+        &config,
         &problem,
         client.properties().hardware.max_cube_count.clone(),
     );
 
-    unsafe {
-        A::BatchMatmul::launch_unchecked::<P::MP, R>(
-            &client,
-            config.cube_dim(),
-            cube_count_plan.resolve(),
-            TensorInputsLaunch::new(
-                TensorArg::<R>::from_raw_parts::<P::EG>(
-                    &lhs.handle,
-                    &lhs.strides,
-                    &lhs.shape,
-                    line_sizes.lhs,
-                ),
-                lhs.scale
-                    .as_ref()
-                    .map(|it| TensorArg::<R>::from_raw_parts::<P::EG>(it, &[1], &[1], 1))
-                    .into(),
-                TensorArg::<R>::from_raw_parts::<P::EG>(
-                    &rhs.handle,
-                    &rhs.strides,
-                    &rhs.shape,
-                    line_sizes.rhs,
-                ),
-                rhs.scale
-                    .as_ref()
-                    .map(|it| TensorArg::<R>::from_raw_parts::<P::EG>(it, &[1], &[1], 1))
-                    .into(),
+    let partials = A::BatchMatmul::num_partials(&config, &problem)
+        .map(|num_partials| partials_workspace::<P::MP, R>(&client, &problem, num_partials));
+
+    let inputs = unsafe {
+        TensorInputsLaunch::new(
+            TensorArg::<R>::from_raw_parts::<P::EG>(
+                &lhs.handle,
+                &lhs.strides,
+                &lhs.shape,
+                line_sizes.lhs,
             ),
+            lhs.scale
+                .as_ref()
+                .map(|it| TensorArg::<R>::from_raw_parts::<P::EG>(it, &[1], &[1], 1))
+                .into(),
             TensorArg::<R>::from_raw_parts::<P::EG>(
-                &out.handle,
-                &out.strides,
-                &out.shape,
-                line_sizes.out,
+                &rhs.handle,
+                &rhs.strides,
+                &rhs.shape,
+                line_sizes.rhs,
             ),
-            cube_count_plan.as_args(),
-            config,
-        );
+            rhs.scale
+                .as_ref()
+                .map(|it| TensorArg::<R>::from_raw_parts::<P::EG>(it, &[1], &[1], 1))
+                .into(),
+        )
+    };
+    let output = unsafe {
+        TensorArg::<R>::from_raw_parts::<P::EG>(
+            &out.handle,
+            &out.strides,
+            &out.shape,
+            line_sizes.out,
+        )
+    };
+
+    match &partials {
+        Some(partials) => {
+            let elem_size = size_of::<P::EG>();
+            let handle_ref = |handle, strides, shape| TensorHandleRef {
+                handle,
+                strides,
+                shape,
+                elem_size,
+                runtime: PhantomData,
+            };
+            let lhs_scale = lhs.scale.as_ref().map(|it| handle_ref(it, &[1], &[1]));
+            let rhs_scale = rhs.scale.as_ref().map(|it| handle_ref(it, &[1], &[1]));
+
+            launch_with_partials::<P::MP, R, A>(
+                &client,
+                &handle_ref(&lhs.handle, &lhs.strides, &lhs.shape),
+                &lhs_scale,
+                &handle_ref(&rhs.handle, &rhs.strides, &rhs.shape),
+                &rhs_scale,
+                inputs,
+                output,
+                partials,
+                &selection,
+                &problem,
+                cube_count_plan,
+                config,
+            )
+            .unwrap();
+        }
+        None => unsafe {
+            A::BatchMatmul::launch_unchecked::<P::MP, R>(
+                &client,
+                config.cube_dim(),
+                cube_count_plan.resolve(),
+                inputs,
+                output,
+                cube_count_plan.as_args(),
+                config,
+            )
+            .unwrap();
+        },
     }
 
     P::assert_result::<R>(
@@ -132,7 +176,7 @@ pub fn test_matmul_algorithm<A, P, R>(
     );
 }
 
-fn tensor_raw_parts<P: TestPrecision, R: Runtime>(
+pub(crate) fn tensor_raw_parts<P: TestPrecision, R: Runtime>(
     client: &ComputeClient<R::Server, R::Channel>,
     problem: &MatmulProblem,
     ident: MatmulIdent,
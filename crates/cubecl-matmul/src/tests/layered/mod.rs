@@ -1,3 +1,6 @@
+pub mod epilogue_test_launcher;
+pub mod grouped_test_launcher;
 mod macros;
 pub mod matmul_test_launcher;
+pub mod quantized_test_launcher;
 pub mod tma_test_launcher;
@@ -0,0 +1,314 @@
+use std::marker::PhantomData;
+
+use cubecl_core::CubeElement;
+use cubecl_core::prelude::*;
+use cubecl_std::{AsymQ8, Q4};
+
+use crate::components::global::{QuantizedStorage, QuantizedTensorRef};
+use crate::components::{MatmulIdent, MatrixLayout};
+use crate::kernels::layered::{Algorithm, Selection, launch_quantized_ref};
+use crate::tests::test_utils::{CastInto, assert_equals_approx, matmul_epsilon};
+
+#[derive(Clone, Copy, Debug)]
+/// How many scales a quantized input has.
+pub enum ScaleGranularity {
+    PerTensor,
+    /// One scale per row of lhs, or per column of rhs.
+    PerChannel,
+    /// One scale per block of the given number of elements along k.
+    BlockK(usize),
+}
+
+#[derive(Clone, Copy, Debug)]
+/// Description of a quantized input to test.
+pub struct QuantizedOperand {
+    pub layout: MatrixLayout,
+    pub granularity: ScaleGranularity,
+    pub asymmetric: bool,
+    pub storage: QuantizedStorage,
+}
+
+impl Default for QuantizedOperand {
+    fn default() -> Self {
+        Self {
+            layout: MatrixLayout::RowMajor,
+            granularity: ScaleGranularity::PerTensor,
+            asymmetric: false,
+            storage: QuantizedStorage::Int8,
+        }
+    }
+}
+
+/// Test the correctness of the quantized matmul of the specified algorithm on the given device,
+/// against a naive CPU matmul of the dequantized inputs.
+pub fn test_quantized_matmul_algorithm<A, ES, R>(
+    client: ComputeClient<R::Server, R::Channel>,
+    (m, n, k): (usize, usize, usize),
+    lhs: QuantizedOperand,
+    rhs: QuantizedOperand,
+) where
+    A: Algorithm,
+    ES: Float + CastInto<f32>,
+    R: Runtime,
+{
+    let env = std::env::var("MATMUL_TEST_MODE");
+
+    let panic_on_launch_err = match env {
+        Ok(val) => match val.as_str() {
+            "panic" => true,
+            "skip" => false,
+            _ => false,
+        },
+        Err(_) => false,
+    };
+
+    let mut rng = XorShift(0x2545F4914F6CDD1D);
+    let lhs_data = QuantizedData::generate::<ES>(&mut rng, MatmulIdent::Lhs, m, k, &lhs);
+    let rhs_data = QuantizedData::generate::<ES>(&mut rng, MatmulIdent::Rhs, k, n, &rhs);
+
+    let lhs_handles = lhs_data.upload::<R>(&client);
+    let rhs_handles = rhs_data.upload::<R>(&client);
+
+    let out_shape = [m, n];
+    let out_strides = [n, 1];
+    let out = client.empty(m * n * size_of::<f32>());
+    let out_handle = TensorHandleRef {
+        handle: &out,
+        strides: &out_strides,
+        shape: &out_shape,
+        elem_size: size_of::<f32>(),
+        runtime: PhantomData,
+    };
+
+    let lhs_values = lhs_handles.values();
+    let lhs_scales = lhs_handles.scales();
+    let lhs_zero_points = lhs_handles.zero_points();
+    let rhs_values = rhs_handles.values();
+    let rhs_scales = rhs_handles.scales();
+    let rhs_zero_points = rhs_handles.zero_points();
+
+    if let Err(err) = launch_quantized_ref::<R, (i8, ES, f32, f32), A>(
+        &client,
+        &QuantizedTensorRef {
+            values: &lhs_values,
+            scales: &lhs_scales,
+            zero_points: lhs_zero_points.as_ref(),
+            storage: lhs.storage,
+        },
+        &QuantizedTensorRef {
+            values: &rhs_values,
+            scales: &rhs_scales,
+            zero_points: rhs_zero_points.as_ref(),
+            storage: rhs.storage,
+        },
+        &out_handle,
+        &Selection::default(),
+    ) {
+        let msg = format!("Can't launch the test: {err}");
+        if panic_on_launch_err {
+            panic!("{msg}");
+        } else {
+            println!("{msg}");
+            return;
+        }
+    }
+
+    let mut expected = vec![0.0; m * n];
+    for i in 0..m {
+        for j in 0..n {
+            expected[i * n + j] = (0..k)
+                .map(|l| lhs_data.dequantized[i * k + l] * rhs_data.dequantized[l * n + j])
+                .sum();
+        }
+    }
+
+    if let Err(e) = assert_equals_approx::<R, f32>(
+        &client,
+        out,
+        &out_shape,
+        &out_strides,
+        &expected,
+        matmul_epsilon::<R, f32, ES>(&client),
+    ) {
+        panic!("Lhs {lhs:?}, rhs {rhs:?}: {e}");
+    }
+}
+
+/// Deterministic generator, so that failures are reproducible.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn int(&mut self, min: i8, max: i8) -> i8 {
+        (min as i64 + (self.next() % (max as i64 - min as i64 + 1) as u64) as i64) as i8
+    }
+
+    fn float(&mut self, min: f32, max: f32) -> f32 {
+        min + (self.next() % 1024) as f32 / 1024.0 * (max - min)
+    }
+}
+
+/// A quantized matrix, with its logical row-major dequantized values.
+struct QuantizedData {
+    operand: QuantizedOperand,
+    rows: usize,
+    cols: usize,
+    /// Quantized values in memory order, before packing
+    values: Vec<i8>,
+    scales_shape: [usize; 2],
+    scales: Vec<f32>,
+    zero_points: Option<Vec<f32>>,
+    /// Dequantized values in row-major order, rounded to the stage precision
+    dequantized: Vec<f32>,
+}
+
+impl QuantizedData {
+    fn generate<ES: Float + CastInto<f32>>(
+        rng: &mut XorShift,
+        ident: MatmulIdent,
+        rows: usize,
+        cols: usize,
+        operand: &QuantizedOperand,
+    ) -> Self {
+        let scales_shape = match (operand.granularity, ident) {
+            (ScaleGranularity::PerTensor, _) => [1, 1],
+            (ScaleGranularity::PerChannel, MatmulIdent::Lhs) => [rows, 1],
+            (ScaleGranularity::PerChannel, _) => [1, cols],
+            (ScaleGranularity::BlockK(block), MatmulIdent::Lhs) => [rows, cols / block],
+            (ScaleGranularity::BlockK(block), _) => [rows / block, cols],
+        };
+        let block_rows = rows / scales_shape[0];
+        let block_cols = cols / scales_shape[1];
+
+        // Keep dequantized values around one
+        let (min, max, max_scale) = match operand.storage {
+            QuantizedStorage::Int8 => (i8::MIN, i8::MAX, 1.0 / 64.0),
+            QuantizedStorage::PackedInt4 => (Q4::MIN, Q4::MAX, 1.0 / 4.0),
+        };
+
+        let num_scales = scales_shape[0] * scales_shape[1];
+        let scales: Vec<f32> = (0..num_scales)
+            .map(|_| rng.float(max_scale / 4.0, max_scale))
+            .collect();
+        let zero_points = operand.asymmetric.then(|| {
+            (0..num_scales)
+                .map(|_| rng.int(-4, 4) as f32)
+                .collect::<Vec<_>>()
+        });
+        let values: Vec<i8> = (0..rows * cols).map(|_| rng.int(min, max)).collect();
+
+        let mut dequantized = vec![0.0; rows * cols];
+        for row in 0..rows {
+            for col in 0..cols {
+                let index = match operand.layout {
+                    MatrixLayout::RowMajor => row * cols + col,
+                    MatrixLayout::ColMajor => col * rows + row,
+                };
+                let scale_index = (row / block_rows) * scales_shape[1] + col / block_cols;
+                let scale = scales[scale_index];
+                let zero_point = zero_points.as_ref().map_or(0.0, |it| it[scale_index]);
+
+                let value = match operand.storage {
+                    QuantizedStorage::Int8 => AsymQ8::dequantize(values[index], scale, zero_point),
+                    QuantizedStorage::PackedInt4 => {
+                        Q4::dequantize(values[index], scale, zero_point)
+                    }
+                };
+                dequantized[row * cols + col] = ES::new(value).cast_into();
+            }
+        }
+
+        Self {
+            operand: *operand,
+            rows,
+            cols,
+            values,
+            scales_shape,
+            scales,
+            zero_points,
+            dequantized,
+        }
+    }
+
+    fn upload<R: Runtime>(&self, client: &ComputeClient<R::Server, R::Channel>) -> UploadedData {
+        let (bytes, rows, cols) = match self.operand.storage {
+            QuantizedStorage::Int8 => (self.values.clone(), self.rows, self.cols),
+            QuantizedStorage::PackedInt4 => {
+                let bytes = self
+                    .values
+                    .chunks(2)
+                    .map(|pair| Q4::pack(pair[0], pair[1]))
+                    .collect();
+                match self.operand.layout {
+                    MatrixLayout::RowMajor => (bytes, self.rows, self.cols / 2),
+                    MatrixLayout::ColMajor => (bytes, self.rows / 2, self.cols),
+                }
+            }
+        };
+        let strides = match self.operand.layout {
+            MatrixLayout::RowMajor => vec![cols, 1],
+            MatrixLayout::ColMajor => vec![1, rows],
+        };
+
+        UploadedData {
+            values: client.create(i8::as_bytes(&bytes)),
+            shape: vec![rows, cols],
+            strides,
+            scales: client.create(f32::as_bytes(&self.scales)),
+            scales_shape: self.scales_shape.to_vec(),
+            scales_strides: vec![self.scales_shape[1], 1],
+            zero_points: self
+                .zero_points
+                .as_ref()
+                .map(|it| client.create(f32::as_bytes(it))),
+        }
+    }
+}
+
+struct UploadedData {
+    values: cubecl_core::server::Handle,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    scales: cubecl_core::server::Handle,
+    scales_shape: Vec<usize>,
+    scales_strides: Vec<usize>,
+    zero_points: Option<cubecl_core::server::Handle>,
+}
+
+impl UploadedData {
+    fn values<R: Runtime>(&self) -> TensorHandleRef<'_, R> {
+        TensorHandleRef {
+            handle: &self.values,
+            strides: &self.strides,
+            shape: &self.shape,
+            elem_size: size_of::<i8>(),
+            runtime: PhantomData,
+        }
+    }
+
+    fn scales<R: Runtime>(&self) -> TensorHandleRef<'_, R> {
+        TensorHandleRef {
+            handle: &self.scales,
+            strides: &self.scales_strides,
+            shape: &self.scales_shape,
+            elem_size: size_of::<f32>(),
+            runtime: PhantomData,
+        }
+    }
+
+    fn zero_points<R: Runtime>(&self) -> Option<TensorHandleRef<'_, R>> {
+        self.zero_points.as_ref().map(|handle| TensorHandleRef {
+            handle,
+            strides: &self.scales_strides,
+            shape: &self.scales_shape,
+            elem_size: size_of::<f32>(),
+            runtime: PhantomData,
+        })
+    }
+}
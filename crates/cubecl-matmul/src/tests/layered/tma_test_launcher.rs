@@ -107,7 +107,8 @@ pub fn test_tma_matmul_algorithm<A, P, R>(
             line_sizes.out,
         )
     };
-    let cube_count_plan = config.hypercube_config().cube_count_plan(
+    let cube_count_plan = A::BatchMatmul::cube_count_plan(
+        &config,
         &problem,
         client.properties().hardware.max_cube_count.clone(),
     );
@@ -121,7 +122,8 @@ pub fn test_tma_matmul_algorithm<A, P, R>(
             output,
             cube_count_plan.as_args(),
             config,
-        );
+        )
+        .unwrap();
     }
 
     P::assert_result::<R>(
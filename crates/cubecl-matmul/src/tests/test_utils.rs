@@ -74,28 +74,7 @@ where
         shape: &[usize],
         strides: &[usize],
     ) {
-        let maybe_f16 = client.properties().feature_enabled(Feature::Cmma {
-            a: ES::as_elem_native().expect("To be a native type"),
-            b: ES::as_elem_native().expect("To be a native type"),
-            c: EG::as_elem_native().expect("To be a native type"),
-            m: 16,
-            k: 16,
-            n: 16,
-        });
-        let maybe_tf32 = client.properties().feature_enabled(Feature::Cmma {
-            a: ES::as_elem_native().expect("To be a native type"),
-            b: ES::as_elem_native().expect("To be a native type"),
-            c: EG::as_elem_native().expect("To be a native type"),
-            m: 16,
-            k: 8,
-            n: 16,
-        });
-
-        // Need to compensate for the temporary conversion to f16/tf32
-        let epsilon = match maybe_f16 || maybe_tf32 {
-            true => 10e-5 / EG::EPSILON.to_f32().unwrap() * half::f16::EPSILON.to_f32(),
-            false => 10e-5,
-        };
+        let epsilon = matmul_epsilon::<R, EG, ES>(client);
 
         let expected = matmul_cpu_reference::<Self>(lhs, rhs, problem)
             .into_iter()
@@ -110,6 +89,34 @@ where
     }
 }
 
+/// Tolerance of a matmul with EG inputs and outputs, multiplied as ES.
+pub(crate) fn matmul_epsilon<R: Runtime, EG: Float, ES: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+) -> f32 {
+    let maybe_f16 = client.properties().feature_enabled(Feature::Cmma {
+        a: ES::as_elem_native().expect("To be a native type"),
+        b: ES::as_elem_native().expect("To be a native type"),
+        c: EG::as_elem_native().expect("To be a native type"),
+        m: 16,
+        k: 16,
+        n: 16,
+    });
+    let maybe_tf32 = client.properties().feature_enabled(Feature::Cmma {
+        a: ES::as_elem_native().expect("To be a native type"),
+        b: ES::as_elem_native().expect("To be a native type"),
+        c: EG::as_elem_native().expect("To be a native type"),
+        m: 16,
+        k: 8,
+        n: 16,
+    });
+
+    // Need to compensate for the temporary conversion to f16/tf32
+    match maybe_f16 || maybe_tf32 {
+        true => 10e-5 / EG::EPSILON.to_f32().unwrap() * half::f16::EPSILON.to_f32(),
+        false => 10e-5,
+    }
+}
+
 /// Compares the content of a handle to a given slice of f32.
 pub(crate) fn assert_equals_approx<R: Runtime, F: Float + CubeElement + Display>(
     client: &ComputeClient<R::Server, R::Channel>,
@@ -366,7 +373,7 @@ macro_rules! sample_float {
                     cubecl_random::seed(seed);
                     let output = TensorHandle::<R, Self>::empty(client, shape.to_vec());
 
-                    cubecl_random::random_uniform::<R, Self>(&client, Self::from_int(-1), Self::from_int(1), output.as_ref());
+                    cubecl_random::random_uniform::<R, Self>(&client, Self::from_int(-1), Self::from_int(1), output.as_ref()).unwrap();
 
                     output
                 }
@@ -395,7 +402,8 @@ impl Sample for flex32 {
             f32::from_int(-1),
             f32::from_int(1),
             output.as_ref(),
-        );
+        )
+        .unwrap();
 
         output
     }
@@ -415,7 +423,8 @@ impl Sample for tf32 {
             f32::from_int(-1),
             f32::from_int(1),
             output.as_ref(),
-        );
+        )
+        .unwrap();
 
         output
     }
@@ -67,6 +67,17 @@ pub fn should_tune_double_buffering(fused: bool, key: &MatmulAutotuneKey) -> boo
         }
 }
 
+/// Whether it's a good idea to try and run matmuls partitioning K across cubes, i.e. Split-K and
+/// Stream-K. They only pay off when K is large and the output alone doesn't fill the device.
+pub fn should_tune_k_partitioning(key: &MatmulAutotuneKey) -> bool {
+    let MatmulProblemDefinition { m, n, k, .. } = key.definition;
+    let num_output_tiles = m.div_ceil(64) * n.div_ceil(64);
+
+    matches!(key.analysis.kind, MatmulKind::General)
+        && k >= 1024
+        && (num_output_tiles < 256 || k >= 8 * m.max(n))
+}
+
 impl MatmulAutotuneKey {
     /// Create the autotune key based on the shape of both lhs and rhs as well as the element type
     /// used for the calculation.
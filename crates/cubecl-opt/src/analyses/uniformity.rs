@@ -67,17 +67,23 @@ impl Uniformity {
                     | Plane::Ballot(_) => self.mark_uniformity(out, block_uniform)?,
                     // Broadcast maps to shuffle or broadcast, if id or value is uniform, so will
                     // the output, otherwise not.
-                    Plane::Broadcast(op) => {
+                    Plane::Broadcast(op) | Plane::Shuffle(op) => {
                         let input_uniform =
                             self.is_var_uniform(op.lhs) || self.is_var_uniform(op.rhs);
                         self.mark_uniformity(out, input_uniform && block_uniform)?;
                     }
+                    // Relative shuffles read a different unit for each unit, so the output is
+                    // only uniform if the value is.
+                    Plane::ShuffleXor(op) | Plane::ShuffleUp(op) | Plane::ShuffleDown(op) => {
+                        let input_uniform = self.is_var_uniform(op.lhs);
+                        self.mark_uniformity(out, input_uniform && block_uniform)?;
+                    }
                 },
                 Operation::Synchronization(sync) => match sync {
                     Synchronization::SyncCube | Synchronization::SyncStorage => {
                         block_uniform = true;
                     }
-                    Synchronization::SyncProxyShared => {}
+                    Synchronization::SyncProxyShared | Synchronization::DeviceFence => {}
                     Synchronization::SyncPlane => {
                         // TODO: not sure
                     }
@@ -70,6 +70,28 @@ impl Optimizer {
                 let loop_break = self.loop_break.back().expect("Can't break outside loop");
                 self.program.add_edge(current_block, *loop_break, 0);
             }
+            Branch::Continue => {
+                let current_block = self.current_block.take().unwrap();
+                let loop_continue = self.loop_continue_target();
+                self.program.add_edge(current_block, loop_continue, 0);
+            }
+        }
+    }
+
+    /// The continue target of the current loop, created if it doesn't exist yet.
+    fn loop_continue_target(&mut self) -> NodeIndex {
+        let loop_continue = self
+            .loop_continue
+            .back()
+            .copied()
+            .expect("Can't continue outside loop");
+        match loop_continue {
+            Some(target) => target,
+            None => {
+                let target = self.program.add_node(BasicBlock::default());
+                *self.loop_continue.back_mut().unwrap() = Some(target);
+                target
+            }
         }
     }
 
@@ -225,16 +247,19 @@ impl Optimizer {
 
         self.program.add_edge(header, body, 0);
 
-        self.loop_break.push_back(next);
-
-        self.current_block = Some(body);
-        self.parse_scope(loop_.scope);
         let continue_target = self.program.add_node(BasicBlock::default());
         self.program[continue_target]
             .block_use
             .push(BlockUse::ContinueTarget);
 
+        self.loop_break.push_back(next);
+        self.loop_continue.push_back(Some(continue_target));
+
+        self.current_block = Some(body);
+        self.parse_scope(loop_.scope);
+
         self.loop_break.pop_back();
+        self.loop_continue.pop_back();
 
         if let Some(current_block) = self.current_block {
             self.program.add_edge(current_block, continue_target, 0);
@@ -275,23 +300,35 @@ impl Optimizer {
         self.program.add_edge(header, next, 0);
 
         self.loop_break.push_back(next);
+        self.loop_continue.push_back(None);
 
         self.current_block = Some(body);
         self.parse_scope(range_loop.scope);
 
         self.loop_break.pop_back();
+        let loop_continue = self.loop_continue.pop_back().unwrap();
 
-        let current_block = self.current_block.expect("For loop has no loopback path");
-
-        let continue_target = if self.program[current_block]
-            .block_use
-            .contains(&BlockUse::Merge)
-        {
-            let target = self.program.add_node(BasicBlock::default());
-            self.program.add_edge(current_block, target, 0);
-            target
-        } else {
-            current_block
+        let continue_target = match loop_continue {
+            // The body used `continue`, so the increment needs its own block
+            Some(target) => {
+                if let Some(current_block) = self.current_block {
+                    self.program.add_edge(current_block, target, 0);
+                }
+                target
+            }
+            None => {
+                let current_block = self.current_block.expect("For loop has no loopback path");
+                if self.program[current_block]
+                    .block_use
+                    .contains(&BlockUse::Merge)
+                {
+                    let target = self.program.add_node(BasicBlock::default());
+                    self.program.add_edge(current_block, target, 0);
+                    target
+                } else {
+                    current_block
+                }
+            }
         };
 
         self.program.add_edge(continue_target, header, 0);
@@ -325,7 +362,7 @@ impl Optimizer {
                 merge: next,
             };
         }
-        self.program[current_block]
+        self.program[continue_target]
             .ops
             .borrow_mut()
             .push(Instruction::new(
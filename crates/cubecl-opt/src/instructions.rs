@@ -75,6 +75,8 @@ impl Optimizer {
             | Arithmetic::Mul(binary_operator)
             | Arithmetic::Div(binary_operator)
             | Arithmetic::Powf(binary_operator)
+            | Arithmetic::Hypot(binary_operator)
+            | Arithmetic::ArcTan2(binary_operator)
             | Arithmetic::Modulo(binary_operator)
             | Arithmetic::Max(binary_operator)
             | Arithmetic::Min(binary_operator)
@@ -84,12 +86,25 @@ impl Optimizer {
 
             Arithmetic::Abs(unary_operator)
             | Arithmetic::Exp(unary_operator)
+            | Arithmetic::Exp2(unary_operator)
             | Arithmetic::Log(unary_operator)
             | Arithmetic::Log1p(unary_operator)
+            | Arithmetic::Log2(unary_operator)
+            | Arithmetic::Log10(unary_operator)
             | Arithmetic::Cos(unary_operator)
             | Arithmetic::Sin(unary_operator)
+            | Arithmetic::Tan(unary_operator)
             | Arithmetic::Tanh(unary_operator)
+            | Arithmetic::Sinh(unary_operator)
+            | Arithmetic::Cosh(unary_operator)
+            | Arithmetic::ArcCos(unary_operator)
+            | Arithmetic::ArcSin(unary_operator)
+            | Arithmetic::ArcTan(unary_operator)
+            | Arithmetic::ArcSinh(unary_operator)
+            | Arithmetic::ArcCosh(unary_operator)
+            | Arithmetic::ArcTanh(unary_operator)
             | Arithmetic::Sqrt(unary_operator)
+            | Arithmetic::InverseSqrt(unary_operator)
             | Arithmetic::Round(unary_operator)
             | Arithmetic::Floor(unary_operator)
             | Arithmetic::Ceil(unary_operator)
@@ -253,7 +268,11 @@ impl Optimizer {
     fn visit_plane(&mut self, plane: &mut Plane, visit_read: impl FnMut(&mut Self, &mut Variable)) {
         match plane {
             Plane::Elect => {}
-            Plane::Broadcast(binary_operator) => self.visit_binop(binary_operator, visit_read),
+            Plane::Broadcast(binary_operator)
+            | Plane::Shuffle(binary_operator)
+            | Plane::ShuffleXor(binary_operator)
+            | Plane::ShuffleUp(binary_operator)
+            | Plane::ShuffleDown(binary_operator) => self.visit_binop(binary_operator, visit_read),
             Plane::All(unary_operator)
             | Plane::Any(unary_operator)
             | Plane::Sum(unary_operator)
@@ -141,6 +141,8 @@ pub struct Optimizer {
     current_block: Option<NodeIndex>,
     /// The current loop's break target
     loop_break: VecDeque<NodeIndex>,
+    /// The current loop's continue target, created on the first `continue` for range loops
+    loop_continue: VecDeque<Option<NodeIndex>>,
     /// The single return block
     pub ret: NodeIndex,
     /// Root scope to allocate variables on
@@ -149,6 +151,8 @@ pub struct Optimizer {
     pub(crate) cube_dim: CubeDim,
     /// The execution mode, `Unchecked` skips bounds check optimizations.
     pub(crate) mode: ExecutionMode,
+    /// The buffer out-of-bounds accesses are recorded into in validation mode
+    pub(crate) error_buffer: Option<Variable>,
     pub(crate) transformers: Vec<Rc<dyn IrTransformer>>,
 }
 
@@ -159,10 +163,12 @@ impl Default for Optimizer {
             allocator: Default::default(),
             current_block: Default::default(),
             loop_break: Default::default(),
+            loop_continue: Default::default(),
             ret: Default::default(),
             root_scope: Scope::root(false),
             cube_dim: Default::default(),
             mode: Default::default(),
+            error_buffer: None,
             analysis_cache: Default::default(),
             transformers: Default::default(),
         }
@@ -177,11 +183,24 @@ impl Optimizer {
         cube_dim: CubeDim,
         mode: ExecutionMode,
         transformers: Vec<Rc<dyn IrTransformer>>,
+    ) -> Self {
+        Self::with_error_buffer(expand, cube_dim, mode, transformers, None)
+    }
+
+    /// Create a new optimizer that records out-of-bounds accesses into `error_buffer` when `mode`
+    /// is [`ExecutionMode::Validate`].
+    pub(crate) fn with_error_buffer(
+        expand: Scope,
+        cube_dim: CubeDim,
+        mode: ExecutionMode,
+        transformers: Vec<Rc<dyn IrTransformer>>,
+        error_buffer: Option<Variable>,
     ) -> Self {
         let mut opt = Self {
             root_scope: expand.clone(),
             cube_dim,
             mode,
+            error_buffer,
             allocator: expand.allocator.clone(),
             transformers,
             ..Default::default()
@@ -349,7 +368,8 @@ impl Optimizer {
 
     /// Recursively parse a scope into the graph
     pub fn parse_scope(&mut self, mut scope: Scope) -> bool {
-        let checked_io: Box<dyn Processor> = Box::new(CheckedIoProcessor::new(self.mode));
+        let checked_io: Box<dyn Processor> =
+            Box::new(CheckedIoProcessor::new(self.mode, self.error_buffer));
         let processed = scope.process([checked_io]);
 
         for var in processed.variables {
@@ -370,7 +390,9 @@ impl Optimizer {
             });
         }
 
-        let is_break = processed.instructions.contains(&Branch::Break.into());
+        // Continue leaves the current scope just like break, so it's treated the same way
+        let is_break = processed.instructions.contains(&Branch::Break.into())
+            || processed.instructions.contains(&Branch::Continue.into());
 
         for mut instruction in processed.instructions {
             let mut removed = false;
@@ -296,6 +296,8 @@ fn try_const_eval_arithmetic(op: &mut Arithmetic) -> Option<ConstantScalarValue>
         Arithmetic::Mul(op) => const_eval!(*op.lhs, op.rhs),
         Arithmetic::Div(op) => const_eval!(/ op.lhs, op.rhs),
         Arithmetic::Powf(op) => const_eval_float!(op.lhs, op.rhs; num::Float::powf),
+        Arithmetic::Hypot(op) => const_eval_float!(op.lhs, op.rhs; num::Float::hypot),
+        Arithmetic::ArcTan2(op) => const_eval_float!(op.lhs, op.rhs; num::Float::atan2),
         Arithmetic::Modulo(op) => const_eval!(% op.lhs, op.rhs),
         Arithmetic::Remainder(op) => const_eval!(% op.lhs, op.rhs),
         Arithmetic::MulHi(op) => {
@@ -364,12 +366,25 @@ fn try_const_eval_arithmetic(op: &mut Arithmetic) -> Option<ConstantScalarValue>
             })
         }
         Arithmetic::Exp(op) => const_eval_float!(op.input; num::Float::exp),
+        Arithmetic::Exp2(op) => const_eval_float!(op.input; num::Float::exp2),
         Arithmetic::Log(op) => const_eval_float!(op.input; num::Float::ln),
         Arithmetic::Log1p(op) => const_eval_float!(op.input; num::Float::ln_1p),
+        Arithmetic::Log2(op) => const_eval_float!(op.input; num::Float::log2),
+        Arithmetic::Log10(op) => const_eval_float!(op.input; num::Float::log10),
         Arithmetic::Cos(op) => const_eval_float!(op.input; num::Float::cos),
         Arithmetic::Sin(op) => const_eval_float!(op.input; num::Float::sin),
+        Arithmetic::Tan(op) => const_eval_float!(op.input; num::Float::tan),
         Arithmetic::Tanh(op) => const_eval_float!(op.input; num::Float::tanh),
+        Arithmetic::Sinh(op) => const_eval_float!(op.input; num::Float::sinh),
+        Arithmetic::Cosh(op) => const_eval_float!(op.input; num::Float::cosh),
+        Arithmetic::ArcCos(op) => const_eval_float!(op.input; num::Float::acos),
+        Arithmetic::ArcSin(op) => const_eval_float!(op.input; num::Float::asin),
+        Arithmetic::ArcTan(op) => const_eval_float!(op.input; num::Float::atan),
+        Arithmetic::ArcSinh(op) => const_eval_float!(op.input; num::Float::asinh),
+        Arithmetic::ArcCosh(op) => const_eval_float!(op.input; num::Float::acosh),
+        Arithmetic::ArcTanh(op) => const_eval_float!(op.input; num::Float::atanh),
         Arithmetic::Sqrt(op) => const_eval_float!(op.input; num::Float::sqrt),
+        Arithmetic::InverseSqrt(op) => const_eval_float!(op.input; inverse_sqrt),
         Arithmetic::Round(op) => const_eval_float!(op.input; num::Float::round),
         Arithmetic::Floor(op) => const_eval_float!(op.input; num::Float::floor),
         Arithmetic::Ceil(op) => const_eval_float!(op.input; num::Float::ceil),
@@ -427,6 +442,10 @@ fn try_const_eval_arithmetic(op: &mut Arithmetic) -> Option<ConstantScalarValue>
     }
 }
 
+fn inverse_sqrt(input: f64) -> f64 {
+    num::Float::recip(num::Float::sqrt(input))
+}
+
 fn try_const_eval_cmp(op: &mut Comparison) -> Option<ConstantScalarValue> {
     match op {
         Comparison::Equal(op) => const_eval_cmp!(== op.lhs, op.rhs),
@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use cubecl_common::{CubeDim, ExecutionMode};
-use cubecl_ir::{Instruction, Scope};
+use cubecl_ir::{Instruction, Scope, Variable};
 
 use crate::Optimizer;
 
@@ -9,6 +9,7 @@ use crate::Optimizer;
 #[derive(Debug, Default)]
 pub struct OptimizerBuilder {
     transformers: Vec<Rc<dyn IrTransformer>>,
+    error_buffer: Option<Variable>,
 }
 
 impl OptimizerBuilder {
@@ -18,9 +19,15 @@ impl OptimizerBuilder {
         self
     }
 
+    /// Set the buffer out-of-bounds accesses are recorded into when validating
+    pub fn with_error_buffer(mut self, error_buffer: Option<Variable>) -> Self {
+        self.error_buffer = error_buffer;
+        self
+    }
+
     /// Build and run optimizer on the scope
     pub fn optimize(self, expand: Scope, cube_dim: CubeDim, mode: ExecutionMode) -> Optimizer {
-        Optimizer::new(expand, cube_dim, mode, self.transformers)
+        Optimizer::with_error_buffer(expand, cube_dim, mode, self.transformers, self.error_buffer)
     }
 }
 
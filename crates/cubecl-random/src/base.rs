@@ -19,7 +19,7 @@ pub(crate) fn random<F: RandomFamily, E: Numeric, R: Runtime>(
     client: &ComputeClient<R::Server, R::Channel>,
     prng: F::Runtime<E>,
     output: TensorHandleRef<'_, R>,
-) {
+) -> Result<(), CompilationError> {
     let seeds = get_seeds();
     let args = prng.args();
 
@@ -50,7 +50,7 @@ pub(crate) fn random<F: RandomFamily, E: Numeric, R: Runtime>(
         args,
         N_VALUES_PER_THREAD as u32,
         output_line_size as u32,
-    );
+    )
 }
 
 fn prng_cube_count(num_elems: usize, cube_dim: CubeDim, n_values_per_thread: usize) -> CubeCount {
@@ -194,3 +194,19 @@ pub fn to_unit_interval_open(int_random: u32) -> f32 {
     let shifted = int_random >> 9;
     (f32::cast_from(shifted) + 1.0) / 8388609.0 // 2^23 + 1
 }
+
+/// Advances the combined generators of a unit and returns the next random `u32`.
+#[cube]
+pub(crate) fn next_random(
+    state_0: &mut u32,
+    state_1: &mut u32,
+    state_2: &mut u32,
+    state_3: &mut u32,
+) -> u32 {
+    *state_0 = taus_step_0(*state_0);
+    *state_1 = taus_step_1(*state_1);
+    *state_2 = taus_step_2(*state_2);
+    *state_3 = lcg_step(*state_3);
+
+    *state_0 ^ *state_1 ^ *state_2 ^ *state_3
+}
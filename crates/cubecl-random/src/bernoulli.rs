@@ -5,7 +5,7 @@ use cubecl_core as cubecl;
 
 use cubecl::{CubeLaunch, CubeType, Runtime};
 
-use crate::RandomFamily;
+use crate::{Philox, PhiloxFamily, PhiloxRuntime, RandomFamily, random_philox};
 
 use super::{
     PrngArgs, PrngRuntime, lcg_step, random, taus_step_0, taus_step_1, taus_step_2,
@@ -26,6 +26,10 @@ impl RandomFamily for BernoulliFamily {
     type Runtime<E: Numeric> = Bernoulli<E>;
 }
 
+impl PhiloxFamily for BernoulliFamily {
+    type Runtime<E: Numeric> = Bernoulli<E>;
+}
+
 #[cube]
 impl<E: Numeric> PrngRuntime<E> for Bernoulli<E> {
     fn inner_loop(
@@ -66,6 +70,14 @@ impl<E: Numeric> PrngRuntime<E> for Bernoulli<E> {
     }
 }
 
+#[cube]
+impl<E: Numeric> PhiloxRuntime<E> for Bernoulli<E> {
+    fn sample(args: &Bernoulli<E>, words: &Line<u32>, lane: u32) -> E {
+        let float_random = to_unit_interval_closed_open(words[lane]);
+        E::cast_from(float_random < args.probability)
+    }
+}
+
 impl<E: Numeric> PrngArgs<E> for Bernoulli<E> {
     type Args = Self;
 
@@ -79,7 +91,7 @@ pub fn random_bernoulli<R: Runtime, E: Numeric>(
     client: &ComputeClient<R::Server, R::Channel>,
     probability: f32,
     out: TensorHandleRef<R>,
-) {
+) -> Result<(), CompilationError> {
     assert_eq!(
         out.elem_size as u32,
         E::elem_size(),
@@ -95,3 +107,27 @@ pub fn random_bernoulli<R: Runtime, E: Numeric>(
         out,
     )
 }
+
+/// Counter-based generator with bernoulli distribution, see [Philox].
+pub fn random_bernoulli_philox<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    state: &mut Philox,
+    probability: f32,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    assert_eq!(
+        out.elem_size as u32,
+        E::elem_size(),
+        "Tensor element type must be the same as type E"
+    );
+
+    random_philox::<BernoulliFamily, E, R>(
+        client,
+        state,
+        Bernoulli::<E> {
+            probability,
+            _phantom: PhantomData,
+        },
+        out,
+    )
+}
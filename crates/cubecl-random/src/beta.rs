@@ -0,0 +1,89 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+use crate::{RandomFamily, sample_gamma};
+
+use super::{PrngArgs, PrngRuntime, random};
+
+#[derive(CubeLaunch, CubeType)]
+pub(crate) struct Beta<E: Numeric> {
+    alpha: E,
+    beta: E,
+}
+
+#[derive(Debug)]
+struct BetaFamily;
+
+impl RandomFamily for BetaFamily {
+    type Runtime<E: Numeric> = Beta<E>;
+}
+
+#[cube]
+impl<E: Numeric> PrngRuntime<E> for Beta<E> {
+    fn inner_loop(
+        args: Beta<E>,
+        write_index_base: u32,
+        n_invocations: u32,
+        #[comptime] n_values_per_thread: u32,
+        #[comptime] line_size: u32,
+        state_0: &mut u32,
+        state_1: &mut u32,
+        state_2: &mut u32,
+        state_3: &mut u32,
+        output: &mut Tensor<Line<E>>,
+    ) {
+        let alpha = f32::cast_from(args.alpha);
+        let beta = f32::cast_from(args.beta);
+
+        let mut output_line = Line::empty(line_size);
+
+        let num_iterations = n_values_per_thread / line_size;
+        #[unroll(num_iterations <= 8)]
+        for line_index in 0..num_iterations {
+            // vectorization
+            #[unroll]
+            for i in 0..line_size {
+                // X / (X + Y) follows Beta(alpha, beta) when X ~ Gamma(alpha) and Y ~ Gamma(beta).
+                let x = sample_gamma(alpha, state_0, state_1, state_2, state_3);
+                let y = sample_gamma(beta, state_0, state_1, state_2, state_3);
+                output_line[i] = E::cast_from(x / (x + y));
+            }
+
+            let write_index = line_index * n_invocations + write_index_base;
+
+            output[write_index] = output_line;
+        }
+    }
+}
+
+impl<E: Numeric> PrngArgs<E> for Beta<E> {
+    type Args = Self;
+
+    fn args<'a, R: Runtime>(self) -> BetaLaunch<'a, E, R> {
+        BetaLaunch::new(ScalarArg::new(self.alpha), ScalarArg::new(self.beta))
+    }
+}
+
+/// Pseudo-random generator with beta distribution
+pub fn random_beta<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    alpha: E,
+    beta: E,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    assert_eq!(
+        out.elem_size as u32,
+        E::elem_size(),
+        "Tensor element type must be the same as type E"
+    );
+    assert!(
+        alpha.to_f32().is_some_and(|alpha| alpha > 0.0),
+        "The alpha parameter of the beta distribution must be positive"
+    );
+    assert!(
+        beta.to_f32().is_some_and(|beta| beta > 0.0),
+        "The beta parameter of the beta distribution must be positive"
+    );
+
+    random::<BetaFamily, E, R>(client, Beta { alpha, beta }, out)
+}
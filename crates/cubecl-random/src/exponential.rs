@@ -0,0 +1,111 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+use crate::{
+    Philox, PhiloxFamily, PhiloxRuntime, RandomFamily, next_random, random_philox,
+    to_unit_interval_open,
+};
+
+use super::{PrngArgs, PrngRuntime, random};
+
+#[derive(CubeLaunch, CubeType)]
+pub(crate) struct Exponential<E: Numeric> {
+    rate: E,
+}
+
+#[derive(Debug)]
+struct ExponentialFamily;
+
+impl RandomFamily for ExponentialFamily {
+    type Runtime<E: Numeric> = Exponential<E>;
+}
+
+impl PhiloxFamily for ExponentialFamily {
+    type Runtime<E: Numeric> = Exponential<E>;
+}
+
+#[cube]
+impl<E: Numeric> PrngRuntime<E> for Exponential<E> {
+    fn inner_loop(
+        args: Exponential<E>,
+        write_index_base: u32,
+        n_invocations: u32,
+        #[comptime] n_values_per_thread: u32,
+        #[comptime] line_size: u32,
+        state_0: &mut u32,
+        state_1: &mut u32,
+        state_2: &mut u32,
+        state_3: &mut u32,
+        output: &mut Tensor<Line<E>>,
+    ) {
+        let mut output_line = Line::empty(line_size);
+
+        let num_iterations = n_values_per_thread / line_size;
+        #[unroll(num_iterations <= 8)]
+        for line_index in 0..num_iterations {
+            // vectorization
+            #[unroll]
+            for i in 0..line_size {
+                let int_random = next_random(state_0, state_1, state_2, state_3);
+                output_line[i] = exponential_sample(&args, int_random);
+            }
+
+            let write_index = line_index * n_invocations + write_index_base;
+
+            output[write_index] = output_line;
+        }
+    }
+}
+
+#[cube]
+impl<E: Numeric> PhiloxRuntime<E> for Exponential<E> {
+    fn sample(args: &Exponential<E>, words: &Line<u32>, lane: u32) -> E {
+        exponential_sample(args, words[lane])
+    }
+}
+
+/// Inverse transform sampling, the unit interval is open so the logarithm stays finite.
+#[cube]
+fn exponential_sample<E: Numeric>(args: &Exponential<E>, int_random: u32) -> E {
+    let f32_random = to_unit_interval_open(int_random);
+    E::cast_from(-Log::log(f32_random) / f32::cast_from(args.rate))
+}
+
+impl<E: Numeric> PrngArgs<E> for Exponential<E> {
+    type Args = Self;
+
+    fn args<'a, R: Runtime>(self) -> ExponentialLaunch<'a, E, R> {
+        ExponentialLaunch::new(ScalarArg::new(self.rate))
+    }
+}
+
+/// Pseudo-random generator with exponential distribution
+pub fn random_exponential<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    rate: E,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    assert_eq!(
+        out.elem_size as u32,
+        E::elem_size(),
+        "Tensor element type must be the same as type E"
+    );
+
+    random::<ExponentialFamily, E, R>(client, Exponential { rate }, out)
+}
+
+/// Counter-based generator with exponential distribution, see [Philox].
+pub fn random_exponential_philox<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    state: &mut Philox,
+    rate: E,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    assert_eq!(
+        out.elem_size as u32,
+        E::elem_size(),
+        "Tensor element type must be the same as type E"
+    );
+
+    random_philox::<ExponentialFamily, E, R>(client, state, Exponential { rate }, out)
+}
@@ -0,0 +1,143 @@
+use std::f32::consts::PI;
+
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+use crate::{RandomFamily, next_random, to_unit_interval_open};
+
+use super::{PrngArgs, PrngRuntime, random};
+
+#[derive(CubeLaunch, CubeType)]
+pub(crate) struct Gamma<E: Numeric> {
+    shape: E,
+    scale: E,
+}
+
+#[derive(Debug)]
+struct GammaFamily;
+
+impl RandomFamily for GammaFamily {
+    type Runtime<E: Numeric> = Gamma<E>;
+}
+
+#[cube]
+impl<E: Numeric> PrngRuntime<E> for Gamma<E> {
+    fn inner_loop(
+        args: Gamma<E>,
+        write_index_base: u32,
+        n_invocations: u32,
+        #[comptime] n_values_per_thread: u32,
+        #[comptime] line_size: u32,
+        state_0: &mut u32,
+        state_1: &mut u32,
+        state_2: &mut u32,
+        state_3: &mut u32,
+        output: &mut Tensor<Line<E>>,
+    ) {
+        let shape = f32::cast_from(args.shape);
+        let scale = f32::cast_from(args.scale);
+
+        let mut output_line = Line::empty(line_size);
+
+        let num_iterations = n_values_per_thread / line_size;
+        #[unroll(num_iterations <= 8)]
+        for line_index in 0..num_iterations {
+            // vectorization
+            #[unroll]
+            for i in 0..line_size {
+                let gamma = sample_gamma(shape, state_0, state_1, state_2, state_3);
+                output_line[i] = E::cast_from(gamma * scale);
+            }
+
+            let write_index = line_index * n_invocations + write_index_base;
+
+            output[write_index] = output_line;
+        }
+    }
+}
+
+/// Samples the standard normal distribution with the Box-Muller transform.
+#[cube]
+pub(crate) fn sample_standard_normal(
+    state_0: &mut u32,
+    state_1: &mut u32,
+    state_2: &mut u32,
+    state_3: &mut u32,
+) -> f32 {
+    let unit_0 = to_unit_interval_open(next_random(state_0, state_1, state_2, state_3));
+    let unit_1 = to_unit_interval_open(next_random(state_0, state_1, state_2, state_3));
+
+    Sqrt::sqrt(Log::log(unit_0) * -2.0) * f32::cos(2.0 * PI * unit_1)
+}
+
+/// Samples the gamma distribution with unit scale using the rejection method of Marsaglia and
+/// Tsang, "A simple method for generating gamma variables", 2000.
+#[cube]
+pub(crate) fn sample_gamma(
+    shape: f32,
+    state_0: &mut u32,
+    state_1: &mut u32,
+    state_2: &mut u32,
+    state_3: &mut u32,
+) -> f32 {
+    // The method requires a shape of at least 1, smaller shapes are sampled with `shape + 1` and
+    // scaled by `U^(1 / shape)`.
+    let boosted = shape < 1.0;
+    let d = select(boosted, shape + 1.0, shape) - 1.0 / 3.0;
+    let c = 1.0 / Sqrt::sqrt(9.0 * d);
+
+    let mut gamma = 0.0f32;
+    let mut accepted = false;
+
+    while !accepted {
+        let x = sample_standard_normal(state_0, state_1, state_2, state_3);
+        let v = 1.0 + c * x;
+
+        if v > 0.0 {
+            let v = v * v * v;
+            let unit = to_unit_interval_open(next_random(state_0, state_1, state_2, state_3));
+
+            accepted = Log::log(unit) < 0.5 * x * x + d - d * v + d * Log::log(v);
+            gamma = d * v;
+        }
+    }
+
+    if boosted {
+        let unit = to_unit_interval_open(next_random(state_0, state_1, state_2, state_3));
+        gamma *= Exp::exp(Log::log(unit) / shape);
+    }
+
+    gamma
+}
+
+impl<E: Numeric> PrngArgs<E> for Gamma<E> {
+    type Args = Self;
+
+    fn args<'a, R: Runtime>(self) -> GammaLaunch<'a, E, R> {
+        GammaLaunch::new(ScalarArg::new(self.shape), ScalarArg::new(self.scale))
+    }
+}
+
+/// Pseudo-random generator with gamma distribution
+pub fn random_gamma<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    shape: E,
+    scale: E,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    assert_eq!(
+        out.elem_size as u32,
+        E::elem_size(),
+        "Tensor element type must be the same as type E"
+    );
+    assert!(
+        shape.to_f32().is_some_and(|shape| shape > 0.0),
+        "The shape of the gamma distribution must be positive"
+    );
+    assert!(
+        scale.to_f32().is_some_and(|scale| scale > 0.0),
+        "The scale of the gamma distribution must be positive"
+    );
+
+    random::<GammaFamily, E, R>(client, Gamma { shape, scale }, out)
+}
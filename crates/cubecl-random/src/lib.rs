@@ -1,13 +1,27 @@
 mod base;
 mod bernoulli;
+mod beta;
+mod exponential;
+mod gamma;
+mod multinomial;
 mod normal;
+mod permutation;
+mod philox;
 mod tests_utils;
+mod truncated_normal;
 mod uniform;
 
 pub use base::*;
 pub use bernoulli::*;
+pub use beta::*;
+pub use exponential::*;
+pub use gamma::*;
+pub use multinomial::*;
 pub use normal::*;
+pub use permutation::*;
+pub use philox::*;
 pub use tests_utils::*;
+pub use truncated_normal::*;
 pub use uniform::*;
 
 #[cfg(feature = "export_tests")]
@@ -0,0 +1,249 @@
+use core::marker::PhantomData;
+
+use cubecl::prelude::*;
+use cubecl_core::{self as cubecl, calculate_cube_count_elemwise};
+
+use crate::{
+    PHILOX_VALUES_PER_COUNTER, Philox, philox_words, to_unit_interval_closed_open,
+    to_unit_interval_open,
+};
+
+/// Counter-based sampling of category indices, see [Philox].
+///
+/// The last dimension of `probabilities` holds the weights of the categories, which don't need
+/// to sum to one. The last dimension of `out` is the number of samples drawn for each row of
+/// weights, the other dimensions matching the ones of `probabilities`. Without replacement, a
+/// category isn't drawn again once selected and the number of samples can't exceed the number of
+/// categories, categories of weight zero being drawn last.
+pub fn random_multinomial<R: Runtime, F: Float, I: Int>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    state: &mut Philox,
+    probabilities: TensorHandleRef<R>,
+    replacement: bool,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    assert_eq!(
+        probabilities.elem_size as u32,
+        F::elem_size(),
+        "Probabilities element type must be the same as type F"
+    );
+    assert_eq!(
+        out.elem_size as u32,
+        I::elem_size(),
+        "Tensor element type must be the same as type I"
+    );
+
+    let rank = probabilities.shape.len();
+    assert_eq!(
+        out.shape.len(),
+        rank,
+        "Output must have the same rank as the probabilities"
+    );
+    assert_eq!(
+        out.shape[..rank - 1],
+        probabilities.shape[..rank - 1],
+        "Output must have the same batch dimensions as the probabilities"
+    );
+
+    let num_categories = probabilities.shape[rank - 1];
+    let num_samples = out.shape[rank - 1];
+    let num_rows = out.size() / num_samples.max(1);
+
+    let cube_dim = CubeDim::default();
+
+    let num_counters = if replacement {
+        let num_draws = num_rows * num_samples;
+
+        multinomial_kernel::launch::<F, I, R>(
+            client,
+            calculate_cube_count_elemwise(num_draws, cube_dim),
+            cube_dim,
+            probabilities.as_tensor_arg(1),
+            out.as_tensor_arg(1),
+            ScalarArg::new(state.seed as u32),
+            ScalarArg::new((state.seed >> 32) as u32),
+            ScalarArg::new(state.offset as u32),
+            ScalarArg::new((state.offset >> 32) as u32),
+        )?;
+
+        num_draws
+    } else {
+        assert!(
+            num_samples <= num_categories,
+            "Can't draw {num_samples} samples from {num_categories} categories without replacement"
+        );
+
+        multinomial_no_replacement_kernel::launch::<F, I, R>(
+            client,
+            calculate_cube_count_elemwise(num_rows, cube_dim),
+            cube_dim,
+            probabilities.as_tensor_arg(1),
+            out.as_tensor_arg(1),
+            ScalarArg::new(state.seed as u32),
+            ScalarArg::new((state.seed >> 32) as u32),
+            ScalarArg::new(state.offset as u32),
+            ScalarArg::new((state.offset >> 32) as u32),
+        )?;
+
+        num_rows * num_categories
+    };
+
+    state.advance(num_counters * PHILOX_VALUES_PER_COUNTER);
+
+    Ok(())
+}
+
+/// Counter-based sampling of one category index for each row of `probabilities`, see
+/// [random_multinomial].
+///
+/// The output has the shape of `probabilities` without its last dimension.
+pub fn random_categorical<R: Runtime, F: Float, I: Int>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    state: &mut Philox,
+    probabilities: TensorHandleRef<R>,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    // A single sample per row, as the last dimension of the output.
+    let shape = [out.shape, &[1]].concat();
+    let strides = [out.strides, &[1]].concat();
+    let out = TensorHandleRef {
+        handle: out.handle,
+        strides: &strides,
+        shape: &shape,
+        elem_size: out.elem_size,
+        runtime: PhantomData,
+    };
+
+    random_multinomial::<R, F, I>(client, state, probabilities, true, out)
+}
+
+/// Offset of the first element of a row, where rows are indexed over all dimensions but the last.
+#[cube]
+fn row_offset<T: CubePrimitive>(tensor: &Tensor<T>, row: u32) -> u32 {
+    let rank = tensor.rank();
+    let mut remainder = row;
+    let mut offset = 0;
+
+    for i in 1..rank {
+        let dim = rank - 1 - i;
+        offset += remainder % tensor.shape(dim) * tensor.stride(dim);
+        remainder /= tensor.shape(dim);
+    }
+
+    offset
+}
+
+/// One unit per sample, drawing a category by inverse transform sampling of the cumulative
+/// weights of its row.
+#[cube(launch)]
+fn multinomial_kernel<F: Float, I: Int>(
+    probabilities: &Tensor<F>,
+    output: &mut Tensor<I>,
+    key_0: u32,
+    key_1: u32,
+    offset_0: u32,
+    offset_1: u32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let rank = output.rank();
+    let num_categories = probabilities.shape(rank - 1);
+    let num_samples = output.shape(rank - 1);
+    let row = ABSOLUTE_POS / num_samples;
+    let sample = ABSOLUTE_POS % num_samples;
+
+    let probabilities_offset = row_offset(probabilities, row);
+    let probabilities_stride = probabilities.stride(rank - 1);
+
+    // Rounding errors may leave the cumulative sum below the drawn value, which then selects the
+    // last category that can be drawn.
+    let mut total = 0.0f32;
+    let mut last_nonzero = 0;
+    for i in 0..num_categories {
+        let probability =
+            f32::cast_from(probabilities[probabilities_offset + i * probabilities_stride]);
+        total += probability;
+        if probability > 0.0 {
+            last_nonzero = i;
+        }
+    }
+
+    let words = philox_words(ABSOLUTE_POS, key_0, key_1, offset_0, offset_1);
+    let target = to_unit_interval_closed_open(words[0]) * total;
+
+    let mut cumulative = 0.0f32;
+    let mut category = last_nonzero;
+    for i in 0..num_categories {
+        cumulative +=
+            f32::cast_from(probabilities[probabilities_offset + i * probabilities_stride]);
+        if cumulative > target {
+            category = i;
+            break;
+        }
+    }
+
+    let output_offset = row_offset(output, row) + sample * output.stride(rank - 1);
+    output[output_offset] = I::cast_from(category);
+}
+
+/// One unit per row, drawing the categories in decreasing order of the keys `ln(u) / p` of
+/// Efraimidis and Spirakis, "Weighted random sampling with a reservoir", 2006.
+///
+/// The keys are regenerated from their counters at each pass instead of being stored, every pass
+/// selecting the largest key ordered after the previously selected one.
+#[cube(launch)]
+fn multinomial_no_replacement_kernel<F: Float, I: Int>(
+    probabilities: &Tensor<F>,
+    output: &mut Tensor<I>,
+    key_0: u32,
+    key_1: u32,
+    offset_0: u32,
+    offset_1: u32,
+) {
+    let rank = output.rank();
+    let num_categories = probabilities.shape(rank - 1);
+    let num_samples = output.shape(rank - 1);
+    let row = ABSOLUTE_POS;
+
+    if row * num_samples >= output.len() {
+        terminate!();
+    }
+
+    let probabilities_offset = row_offset(probabilities, row);
+    let probabilities_stride = probabilities.stride(rank - 1);
+    let output_offset = row_offset(output, row);
+    let output_stride = output.stride(rank - 1);
+
+    let mut previous_key = 0.0f32;
+    let mut previous_category = 0;
+
+    for sample in 0..num_samples {
+        let mut best_key = 0.0f32;
+        let mut best_category = num_categories;
+
+        for i in 0..num_categories {
+            let probability =
+                f32::cast_from(probabilities[probabilities_offset + i * probabilities_stride]);
+            let words = philox_words(row * num_categories + i, key_0, key_1, offset_0, offset_1);
+            // Categories of weight zero get a key of negative infinity, ties being ordered by
+            // index.
+            let key = Log::log(to_unit_interval_open(words[0])) / probability;
+
+            let remaining =
+                sample == 0 || key < previous_key || (key == previous_key && i > previous_category);
+            let better = best_category == num_categories || key > best_key;
+
+            if remaining && better {
+                best_key = key;
+                best_category = i;
+            }
+        }
+
+        output[output_offset + sample * output_stride] = I::cast_from(best_category);
+
+        previous_key = best_key;
+        previous_category = best_category;
+    }
+}
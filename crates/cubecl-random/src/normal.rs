@@ -4,7 +4,10 @@ use std::f32::consts::PI;
 
 use super::{PrngArgs, PrngRuntime, random};
 
-use crate::{RandomFamily, lcg_step, taus_step_0, taus_step_1, taus_step_2, to_unit_interval_open};
+use crate::{
+    Philox, PhiloxFamily, PhiloxRuntime, RandomFamily, lcg_step, random_philox, taus_step_0,
+    taus_step_1, taus_step_2, to_unit_interval_open,
+};
 
 #[derive(CubeLaunch, CubeType)]
 pub(crate) struct Normal<E: Numeric> {
@@ -19,6 +22,10 @@ impl RandomFamily for NormalFamily {
     type Runtime<E: Numeric> = Normal<E>;
 }
 
+impl PhiloxFamily for NormalFamily {
+    type Runtime<E: Numeric> = Normal<E>;
+}
+
 #[cube]
 impl<E: Numeric> PrngRuntime<E> for Normal<E> {
     fn inner_loop(
@@ -85,6 +92,25 @@ impl<E: Numeric> PrngRuntime<E> for Normal<E> {
     }
 }
 
+#[cube]
+impl<E: Numeric> PhiloxRuntime<E> for Normal<E> {
+    fn sample(args: &Normal<E>, words: &Line<u32>, lane: u32) -> E {
+        // Each pair of words gives two values, the even lane using the cosine and the odd one the
+        // sine.
+        let pair = lane / 2 * 2;
+        let unit_0 = to_unit_interval_open(words[pair]);
+        let unit_1 = to_unit_interval_open(words[pair + 1]);
+
+        // Box-Muller transform
+        let coeff = Log::log(unit_0) * -2.0;
+        let coeff = Sqrt::sqrt(coeff) * f32::cast_from(args.std);
+        let trigo_arg = 2.0 * PI * unit_1;
+        let trigo = select((lane & 1) == 0, f32::cos(trigo_arg), f32::sin(trigo_arg));
+
+        E::cast_from(trigo * coeff + f32::cast_from(args.mean))
+    }
+}
+
 impl<E: Numeric> PrngArgs<E> for Normal<E> {
     type Args = Self;
 
@@ -99,7 +125,7 @@ pub fn random_normal<R: Runtime, E: Numeric>(
     mean: E,
     std: E,
     out: TensorHandleRef<R>,
-) {
+) -> Result<(), CompilationError> {
     assert_eq!(
         out.elem_size as u32,
         E::elem_size(),
@@ -108,3 +134,20 @@ pub fn random_normal<R: Runtime, E: Numeric>(
 
     random::<NormalFamily, E, R>(client, Normal { mean, std }, out)
 }
+
+/// Counter-based generator with normal distribution, see [Philox].
+pub fn random_normal_philox<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    state: &mut Philox,
+    mean: E,
+    std: E,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    assert_eq!(
+        out.elem_size as u32,
+        E::elem_size(),
+        "Tensor element type must be the same as type E"
+    );
+
+    random_philox::<NormalFamily, E, R>(client, state, Normal { mean, std }, out)
+}
@@ -0,0 +1,102 @@
+use cubecl::prelude::*;
+use cubecl_core::{self as cubecl, calculate_cube_count_elemwise};
+
+use crate::{PHILOX_VALUES_PER_COUNTER, Philox, philox4x32_10_line};
+
+/// Number of rounds of the Feistel network.
+const FEISTEL_ROUNDS: u32 = 8;
+
+/// Counter-based random permutation of the integers `0..n`, `n` being the number of elements of
+/// `out`, see [Philox].
+///
+/// Every unit computes its value independently by encrypting its index with a Feistel network
+/// keyed by the generator, walking the cycle of the index until landing back in `0..n`. The
+/// result is a pseudo-random permutation, which doesn't reach every permutation with the same
+/// probability.
+pub fn random_permutation<R: Runtime, I: Int>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    state: &mut Philox,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    assert_eq!(
+        out.elem_size as u32,
+        I::elem_size(),
+        "Tensor element type must be the same as type I"
+    );
+
+    let num_elems = out.size();
+    assert!(
+        num_elems <= u32::MAX as usize,
+        "Can't permute more than u32::MAX elements"
+    );
+
+    // The network permutes the smallest even number of bits covering all indices.
+    let num_bits = usize::BITS - num_elems.saturating_sub(1).leading_zeros();
+    let half_bits = Ord::max(num_bits.div_ceil(2), 1);
+
+    // The words of the next counter tweak the round functions, so each call uses a different
+    // permutation.
+    let tweak = state.words(0);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    permutation_kernel::launch::<I, R>(
+        client,
+        cube_count,
+        cube_dim,
+        out.as_tensor_arg(1),
+        ScalarArg::new(half_bits),
+        ScalarArg::new(state.seed as u32),
+        ScalarArg::new((state.seed >> 32) as u32),
+        ScalarArg::new(tweak[0]),
+        ScalarArg::new(tweak[1]),
+    )?;
+
+    state.advance(PHILOX_VALUES_PER_COUNTER);
+
+    Ok(())
+}
+
+#[cube(launch)]
+fn permutation_kernel<I: Int>(
+    output: &mut Tensor<I>,
+    half_bits: u32,
+    key_0: u32,
+    key_1: u32,
+    tweak_0: u32,
+    tweak_1: u32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    // Cycle walking, the network being a bijection on `0..2^(2 * half_bits)`, the first value in
+    // `0..n` reached from an index is unique.
+    let mut value = ABSOLUTE_POS;
+    loop {
+        value = feistel(value, half_bits, key_0, key_1, tweak_0, tweak_1);
+        if value < output.len() {
+            break;
+        }
+    }
+
+    output[ABSOLUTE_POS] = I::cast_from(value);
+}
+
+#[cube]
+fn feistel(value: u32, half_bits: u32, key_0: u32, key_1: u32, tweak_0: u32, tweak_1: u32) -> u32 {
+    let mask = (1u32 << half_bits) - 1;
+    let mut left = value >> half_bits;
+    let mut right = value & mask;
+
+    #[unroll]
+    for round in 0..FEISTEL_ROUNDS {
+        let words = philox4x32_10_line(right, round, tweak_0, tweak_1, key_0, key_1);
+        let next = left ^ (words[0] & mask);
+        left = right;
+        right = next;
+    }
+
+    (left << half_bits) | right
+}
@@ -0,0 +1,227 @@
+use cubecl::prelude::*;
+use cubecl_core::{self as cubecl, calculate_cube_count_elemwise, tensor_line_size_parallel};
+
+use super::PrngArgs;
+
+/// Number of values generated from a single Philox counter.
+pub const PHILOX_VALUES_PER_COUNTER: usize = 4;
+
+const PHILOX_M0: u32 = 0xD2511F53;
+const PHILOX_M1: u32 = 0xCD9E8D57;
+const PHILOX_W0: u32 = 0x9E3779B9;
+const PHILOX_W1: u32 = 0xBB67AE85;
+
+/// State of a counter-based Philox4x32-10 generator.
+///
+/// Every value of a generated tensor only depends on the seed, the offset and its position in the
+/// tensor, so results don't depend on the call order across threads or on the launch
+/// configuration. The offset counts the number of counters already consumed, each counter
+/// producing [PHILOX_VALUES_PER_COUNTER] values, and is advanced by every generation so that
+/// successive calls produce different values. Cloning the state before a call allows replaying it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Philox {
+    /// The key of the generator.
+    pub seed: u64,
+    /// The first counter used by the next generation.
+    pub offset: u64,
+}
+
+impl Philox {
+    /// Create a generator starting at offset 0.
+    pub fn new(seed: u64) -> Self {
+        Self { seed, offset: 0 }
+    }
+
+    /// Create a generator starting at the given offset.
+    pub fn with_offset(seed: u64, offset: u64) -> Self {
+        Self { seed, offset }
+    }
+
+    /// Advance the offset past the counters used to generate `num_values` values.
+    pub fn advance(&mut self, num_values: usize) {
+        let num_counters = num_values.div_ceil(PHILOX_VALUES_PER_COUNTER);
+        self.offset = self.offset.wrapping_add(num_counters as u64);
+    }
+
+    /// The random words generated for the counter at `index` after the offset, computed on the
+    /// host.
+    pub fn words(&self, index: u64) -> [u32; 4] {
+        let counter = self.offset.wrapping_add(index);
+        philox4x32_10(
+            [counter as u32, (counter >> 32) as u32, 0, 0],
+            [self.seed as u32, (self.seed >> 32) as u32],
+        )
+    }
+}
+
+/// The Philox4x32-10 block function, computed on the host.
+pub fn philox4x32_10(counter: [u32; 4], key: [u32; 2]) -> [u32; 4] {
+    let [mut c0, mut c1, mut c2, mut c3] = counter;
+    let [mut k0, mut k1] = key;
+
+    for _ in 0..10 {
+        let product_0 = PHILOX_M0 as u64 * c0 as u64;
+        let product_1 = PHILOX_M1 as u64 * c2 as u64;
+        let (hi_0, lo_0) = ((product_0 >> 32) as u32, product_0 as u32);
+        let (hi_1, lo_1) = ((product_1 >> 32) as u32, product_1 as u32);
+
+        c0 = hi_1 ^ c1 ^ k0;
+        c1 = lo_1;
+        c2 = hi_0 ^ c3 ^ k1;
+        c3 = lo_0;
+
+        k0 = k0.wrapping_add(PHILOX_W0);
+        k1 = k1.wrapping_add(PHILOX_W1);
+    }
+
+    [c0, c1, c2, c3]
+}
+
+/// The Philox4x32-10 block function, returning the four random words in a line.
+#[cube]
+pub fn philox4x32_10_line(
+    counter_0: u32,
+    counter_1: u32,
+    counter_2: u32,
+    counter_3: u32,
+    key_0: u32,
+    key_1: u32,
+) -> Line<u32> {
+    let mut c0 = counter_0;
+    let mut c1 = counter_1;
+    let mut c2 = counter_2;
+    let mut c3 = counter_3;
+    let mut k0 = key_0;
+    let mut k1 = key_1;
+
+    #[unroll]
+    for _ in 0..10 {
+        let hi_0 = u32::mul_hi(c0, PHILOX_M0);
+        let lo_0 = c0 * PHILOX_M0;
+        let hi_1 = u32::mul_hi(c2, PHILOX_M1);
+        let lo_1 = c2 * PHILOX_M1;
+
+        c0 = hi_1 ^ c1 ^ k0;
+        c1 = lo_1;
+        c2 = hi_0 ^ c3 ^ k1;
+        c3 = lo_0;
+
+        k0 += PHILOX_W0;
+        k1 += PHILOX_W1;
+    }
+
+    let mut words = Line::empty(4u32);
+    words[0] = c0;
+    words[1] = c1;
+    words[2] = c2;
+    words[3] = c3;
+    words
+}
+
+/// The random words of the counter at `index` after the offset.
+#[cube]
+pub(crate) fn philox_words(
+    index: u32,
+    key_0: u32,
+    key_1: u32,
+    offset_0: u32,
+    offset_1: u32,
+) -> Line<u32> {
+    // 64-bit addition of the offset and the index of the counter.
+    let counter_0 = offset_0 + index;
+    let counter_1 = offset_1 + u32::cast_from(counter_0 < index);
+
+    philox4x32_10_line(counter_0, counter_1, 0, 0, key_0, key_1)
+}
+
+#[cube]
+pub(crate) trait PhiloxRuntime<E: Numeric>: Send + Sync + 'static + PrngArgs<E> {
+    /// Converts the random words of a counter into the value at position `lane` among the values
+    /// of the counter.
+    fn sample(args: &Self::Args, words: &Line<u32>, lane: u32) -> E;
+}
+
+pub(crate) trait PhiloxFamily: Send + Sync + 'static + std::fmt::Debug {
+    type Runtime<E: Numeric>: PhiloxRuntime<E>;
+}
+
+type Args<F, E> = <<F as PhiloxFamily>::Runtime<E> as PrngArgs<E>>::Args;
+
+/// Counter-based generator, where every value only depends on the generator state and its index.
+pub(crate) fn random_philox<F: PhiloxFamily, E: Numeric, R: Runtime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    state: &mut Philox,
+    prng: F::Runtime<E>,
+    output: TensorHandleRef<'_, R>,
+) -> Result<(), CompilationError> {
+    let num_elems = output.size();
+    let args = prng.args();
+
+    // The values of a counter are written by the same unit, so lines can't span two counters.
+    let output_line_size = tensor_line_size_parallel(
+        R::line_size_elem(&E::as_elem_native_unchecked())
+            .filter(|line_size| PHILOX_VALUES_PER_COUNTER % *line_size as usize == 0),
+        output.shape,
+        output.strides,
+        output.strides.len() - 1,
+    );
+
+    let cube_dim = CubeDim::default();
+    let num_counters = num_elems.div_ceil(PHILOX_VALUES_PER_COUNTER);
+    let cube_count = calculate_cube_count_elemwise(num_counters, cube_dim);
+
+    let output = output.as_tensor_arg(output_line_size);
+
+    philox_kernel::launch::<F, E, R>(
+        client,
+        cube_count,
+        cube_dim,
+        output,
+        ScalarArg::new(state.seed as u32),
+        ScalarArg::new((state.seed >> 32) as u32),
+        ScalarArg::new(state.offset as u32),
+        ScalarArg::new((state.offset >> 32) as u32),
+        args,
+        output_line_size as u32,
+    )?;
+
+    state.advance(num_elems);
+
+    Ok(())
+}
+
+#[cube(launch)]
+fn philox_kernel<F: PhiloxFamily, E: Numeric>(
+    output: &mut Tensor<Line<E>>,
+    key_0: u32,
+    key_1: u32,
+    offset_0: u32,
+    offset_1: u32,
+    args: Args<F, E>,
+    #[comptime] line_size: u32,
+) {
+    let values_per_counter = comptime!(PHILOX_VALUES_PER_COUNTER as u32);
+    let first_value = ABSOLUTE_POS * values_per_counter;
+
+    if first_value >= output.len() * line_size {
+        terminate!();
+    }
+
+    let words = philox_words(ABSOLUTE_POS, key_0, key_1, offset_0, offset_1);
+
+    #[unroll]
+    for line in 0..values_per_counter / line_size {
+        let line_index = first_value / line_size + line;
+
+        if line_index < output.len() {
+            let mut output_line = Line::empty(line_size);
+
+            #[unroll]
+            for i in 0..line_size {
+                output_line[i] = F::Runtime::<E>::sample(&args, &words, line * line_size + i);
+            }
+
+            output[line_index] = output_line;
+        }
+    }
+}
@@ -13,7 +13,7 @@ macro_rules! testgen_random_bernoulli {
                 let client = R::client(&Default::default());
                 let output = TensorHandle::<R, E>::empty(&client, shape.to_vec());
 
-                random_bernoulli::<R, E>(&client, prob, output.as_ref());
+                random_bernoulli::<R, E>(&client, prob, output.as_ref()).unwrap();
 
                 let output_data = client.read_one(output.handle.binding());
                 let output_data = E::from_bytes(&output_data);
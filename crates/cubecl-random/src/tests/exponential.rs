@@ -0,0 +1,66 @@
+#[macro_export]
+macro_rules! testgen_random_exponential {
+    () => {
+        mod test_random_exponential {
+            use super::*;
+
+            pub fn get_random_exponential_data<R: Runtime, E: CubeElement + Numeric>(
+                shape: &[usize],
+                rate: E,
+            ) -> Vec<E> {
+                seed(0);
+
+                let client = R::client(&Default::default());
+                let output = TensorHandle::<R, E>::empty(&client, shape.to_vec());
+
+                random_exponential::<R, E>(&client, rate, output.as_ref()).unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = E::from_bytes(&output_data);
+
+                output_data.to_owned()
+            }
+
+            #[test]
+            fn values_are_positive() {
+                let output_data = get_random_exponential_data::<TestRuntime, f32>(&[100, 100], 2.);
+
+                assert!(output_data.iter().all(|value| *value > 0.));
+            }
+
+            #[test]
+            fn empirical_moments_close_to_expectation() {
+                let rate = 2.;
+
+                let output_data =
+                    get_random_exponential_data::<TestRuntime, f32>(&[100, 100], rate);
+
+                assert_mean_approx_equal(&output_data, 1. / rate);
+                assert_variance_approx_equal(&output_data, 1. / (rate * rate));
+                // The median of the distribution is ln(2) / rate.
+                assert_cdf_approx_equal(&output_data, f32::consts::LN_2 / rate, 0.5);
+            }
+
+            #[test]
+            fn philox_empirical_moments_close_to_expectation() {
+                let client = TestRuntime::client(&Default::default());
+                let output = TensorHandle::<TestRuntime, f32>::empty(&client, vec![100, 100]);
+                let rate = 0.5;
+
+                random_exponential_philox::<TestRuntime, f32>(
+                    &client,
+                    &mut Philox::new(0),
+                    rate,
+                    output.as_ref(),
+                )
+                .unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = f32::from_bytes(&output_data);
+
+                assert_mean_approx_equal(output_data, 1. / rate);
+                assert_cdf_approx_equal(output_data, f32::consts::LN_2 / rate, 0.5);
+            }
+        }
+    };
+}
@@ -0,0 +1,83 @@
+#[macro_export]
+macro_rules! testgen_random_gamma {
+    () => {
+        mod test_random_gamma {
+            use super::*;
+
+            pub fn get_random_gamma_data<R: Runtime, E: CubeElement + Numeric>(
+                shape: &[usize],
+                gamma_shape: E,
+                scale: E,
+            ) -> Vec<E> {
+                seed(0);
+
+                let client = R::client(&Default::default());
+                let output = TensorHandle::<R, E>::empty(&client, shape.to_vec());
+
+                random_gamma::<R, E>(&client, gamma_shape, scale, output.as_ref()).unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = E::from_bytes(&output_data);
+
+                output_data.to_owned()
+            }
+
+            pub fn get_random_beta_data<R: Runtime, E: CubeElement + Numeric>(
+                shape: &[usize],
+                alpha: E,
+                beta: E,
+            ) -> Vec<E> {
+                seed(0);
+
+                let client = R::client(&Default::default());
+                let output = TensorHandle::<R, E>::empty(&client, shape.to_vec());
+
+                random_beta::<R, E>(&client, alpha, beta, output.as_ref()).unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = E::from_bytes(&output_data);
+
+                output_data.to_owned()
+            }
+
+            #[test]
+            fn gamma_empirical_moments_close_to_expectation() {
+                let (gamma_shape, scale) = (3., 2.);
+
+                let output_data =
+                    get_random_gamma_data::<TestRuntime, f32>(&[100, 100], gamma_shape, scale);
+
+                assert!(output_data.iter().all(|value| *value > 0.));
+                assert_mean_approx_equal(&output_data, gamma_shape * scale);
+                assert_variance_approx_equal(&output_data, gamma_shape * scale * scale);
+            }
+
+            #[test]
+            fn gamma_shape_below_one_empirical_distribution_close_to_expectation() {
+                let (gamma_shape, scale) = (0.5, 1.);
+
+                let output_data =
+                    get_random_gamma_data::<TestRuntime, f32>(&[100, 100], gamma_shape, scale);
+
+                assert!(output_data.iter().all(|value| *value >= 0.));
+                assert_mean_approx_equal(&output_data, gamma_shape * scale);
+                // Gamma(0.5, 1) is half of a chi-squared distribution with one degree of freedom,
+                // with the CDF erf(sqrt(x)).
+                assert_cdf_approx_equal(&output_data, 0.5, 0.6827);
+            }
+
+            #[test]
+            fn beta_empirical_moments_close_to_expectation() {
+                let (alpha, beta) = (2., 5.);
+
+                let output_data =
+                    get_random_beta_data::<TestRuntime, f32>(&[100, 100], alpha, beta);
+
+                let sum = alpha + beta;
+                assert!(output_data.iter().all(|value| (0. ..=1.).contains(value)));
+                assert_mean_approx_equal(&output_data, alpha / sum);
+                assert_variance_approx_equal(&output_data, alpha * beta / (sum * sum * (sum + 1.)));
+            }
+        }
+    };
+}
@@ -1,6 +1,12 @@
 pub mod bernoulli;
+pub mod exponential;
+pub mod gamma;
 pub mod interval;
+pub mod multinomial;
 pub mod normal;
+pub mod permutation;
+pub mod philox;
+pub mod truncated_normal;
 pub mod uniform;
 
 #[allow(missing_docs)]
@@ -20,5 +26,11 @@ macro_rules! testgen_random {
         cubecl_random::testgen_random_normal!();
         cubecl_random::testgen_random_uniform!();
         cubecl_random::testgen_random_interval!();
+        cubecl_random::testgen_random_philox!();
+        cubecl_random::testgen_random_exponential!();
+        cubecl_random::testgen_random_gamma!();
+        cubecl_random::testgen_random_truncated_normal!();
+        cubecl_random::testgen_random_multinomial!();
+        cubecl_random::testgen_random_permutation!();
     };
 }
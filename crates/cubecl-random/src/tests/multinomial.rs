@@ -0,0 +1,159 @@
+#[macro_export]
+macro_rules! testgen_random_multinomial {
+    () => {
+        mod test_random_multinomial {
+            use super::*;
+
+            pub fn get_multinomial_data<R: Runtime>(
+                state: &mut Philox,
+                probabilities: &[f32],
+                probabilities_shape: &[usize],
+                num_samples: usize,
+                replacement: bool,
+            ) -> Vec<u32> {
+                let client = R::client(&Default::default());
+                let probabilities = TensorHandle::<R, f32>::new_contiguous(
+                    probabilities_shape.to_vec(),
+                    client.create(f32::as_bytes(probabilities)),
+                );
+
+                let mut shape = probabilities_shape.to_vec();
+                *shape.last_mut().unwrap() = num_samples;
+                let output = TensorHandle::<R, u32>::empty(&client, shape.clone());
+
+                random_multinomial::<R, f32, u32>(
+                    &client,
+                    state,
+                    probabilities.as_ref(),
+                    replacement,
+                    output.as_ref(),
+                )
+                .unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = u32::from_bytes(&output_data);
+
+                output_data[..shape.iter().product::<usize>()].to_owned()
+            }
+
+            fn assert_frequencies(samples: &[u32], weights: &[f32]) {
+                let total: f32 = weights.iter().sum();
+
+                for (category, weight) in weights.iter().enumerate() {
+                    let count = samples.iter().filter(|s| **s == category as u32).count();
+                    let frequency = count as f32 / samples.len() as f32;
+                    let expected = weight / total;
+
+                    assert!(
+                        f32::abs(frequency - expected) < 0.02,
+                        "category {category}: frequency={frequency}, expected={expected}"
+                    );
+                }
+            }
+
+            #[test]
+            fn with_replacement_frequencies_proportional_to_weights() {
+                let weights = [1., 2., 0., 7., 1., 2., 3., 4.];
+
+                let output_data = get_multinomial_data::<TestRuntime>(
+                    &mut Philox::new(0),
+                    &weights,
+                    &[2, 4],
+                    5000,
+                    true,
+                );
+
+                assert_frequencies(&output_data[..5000], &weights[..4]);
+                assert_frequencies(&output_data[5000..], &weights[4..]);
+            }
+
+            #[test]
+            fn without_replacement_draws_every_category_once() {
+                let weights = [0., 1., 2., 3., 0., 5., 6., 7., 8., 9.];
+
+                let output_data = get_multinomial_data::<TestRuntime>(
+                    &mut Philox::new(0),
+                    &[weights, weights, weights].concat(),
+                    &[3, 10],
+                    10,
+                    false,
+                );
+
+                for row in output_data.chunks(10) {
+                    let mut sorted = row.to_vec();
+                    sorted.sort();
+                    assert_eq!(sorted, (0..10).collect::<Vec<u32>>());
+                    // Categories of weight zero are drawn last, ordered by index.
+                    assert_eq!(&row[8..], &[0, 4]);
+                }
+            }
+
+            #[test]
+            fn without_replacement_first_draw_proportional_to_weights() {
+                let weights = [1., 2., 3., 4.];
+                let num_rows = 4000;
+
+                let output_data = get_multinomial_data::<TestRuntime>(
+                    &mut Philox::new(0),
+                    &weights.repeat(num_rows),
+                    &[num_rows, 4],
+                    2,
+                    false,
+                );
+
+                let first_draws: Vec<u32> = output_data.chunks(2).map(|row| row[0]).collect();
+                assert_frequencies(&first_draws, &weights);
+                assert!(output_data.chunks(2).all(|row| row[0] != row[1]));
+            }
+
+            #[test]
+            fn categorical_frequencies_proportional_to_weights() {
+                let client = TestRuntime::client(&Default::default());
+                let weights = [3., 1., 6.];
+                let num_rows = 5000;
+
+                let probabilities = TensorHandle::<TestRuntime, f32>::new_contiguous(
+                    vec![num_rows, 3],
+                    client.create(f32::as_bytes(&weights.repeat(num_rows))),
+                );
+                let output = TensorHandle::<TestRuntime, u32>::empty(&client, vec![num_rows]);
+
+                random_categorical::<TestRuntime, f32, u32>(
+                    &client,
+                    &mut Philox::new(0),
+                    probabilities.as_ref(),
+                    output.as_ref(),
+                )
+                .unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = u32::from_bytes(&output_data);
+
+                assert_frequencies(&output_data[..num_rows], &weights);
+            }
+
+            #[test]
+            fn state_advances_and_replays() {
+                let weights = [1.; 16];
+                let mut state = Philox::new(0);
+                let replay = state;
+
+                let first =
+                    get_multinomial_data::<TestRuntime>(&mut state, &weights, &[16], 16, true);
+                assert_eq!(state.offset, 16);
+                let second =
+                    get_multinomial_data::<TestRuntime>(&mut state, &weights, &[16], 16, true);
+                let replayed = get_multinomial_data::<TestRuntime>(
+                    &mut replay.clone(),
+                    &weights,
+                    &[16],
+                    16,
+                    true,
+                );
+
+                assert_ne!(first, second);
+                assert_eq!(first, replayed);
+            }
+        }
+    };
+}
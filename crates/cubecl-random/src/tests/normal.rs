@@ -14,7 +14,7 @@ macro_rules! testgen_random_normal {
                 let client = R::client(&Default::default());
                 let output = TensorHandle::<R, E>::empty(&client, shape.to_vec());
 
-                random_normal::<R, E>(&client, mean, std, output.as_ref());
+                random_normal::<R, E>(&client, mean, std, output.as_ref()).unwrap();
 
                 let output_data = client.read_one(output.handle.binding());
                 let output_data = E::from_bytes(&output_data);
@@ -0,0 +1,52 @@
+#[macro_export]
+macro_rules! testgen_random_permutation {
+    () => {
+        mod test_random_permutation {
+            use super::*;
+
+            pub fn get_permutation_data<R: Runtime>(state: &mut Philox, n: usize) -> Vec<u32> {
+                let client = R::client(&Default::default());
+                let output = TensorHandle::<R, u32>::empty(&client, vec![n]);
+
+                random_permutation::<R, u32>(&client, state, output.as_ref()).unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = u32::from_bytes(&output_data);
+
+                output_data[..n].to_owned()
+            }
+
+            #[test]
+            fn output_is_permutation() {
+                for n in [1, 2, 7, 64, 1000, 4097] {
+                    let output_data = get_permutation_data::<TestRuntime>(&mut Philox::new(0), n);
+
+                    let mut sorted = output_data.clone();
+                    sorted.sort();
+                    assert_eq!(sorted, (0..n as u32).collect::<Vec<u32>>(), "n = {n}");
+                }
+            }
+
+            #[test]
+            fn runs_test() {
+                let n = 10000;
+                let output_data = get_permutation_data::<TestRuntime>(&mut Philox::new(0), n);
+
+                assert_wald_wolfowitz_runs_test(&output_data, 0., n as f32);
+            }
+
+            #[test]
+            fn state_advances_and_replays() {
+                let mut state = Philox::new(0);
+                let replay = state;
+
+                let first = get_permutation_data::<TestRuntime>(&mut state, 100);
+                let second = get_permutation_data::<TestRuntime>(&mut state, 100);
+                let replayed = get_permutation_data::<TestRuntime>(&mut replay.clone(), 100);
+
+                assert_ne!(first, second);
+                assert_eq!(first, replayed);
+            }
+        }
+    };
+}
@@ -0,0 +1,137 @@
+#[macro_export]
+macro_rules! testgen_random_philox {
+    () => {
+        mod test_random_philox {
+            use super::*;
+
+            pub fn get_philox_uniform_data<R: Runtime>(
+                state: &mut Philox,
+                shape: &[usize],
+            ) -> Vec<f32> {
+                let client = R::client(&Default::default());
+                let output = TensorHandle::<R, f32>::empty(&client, shape.to_vec());
+
+                random_uniform_philox::<R, f32>(&client, state, 0., 1., output.as_ref()).unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = f32::from_bytes(&output_data);
+
+                // The buffer may be padded past the values of the tensor.
+                output_data[..shape.iter().product::<usize>()].to_owned()
+            }
+
+            pub fn expected_uniform_data(state: Philox, num_values: usize) -> Vec<f32> {
+                (0..num_values)
+                    .map(|index| {
+                        let words = state.words((index / PHILOX_VALUES_PER_COUNTER) as u64);
+                        let word = words[index % PHILOX_VALUES_PER_COUNTER];
+                        (word >> 8) as f32 / 16777216.0
+                    })
+                    .collect()
+            }
+
+            #[test]
+            fn philox_matches_known_answers() {
+                assert_eq!(
+                    philox4x32_10([0; 4], [0; 2]),
+                    [0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8]
+                );
+                assert_eq!(
+                    philox4x32_10([u32::MAX; 4], [u32::MAX; 2]),
+                    [0x408f276d, 0x41c83b0e, 0xa20bc7c6, 0x6d5451fd]
+                );
+                assert_eq!(
+                    philox4x32_10(
+                        [0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344],
+                        [0xa4093822, 0x299f31d0]
+                    ),
+                    [0xd16cfe09, 0x94fdcceb, 0x5001e420, 0x24126ea1]
+                );
+            }
+
+            #[test]
+            fn values_only_depend_on_index() {
+                // Different shapes use different line sizes and cube counts.
+                for shape in [&[91][..], &[64, 64], &[3, 5, 7]] {
+                    let state = Philox::with_offset(42, 7);
+                    let num_values = shape.iter().product();
+
+                    let output_data =
+                        get_philox_uniform_data::<TestRuntime>(&mut state.clone(), shape);
+
+                    assert_eq!(output_data, expected_uniform_data(state, num_values));
+                }
+            }
+
+            #[test]
+            fn offset_carries_to_high_bits() {
+                let state = Philox::with_offset(3, u32::MAX as u64 - 2);
+
+                let output_data = get_philox_uniform_data::<TestRuntime>(&mut state.clone(), &[64]);
+
+                assert_eq!(output_data, expected_uniform_data(state, 64));
+            }
+
+            #[test]
+            fn state_advances_and_replays() {
+                let mut state = Philox::new(0);
+                let replay = state;
+
+                let first = get_philox_uniform_data::<TestRuntime>(&mut state, &[30]);
+                assert_eq!(state.offset, 8);
+                let second = get_philox_uniform_data::<TestRuntime>(&mut state, &[30]);
+                let replayed = get_philox_uniform_data::<TestRuntime>(&mut replay.clone(), &[30]);
+
+                assert_ne!(first, second);
+                assert_eq!(first, replayed);
+            }
+
+            #[test]
+            fn uniform_runs_test() {
+                let output_data =
+                    get_philox_uniform_data::<TestRuntime>(&mut Philox::new(0), &[512, 512]);
+
+                assert_wald_wolfowitz_runs_test(&output_data, 0., 1.);
+            }
+
+            #[test]
+            fn normal_respects_68_95_99_rule() {
+                let client = TestRuntime::client(&Default::default());
+                let output = TensorHandle::<TestRuntime, f32>::empty(&client, vec![1000, 1000]);
+
+                random_normal_philox::<TestRuntime, f32>(
+                    &client,
+                    &mut Philox::new(0),
+                    0.,
+                    1.,
+                    output.as_ref(),
+                )
+                .unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = f32::from_bytes(&output_data);
+
+                assert_normal_respects_68_95_99_rule(output_data, 0., 1.);
+            }
+
+            #[test]
+            fn bernoulli_number_of_1_proportional_to_prob() {
+                let client = TestRuntime::client(&Default::default());
+                let output = TensorHandle::<TestRuntime, f32>::empty(&client, vec![40, 40]);
+
+                random_bernoulli_philox::<TestRuntime, f32>(
+                    &client,
+                    &mut Philox::new(0),
+                    0.7,
+                    output.as_ref(),
+                )
+                .unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = f32::from_bytes(&output_data);
+
+                assert_number_of_1_proportional_to_prob(output_data, 0.7);
+            }
+        }
+    };
+}
@@ -0,0 +1,91 @@
+#[macro_export]
+macro_rules! testgen_random_truncated_normal {
+    () => {
+        mod test_random_truncated_normal {
+            use super::*;
+
+            pub fn get_random_truncated_normal_data<R: Runtime, E: CubeElement + Numeric>(
+                shape: &[usize],
+                mean: E,
+                std: E,
+                lower_bound: E,
+                upper_bound: E,
+            ) -> Vec<E> {
+                seed(0);
+
+                let client = R::client(&Default::default());
+                let output = TensorHandle::<R, E>::empty(&client, shape.to_vec());
+
+                random_truncated_normal::<R, E>(
+                    &client,
+                    mean,
+                    std,
+                    lower_bound,
+                    upper_bound,
+                    output.as_ref(),
+                )
+                .unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = E::from_bytes(&output_data);
+
+                output_data.to_owned()
+            }
+
+            #[test]
+            fn values_within_bounds() {
+                let output_data = get_random_truncated_normal_data::<TestRuntime, f32>(
+                    &[100, 100],
+                    0.,
+                    1.,
+                    -1.,
+                    2.,
+                );
+
+                assert!(output_data.iter().all(|value| (-1. ..=2.).contains(value)));
+                // (pdf(-1) - pdf(2)) / (cdf(2) - cdf(-1))
+                assert_mean_approx_equal(&output_data, 0.2296);
+            }
+
+            #[test]
+            fn symmetric_bounds_have_same_median() {
+                let output_data = get_random_truncated_normal_data::<TestRuntime, f32>(
+                    &[100, 100],
+                    3.,
+                    2.,
+                    -1.,
+                    7.,
+                );
+
+                assert!(output_data.iter().all(|value| (-1. ..=7.).contains(value)));
+                assert_cdf_approx_equal(&output_data, 3., 0.5);
+            }
+
+            #[test]
+            fn philox_values_within_upper_tail() {
+                let client = TestRuntime::client(&Default::default());
+                let output = TensorHandle::<TestRuntime, f32>::empty(&client, vec![100, 100]);
+
+                random_truncated_normal_philox::<TestRuntime, f32>(
+                    &client,
+                    &mut Philox::new(0),
+                    0.,
+                    1.,
+                    1.,
+                    3.,
+                    output.as_ref(),
+                )
+                .unwrap();
+
+                let output_data = client.read_one(output.handle.binding());
+                let output_data = f32::from_bytes(&output_data);
+
+                assert!(output_data.iter().all(|value| (1. ..=3.).contains(value)));
+                // (pdf(1) - pdf(3)) / (cdf(3) - cdf(1))
+                assert_mean_approx_equal(output_data, 1.51);
+                // (cdf(1.5) - cdf(1)) / (cdf(3) - cdf(1))
+                assert_cdf_approx_equal(output_data, 1.5, 0.5839);
+            }
+        }
+    };
+}
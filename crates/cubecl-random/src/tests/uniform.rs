@@ -15,7 +15,7 @@ macro_rules! testgen_random_uniform {
                 let client = R::client(&Default::default());
                 let output = TensorHandle::<R, E>::empty(&client, shape.to_vec());
 
-                random_uniform::<R, E>(&client, lower_bound, upper_bound, output.as_ref());
+                random_uniform::<R, E>(&client, lower_bound, upper_bound, output.as_ref()).unwrap();
 
                 let output_data = client.read_one(output.handle.binding());
                 let output_data = E::from_bytes(&output_data);
@@ -65,6 +65,40 @@ pub fn assert_mean_approx_equal<E: Numeric>(data: &[E], expected_mean: f32) {
     );
 }
 
+/// Asserts that the sample variance of a dataset is within 5% of an expected value.
+pub fn assert_variance_approx_equal<E: Numeric>(data: &[E], expected_variance: f32) {
+    let n = data.len() as f32;
+    let mean = data.iter().map(|elem| elem.to_f32().unwrap()).sum::<f32>() / n;
+    let variance = data
+        .iter()
+        .map(|elem| {
+            let d = elem.to_f32().unwrap() - mean;
+            d * d
+        })
+        .sum::<f32>()
+        / (n - 1.);
+
+    assert!(
+        f32::abs(variance - expected_variance) < 0.05 * expected_variance,
+        "Variance validation failed: variance={variance}, expected variance={expected_variance}",
+    );
+}
+
+/// Asserts that the proportion of values lower or equal to `value` is approximately equal to the
+/// expected cumulative distribution function at `value`.
+pub fn assert_cdf_approx_equal<E: Numeric>(data: &[E], value: f32, expected_cdf: f32) {
+    let count = data
+        .iter()
+        .filter(|elem| elem.to_f32().unwrap() <= value)
+        .count();
+    let cdf = count as f32 / data.len() as f32;
+
+    assert!(
+        f32::abs(cdf - expected_cdf) < 0.02,
+        "CDF validation failed: cdf={cdf}, expected cdf={expected_cdf} at {value}",
+    );
+}
+
 /// Asserts that the distribution follows the 68-95-99 rule of normal distributions,
 /// following the given mean and standard deviation.
 pub fn assert_normal_respects_68_95_99_rule<E: Numeric>(data: &[E], mu: f32, s: f32) {
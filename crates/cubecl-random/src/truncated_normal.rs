@@ -0,0 +1,191 @@
+use std::f32::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+use crate::{
+    Philox, PhiloxFamily, PhiloxRuntime, RandomFamily, next_random, random_philox,
+    to_unit_interval_open,
+};
+
+use super::{PrngArgs, PrngRuntime, random};
+
+#[derive(CubeLaunch, CubeType)]
+pub(crate) struct TruncatedNormal<E: Numeric> {
+    mean: E,
+    std: E,
+    lower_bound: E,
+    upper_bound: E,
+}
+
+#[derive(Debug)]
+struct TruncatedNormalFamily;
+
+impl RandomFamily for TruncatedNormalFamily {
+    type Runtime<E: Numeric> = TruncatedNormal<E>;
+}
+
+impl PhiloxFamily for TruncatedNormalFamily {
+    type Runtime<E: Numeric> = TruncatedNormal<E>;
+}
+
+#[cube]
+impl<E: Numeric> PrngRuntime<E> for TruncatedNormal<E> {
+    fn inner_loop(
+        args: TruncatedNormal<E>,
+        write_index_base: u32,
+        n_invocations: u32,
+        #[comptime] n_values_per_thread: u32,
+        #[comptime] line_size: u32,
+        state_0: &mut u32,
+        state_1: &mut u32,
+        state_2: &mut u32,
+        state_3: &mut u32,
+        output: &mut Tensor<Line<E>>,
+    ) {
+        let mut output_line = Line::empty(line_size);
+
+        let num_iterations = n_values_per_thread / line_size;
+        #[unroll(num_iterations <= 8)]
+        for line_index in 0..num_iterations {
+            // vectorization
+            #[unroll]
+            for i in 0..line_size {
+                let int_random = next_random(state_0, state_1, state_2, state_3);
+                output_line[i] = truncated_normal_sample(&args, int_random);
+            }
+
+            let write_index = line_index * n_invocations + write_index_base;
+
+            output[write_index] = output_line;
+        }
+    }
+}
+
+#[cube]
+impl<E: Numeric> PhiloxRuntime<E> for TruncatedNormal<E> {
+    fn sample(args: &TruncatedNormal<E>, words: &Line<u32>, lane: u32) -> E {
+        truncated_normal_sample(args, words[lane])
+    }
+}
+
+/// Inverse transform sampling, restricted to the part of the standard normal CDF between the
+/// bounds.
+#[cube]
+fn truncated_normal_sample<E: Numeric>(args: &TruncatedNormal<E>, int_random: u32) -> E {
+    let mean = f32::cast_from(args.mean);
+    let std = f32::cast_from(args.std);
+    let lower = (f32::cast_from(args.lower_bound) - mean) / std;
+    let upper = (f32::cast_from(args.upper_bound) - mean) / std;
+
+    // The CDF is more precise close to 0 than close to 1, so an interval in the upper half is
+    // mirrored to the lower half.
+    let mirror = lower > 0.0;
+    let low = select(mirror, -upper, lower);
+    let high = select(mirror, -lower, upper);
+
+    let cdf_low = 0.5 * (1.0 + Erf::erf(low * FRAC_1_SQRT_2));
+    let cdf_high = 0.5 * (1.0 + Erf::erf(high * FRAC_1_SQRT_2));
+
+    let f32_random = to_unit_interval_open(int_random);
+    let cdf = cdf_low + f32_random * (cdf_high - cdf_low);
+    let value = SQRT_2 * erfinv(2.0 * cdf - 1.0);
+    // Rounding errors of the approximations can land slightly outside of the bounds.
+    let value = Max::max(Min::min(value, high), low);
+    let value = select(mirror, -value, value);
+
+    E::cast_from(value * std + mean)
+}
+
+/// Single precision approximation of the inverse error function.
+///
+/// From M. Giles, "Approximating the erfinv function", GPU Computing Gems Jade Edition, 2011.
+#[cube]
+fn erfinv(x: f32) -> f32 {
+    let w = -Log::log((1.0 - x) * (1.0 + x));
+
+    // The coefficients of both branches are selected, evaluating a single polynomial.
+    let central = w < 5.0;
+    let w = select(central, w - 2.5, Sqrt::sqrt(w) - 3.0);
+
+    let mut p = select(central, 2.8102264e-8, -2.0021426e-4);
+    p = select(central, 3.4327394e-7, 1.0095056e-4) + p * w;
+    p = select(central, -3.5233877e-6, 1.3493432e-3) + p * w;
+    p = select(central, -4.3915065e-6, -3.6734284e-3) + p * w;
+    p = select(central, 2.1858087e-4, 5.7395077e-3) + p * w;
+    p = select(central, -1.253725e-3, -7.6224613e-3) + p * w;
+    p = select(central, -4.1776816e-3, 9.4388705e-3) + p * w;
+    p = select(central, 2.4664073e-1, 1.001674) + p * w;
+    p = select(central, 1.5014094, 2.8329768) + p * w;
+
+    p * x
+}
+
+impl<E: Numeric> PrngArgs<E> for TruncatedNormal<E> {
+    type Args = Self;
+
+    fn args<'a, R: Runtime>(self) -> TruncatedNormalLaunch<'a, E, R> {
+        TruncatedNormalLaunch::new(
+            ScalarArg::new(self.mean),
+            ScalarArg::new(self.std),
+            ScalarArg::new(self.lower_bound),
+            ScalarArg::new(self.upper_bound),
+        )
+    }
+}
+
+/// Pseudo-random generator with normal distribution truncated to `[lower_bound, upper_bound]`
+pub fn random_truncated_normal<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    mean: E,
+    std: E,
+    lower_bound: E,
+    upper_bound: E,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    assert_eq!(
+        out.elem_size as u32,
+        E::elem_size(),
+        "Tensor element type must be the same as type E"
+    );
+
+    random::<TruncatedNormalFamily, E, R>(
+        client,
+        TruncatedNormal {
+            mean,
+            std,
+            lower_bound,
+            upper_bound,
+        },
+        out,
+    )
+}
+
+/// Counter-based generator with truncated normal distribution, see [Philox].
+pub fn random_truncated_normal_philox<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    state: &mut Philox,
+    mean: E,
+    std: E,
+    lower_bound: E,
+    upper_bound: E,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    assert_eq!(
+        out.elem_size as u32,
+        E::elem_size(),
+        "Tensor element type must be the same as type E"
+    );
+
+    random_philox::<TruncatedNormalFamily, E, R>(
+        client,
+        state,
+        TruncatedNormal {
+            mean,
+            std,
+            lower_bound,
+            upper_bound,
+        },
+        out,
+    )
+}
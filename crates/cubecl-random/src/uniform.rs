@@ -2,7 +2,8 @@ use cubecl::prelude::*;
 use cubecl_core as cubecl;
 
 use crate::{
-    RandomFamily, lcg_step, taus_step_0, taus_step_1, taus_step_2, to_unit_interval_closed_open,
+    Philox, PhiloxFamily, PhiloxRuntime, RandomFamily, lcg_step, random_philox, taus_step_0,
+    taus_step_1, taus_step_2, to_unit_interval_closed_open,
 };
 
 use super::{PrngArgs, PrngRuntime, random};
@@ -20,6 +21,10 @@ impl RandomFamily for UniformFamily {
     type Runtime<E: Numeric> = Uniform<E>;
 }
 
+impl PhiloxFamily for UniformFamily {
+    type Runtime<E: Numeric> = Uniform<E>;
+}
+
 #[cube]
 impl<E: Numeric> PrngRuntime<E> for Uniform<E> {
     fn inner_loop(
@@ -69,6 +74,18 @@ impl<E: Numeric> PrngRuntime<E> for Uniform<E> {
     }
 }
 
+#[cube]
+impl<E: Numeric> PhiloxRuntime<E> for Uniform<E> {
+    fn sample(args: &Uniform<E>, words: &Line<u32>, lane: u32) -> E {
+        let scale = args.upper_bound - args.lower_bound;
+        let f32_random = to_unit_interval_closed_open(words[lane]);
+
+        let f32_uniform = f32_random * f32::cast_from(scale) + f32::cast_from(args.lower_bound);
+
+        E::cast_from(f32_uniform)
+    }
+}
+
 impl<E: Numeric> PrngArgs<E> for Uniform<E> {
     type Args = Self;
 
@@ -86,7 +103,7 @@ pub fn random_uniform<R: Runtime, E: Numeric>(
     lower_bound: E,
     upper_bound: E,
     out: TensorHandleRef<R>,
-) {
+) -> Result<(), CompilationError> {
     assert_eq!(
         out.elem_size as u32,
         E::elem_size(),
@@ -102,3 +119,28 @@ pub fn random_uniform<R: Runtime, E: Numeric>(
         out,
     )
 }
+
+/// Counter-based generator with uniform distribution, see [Philox].
+pub fn random_uniform_philox<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    state: &mut Philox,
+    lower_bound: E,
+    upper_bound: E,
+    out: TensorHandleRef<R>,
+) -> Result<(), CompilationError> {
+    assert_eq!(
+        out.elem_size as u32,
+        E::elem_size(),
+        "Tensor element type must be the same as type E"
+    );
+
+    random_philox::<UniformFamily, E, R>(
+        client,
+        state,
+        Uniform {
+            lower_bound,
+            upper_bound,
+        },
+        out,
+    )
+}
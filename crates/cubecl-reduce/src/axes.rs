@@ -0,0 +1,404 @@
+use cubecl_core::{
+    prelude::*,
+    tune::{LocalTuner, Tunable, TunableSet, local_tuner},
+};
+use cubecl_runtime::server::Handle;
+use cubecl_std::tensor::{TensorHandle, compact_strides, into_contiguous};
+
+use crate::{
+    ReduceError, ReduceFamily, ReducePrecision, ReduceStrategy, reduce, tune_key::ReduceAutotuneKey,
+};
+
+/// Reductions with fewer outputs than this are split in two passes when the instruction allows it,
+/// aiming for about that many partial results in the first pass.
+const SPLIT_MAX_REDUCE_COUNT: usize = 1024;
+
+/// The minimum number of items reduced into each partial result when a reduction is split.
+const SPLIT_MIN_CHUNK_SIZE: usize = 256;
+
+/// Reduce the given `axes` of the `input` tensor using the instruction `Inst` and write the result into `output`.
+///
+/// The shape of `output` must be the same as input except with a value of 1 for all the given `axes`.
+/// Reducing all the axes of the input collapses it into a single value.
+///
+/// The reduced axes are reduced as a single axis, whose coordinate is the index into the reduced
+/// axes flattened in row-major order. This is the coordinate returned by [`ArgMax`](crate::instructions::ArgMax)
+/// and [`ArgMin`](crate::instructions::ArgMin).
+/// When the reduced axes can't be viewed as a single axis because of their strides,
+/// the input is first copied into a contiguous tensor with the reduced axes last.
+///
+/// When the reduction has few outputs compared to the number of reduced items and the instruction is
+/// [splittable](ReduceFamily::is_splittable), the reduced axis is split in equally sized chunks.
+/// A first pass reduces each chunk into a partial result, stored with the accumulation precision,
+/// and a second pass reduces the partial results. This keeps the device busy when reducing a large tensor
+/// into a few values. This also applies when a single axis is reduced.
+/// Whether the split or a single pass is faster is then autotuned.
+///
+/// See [`reduce`] for the meaning of `strategy`, which is used for all passes.
+///
+/// Return an error if the `axes` are empty, contain duplicates or axes larger than the `input` rank
+/// or if the shape of `output` is invalid.
+pub fn reduce_axes<R: Runtime, P: ReducePrecision, Out: Numeric, Inst: ReduceFamily>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<R>,
+    output: TensorHandleRef<R>,
+    axes: &[usize],
+    strategy: Option<ReduceStrategy>,
+    inst_config: Inst::Config,
+) -> Result<(), ReduceError> {
+    validate_axes(input.shape.len(), axes)?;
+    valid_output_shape(input.shape, output.shape, axes)?;
+    let strategy = strategy
+        .map(|s| s.validate::<R>(client))
+        .unwrap_or(Ok(ReduceStrategy::new::<R>(client, true)))?;
+
+    let rank = input.shape.len();
+    let kept_axes = (0..rank)
+        .filter(|axis| !axes.contains(axis))
+        .collect::<Vec<_>>();
+    let reduced_axes = (0..rank)
+        .filter(|axis| axes.contains(axis))
+        .collect::<Vec<_>>();
+
+    let kept_shape = kept_axes
+        .iter()
+        .map(|axis| input.shape[*axis])
+        .collect::<Vec<_>>();
+    let reduce_size = reduced_axes
+        .iter()
+        .map(|axis| input.shape[*axis])
+        .product::<usize>();
+
+    let input = match merged_stride(input.shape, input.strides, &reduced_axes) {
+        Some(reduce_stride) => MergedInput {
+            handle: input.handle.clone(),
+            shape: input.shape.to_vec(),
+            axes: reduced_axes,
+            kept_shape,
+            kept_strides: kept_axes.iter().map(|axis| input.strides[*axis]).collect(),
+            reduce_size,
+            reduce_stride,
+            elem_size: input.elem_size,
+        },
+        None => {
+            // Move the reduced axes last, so that they become a single contiguous axis once copied.
+            let permuted_axes = kept_axes.iter().chain(reduced_axes.iter());
+            let permuted_shape = permuted_axes
+                .clone()
+                .map(|axis| input.shape[*axis])
+                .collect::<Vec<_>>();
+            let permuted_strides = permuted_axes
+                .map(|axis| input.strides[*axis])
+                .collect::<Vec<_>>();
+            let permuted = unsafe {
+                TensorHandleRef::<R>::from_raw_parts(
+                    input.handle,
+                    &permuted_strides,
+                    &permuted_shape,
+                    input.elem_size,
+                )
+            };
+            let contiguous = into_contiguous::<R, P::EI>(client, &permuted);
+
+            MergedInput {
+                handle: contiguous.handle,
+                shape: input.shape.to_vec(),
+                axes: reduced_axes,
+                kept_strides: contiguous.strides[..kept_axes.len()].to_vec(),
+                kept_shape,
+                reduce_size,
+                reduce_stride: 1,
+                elem_size: input.elem_size,
+            }
+        }
+    };
+
+    // The output is viewed with the reduced axes merged into a single last axis.
+    let mut output_shape = input.kept_shape.clone();
+    output_shape.push(1);
+    let mut output_strides = kept_axes
+        .iter()
+        .map(|axis| output.strides[*axis])
+        .collect::<Vec<_>>();
+    output_strides.push(1);
+    let output = TensorHandle::<R, Out>::new(output.handle.clone(), output_shape, output_strides);
+
+    let can_split = Inst::is_splittable(&inst_config)
+        && split_chunk_count(input.reduce_count(), input.reduce_size) > 1;
+
+    if can_split {
+        autotune_reduce_merged::<R, P, Out, Inst>(client, input, output, strategy, inst_config);
+        Ok(())
+    } else {
+        reduce_single_pass::<R, P, Out, Inst>(client.clone(), input, output, strategy, inst_config)
+    }
+}
+
+/// An input tensor whose reduced axes are viewed as a single axis.
+#[derive(Clone)]
+struct MergedInput {
+    handle: Handle,
+    /// The shape of the input before merging the reduced axes, which keys the autotune.
+    shape: Vec<usize>,
+    /// The reduced axes, in increasing order.
+    axes: Vec<usize>,
+    kept_shape: Vec<usize>,
+    kept_strides: Vec<usize>,
+    reduce_size: usize,
+    reduce_stride: usize,
+    elem_size: usize,
+}
+
+impl MergedInput {
+    /// The number of values in the output.
+    fn reduce_count(&self) -> usize {
+        self.kept_shape.iter().product()
+    }
+}
+
+/// Reduce the merged axis of the input with the fastest of a single pass and a split in two passes.
+fn autotune_reduce_merged<R: Runtime, P: ReducePrecision, Out: Numeric, Inst: ReduceFamily>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: MergedInput,
+    output: TensorHandle<R, Out>,
+    strategy: ReduceStrategy,
+    inst_config: Inst::Config,
+) {
+    static TUNER: LocalTuner<ReduceAutotuneKey, &'static str> = local_tuner!("reduce-axes");
+
+    let tunables = TUNER.init(|| {
+        TunableSet::new(create_key::<R, P, Out, Inst>, input_gen::<R, Out, Inst>)
+            .with(Tunable::new(reduce_single_pass::<R, P, Out, Inst>))
+            .with(Tunable::new(reduce_split::<R, P, Out, Inst>))
+    });
+
+    TUNER.execute(
+        &R::name(client),
+        client,
+        tunables,
+        (client.clone(), input, output, strategy, inst_config),
+    );
+}
+
+/// The inputs of the autotuned reductions.
+type TuneInputs<R, Out, Inst> = (
+    ComputeClient<<R as Runtime>::Server, <R as Runtime>::Channel>,
+    MergedInput,
+    TensorHandle<R, Out>,
+    ReduceStrategy,
+    <Inst as ReduceFamily>::Config,
+);
+
+fn create_key<R: Runtime, P: ReducePrecision, Out: Numeric, Inst: ReduceFamily>(
+    _client: &ComputeClient<R::Server, R::Channel>,
+    input: &MergedInput,
+    _output: &TensorHandle<R, Out>,
+    _strategy: &ReduceStrategy,
+    _inst_config: &Inst::Config,
+) -> ReduceAutotuneKey {
+    ReduceAutotuneKey::generate_axes(
+        P::EI::as_elem_native_unchecked(),
+        Out::as_elem_native_unchecked(),
+        P::EA::as_elem_native_unchecked(),
+        &input.shape,
+        input.reduce_stride == 1,
+        &input.axes,
+    )
+}
+
+fn input_gen<R: Runtime, Out: Numeric, Inst: ReduceFamily>(
+    _key: &ReduceAutotuneKey,
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &MergedInput,
+    output: &TensorHandle<R, Out>,
+    strategy: &ReduceStrategy,
+    inst_config: &Inst::Config,
+) -> TuneInputs<R, Out, Inst> {
+    (
+        client.clone(),
+        input.clone(),
+        output.clone(),
+        *strategy,
+        *inst_config,
+    )
+}
+
+/// Reduce the merged axis of the input in a single pass.
+fn reduce_single_pass<R: Runtime, P: ReducePrecision, Out: Numeric, Inst: ReduceFamily>(
+    client: ComputeClient<R::Server, R::Channel>,
+    input: MergedInput,
+    output: TensorHandle<R, Out>,
+    strategy: ReduceStrategy,
+    inst_config: Inst::Config,
+) -> Result<(), ReduceError> {
+    let axis = input.kept_shape.len();
+
+    let mut input_shape = input.kept_shape;
+    input_shape.push(input.reduce_size);
+    let mut input_strides = input.kept_strides;
+    input_strides.push(input.reduce_stride);
+    let input = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            &input.handle,
+            &input_strides,
+            &input_shape,
+            input.elem_size,
+        )
+    };
+
+    reduce::<R, P, Out, Inst>(
+        &client,
+        input,
+        output.as_ref(),
+        axis,
+        Some(strategy),
+        inst_config,
+    )
+}
+
+/// Reduce the merged axis of the input by splitting it in chunks, reduced into partial results
+/// by a first pass, which are reduced by a second pass.
+fn reduce_split<R: Runtime, P: ReducePrecision, Out: Numeric, Inst: ReduceFamily>(
+    client: ComputeClient<R::Server, R::Channel>,
+    input: MergedInput,
+    output: TensorHandle<R, Out>,
+    strategy: ReduceStrategy,
+    inst_config: Inst::Config,
+) -> Result<(), ReduceError> {
+    let axis = input.kept_shape.len();
+    let reduce_count = input.reduce_count();
+    let chunk_count = split_chunk_count(reduce_count, input.reduce_size);
+    let chunk_size = input.reduce_size / chunk_count;
+
+    // First pass, reducing each chunk into a partial result.
+    let mut partial_shape = input.kept_shape.clone();
+    partial_shape.extend([chunk_count, 1]);
+
+    let mut input_shape = input.kept_shape;
+    input_shape.extend([chunk_count, chunk_size]);
+    let mut input_strides = input.kept_strides;
+    input_strides.extend([input.reduce_stride * chunk_size, input.reduce_stride]);
+    let input = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            &input.handle,
+            &input_strides,
+            &input_shape,
+            input.elem_size,
+        )
+    };
+
+    let partial_elem_size = P::EA::as_elem_native_unchecked().size();
+    let partial_handle = client.empty(reduce_count * chunk_count * partial_elem_size);
+    let partial_strides = compact_strides(&partial_shape);
+    let partial = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            &partial_handle,
+            &partial_strides,
+            &partial_shape,
+            partial_elem_size,
+        )
+    };
+
+    reduce::<R, P, P::EA, Inst>(
+        &client,
+        input,
+        partial,
+        axis + 1,
+        Some(strategy),
+        inst_config,
+    )?;
+
+    // Second pass, reducing the partial results.
+    partial_shape.pop();
+    let partial_strides = compact_strides(&partial_shape);
+    let partial = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            &partial_handle,
+            &partial_strides,
+            &partial_shape,
+            partial_elem_size,
+        )
+    };
+
+    reduce::<R, (P::EA, P::EA), Out, Inst>(
+        &client,
+        partial,
+        output.as_ref(),
+        axis,
+        Some(strategy),
+        inst_config,
+    )
+}
+
+/// The number of chunks the reduced axis is split into, 1 meaning that the reduction isn't split.
+///
+/// Chunks must have the same size, so that each partial result has the same weight.
+fn split_chunk_count(reduce_count: usize, reduce_size: usize) -> usize {
+    if reduce_count >= SPLIT_MAX_REDUCE_COUNT {
+        return 1;
+    }
+
+    let max_chunk_count =
+        (SPLIT_MAX_REDUCE_COUNT / reduce_count.max(1)).min(reduce_size / SPLIT_MIN_CHUNK_SIZE);
+
+    (1..=max_chunk_count)
+        .rev()
+        .find(|chunk_count| reduce_size % chunk_count == 0)
+        .unwrap_or(1)
+}
+
+/// The stride of the axis obtained by flattening the given axes in row-major order,
+/// if they can be viewed as a single axis.
+fn merged_stride(shape: &[usize], strides: &[usize], axes: &[usize]) -> Option<usize> {
+    // Axes of size 1 don't constrain the layout.
+    let axes = axes
+        .iter()
+        .filter(|axis| shape[**axis] != 1)
+        .copied()
+        .collect::<Vec<_>>();
+
+    let Some(last) = axes.last() else {
+        return Some(1);
+    };
+
+    axes.windows(2)
+        .all(|pair| strides[pair[0]] == strides[pair[1]] * shape[pair[1]])
+        .then_some(strides[*last])
+}
+
+// Check that the axes are distinct, non-empty and less than the rank of the input.
+fn validate_axes(rank: usize, axes: &[usize]) -> Result<(), ReduceError> {
+    let is_valid = !axes.is_empty()
+        && axes.iter().all(|axis| *axis < rank)
+        && axes
+            .iter()
+            .enumerate()
+            .all(|(i, axis)| !axes[..i].contains(axis));
+
+    if !is_valid {
+        return Err(ReduceError::InvalidAxes {
+            axes: axes.to_vec(),
+            rank,
+        });
+    }
+    Ok(())
+}
+
+// Check that the output shape match the input shape with the given axes set to 1.
+fn valid_output_shape(
+    input_shape: &[usize],
+    output_shape: &[usize],
+    axes: &[usize],
+) -> Result<(), ReduceError> {
+    let mut expected_shape = input_shape.to_vec();
+    for axis in axes {
+        expected_shape[*axis] = 1;
+    }
+    if output_shape != expected_shape {
+        return Err(ReduceError::MismatchShape {
+            expected_shape,
+            output_shape: output_shape.to_vec(),
+        });
+    }
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use core::fmt;
 
-use cubecl_core::ir::Elem;
+use cubecl_core::{CompilationError, ir::Elem, tune::AutotuneError};
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum ReduceError {
@@ -12,6 +12,8 @@ pub enum ReduceError {
     ImprecisePlaneDim,
     /// Indicate the axis is too large.
     InvalidAxis { axis: usize, rank: usize },
+    /// Indicate the axes are empty, duplicated or too large.
+    InvalidAxes { axes: Vec<usize>, rank: usize },
     /// Indicate that the shape of the output tensor is invalid for the given input and axis.
     MismatchShape {
         expected_shape: Vec<usize>,
@@ -19,6 +21,23 @@ pub enum ReduceError {
     },
     /// Indicate that we can't launch a shared sum because the atomic addition is not supported.
     MissingAtomicAdd(Elem),
+    /// Indicate that the reduce kernel couldn't be compiled for the client.
+    Compilation(CompilationError),
+}
+
+impl From<CompilationError> for ReduceError {
+    fn from(value: CompilationError) -> Self {
+        Self::Compilation(value)
+    }
+}
+
+impl From<ReduceError> for AutotuneError {
+    fn from(value: ReduceError) -> Self {
+        match value {
+            ReduceError::Compilation(err) => Self::Compilation(err),
+            err => Self::Unknown(err.to_string()),
+        }
+    }
 }
 
 impl fmt::Display for ReduceError {
@@ -39,6 +58,10 @@ impl fmt::Display for ReduceError {
                 f,
                 "The provided axis ({axis}) must be smaller than the input tensor rank ({rank})."
             ),
+            Self::InvalidAxes { axes, rank } => write!(
+                f,
+                "The provided axes ({axes:?}) must be distinct, non-empty and smaller than the input tensor rank ({rank})."
+            ),
             Self::MismatchShape {
                 expected_shape,
                 output_shape,
@@ -51,6 +74,7 @@ impl fmt::Display for ReduceError {
             Self::MissingAtomicAdd(elem) => {
                 write!(f, "Atomic add not supported by the client for {elem}")
             }
+            Self::Compilation(err) => write!(f, "{err}"),
         }
     }
 }
@@ -6,6 +6,14 @@ use crate::precision::ReducePrecision;
 pub trait ReduceFamily: Send + Sync + 'static + std::fmt::Debug {
     type Instruction<P: ReducePrecision>: ReduceInstruction<P, Config = Self::Config>;
     type Config: CubeComptime + Send + Sync;
+
+    /// Whether reducing equally sized chunks of a slice and then reducing the partial results
+    /// with the same instruction gives the reduction of the whole slice.
+    ///
+    /// This allows [`reduce_axes`](crate::reduce_axes) to split large reductions in two passes.
+    fn is_splittable(_config: &Self::Config) -> bool {
+        false
+    }
 }
 
 #[derive(CubeType)]
@@ -90,6 +98,10 @@ pub trait ReduceInstruction<P: ReducePrecision>:
     ) -> Line<Out>;
 }
 
+/// The coordinates along the reduced axis of the items given to [`ReduceInstruction::reduce`].
+///
+/// Items outside of the reduced axis, replaced by [`ReduceInstruction::null_input`], have the
+/// coordinate `u32::MAX`.
 #[derive(CubeType)]
 pub enum ReduceCoordinate {
     Required(Line<u32>),
@@ -0,0 +1,168 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::precision::ReducePrecision;
+
+use super::{
+    ReduceCoordinate, ReduceFamily, ReduceInstruction, ReduceRequirements, SharedAccumulator,
+};
+
+/// Compute `ln(sum(exp(x)))` without overflowing, keeping the running maximum of the items and
+/// the sum of their exponentials scaled by that maximum.
+#[derive(Debug, CubeType, Clone)]
+pub struct LogSumExp {}
+
+impl ReduceFamily for LogSumExp {
+    type Instruction<P: ReducePrecision> = Self;
+    type Config = ();
+
+    fn is_splittable(_config: &Self::Config) -> bool {
+        true
+    }
+}
+
+/// A pair of shared memory used for [`LogSumExp`].
+#[derive(CubeType)]
+pub struct LogSumExpAccumulator<N: Numeric> {
+    pub maxs: SharedMemory<Line<N>>,
+    pub sums: SharedMemory<Line<N>>,
+}
+
+#[cube]
+impl<In: Numeric> SharedAccumulator for LogSumExpAccumulator<In> {
+    type Item = (Line<In>, Line<In>);
+
+    fn allocate(
+        #[comptime] length: u32,
+        #[comptime] line_size: u32,
+        #[comptime] _coordinate: bool,
+    ) -> Self {
+        LogSumExpAccumulator::<In> {
+            maxs: SharedMemory::new_lined(length, line_size),
+            sums: SharedMemory::new_lined(length, line_size),
+        }
+    }
+
+    fn read(accumulator: &Self, index: u32) -> Self::Item {
+        (accumulator.maxs[index], accumulator.sums[index])
+    }
+
+    fn write(accumulator: &mut Self, index: u32, item: Self::Item) {
+        accumulator.maxs[index] = item.0;
+        accumulator.sums[index] = item.1;
+    }
+}
+
+#[cube]
+impl LogSumExp {
+    /// Fuse two pairs of maximum and scaled sum, rescaling both sums to the largest maximum.
+    pub fn combine_log_sum_exp<N: Numeric>(
+        lhs: (Line<N>, Line<N>),
+        rhs: (Line<N>, Line<N>),
+    ) -> (Line<N>, Line<N>) {
+        let max = Max::max(lhs.0, rhs.0);
+        let sum = lhs.1 * Self::exp(lhs.0 - max) + rhs.1 * Self::exp(rhs.0 - max);
+        (max, sum)
+    }
+
+    /// The exponential, computed in `f32` since the items may not be floats.
+    fn exp<N: Numeric>(x: Line<N>) -> Line<N> {
+        let exp: Line<f32> = Exp::exp(Line::<f32>::cast_from(x));
+        Line::cast_from(exp)
+    }
+
+    fn finalize<N: Numeric>(accumulator: (Line<N>, Line<N>)) -> Line<f32> {
+        Line::<f32>::cast_from(accumulator.0) + Log::log(Line::<f32>::cast_from(accumulator.1))
+    }
+}
+
+#[cube]
+impl<P: ReducePrecision> ReduceInstruction<P> for LogSumExp {
+    type AccumulatorItem = (Line<P::EA>, Line<P::EA>);
+    type SharedAccumulator = LogSumExpAccumulator<P::EA>;
+    type Config = ();
+
+    fn requirements(_this: &Self) -> ReduceRequirements {
+        ReduceRequirements { coordinates: false }
+    }
+
+    fn from_config(_config: Self::Config) -> Self {
+        LogSumExp {}
+    }
+
+    fn null_input(_this: &Self, #[comptime] line_size: u32) -> Line<P::EI> {
+        Line::empty(line_size).fill(P::EI::min_value())
+    }
+
+    fn null_accumulator(_this: &Self, #[comptime] line_size: u32) -> Self::AccumulatorItem {
+        (
+            Line::empty(line_size).fill(P::EA::min_value()),
+            Line::empty(line_size).fill(P::EA::from_int(0)),
+        )
+    }
+
+    fn assign_accumulator(
+        _this: &Self,
+        destination: &mut Self::AccumulatorItem,
+        source: &Self::AccumulatorItem,
+    ) {
+        destination.0 = source.0;
+        destination.1 = source.1;
+    }
+
+    fn reduce(
+        _this: &Self,
+        accumulator: &Self::AccumulatorItem,
+        item: Line<P::EI>,
+        _coordinate: ReduceCoordinate,
+        #[comptime] use_planes: bool,
+    ) -> Self::AccumulatorItem {
+        let item = Line::<P::EA>::cast_from(item);
+
+        let candidate = if use_planes {
+            let max = plane_max(item);
+            (max, plane_sum(Self::exp(item - max)))
+        } else {
+            (item, Line::empty(item.size()).fill(P::EA::from_int(1)))
+        };
+
+        Self::combine_log_sum_exp((accumulator.0, accumulator.1), candidate)
+    }
+
+    fn fuse_accumulators(
+        _this: &Self,
+        lhs: Self::AccumulatorItem,
+        rhs: Self::AccumulatorItem,
+    ) -> Self::AccumulatorItem {
+        Self::combine_log_sum_exp(lhs, rhs)
+    }
+
+    fn merge_line<Out: Numeric>(
+        _this: &Self,
+        accumulator: Self::AccumulatorItem,
+        _shape_axis_reduce: u32,
+    ) -> Out {
+        let mut max = Line::<P::EA>::new(accumulator.0[0]);
+        let mut sum = Line::<P::EA>::new(accumulator.1[0]);
+
+        #[unroll]
+        for k in 1..accumulator.0.size() {
+            let merged = Self::combine_log_sum_exp(
+                (max, sum),
+                (Line::new(accumulator.0[k]), Line::new(accumulator.1[k])),
+            );
+            max = merged.0;
+            sum = merged.1;
+        }
+
+        Out::cast_from(Self::finalize((max, sum))[0])
+    }
+
+    fn to_output_perpendicular<Out: Numeric>(
+        _this: &Self,
+        accumulator: Self::AccumulatorItem,
+        _shape_axis_reduce: u32,
+    ) -> Line<Out> {
+        Line::cast_from(Self::finalize(accumulator))
+    }
+}
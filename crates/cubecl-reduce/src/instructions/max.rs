@@ -13,6 +13,10 @@ pub struct Max;
 impl ReduceFamily for Max {
     type Instruction<P: ReducePrecision> = Self;
     type Config = ();
+
+    fn is_splittable(_config: &Self::Config) -> bool {
+        true
+    }
 }
 
 #[cube]
@@ -13,6 +13,10 @@ pub struct MaxAbs;
 impl ReduceFamily for MaxAbs {
     type Instruction<P: ReducePrecision> = Self;
     type Config = ();
+
+    fn is_splittable(_config: &Self::Config) -> bool {
+        true
+    }
 }
 
 #[cube]
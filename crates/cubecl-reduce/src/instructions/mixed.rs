@@ -36,6 +36,10 @@ pub enum ReduceFnConfig {
 impl ReduceFamily for ReduceFn {
     type Instruction<P: ReducePrecision> = Self;
     type Config = ReduceFnConfig;
+
+    fn is_splittable(config: &Self::Config) -> bool {
+        !matches!(config, ReduceFnConfig::ArgMax | ReduceFnConfig::ArgMin)
+    }
 }
 
 #[derive(CubeType)]
@@ -1,23 +1,31 @@
 mod argmax;
 mod argmin;
 mod base;
+mod logsumexp;
 mod max;
 mod maxabs;
 mod mean;
 mod min;
 mod mixed;
+mod norm;
 mod prod;
+mod stddev;
 mod sum;
 mod utils;
+mod variance;
 
 pub use argmax::*;
 pub use argmin::*;
 pub use base::*;
+pub use logsumexp::*;
 pub use max::*;
 pub use maxabs::*;
 pub use mean::*;
 pub use min::*;
 pub use mixed::*;
+pub use norm::*;
 pub use prod::*;
+pub use stddev::*;
 pub use sum::*;
 pub(crate) use utils::*;
+pub use variance::*;
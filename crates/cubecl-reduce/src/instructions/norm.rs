@@ -0,0 +1,132 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::precision::ReducePrecision;
+
+use super::{ReduceCoordinate, ReduceFamily, ReduceInstruction, ReduceRequirements};
+
+/// Compute the `p`-norm `sum(|x|^p)^(1/p)`, with specialized versions for the L1 and L2 norms.
+///
+/// The order `p` must be at least 1, see [`MaxAbs`](super::MaxAbs) for the infinity norm.
+#[derive(Debug, CubeType, Clone)]
+pub struct Norm {
+    #[cube(comptime)]
+    pub p: u32,
+}
+
+impl ReduceFamily for Norm {
+    type Instruction<P: ReducePrecision> = Self;
+    type Config = u32;
+
+    fn is_splittable(_config: &Self::Config) -> bool {
+        true
+    }
+}
+
+#[cube]
+impl Norm {
+    /// The item raised to the power `p`, in absolute value.
+    fn power<In: Numeric, Acc: Numeric>(item: Line<In>, #[comptime] p: u32) -> Line<Acc> {
+        if comptime![p == 1] {
+            Line::cast_from(Line::abs(item))
+        } else if comptime![p == 2] {
+            let item = Line::<Acc>::cast_from(item);
+            item * item
+        } else {
+            let item = Line::<f32>::cast_from(Line::abs(item));
+            let p = Line::empty(item.size()).fill(comptime![p as f32]);
+            Line::cast_from(Powf::powf(item, p))
+        }
+    }
+
+    /// The `p`-th root of the sum.
+    fn root<Acc: Numeric>(sum: Line<Acc>, #[comptime] p: u32) -> Line<f32> {
+        let sum = Line::<f32>::cast_from(sum);
+
+        if comptime![p == 1] {
+            sum
+        } else if comptime![p == 2] {
+            Sqrt::sqrt(sum)
+        } else {
+            let exponent = Line::empty(sum.size()).fill(comptime![1.0 / p as f32]);
+            Powf::powf(sum, exponent)
+        }
+    }
+}
+
+#[cube]
+impl<P: ReducePrecision> ReduceInstruction<P> for Norm {
+    type AccumulatorItem = Line<P::EA>;
+    type SharedAccumulator = SharedMemory<Line<P::EA>>;
+    type Config = u32;
+
+    fn requirements(_this: &Self) -> ReduceRequirements {
+        ReduceRequirements { coordinates: false }
+    }
+
+    fn from_config(#[comptime] config: Self::Config) -> Self {
+        comptime! {assert!(config >= 1, "The order of a norm must be at least 1")};
+        Norm { p: config }
+    }
+
+    fn null_input(_this: &Self, #[comptime] line_size: u32) -> Line<P::EI> {
+        Line::empty(line_size).fill(P::EI::from_int(0))
+    }
+
+    fn null_accumulator(_this: &Self, #[comptime] line_size: u32) -> Self::AccumulatorItem {
+        Line::empty(line_size).fill(P::EA::from_int(0))
+    }
+
+    fn assign_accumulator(
+        _this: &Self,
+        destination: &mut Self::AccumulatorItem,
+        source: &Self::AccumulatorItem,
+    ) {
+        *destination = *source;
+    }
+
+    fn reduce(
+        this: &Self,
+        accumulator: &Self::AccumulatorItem,
+        item: Line<P::EI>,
+        _coordinate: ReduceCoordinate,
+        #[comptime] use_planes: bool,
+    ) -> Self::AccumulatorItem {
+        let power = Self::power::<P::EI, P::EA>(item, this.p);
+
+        if use_planes {
+            *accumulator + plane_sum(power)
+        } else {
+            *accumulator + power
+        }
+    }
+
+    fn fuse_accumulators(
+        _this: &Self,
+        lhs: Self::AccumulatorItem,
+        rhs: Self::AccumulatorItem,
+    ) -> Self::AccumulatorItem {
+        lhs + rhs
+    }
+
+    fn merge_line<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        _shape_axis_reduce: u32,
+    ) -> Out {
+        let mut sum = P::EA::from_int(0);
+        #[unroll]
+        for k in 0..accumulator.size() {
+            sum += accumulator[k];
+        }
+        Out::cast_from(Self::root(Line::<P::EA>::new(sum), this.p)[0])
+    }
+
+    fn to_output_perpendicular<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        _shape_axis_reduce: u32,
+    ) -> Line<Out> {
+        Line::cast_from(Self::root(accumulator, this.p))
+    }
+}
@@ -11,6 +11,10 @@ pub struct Prod {}
 impl ReduceFamily for Prod {
     type Instruction<P: ReducePrecision> = Self;
     type Config = ();
+
+    fn is_splittable(_config: &Self::Config) -> bool {
+        true
+    }
 }
 
 #[cube]
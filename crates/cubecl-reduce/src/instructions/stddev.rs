@@ -0,0 +1,102 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::precision::ReducePrecision;
+
+use super::{ReduceCoordinate, ReduceFamily, ReduceInstruction, ReduceRequirements, Variance};
+
+/// Compute the standard deviation, the square root of the [`Variance`] with the same
+/// `correction`.
+#[derive(Debug, CubeType, Clone)]
+pub struct Std {
+    pub(crate) variance: Variance,
+}
+
+impl ReduceFamily for Std {
+    type Instruction<P: ReducePrecision> = Self;
+    type Config = u32;
+}
+
+#[cube]
+impl<P: ReducePrecision> ReduceInstruction<P> for Std {
+    type AccumulatorItem = <Variance as ReduceInstruction<P>>::AccumulatorItem;
+    type SharedAccumulator = <Variance as ReduceInstruction<P>>::SharedAccumulator;
+    type Config = u32;
+
+    fn requirements(this: &Self) -> ReduceRequirements {
+        <Variance as ReduceInstruction<P>>::requirements(&this.variance)
+    }
+
+    fn from_config(#[comptime] config: Self::Config) -> Self {
+        Std {
+            variance: Variance { correction: config },
+        }
+    }
+
+    fn null_input(this: &Self, #[comptime] line_size: u32) -> Line<P::EI> {
+        <Variance as ReduceInstruction<P>>::null_input(&this.variance, line_size)
+    }
+
+    fn null_accumulator(this: &Self, #[comptime] line_size: u32) -> Self::AccumulatorItem {
+        <Variance as ReduceInstruction<P>>::null_accumulator(&this.variance, line_size)
+    }
+
+    fn assign_accumulator(
+        this: &Self,
+        destination: &mut Self::AccumulatorItem,
+        source: &Self::AccumulatorItem,
+    ) {
+        <Variance as ReduceInstruction<P>>::assign_accumulator(&this.variance, destination, source);
+    }
+
+    fn reduce(
+        this: &Self,
+        accumulator: &Self::AccumulatorItem,
+        item: Line<P::EI>,
+        coordinate: ReduceCoordinate,
+        #[comptime] use_planes: bool,
+    ) -> Self::AccumulatorItem {
+        <Variance as ReduceInstruction<P>>::reduce(
+            &this.variance,
+            accumulator,
+            item,
+            coordinate,
+            use_planes,
+        )
+    }
+
+    fn fuse_accumulators(
+        this: &Self,
+        lhs: Self::AccumulatorItem,
+        rhs: Self::AccumulatorItem,
+    ) -> Self::AccumulatorItem {
+        <Variance as ReduceInstruction<P>>::fuse_accumulators(&this.variance, lhs, rhs)
+    }
+
+    fn merge_line<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        shape_axis_reduce: u32,
+    ) -> Out {
+        let variance = <Variance as ReduceInstruction<P>>::merge_line::<f32>(
+            &this.variance,
+            accumulator,
+            shape_axis_reduce,
+        );
+        Out::cast_from(f32::sqrt(variance))
+    }
+
+    fn to_output_perpendicular<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        shape_axis_reduce: u32,
+    ) -> Line<Out> {
+        let variance = <Variance as ReduceInstruction<P>>::to_output_perpendicular::<f32>(
+            &this.variance,
+            accumulator,
+            shape_axis_reduce,
+        );
+        let std: Line<f32> = Sqrt::sqrt(variance);
+        Line::cast_from(std)
+    }
+}
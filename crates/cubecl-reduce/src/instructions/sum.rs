@@ -11,6 +11,10 @@ pub struct Sum {}
 impl ReduceFamily for Sum {
     type Instruction<P: ReducePrecision> = Self;
     type Config = ();
+
+    fn is_splittable(_config: &Self::Config) -> bool {
+        true
+    }
 }
 
 #[cube]
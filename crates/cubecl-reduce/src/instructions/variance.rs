@@ -0,0 +1,206 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::precision::ReducePrecision;
+
+use super::{
+    ReduceCoordinate, ReduceCoordinateExpand, ReduceFamily, ReduceInstruction, ReduceRequirements,
+    SharedAccumulator,
+};
+
+/// Compute the variance with Welford's algorithm, dividing the sum of squared deviations from the
+/// mean by `count - correction`.
+///
+/// A `correction` of 1 gives the unbiased sample variance and a `correction` of 0 the population
+/// variance.
+#[derive(Debug, CubeType, Clone)]
+pub struct Variance {
+    #[cube(comptime)]
+    pub correction: u32,
+}
+
+impl ReduceFamily for Variance {
+    type Instruction<P: ReducePrecision> = Self;
+    type Config = u32;
+}
+
+/// The count, mean and sum of squared deviations from the mean of the reduced items.
+pub type WelfordItem<N> = (Line<N>, Line<N>, Line<N>);
+
+/// Three shared memories used for [`Variance`] and [`Std`](super::Std).
+#[derive(CubeType)]
+pub struct WelfordAccumulator<N: Numeric> {
+    pub counts: SharedMemory<Line<N>>,
+    pub means: SharedMemory<Line<N>>,
+    pub m2s: SharedMemory<Line<N>>,
+}
+
+#[cube]
+impl<In: Numeric> SharedAccumulator for WelfordAccumulator<In> {
+    type Item = WelfordItem<In>;
+
+    fn allocate(
+        #[comptime] length: u32,
+        #[comptime] line_size: u32,
+        #[comptime] _coordinate: bool,
+    ) -> Self {
+        WelfordAccumulator::<In> {
+            counts: SharedMemory::new_lined(length, line_size),
+            means: SharedMemory::new_lined(length, line_size),
+            m2s: SharedMemory::new_lined(length, line_size),
+        }
+    }
+
+    fn read(accumulator: &Self, index: u32) -> Self::Item {
+        (
+            accumulator.counts[index],
+            accumulator.means[index],
+            accumulator.m2s[index],
+        )
+    }
+
+    fn write(accumulator: &mut Self, index: u32, item: Self::Item) {
+        accumulator.counts[index] = item.0;
+        accumulator.means[index] = item.1;
+        accumulator.m2s[index] = item.2;
+    }
+}
+
+#[cube]
+impl Variance {
+    /// Fuse the statistics of two disjoint sets of items, following Chan et al.,
+    /// "Updating formulae and a pairwise algorithm for computing sample variances", 1979.
+    pub fn combine_welford<N: Numeric>(lhs: WelfordItem<N>, rhs: WelfordItem<N>) -> WelfordItem<N> {
+        let count = lhs.0 + rhs.0;
+        // Avoid dividing by zero when both sets are empty.
+        let divisor = Max::max(count, Line::empty(count.size()).fill(N::from_int(1)));
+
+        let delta = rhs.1 - lhs.1;
+        let mean = lhs.1 + delta * rhs.0 / divisor;
+        let m2 = lhs.2 + rhs.2 + delta * delta * lhs.0 * rhs.0 / divisor;
+
+        (count, mean, m2)
+    }
+
+    /// Fuse the statistics of all elements of the lines into lines of size 1.
+    fn merge_welford<N: Numeric>(accumulator: WelfordItem<N>) -> WelfordItem<N> {
+        let mut count = Line::new(accumulator.0[0]);
+        let mut mean = Line::new(accumulator.1[0]);
+        let mut m2 = Line::new(accumulator.2[0]);
+
+        #[unroll]
+        for k in 1..accumulator.0.size() {
+            let merged = Self::combine_welford(
+                (count, mean, m2),
+                (
+                    Line::new(accumulator.0[k]),
+                    Line::new(accumulator.1[k]),
+                    Line::new(accumulator.2[k]),
+                ),
+            );
+            count = merged.0;
+            mean = merged.1;
+            m2 = merged.2;
+        }
+
+        (count, mean, m2)
+    }
+}
+
+#[cube]
+impl<P: ReducePrecision> ReduceInstruction<P> for Variance {
+    type AccumulatorItem = WelfordItem<P::EA>;
+    type SharedAccumulator = WelfordAccumulator<P::EA>;
+    type Config = u32;
+
+    fn requirements(_this: &Self) -> ReduceRequirements {
+        // Coordinates identify the null inputs, which must not be counted.
+        ReduceRequirements { coordinates: true }
+    }
+
+    fn from_config(#[comptime] config: Self::Config) -> Self {
+        Variance { correction: config }
+    }
+
+    fn null_input(_this: &Self, #[comptime] line_size: u32) -> Line<P::EI> {
+        Line::empty(line_size).fill(P::EI::from_int(0))
+    }
+
+    fn null_accumulator(_this: &Self, #[comptime] line_size: u32) -> Self::AccumulatorItem {
+        let zero = Line::empty(line_size).fill(P::EA::from_int(0));
+        (zero, zero, zero)
+    }
+
+    fn assign_accumulator(
+        _this: &Self,
+        destination: &mut Self::AccumulatorItem,
+        source: &Self::AccumulatorItem,
+    ) {
+        destination.0 = source.0;
+        destination.1 = source.1;
+        destination.2 = source.2;
+    }
+
+    fn reduce(
+        _this: &Self,
+        accumulator: &Self::AccumulatorItem,
+        item: Line<P::EI>,
+        coordinate: ReduceCoordinate,
+        #[comptime] use_planes: bool,
+    ) -> Self::AccumulatorItem {
+        let coordinate = match coordinate {
+            ReduceCoordinate::Required(val) => val,
+            ReduceCoordinate::NotRequired => {
+                comptime! {panic!("Coordinates are required for Variance")};
+                #[allow(unreachable_code)]
+                Line::new(0)
+            }
+        };
+
+        let line_size = item.size();
+        let zero = Line::empty(line_size).fill(P::EA::from_int(0));
+        let valid = coordinate.not_equal(Line::empty(line_size).fill(u32::MAX));
+        let count = select_many(valid, Line::empty(line_size).fill(P::EA::from_int(1)), zero);
+        let item = Line::<P::EA>::cast_from(item);
+
+        let candidate = if use_planes {
+            let plane_count = plane_sum(count);
+            let divisor = Max::max(plane_count, Line::empty(line_size).fill(P::EA::from_int(1)));
+            let mean = plane_sum(item * count) / divisor;
+            let deviation = (item - mean) * count;
+            (plane_count, mean, plane_sum(deviation * deviation))
+        } else {
+            (count, item, zero)
+        };
+
+        Self::combine_welford((accumulator.0, accumulator.1, accumulator.2), candidate)
+    }
+
+    fn fuse_accumulators(
+        _this: &Self,
+        lhs: Self::AccumulatorItem,
+        rhs: Self::AccumulatorItem,
+    ) -> Self::AccumulatorItem {
+        Self::combine_welford(lhs, rhs)
+    }
+
+    fn merge_line<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        _shape_axis_reduce: u32,
+    ) -> Out {
+        let (count, _mean, m2) = Self::merge_welford(accumulator);
+        let correction = P::EA::from_int(comptime![this.correction as i64]);
+        Out::cast_from(m2[0] / (count[0] - correction))
+    }
+
+    fn to_output_perpendicular<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        _shape_axis_reduce: u32,
+    ) -> Line<Out> {
+        let correction = Line::empty(accumulator.0.size())
+            .fill(P::EA::from_int(comptime![this.correction as i64]));
+        Line::cast_from(accumulator.2 / (accumulator.0 - correction))
+    }
+}
@@ -4,6 +4,7 @@ use cubecl_std::tensor::r#virtual::ReadWrite;
 use cubecl_std::tensor::r#virtual::VirtualTensor;
 
 use crate::BoundChecksInner;
+use crate::ReduceError;
 use crate::args::ReduceArgs;
 use crate::args::TensorArgs;
 use crate::args::init_tensors;
@@ -23,7 +24,7 @@ pub(crate) fn launch_reduce<Run: Runtime, P: ReducePrecision, Out: Numeric, Rd:
     config: ReduceConfig,
     strategy: ReduceStrategy,
     inst: Rd::Config,
-) {
+) -> Result<(), ReduceError> {
     let settings = ReduceParams {
         shared: strategy.shared.then(|| {
             if strategy.use_planes {
@@ -49,8 +50,10 @@ pub(crate) fn launch_reduce<Run: Runtime, P: ReducePrecision, Out: Numeric, Rd:
             ScalarArg::new(axis),
             settings,
             inst,
-        );
+        )?;
     }
+
+    Ok(())
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
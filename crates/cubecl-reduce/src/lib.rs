@@ -7,6 +7,7 @@
 //!
 //! This crate provides a main entrypoint as the [`reduce`] function which allows to automatically
 //! perform a reduction for a given instruction implementing the [`ReduceInstruction`] trait and a given [`ReduceStrategy`].
+//! The [`reduce_axes`] function extends it to reductions over multiple axes, including full reductions of a tensor into a single value.
 //! It also provides implementation of the [`ReduceInstruction`] trait for common operations in the [`instructions`] module.
 //! Finally, it provides many reusable primitives to perform different general reduction algorithms in the [`primitives`] module.
 
@@ -15,6 +16,7 @@ pub mod instructions;
 pub mod primitives;
 pub mod tune_key;
 
+mod axes;
 mod config;
 mod error;
 mod launch;
@@ -22,6 +24,7 @@ mod precision;
 mod shared_sum;
 mod strategy;
 
+pub use axes::*;
 pub use config::*;
 pub use error::*;
 pub use instructions::ReduceFamily;
@@ -123,8 +126,7 @@ pub fn reduce<R: Runtime, P: ReducePrecision, Out: Numeric, Inst: ReduceFamily>(
         config,
         strategy,
         inst_config,
-    );
-    Ok(())
+    )
 }
 
 // Check that the given axis is less than the rank of the input.
@@ -1,7 +1,7 @@
 use cubecl_core::{flex32, prelude::Numeric};
 
 /// Precision used for the reduction.
-pub trait ReducePrecision {
+pub trait ReducePrecision: 'static {
     /// Precision used for the input tensor.
     type EI: Numeric;
     /// Precision used for the accumulation.
@@ -193,11 +193,14 @@ pub fn reduce_slice_plane<P: ReducePrecision, I: List<Line<P::EI>>, R: ReduceIns
 
         let requirements = R::requirements(inst);
         let coordinates = if comptime![requirements.coordinates] {
-            ReduceCoordinate::new_Required(fill_coordinate_line(
-                unit_coordinate,
-                line_size,
-                line_mode,
-            ))
+            let coordinate = fill_coordinate_line(unit_coordinate, line_size, line_mode);
+            let coordinate = select(
+                unit_coordinate < range.coordinate_end,
+                coordinate,
+                Line::empty(line_size).fill(u32::MAX),
+            );
+
+            ReduceCoordinate::new_Required(coordinate)
         } else {
             ReduceCoordinate::new_NotRequired()
         };
@@ -97,7 +97,7 @@ pub fn shared_sum<R: Runtime, N: Numeric + CubeElement>(
             cube_dim.num_elems(),
             line_size,
             num_lines_per_unit,
-        );
+        )?;
     }
 
     Ok(())
@@ -8,7 +8,8 @@ use rand::{
 };
 
 use crate::{
-    ReduceError, ReduceStrategy, instructions::*, precision::ReducePrecision, reduce, shared_sum,
+    ReduceError, ReduceStrategy, instructions::*, precision::ReducePrecision, reduce, reduce_axes,
+    shared_sum,
 };
 
 // All random values generated for tests will be in the set
@@ -208,8 +209,8 @@ macro_rules! testgen_reduce {
 }
 
 // For a given tensor description and cube settings
-// run the tests for `Sum`, `Prod`, `Mean`, `ArgMax` and `ArgMin`
-// for all strategies.
+// run the tests for `Sum`, `Prod`, `Mean`, `ArgMax`, `ArgMin`, `Variance`, `Std`, `LogSumExp`
+// and `Norm` for all strategies.
 // For each test, a reference reduction is computed on the CPU to compare the outcome of the kernel.
 #[macro_export]
 macro_rules! impl_test_reduce {
@@ -315,6 +316,251 @@ macro_rules! impl_test_reduce_with_strategy {
                     };
                     test.test_sum::<$float, TestRuntime>(&Default::default());
                 }
+
+                #[test]
+                pub fn [< variance_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: Some($axis),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                    };
+                    test.test_variance::<$float, TestRuntime>(&Default::default());
+                }
+
+                #[test]
+                pub fn [< std_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: Some($axis),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                    };
+                    test.test_std::<$float, TestRuntime>(&Default::default());
+                }
+
+                #[test]
+                pub fn [< log_sum_exp_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: Some($axis),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                    };
+                    test.test_log_sum_exp::<$float, TestRuntime>(&Default::default());
+                }
+
+                #[test]
+                pub fn [< l1_norm_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: Some($axis),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                    };
+                    test.test_norm::<$float, TestRuntime>(&Default::default(), 1);
+                }
+
+                #[test]
+                pub fn [< l2_norm_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: Some($axis),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                    };
+                    test.test_norm::<$float, TestRuntime>(&Default::default(), 2);
+                }
+
+                #[test]
+                pub fn [< l3_norm_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: Some($axis),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                    };
+                    test.test_norm::<$float, TestRuntime>(&Default::default(), 3);
+                }
+            )*
+        }
+    };
+}
+
+// This macro generate all the tests for reductions over multiple axes.
+#[macro_export]
+macro_rules! testgen_reduce_axes {
+    // Generate all the tests for a list of types.
+    ([$($float:ident), *]) => {
+        mod test_reduce_axes {
+            use super::*;
+            ::paste::paste! {
+                $(mod [<$float _ty>] {
+                    use super::*;
+
+                    $crate::testgen_reduce_axes!($float);
+                })*
+            }
+        }
+    };
+
+    // Generate all the tests for f32
+    () => {
+        mod test_reduce_axes {
+            use super::*;
+            $crate::testgen_reduce_axes!(f32);
+        }
+    };
+
+    // Generate all the tests for a specific float type.
+    ($float:ident) => {
+        use cubecl_reduce::test::AxesTestCase;
+
+        $crate::impl_test_reduce_axes!(
+            $float,
+            [
+                {
+                    id: "full_matrix",
+                    shape: [256, 256],
+                    stride: [256, 1],
+                    axes: [0, 1],
+                },
+                {
+                    id: "full_rank_three_tensor_permuted",
+                    shape: [8, 16, 32],
+                    stride: [1, 256, 8],
+                    axes: [0, 1, 2],
+                },
+                {
+                    id: "inner_axes",
+                    shape: [4, 16, 32],
+                    stride: [512, 32, 1],
+                    axes: [1, 2],
+                },
+                {
+                    id: "outer_axes",
+                    shape: [16, 8, 32],
+                    stride: [256, 32, 1],
+                    axes: [0, 1],
+                },
+                {
+                    id: "non_adjacent_axes",
+                    shape: [8, 16, 32],
+                    stride: [512, 32, 1],
+                    axes: [0, 2],
+                },
+                {
+                    id: "broadcast_full",
+                    shape: [4, 32],
+                    stride: [0, 1],
+                    axes: [0, 1],
+                },
+                {
+                    id: "full_vector_large",
+                    shape: [65536],
+                    stride: [1],
+                    axes: [0],
+                }
+            ]
+        );
+    };
+}
+
+// For a given tensor description and set of axes
+// run the tests for `Sum`, `Mean`, `ArgMax` and `Variance` for all strategies.
+// For each test, a reference reduction is computed on the CPU to compare the outcome of the kernels.
+#[macro_export]
+macro_rules! impl_test_reduce_axes {
+    (
+        $float:ident,
+        [
+            $(
+                {
+                    id: $id:literal,
+                    shape: $shape:expr,
+                    stride: $stride:expr,
+                    axes: $axes:expr,
+                }
+            ),*
+        ]
+    ) => {
+        ::paste::paste! {
+            $(
+                $crate::impl_test_reduce_axes_with_strategy!{
+                    $float,
+                    {
+                        id: $id,
+                        shape: $shape,
+                        stride: $stride,
+                        axes: $axes,
+                    },
+                    [ use_planes: false, shared: false ],
+                    [ use_planes: true, shared: false ],
+                    [ use_planes: false, shared: true ],
+                    [ use_planes: true, shared: true ]
+                }
+            )*
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_test_reduce_axes_with_strategy {
+    (
+        $float:ident,
+        {
+            id: $id:literal,
+            shape: $shape:expr,
+            stride: $stride:expr,
+            axes: $axes:expr,
+        },
+        $([use_planes: $use_planes:expr, shared: $shared:expr]),*
+    ) => {
+        ::paste::paste! {
+            $(
+                #[test]
+                pub fn [< sum_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = AxesTestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axes: $axes.into(),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                    };
+                    test.test_sum::<$float, TestRuntime>(&Default::default());
+                }
+
+                #[test]
+                pub fn [< mean_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = AxesTestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axes: $axes.into(),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                    };
+                    test.test_mean::<$float, TestRuntime>(&Default::default());
+                }
+
+                #[test]
+                pub fn [< argmax_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = AxesTestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axes: $axes.into(),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                    };
+                    test.test_argmax::<$float, TestRuntime>(&Default::default());
+                }
+
+                #[test]
+                pub fn [< variance_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = AxesTestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axes: $axes.into(),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                    };
+                    test.test_variance::<$float, TestRuntime>(&Default::default());
+                }
             )*
         }
     };
@@ -470,6 +716,135 @@ impl TestCase {
         expected
     }
 
+    pub fn test_variance<F, R>(&self, device: &R::Device)
+    where
+        F: ReducePrecision + std::fmt::Display,
+        F::EI: CubeElement + Float + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F::EI> = self.random_input_values();
+        let expected_values = match self.axis {
+            Some(axis) if self.stride[axis] == 0 => vec![F::EI::from_int(0); input_values.len()],
+            _ => self.cpu_variance(&input_values, 1),
+        };
+        self.run_reduce_test_with_config::<F, F::EI, R, Variance>(
+            device,
+            input_values,
+            expected_values,
+            1,
+        )
+    }
+
+    pub fn test_std<F, R>(&self, device: &R::Device)
+    where
+        F: ReducePrecision + std::fmt::Display,
+        F::EI: CubeElement + Float + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F::EI> = self.random_input_values();
+        let expected_values = match self.axis {
+            Some(axis) if self.stride[axis] == 0 => vec![F::EI::from_int(0); input_values.len()],
+            _ => Self::map_values(&self.cpu_variance(&input_values, 1), f32::sqrt),
+        };
+        self.run_reduce_test_with_config::<F, F::EI, R, Std>(
+            device,
+            input_values,
+            expected_values,
+            1,
+        )
+    }
+
+    fn cpu_variance<F: Float>(&self, values: &[F], correction: usize) -> Vec<F> {
+        let means = self.cpu_mean(values);
+        let mut expected = vec![0.0; self.num_output_values()];
+
+        for (input_index, value) in values.iter().enumerate() {
+            if let Some(output_index) = self.to_output_index(input_index) {
+                let deviation = value.to_f64().unwrap() - means[output_index].to_f64().unwrap();
+                expected[output_index] += deviation * deviation;
+            }
+        }
+
+        let divisor = (self.shape[self.axis.unwrap()] - correction) as f64;
+        expected
+            .into_iter()
+            .map(|m2| F::new((m2 / divisor) as f32))
+            .collect()
+    }
+
+    pub fn test_log_sum_exp<F, R>(&self, device: &R::Device)
+    where
+        F: ReducePrecision + std::fmt::Display,
+        F::EI: CubeElement + Float + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F::EI> = self.random_input_values();
+        let expected_values = match self.axis {
+            Some(axis) if self.stride[axis] == 0 => {
+                Self::map_values(&input_values, |v| v + (self.shape[axis] as f32).ln())
+            }
+            _ => self.cpu_log_sum_exp(&input_values),
+        };
+        self.run_reduce_test::<F, F::EI, R, LogSumExp>(device, input_values, expected_values)
+    }
+
+    fn cpu_log_sum_exp<F: Float>(&self, values: &[F]) -> Vec<F> {
+        let mut expected = vec![0.0; self.num_output_values()];
+
+        for (input_index, value) in values.iter().enumerate() {
+            if let Some(output_index) = self.to_output_index(input_index) {
+                expected[output_index] += value.to_f64().unwrap().exp();
+            }
+        }
+        expected
+            .into_iter()
+            .map(|sum| F::new(sum.ln() as f32))
+            .collect()
+    }
+
+    pub fn test_norm<F, R>(&self, device: &R::Device, p: u32)
+    where
+        F: ReducePrecision + std::fmt::Display,
+        F::EI: CubeElement + Float + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F::EI> = self.random_input_values();
+        let expected_values = match self.axis {
+            Some(axis) if self.stride[axis] == 0 => {
+                let scale = (self.shape[axis] as f32).powf(1.0 / p as f32);
+                Self::map_values(&input_values, |v| v.abs() * scale)
+            }
+            _ => self.cpu_norm(&input_values, p),
+        };
+        self.run_reduce_test_with_config::<F, F::EI, R, Norm>(
+            device,
+            input_values,
+            expected_values,
+            p,
+        )
+    }
+
+    fn cpu_norm<F: Float>(&self, values: &[F], p: u32) -> Vec<F> {
+        let mut expected = vec![0.0; self.num_output_values()];
+
+        for (input_index, value) in values.iter().enumerate() {
+            if let Some(output_index) = self.to_output_index(input_index) {
+                expected[output_index] += value.to_f64().unwrap().abs().powi(p as i32);
+            }
+        }
+        expected
+            .into_iter()
+            .map(|sum| F::new(sum.powf(1.0 / p as f64) as f32))
+            .collect()
+    }
+
+    fn map_values<F: Float>(values: &[F], func: impl Fn(f32) -> f32) -> Vec<F> {
+        values
+            .iter()
+            .map(|value| F::new(func(value.to_f32().unwrap())))
+            .collect()
+    }
+
     pub fn test_shared_sum<F, R>(&self, device: &R::Device)
     where
         F: Float + CubeElement + std::fmt::Display,
@@ -494,6 +869,22 @@ impl TestCase {
         O: Numeric + CubeElement + std::fmt::Display,
         R: Runtime,
         K: ReduceFamily<Config = ()>,
+    {
+        self.run_reduce_test_with_config::<P, O, R, K>(device, input_values, expected_values, ())
+    }
+
+    pub fn run_reduce_test_with_config<P, O, R, K>(
+        &self,
+        device: &R::Device,
+        input_values: Vec<P::EI>,
+        expected_values: Vec<O>,
+        config: K::Config,
+    ) where
+        P: ReducePrecision,
+        P::EI: CubeElement,
+        O: Numeric + CubeElement + std::fmt::Display,
+        R: Runtime,
+        K: ReduceFamily,
     {
         let client = R::client(device);
 
@@ -530,7 +921,7 @@ impl TestCase {
             output,
             self.axis.unwrap(),
             self.strategy,
-            (),
+            config,
         );
         if result.is_err_and(|e| {
             e == ReduceError::PlanesUnavailable || e == ReduceError::ImprecisePlaneDim
@@ -667,6 +1058,182 @@ impl TestCase {
     }
 }
 
+#[derive(Debug)]
+pub struct AxesTestCase {
+    pub shape: Vec<usize>,
+    pub stride: Vec<usize>,
+    pub axes: Vec<usize>,
+    pub strategy: Option<ReduceStrategy>,
+}
+
+impl AxesTestCase {
+    pub fn test_sum<F, R>(&self, device: &R::Device)
+    where
+        F: ReducePrecision + std::fmt::Display,
+        F::EI: CubeElement + Float + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F::EI> = self.random_input_values();
+        let expected_values = self.cpu_reduce(&input_values, |values| {
+            F::EI::new(values.iter().sum::<f64>() as f32)
+        });
+        self.run_reduce_axes_test::<F, F::EI, R, Sum>(device, input_values, expected_values, ())
+    }
+
+    pub fn test_mean<F, R>(&self, device: &R::Device)
+    where
+        F: ReducePrecision + std::fmt::Display,
+        F::EI: CubeElement + Float + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F::EI> = self.random_input_values();
+        let expected_values = self.cpu_reduce(&input_values, |values| {
+            F::EI::new((values.iter().sum::<f64>() / values.len() as f64) as f32)
+        });
+        self.run_reduce_axes_test::<F, F::EI, R, Mean>(device, input_values, expected_values, ())
+    }
+
+    pub fn test_argmax<F, R>(&self, device: &R::Device)
+    where
+        F: ReducePrecision + std::fmt::Display,
+        F::EI: CubeElement + Float,
+        R: Runtime,
+    {
+        let input_values: Vec<F::EI> = self.random_input_values();
+        let expected_values = self.cpu_reduce(&input_values, |values| {
+            let mut best = 0;
+            for (index, value) in values.iter().enumerate() {
+                if *value > values[best] {
+                    best = index;
+                }
+            }
+            best as u32
+        });
+        self.run_reduce_axes_test::<F, u32, R, ArgMax>(device, input_values, expected_values, ())
+    }
+
+    pub fn test_variance<F, R>(&self, device: &R::Device)
+    where
+        F: ReducePrecision + std::fmt::Display,
+        F::EI: CubeElement + Float + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F::EI> = self.random_input_values();
+        let expected_values = self.cpu_reduce(&input_values, |values| {
+            let mean = values.iter().sum::<f64>() / values.len() as f64;
+            let m2 = values
+                .iter()
+                .map(|value| (value - mean) * (value - mean))
+                .sum::<f64>();
+            F::EI::new((m2 / (values.len() - 1) as f64) as f32)
+        });
+        self.run_reduce_axes_test::<F, F::EI, R, Variance>(device, input_values, expected_values, 1)
+    }
+
+    /// Reduce the values of each output with `func`, the values being ordered by their
+    /// coordinates along the reduced axes flattened in row-major order.
+    fn cpu_reduce<F: Float, O>(&self, values: &[F], func: impl Fn(&[f64]) -> O) -> Vec<O> {
+        let num_values = self.shape.iter().product::<usize>();
+        let mut grouped = vec![Vec::new(); num_values / self.reduce_size()];
+
+        // Iterate over the coordinates in row-major order.
+        for linear_index in 0..num_values {
+            let mut remainder = linear_index;
+            let mut input_index = 0;
+            let mut output_index = 0;
+            let mut output_stride = 1;
+
+            for axis in (0..self.shape.len()).rev() {
+                let coordinate = remainder % self.shape[axis];
+                remainder /= self.shape[axis];
+
+                input_index += coordinate * self.stride[axis];
+                if !self.axes.contains(&axis) {
+                    output_index += coordinate * output_stride;
+                    output_stride *= self.shape[axis];
+                }
+            }
+
+            grouped[output_index].push(values[input_index].to_f64().unwrap());
+        }
+
+        grouped.iter().map(|values| func(values)).collect()
+    }
+
+    pub fn run_reduce_axes_test<P, O, R, K>(
+        &self,
+        device: &R::Device,
+        input_values: Vec<P::EI>,
+        expected_values: Vec<O>,
+        config: K::Config,
+    ) where
+        P: ReducePrecision,
+        P::EI: CubeElement,
+        O: Numeric + CubeElement + std::fmt::Display,
+        R: Runtime,
+        K: ReduceFamily,
+    {
+        let client = R::client(device);
+
+        let input_handle = client.create(<P::EI as CubeElement>::as_bytes(&input_values));
+
+        // Contiguous output with the same shape as input except for the reduced axes.
+        let output_handle =
+            client.create(O::as_bytes(&vec![O::from_int(0); expected_values.len()]));
+        let output_shape = self
+            .shape
+            .iter()
+            .enumerate()
+            .map(|(axis, shape)| if self.axes.contains(&axis) { 1 } else { *shape })
+            .collect::<Vec<_>>();
+        let output_stride = cubecl_std::tensor::compact_strides(&output_shape);
+
+        let input = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &input_handle,
+                &self.stride,
+                &self.shape,
+                size_of::<P::EI>(),
+            )
+        };
+        let output = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &output_handle,
+                &output_stride,
+                &output_shape,
+                size_of::<O>(),
+            )
+        };
+
+        let result =
+            reduce_axes::<R, P, O, K>(&client, input, output, &self.axes, self.strategy, config);
+        if result.is_err_and(|e| {
+            e == ReduceError::PlanesUnavailable || e == ReduceError::ImprecisePlaneDim
+        }) {
+            return; // We don't test in that case.
+        }
+
+        let binding = output_handle.binding();
+        let bytes = client.read_one(binding);
+        let output_values = O::from_bytes(&bytes);
+        assert_approx_equal(output_values, &expected_values);
+    }
+
+    fn reduce_size(&self) -> usize {
+        self.axes.iter().map(|axis| self.shape[*axis]).product()
+    }
+
+    fn random_input_values<F: Float>(&self) -> Vec<F> {
+        TestCase {
+            shape: self.shape.clone(),
+            stride: self.stride.clone(),
+            axis: None,
+            strategy: None,
+        }
+        .random_input_values()
+    }
+}
+
 pub fn assert_approx_equal<N: Numeric>(actual: &[N], expected: &[N]) {
     for (i, (a, e)) in actual.iter().zip(expected.iter()).enumerate() {
         let a = a.to_f32().unwrap();
@@ -25,19 +25,41 @@ impl ReduceAutotuneKey {
         input_shape: &[usize],
         axis_is_contiguous: bool,
         axis: usize,
+    ) -> Self {
+        Self::generate_axes(
+            elem_input,
+            elem_output,
+            elem_acc,
+            input_shape,
+            axis_is_contiguous,
+            &[axis],
+        )
+    }
+
+    /// Generate the key of a reduction over multiple axes, see [`reduce_axes`](crate::reduce_axes).
+    ///
+    /// The reduced axes are keyed as a single axis with a shape equal to the product of their
+    /// shapes, `axis_is_contiguous` telling whether that merged axis is contiguous.
+    pub fn generate_axes(
+        elem_input: Elem,
+        elem_output: Elem,
+        elem_acc: Elem,
+        input_shape: &[usize],
+        axis_is_contiguous: bool,
+        axes: &[usize],
     ) -> Self {
         let rank = input_shape.len();
 
-        if axis > rank {
+        if let Some(axis) = axes.iter().find(|axis| **axis >= rank) {
             panic!("axis {axis} is out-of-bound for a rank of {rank}");
         }
 
-        let reduce_axis_shape = input_shape[axis];
+        let reduce_axis_shape = axes.iter().map(|axis| input_shape[*axis]).product();
 
         let reduce_count = input_shape
             .iter()
             .enumerate()
-            .filter_map(|(i, shape)| (i != axis).then_some(shape))
+            .filter_map(|(i, shape)| (!axes.contains(&i)).then_some(shape))
             .product();
 
         let potential_line_size = Self::potential_line_size(elem_input.size(), reduce_axis_shape);
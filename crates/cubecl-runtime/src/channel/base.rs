@@ -1,14 +1,16 @@
 use cubecl_common::{ExecutionMode, future::DynFut, profile::ProfileDuration};
 
 use crate::{
+    kernel::CompilationError,
     logging::ServerLogger,
-    memory_management::MemoryAllocationMode,
+    memory_management::{MemoryAllocationMode, MemoryTrace, OutOfMemory},
     server::{
         Binding, BindingWithMeta, Bindings, ComputeServer, CubeCount, Handle, ProfileError,
         ProfilingToken,
     },
     storage::{BindingResource, ComputeStorage},
 };
+use alloc::string::String;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
@@ -30,8 +32,9 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug + Send
         binding: Binding,
     ) -> BindingResource<<Server::Storage as ComputeStorage>::Resource>;
 
-    /// Given a resource as bytes, stores it and returns the resource handle
-    fn create(&self, data: &[u8]) -> Handle;
+    /// Given a resource as bytes, stores it and returns the resource handle, or an error if
+    /// the memory limit is exceeded
+    fn try_create(&self, data: &[u8]) -> Result<Handle, OutOfMemory>;
 
     /// Given a resource as bytes and a shape, stores it and returns the tensor handle
     fn create_tensors(
@@ -41,8 +44,9 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug + Send
         elem_size: Vec<usize>,
     ) -> Vec<(Handle, Vec<usize>)>;
 
-    /// Reserves `size` bytes in the storage, and returns a handle over them
-    fn empty(&self, size: usize) -> Handle;
+    /// Reserves `size` bytes in the storage, and returns a handle over them, or an error if the
+    /// memory limit is exceeded
+    fn try_empty(&self, size: usize) -> Result<Handle, OutOfMemory>;
 
     /// Reserves a tensor with `shape` in the storage, and returns a handle to it
     fn empty_tensors(
@@ -51,9 +55,21 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug + Send
         elem_size: Vec<usize>,
     ) -> Vec<(Handle, Vec<usize>)>;
 
+    /// Copies the bytes of `src` into `dst` on the device
+    fn copy(&self, src: Binding, dst: Binding);
+
+    /// Copies a resource of another server into a new buffer of `size` bytes, or returns `None`
+    /// if peer copies aren't supported
+    fn copy_from_peer(
+        &self,
+        src: BindingResource<<Server::Storage as ComputeStorage>::Resource>,
+        size: usize,
+    ) -> Option<Handle>;
+
     /// Executes the `kernel` over the given `bindings`.
     ///
-    /// Optionally returns some debug information about the compilation to be logged.
+    /// Returns an error if the kernel can't be compiled.
+    ///
     /// # Safety
     ///
     /// When executing with mode [ExecutionMode::Unchecked], out-of-bound reads and writes can happen.
@@ -64,7 +80,7 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug + Send
         bindings: Bindings,
         mode: ExecutionMode,
         logger: Arc<ServerLogger>,
-    );
+    ) -> Result<(), CompilationError>;
 
     /// Flush outstanding work of the server.
     fn flush(&self);
@@ -78,6 +94,15 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug + Send
     /// Ask the server to release memory that it can release.
     fn memory_cleanup(&self);
 
+    /// Enable or disable the tracing of memory events.
+    fn memory_tracing(&self, enabled: bool);
+
+    /// Set the tag attached to the memory events recorded from now on.
+    fn memory_trace_tag(&self, tag: Option<String>);
+
+    /// Take the recorded memory events with a snapshot of the memory pools.
+    fn memory_trace(&self) -> MemoryTrace;
+
     /// Start a profile on the server. This allows you to profile kernels.
     ///
     /// This will measure execution time either by measuring the 'full' execution time by synchronizing
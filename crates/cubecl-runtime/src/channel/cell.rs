@@ -1,10 +1,13 @@
 use super::ComputeChannel;
+use crate::kernel::CompilationError;
 use crate::logging::ServerLogger;
+use crate::memory_management::OutOfMemory;
 use crate::server::{
     Binding, BindingWithMeta, Bindings, ComputeServer, CubeCount, Handle, ProfileError,
     ProfilingToken,
 };
 use crate::storage::{BindingResource, ComputeStorage};
+use alloc::string::String;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 use cubecl_common::ExecutionMode;
@@ -71,8 +74,8 @@ where
         self.server.borrow_mut().get_resource(binding)
     }
 
-    fn create(&self, resource: &[u8]) -> Handle {
-        self.server.borrow_mut().create(resource)
+    fn try_create(&self, resource: &[u8]) -> Result<Handle, OutOfMemory> {
+        self.server.borrow_mut().try_create(resource)
     }
 
     fn create_tensors(
@@ -86,8 +89,8 @@ where
             .create_tensors(data, shape, elem_size)
     }
 
-    fn empty(&self, size: usize) -> Handle {
-        self.server.borrow_mut().empty(size)
+    fn try_empty(&self, size: usize) -> Result<Handle, OutOfMemory> {
+        self.server.borrow_mut().try_empty(size)
     }
 
     fn empty_tensors(
@@ -98,6 +101,18 @@ where
         self.server.borrow_mut().empty_tensors(shape, elem_size)
     }
 
+    fn copy(&self, src: Binding, dst: Binding) {
+        self.server.borrow_mut().copy(src, dst)
+    }
+
+    fn copy_from_peer(
+        &self,
+        src: BindingResource<<Server::Storage as ComputeStorage>::Resource>,
+        size: usize,
+    ) -> Option<Handle> {
+        self.server.borrow_mut().copy_from_peer(src, size)
+    }
+
     unsafe fn execute(
         &self,
         kernel_description: Server::Kernel,
@@ -105,7 +120,7 @@ where
         bindings: Bindings,
         kind: ExecutionMode,
         logger: Arc<ServerLogger>,
-    ) {
+    ) -> Result<(), CompilationError> {
         unsafe {
             self.server
                 .borrow_mut()
@@ -125,6 +140,18 @@ where
         self.server.borrow_mut().memory_cleanup();
     }
 
+    fn memory_tracing(&self, enabled: bool) {
+        self.server.borrow_mut().memory_tracing(enabled);
+    }
+
+    fn memory_trace_tag(&self, tag: Option<String>) {
+        self.server.borrow_mut().memory_trace_tag(tag);
+    }
+
+    fn memory_trace(&self) -> crate::memory_management::MemoryTrace {
+        self.server.borrow_mut().memory_trace()
+    }
+
     fn start_profile(&self) -> ProfilingToken {
         self.server.borrow_mut().start_profile()
     }
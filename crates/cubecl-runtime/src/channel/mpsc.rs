@@ -8,8 +8,9 @@ use cubecl_common::{
 
 use super::ComputeChannel;
 use crate::{
+    kernel::CompilationError,
     logging::ServerLogger,
-    memory_management::{MemoryAllocationMode, MemoryUsage},
+    memory_management::{MemoryAllocationMode, MemoryTrace, MemoryUsage, OutOfMemory},
     server::{
         Binding, BindingWithMeta, Bindings, ComputeServer, CubeCount, Handle, ProfileError,
         ProfilingToken,
@@ -47,28 +48,38 @@ where
         Binding,
         Callback<BindingResource<<Server::Storage as ComputeStorage>::Resource>>,
     ),
-    Create(Vec<u8>, Callback<Handle>),
+    Create(Vec<u8>, Callback<Result<Handle, OutOfMemory>>),
     CreateTensor(
         Vec<Vec<u8>>,
         Vec<Vec<usize>>,
         Vec<usize>,
         Callback<Vec<(Handle, Vec<usize>)>>,
     ),
-    Empty(usize, Callback<Handle>),
+    Empty(usize, Callback<Result<Handle, OutOfMemory>>),
     EmptyTensor(
         Vec<Vec<usize>>,
         Vec<usize>,
         Callback<Vec<(Handle, Vec<usize>)>>,
     ),
+    Copy(Binding, Binding),
+    CopyFromPeer(
+        BindingResource<<Server::Storage as ComputeStorage>::Resource>,
+        usize,
+        Callback<Option<Handle>>,
+    ),
     ExecuteKernel(
         (Server::Kernel, CubeCount, ExecutionMode),
         Bindings,
         Arc<ServerLogger>,
+        Callback<Result<(), CompilationError>>,
     ),
     Flush,
     Sync(Callback<()>),
     MemoryUsage(Callback<MemoryUsage>),
     MemoryCleanup,
+    MemoryTracing(bool),
+    MemoryTraceTag(Option<String>),
+    MemoryTrace(Callback<MemoryTrace>),
     AllocationMode(MemoryAllocationMode),
     StartProfile(Callback<ProfilingToken>),
     StopMeasure(
@@ -101,7 +112,7 @@ where
                         callback.send(data).await.unwrap();
                     }
                     Message::Create(data, callback) => {
-                        let handle = server.create(&data);
+                        let handle = server.try_create(&data);
                         callback.send(handle).await.unwrap();
                     }
                     Message::CreateTensor(data, shape, elem_size, callback) => {
@@ -111,7 +122,7 @@ where
                         callback.send(handle).await.unwrap();
                     }
                     Message::Empty(size, callback) => {
-                        let handle = server.empty(size);
+                        let handle = server.try_empty(size);
                         callback.send(handle).await.unwrap();
                     }
                     Message::EmptyTensor(shape, elem_size, callback) => {
@@ -119,9 +130,19 @@ where
                         let handle = server.empty_tensors(shape, elem_size);
                         callback.send(handle).await.unwrap();
                     }
-                    Message::ExecuteKernel(kernel, bindings, logger) => unsafe {
-                        server.execute(kernel.0, kernel.1, bindings, kernel.2, logger);
-                    },
+                    Message::Copy(src, dst) => {
+                        server.copy(src, dst);
+                    }
+                    Message::CopyFromPeer(src, size, callback) => {
+                        let handle = server.copy_from_peer(src, size);
+                        callback.send(handle).await.unwrap();
+                    }
+                    Message::ExecuteKernel(kernel, bindings, logger, callback) => {
+                        let result = unsafe {
+                            server.execute(kernel.0, kernel.1, bindings, kernel.2, logger)
+                        };
+                        callback.send(result).await.unwrap();
+                    }
                     Message::Sync(callback) => {
                         server.sync().await;
                         callback.send(()).await.unwrap();
@@ -135,6 +156,15 @@ where
                     Message::MemoryCleanup => {
                         server.memory_cleanup();
                     }
+                    Message::MemoryTracing(enabled) => {
+                        server.memory_tracing(enabled);
+                    }
+                    Message::MemoryTraceTag(tag) => {
+                        server.memory_trace_tag(tag);
+                    }
+                    Message::MemoryTrace(callback) => {
+                        callback.send(server.memory_trace()).await.unwrap();
+                    }
                     Message::StartProfile(callback) => {
                         let token = server.start_profile();
                         callback.send(token).await.unwrap();
@@ -206,7 +236,7 @@ where
         handle_response(response.recv_blocking())
     }
 
-    fn create(&self, data: &[u8]) -> Handle {
+    fn try_create(&self, data: &[u8]) -> Result<Handle, OutOfMemory> {
         let (callback, response) = async_channel::unbounded();
 
         self.state
@@ -238,7 +268,7 @@ where
         handle_response(response.recv_blocking())
     }
 
-    fn empty(&self, size: usize) -> Handle {
+    fn try_empty(&self, size: usize) -> Result<Handle, OutOfMemory> {
         let (callback, response) = async_channel::unbounded();
         self.state
             .sender
@@ -266,6 +296,27 @@ where
         handle_response(response.recv_blocking())
     }
 
+    fn copy(&self, src: Binding, dst: Binding) {
+        self.state
+            .sender
+            .send_blocking(Message::Copy(src, dst))
+            .unwrap();
+    }
+
+    fn copy_from_peer(
+        &self,
+        src: BindingResource<<Server::Storage as ComputeStorage>::Resource>,
+        size: usize,
+    ) -> Option<Handle> {
+        let (callback, response) = async_channel::unbounded();
+        self.state
+            .sender
+            .send_blocking(Message::CopyFromPeer(src, size, callback))
+            .unwrap();
+
+        handle_response(response.recv_blocking())
+    }
+
     unsafe fn execute(
         &self,
         kernel: Server::Kernel,
@@ -273,15 +324,19 @@ where
         bindings: Bindings,
         kind: ExecutionMode,
         logger: Arc<ServerLogger>,
-    ) {
+    ) -> Result<(), CompilationError> {
+        let (callback, response) = async_channel::unbounded();
         self.state
             .sender
             .send_blocking(Message::ExecuteKernel(
                 (kernel, count, kind),
                 bindings,
                 logger,
+                callback,
             ))
             .unwrap();
+
+        handle_response(response.recv_blocking())
     }
 
     fn flush(&self) {
@@ -314,6 +369,29 @@ where
             .unwrap()
     }
 
+    fn memory_tracing(&self, enabled: bool) {
+        self.state
+            .sender
+            .send_blocking(Message::MemoryTracing(enabled))
+            .unwrap()
+    }
+
+    fn memory_trace_tag(&self, tag: Option<String>) {
+        self.state
+            .sender
+            .send_blocking(Message::MemoryTraceTag(tag))
+            .unwrap()
+    }
+
+    fn memory_trace(&self) -> MemoryTrace {
+        let (callback, response) = async_channel::unbounded();
+        self.state
+            .sender
+            .send_blocking(Message::MemoryTrace(callback))
+            .unwrap();
+        handle_response(response.recv_blocking())
+    }
+
     fn start_profile(&self) -> ProfilingToken {
         let (callback, response) = async_channel::unbounded();
 
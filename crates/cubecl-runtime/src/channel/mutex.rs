@@ -1,11 +1,13 @@
 use super::ComputeChannel;
+use crate::kernel::CompilationError;
 use crate::logging::ServerLogger;
-use crate::memory_management::MemoryAllocationMode;
+use crate::memory_management::{MemoryAllocationMode, OutOfMemory};
 use crate::server::{
     Binding, BindingWithMeta, Bindings, ComputeServer, CubeCount, Handle, ProfileError,
     ProfilingToken,
 };
 use crate::storage::{BindingResource, ComputeStorage};
+use alloc::string::String;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 use cubecl_common::ExecutionMode;
@@ -65,8 +67,8 @@ where
         self.server.lock().get_resource(binding)
     }
 
-    fn create(&self, data: &[u8]) -> Handle {
-        self.server.lock().create(data)
+    fn try_create(&self, data: &[u8]) -> Result<Handle, OutOfMemory> {
+        self.server.lock().try_create(data)
     }
 
     fn create_tensors(
@@ -78,8 +80,8 @@ where
         self.server.lock().create_tensors(data, shape, elem_size)
     }
 
-    fn empty(&self, size: usize) -> Handle {
-        self.server.lock().empty(size)
+    fn try_empty(&self, size: usize) -> Result<Handle, OutOfMemory> {
+        self.server.lock().try_empty(size)
     }
 
     fn empty_tensors(
@@ -90,6 +92,18 @@ where
         self.server.lock().empty_tensors(shape, elem_size)
     }
 
+    fn copy(&self, src: Binding, dst: Binding) {
+        self.server.lock().copy(src, dst)
+    }
+
+    fn copy_from_peer(
+        &self,
+        src: BindingResource<<Server::Storage as ComputeStorage>::Resource>,
+        size: usize,
+    ) -> Option<Handle> {
+        self.server.lock().copy_from_peer(src, size)
+    }
+
     unsafe fn execute(
         &self,
         kernel: Server::Kernel,
@@ -97,7 +111,7 @@ where
         handles: Bindings,
         kind: ExecutionMode,
         logger: Arc<ServerLogger>,
-    ) {
+    ) -> Result<(), CompilationError> {
         unsafe {
             self.server
                 .lock()
@@ -117,6 +131,18 @@ where
         self.server.lock().memory_cleanup();
     }
 
+    fn memory_tracing(&self, enabled: bool) {
+        self.server.lock().memory_tracing(enabled);
+    }
+
+    fn memory_trace_tag(&self, tag: Option<String>) {
+        self.server.lock().memory_trace_tag(tag);
+    }
+
+    fn memory_trace(&self) -> crate::memory_management::MemoryTrace {
+        self.server.lock().memory_trace()
+    }
+
     fn start_profile(&self) -> ProfilingToken {
         self.server.lock().start_profile()
     }
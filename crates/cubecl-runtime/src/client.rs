@@ -1,14 +1,16 @@
 use crate::{
     DeviceProperties,
     channel::ComputeChannel,
-    config::{TypeNameFormatLevel, type_name_format},
-    kernel::KernelMetadata,
+    config::{GlobalConfig, TypeNameFormatLevel, type_name_format},
+    kernel::{CompilationError, KernelMetadata},
     logging::{ProfileLevel, ServerLogger},
-    memory_management::{MemoryAllocationMode, MemoryUsage},
+    memory_management::{MemoryAllocationMode, MemoryTrace, MemoryUsage, OutOfMemory},
     server::{Binding, BindingWithMeta, Bindings, ComputeServer, CubeCount, Handle, ProfileError},
     storage::{BindingResource, ComputeStorage},
+    validation::{self, ERROR_BUFFER_LEN, OutOfBoundsRecord, PendingValidation},
 };
 use alloc::format;
+use alloc::string::String;
 use alloc::sync::Arc;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -39,6 +41,11 @@ struct ComputeClientState<Server: ComputeServer> {
     info: Server::Info,
     logger: Arc<ServerLogger>,
 
+    /// Whether checked kernels are executed with [ExecutionMode::Validate].
+    validation: bool,
+    pending_validations: spin::Mutex<Vec<PendingValidation>>,
+    out_of_bounds: spin::Mutex<Vec<OutOfBoundsRecord>>,
+
     #[cfg(multi_threading)]
     current_profiling: spin::RwLock<Option<StreamId>>,
 }
@@ -81,6 +88,9 @@ where
         let state = ComputeClientState {
             properties,
             logger: Arc::new(logger),
+            validation: GlobalConfig::get().compilation.validation,
+            pending_validations: spin::Mutex::new(Vec::new()),
+            out_of_bounds: spin::Mutex::new(Vec::new()),
             #[cfg(multi_threading)]
             current_profiling: spin::RwLock::new(None),
             // Create the GPU client if needed.
@@ -113,7 +123,12 @@ where
     ) -> impl Future<Output = Vec<Vec<u8>>> + Send + use<Server, Channel> {
         self.profile_guard();
 
-        self.channel.read(bindings)
+        let client = self.clone();
+        async move {
+            let data = client.channel.read(bindings).await;
+            client.check_validations().await;
+            data
+        }
     }
 
     /// Given bindings, returns owned resources as bytes.
@@ -124,7 +139,7 @@ where
     pub fn read(&self, bindings: Vec<Binding>) -> Vec<Vec<u8>> {
         self.profile_guard();
 
-        cubecl_common::reader::read_sync(self.channel.read(bindings))
+        cubecl_common::reader::read_sync(self.read_async(bindings))
     }
 
     /// Given a binding, returns owned resource as bytes.
@@ -132,16 +147,16 @@ where
     /// # Remarks
     /// Panics if the read operation fails.
     pub fn read_one(&self, binding: Binding) -> Vec<u8> {
-        self.profile_guard();
-
-        cubecl_common::reader::read_sync(self.channel.read([binding].into())).remove(0)
+        self.read(vec![binding]).remove(0)
     }
 
     /// Given bindings, returns owned resources as bytes.
     pub async fn read_tensor_async(&self, bindings: Vec<BindingWithMeta>) -> Vec<Vec<u8>> {
         self.profile_guard();
 
-        self.channel.read_tensor(bindings).await
+        let data = self.channel.read_tensor(bindings).await;
+        self.check_validations().await;
+        data
     }
 
     /// Given bindings, returns owned resources as bytes.
@@ -157,17 +172,13 @@ where
     ///
     /// Also see [ComputeClient::create_tensor].
     pub fn read_tensor(&self, bindings: Vec<BindingWithMeta>) -> Vec<Vec<u8>> {
-        self.profile_guard();
-
-        cubecl_common::reader::read_sync(self.channel.read_tensor(bindings))
+        cubecl_common::reader::read_sync(self.read_tensor_async(bindings))
     }
 
     /// Given a binding, returns owned resource as bytes.
     /// See [ComputeClient::read_tensor]
     pub async fn read_one_tensor_async(&self, binding: BindingWithMeta) -> Vec<u8> {
-        self.profile_guard();
-
-        self.channel.read_tensor([binding].into()).await.remove(0)
+        self.read_tensor_async(vec![binding]).await.remove(0)
     }
 
     /// Given a binding, returns owned resource as bytes.
@@ -190,10 +201,23 @@ where
     }
 
     /// Given a resource, stores it and returns the resource handle.
+    ///
+    /// # Panics
+    ///
+    /// If the memory limit is exceeded, see [ComputeClient::try_create].
     pub fn create(&self, data: &[u8]) -> Handle {
+        self.try_create(data).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Given a resource, stores it and returns the resource handle.
+    ///
+    /// When the reservation doesn't fit in the memory limit, the unused memory is released and the
+    /// reservation is retried, returning an [OutOfMemory] error with the memory usage if it still
+    /// doesn't fit.
+    pub fn try_create(&self, data: &[u8]) -> Result<Handle, OutOfMemory> {
         self.profile_guard();
 
-        self.channel.create(data)
+        self.channel.try_create(data)
     }
 
     /// Given a resource and shape, stores it and returns the tensor handle and strides.
@@ -236,10 +260,23 @@ where
     }
 
     /// Reserves `size` bytes in the storage, and returns a handle over them.
+    ///
+    /// # Panics
+    ///
+    /// If the memory limit is exceeded, see [ComputeClient::try_empty].
     pub fn empty(&self, size: usize) -> Handle {
+        self.try_empty(size).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Reserves `size` bytes in the storage, and returns a handle over them.
+    ///
+    /// When the reservation doesn't fit in the memory limit, the unused memory is released and the
+    /// reservation is retried, returning an [OutOfMemory] error with the memory usage if it still
+    /// doesn't fit.
+    pub fn try_empty(&self, size: usize) -> Result<Handle, OutOfMemory> {
         self.profile_guard();
 
-        self.channel.empty(size)
+        self.channel.try_empty(size)
     }
 
     /// Reserves `shape` in the storage, and returns a tensor handle for it.
@@ -263,6 +300,44 @@ where
         self.channel.empty_tensors(shapes, elem_size)
     }
 
+    /// Copies the bytes of `src` into `dst` on the device, without going through host memory.
+    ///
+    /// The copy happens after the tasks already submitted to this client, and `dst` must be at
+    /// least as big as `src`.
+    pub fn copy(&self, src: Binding, dst: Binding) {
+        self.profile_guard();
+
+        self.channel.copy(src, dst)
+    }
+
+    /// Copies `src` into a new buffer of `dst_client`, which may live on another device, and
+    /// returns its handle.
+    ///
+    /// The data is copied directly between devices when the runtime supports peer copies, and is
+    /// staged through host memory otherwise.
+    pub fn transfer_to(&self, src: &Handle, dst_client: &Self) -> Handle {
+        self.profile_guard();
+        dst_client.profile_guard();
+
+        let size = src.size() as usize;
+
+        // The other server can't order its copy after the pending tasks of this one.
+        cubecl_common::reader::read_sync(self.channel.sync());
+
+        let resource = self.channel.get_resource(src.clone().binding());
+        match dst_client.channel.copy_from_peer(resource, size) {
+            Some(handle) => {
+                // The source must not be reused before the copy is done.
+                cubecl_common::reader::read_sync(dst_client.channel.sync());
+                handle
+            }
+            None => {
+                let data = self.read_one(src.clone().binding());
+                dst_client.create(&data)
+            }
+        }
+    }
+
     #[track_caller]
     unsafe fn execute_inner(
         &self,
@@ -270,7 +345,16 @@ where
         count: CubeCount,
         bindings: Bindings,
         mode: ExecutionMode,
-    ) {
+    ) -> Result<(), CompilationError> {
+        let mut bindings = bindings;
+        let mode = match mode {
+            ExecutionMode::Checked if self.state.validation => ExecutionMode::Validate,
+            mode => mode,
+        };
+        if let ExecutionMode::Validate = mode {
+            self.bind_error_buffer(&kernel, &mut bindings);
+        }
+
         let level = self.state.logger.profile_level();
 
         match level {
@@ -282,7 +366,7 @@ where
                 unsafe {
                     self.channel
                         .execute(kernel, count, bindings, mode, self.state.logger.clone())
-                };
+                }?;
 
                 if matches!(level, Some(ProfileLevel::ExecutionOnly)) {
                     let info = type_name_format(name, TypeNameFormatLevel::Balanced);
@@ -292,20 +376,25 @@ where
             Some(level) => {
                 let name = kernel.name();
                 let kernel_id = kernel.id();
+                let mut result = Ok(());
                 let profile = self
                     .profile(
-                        || unsafe {
-                            self.channel.execute(
-                                kernel,
-                                count.clone(),
-                                bindings,
-                                mode,
-                                self.state.logger.clone(),
-                            )
+                        || {
+                            result = unsafe {
+                                self.channel.execute(
+                                    kernel,
+                                    count.clone(),
+                                    bindings,
+                                    mode,
+                                    self.state.logger.clone(),
+                                )
+                            }
                         },
                         name,
                     )
                     .unwrap();
+                result?;
+
                 let info = match level {
                     ProfileLevel::Full => {
                         format!("{name}: {kernel_id} CubeCount {count:?}")
@@ -315,19 +404,84 @@ where
                 self.state.logger.register_profiled(info, profile);
             }
         }
+
+        Ok(())
+    }
+
+    /// Allocate a zeroed error buffer for a validated launch and bind it after every other buffer.
+    fn bind_error_buffer(&self, kernel: &Server::Kernel, bindings: &mut Bindings) {
+        let data = vec![0u32; ERROR_BUFFER_LEN as usize];
+        let handle = self
+            .channel
+            .try_create(bytemuck::cast_slice(&data))
+            .unwrap_or_else(|err| panic!("{err}"));
+        validation::bind_error_buffer(bindings, handle.clone().binding());
+
+        self.state
+            .pending_validations
+            .lock()
+            .push(PendingValidation {
+                kernel_name: kernel.name(),
+                kernel_id: kernel.id(),
+                handle,
+            });
+    }
+
+    /// Read the error buffers of every validated launch and report the out-of-bounds accesses.
+    async fn check_validations(&self) {
+        let pending = core::mem::take(&mut *self.state.pending_validations.lock());
+        if pending.is_empty() {
+            return;
+        }
+
+        let bindings = pending
+            .iter()
+            .map(|it| it.handle.clone().binding())
+            .collect();
+        let data = self.channel.read(bindings).await;
+
+        let mut out_of_bounds = self.state.out_of_bounds.lock();
+        for (launch, data) in pending.iter().zip(data) {
+            let (records, count) = launch.records(&data);
+            for record in records.iter() {
+                log::error!("{record}");
+            }
+            if count as usize > records.len() {
+                log::error!(
+                    "{} more out-of-bounds accesses in kernel {} weren't recorded",
+                    count as usize - records.len(),
+                    launch.kernel_name
+                );
+            }
+            out_of_bounds.extend(records);
+        }
+    }
+
+    /// Take the out-of-bounds accesses recorded by validated kernels up to the last
+    /// [sync](Self::sync) or read. Kernels are validated when `compilation.validation` is enabled
+    /// in the [config](GlobalConfig).
+    pub fn take_out_of_bounds_records(&self) -> Vec<OutOfBoundsRecord> {
+        core::mem::take(&mut *self.state.out_of_bounds.lock())
     }
 
     /// Executes the `kernel` over the given `bindings`.
+    ///
+    /// Returns an error if the kernel can't be compiled for this client.
     #[track_caller]
-    pub fn execute(&self, kernel: Server::Kernel, count: CubeCount, bindings: Bindings) {
+    pub fn execute(
+        &self,
+        kernel: Server::Kernel,
+        count: CubeCount,
+        bindings: Bindings,
+    ) -> Result<(), CompilationError> {
         // SAFETY: Using checked execution mode.
-        unsafe {
-            self.execute_inner(kernel, count, bindings, ExecutionMode::Checked);
-        }
+        unsafe { self.execute_inner(kernel, count, bindings, ExecutionMode::Checked) }
     }
 
     /// Executes the `kernel` over the given `bindings` without performing any bound checks.
     ///
+    /// Returns an error if the kernel can't be compiled for this client.
+    ///
     /// # Safety
     ///
     /// To ensure this is safe, you must verify your kernel:
@@ -339,11 +493,9 @@ where
         kernel: Server::Kernel,
         count: CubeCount,
         bindings: Bindings,
-    ) {
+    ) -> Result<(), CompilationError> {
         // SAFETY: Caller has to uphold kernel being safe.
-        unsafe {
-            self.execute_inner(kernel, count, bindings, ExecutionMode::Unchecked);
-        }
+        unsafe { self.execute_inner(kernel, count, bindings, ExecutionMode::Unchecked) }
     }
 
     /// Flush all outstanding commands.
@@ -358,6 +510,7 @@ where
         self.profile_guard();
 
         self.channel.sync().await;
+        self.check_validations().await;
         self.state.logger.profile_summary();
     }
 
@@ -430,6 +583,32 @@ where
         self.channel.memory_cleanup()
     }
 
+    /// Enable or disable the tracing of the reservations, allocations, deallocations and cleanups
+    /// of this client, retrieved with [Self::memory_trace()].
+    ///
+    /// Disabling tracing discards the events recorded so far.
+    pub fn memory_tracing(&self, enabled: bool) {
+        self.profile_guard();
+
+        self.channel.memory_tracing(enabled)
+    }
+
+    /// Set the tag attached to the memory events recorded from now on, for example the name of
+    /// the current operation.
+    pub fn memory_trace_tag(&self, tag: Option<String>) {
+        self.profile_guard();
+
+        self.channel.memory_trace_tag(tag)
+    }
+
+    /// Take the memory events recorded since tracing was enabled or since the last call, with a
+    /// snapshot of the pages and slices of every memory pool.
+    pub fn memory_trace(&self) -> MemoryTrace {
+        self.profile_guard();
+
+        self.channel.memory_trace()
+    }
+
     /// Measure the execution time of some inner operations.
     #[track_caller]
     pub fn profile<O>(
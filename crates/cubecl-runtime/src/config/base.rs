@@ -1,4 +1,7 @@
-use super::{autotune::AutotuneConfig, compilation::CompilationConfig, profiling::ProfilingConfig};
+use super::{
+    autotune::AutotuneConfig, compilation::CompilationConfig, memory::MemoryConfig,
+    profiling::ProfilingConfig,
+};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::sync::Arc;
@@ -6,7 +9,8 @@ use alloc::sync::Arc;
 /// Static mutex holding the global configuration, initialized as `None`.
 static CUBE_GLOBAL_CONFIG: spin::Mutex<Option<Arc<GlobalConfig>>> = spin::Mutex::new(None);
 
-/// Represents the global configuration for CubeCL, combining profiling, autotuning, and compilation settings.
+/// Represents the global configuration for CubeCL, combining profiling, autotuning, compilation and
+/// memory settings.
 #[derive(Default, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct GlobalConfig {
     /// Configuration for profiling CubeCL operations.
@@ -20,6 +24,10 @@ pub struct GlobalConfig {
     /// Configuration for compilation settings.
     #[serde(default)]
     pub compilation: CompilationConfig,
+
+    /// Configuration for memory management.
+    #[serde(default)]
+    pub memory: MemoryConfig,
 }
 
 impl GlobalConfig {
@@ -91,6 +99,7 @@ impl GlobalConfig {
     /// Overrides configuration fields based on environment variables.
     pub fn override_from_env(mut self) -> Self {
         use super::compilation::CompilationLogLevel;
+        use super::memory::MemoryPoolsConfig;
         use crate::config::{
             autotune::{AutotuneLevel, AutotuneLogLevel},
             profiling::ProfilingLogLevel,
@@ -152,6 +161,9 @@ impl GlobalConfig {
                 "profile-full" => {
                     self.profiling.logger.level = ProfilingLogLevel::Full;
                 }
+                "validate" => {
+                    self.compilation.validation = true;
+                }
                 _ => {}
             }
         };
@@ -174,6 +186,30 @@ impl GlobalConfig {
             }
         }
 
+        if let Ok(val) = std::env::var("CUBECL_MEMORY_POOLS") {
+            match val.as_str() {
+                "subslices" => {
+                    self.memory.pools = Some(MemoryPoolsConfig::SubSlices);
+                }
+                "exclusive_pages" => {
+                    self.memory.pools = Some(MemoryPoolsConfig::ExclusivePages);
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(val) = env_u64("CUBECL_MEMORY_MAX_PAGE_SIZE") {
+            self.memory.max_page_size = Some(val);
+        }
+
+        if let Some(val) = env_u64("CUBECL_MEMORY_DEALLOC_PERIOD") {
+            self.memory.dealloc_period = Some(val);
+        }
+
+        if let Some(val) = env_u64("CUBECL_MEMORY_LIMIT") {
+            self.memory.memory_limit = Some(val);
+        }
+
         self
     }
 
@@ -215,6 +251,12 @@ impl GlobalConfig {
     }
 }
 
+// Reads an environment variable holding an unsigned integer, ignoring invalid values.
+#[cfg(std_io)]
+fn env_u64(name: &str) -> Option<u64> {
+    std::env::var(name).ok()?.trim().parse().ok()
+}
+
 #[derive(Clone, Copy, Debug)]
 /// How to format cubecl type names.
 pub enum TypeNameFormatLevel {
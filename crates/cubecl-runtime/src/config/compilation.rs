@@ -12,6 +12,11 @@ pub struct CompilationConfig {
     #[serde(default)]
     #[cfg(std_io)]
     pub cache: Option<CacheConfig>,
+    /// Whether checked kernels should record and report out-of-bounds accesses.
+    ///
+    /// Checked kernels are then executed with [ExecutionMode::Validate](cubecl_common::ExecutionMode::Validate).
+    #[serde(default)]
+    pub validation: bool,
 }
 
 /// Log levels for compilation in CubeCL.
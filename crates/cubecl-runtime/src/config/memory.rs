@@ -0,0 +1,69 @@
+use alloc::vec::Vec;
+
+/// Configuration for memory management in CubeCL.
+///
+/// Every field is optional, and only overrides the memory configuration chosen by the runtime
+/// when it is set.
+#[derive(Default, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MemoryConfig {
+    /// Memory pools used by the memory management.
+    #[serde(default)]
+    pub pools: Option<MemoryPoolsConfig>,
+
+    /// Maximum size of a page in bytes, which also bounds the size of a single allocation.
+    ///
+    /// The page size of the device is used when it is smaller.
+    #[serde(default)]
+    pub max_page_size: Option<u64>,
+
+    /// Number of allocations after which unused pages are deallocated, applied to every pool.
+    #[serde(default)]
+    pub dealloc_period: Option<u64>,
+
+    /// Maximum number of bytes each client can reserve on its device.
+    ///
+    /// When a reservation exceeds it, the unused memory is released before retrying, and an out of
+    /// memory error is returned if it still doesn't fit.
+    #[serde(default)]
+    pub memory_limit: Option<u64>,
+}
+
+/// Memory pools used by the memory management.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum MemoryPoolsConfig {
+    /// Pools allocating sub slices of bigger pages, when supported by the runtime.
+    #[serde(rename = "subslices")]
+    SubSlices,
+
+    /// Pools where every allocation is a separate page.
+    #[serde(rename = "exclusive_pages")]
+    ExclusivePages,
+
+    /// Custom pools, the first one able to hold an allocation being used.
+    #[serde(rename = "custom")]
+    Custom(Vec<MemoryPoolConfig>),
+}
+
+/// Configuration of a single custom memory pool.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type")]
+pub enum MemoryPoolConfig {
+    /// A pool where every allocation is a separate page.
+    #[serde(rename = "exclusive_pages")]
+    ExclusivePages {
+        /// The maximum number of bytes of an allocation in this pool.
+        max_alloc_size: u64,
+        /// Number of allocations after which unused pages are deallocated.
+        #[serde(default)]
+        dealloc_period: Option<u64>,
+    },
+
+    /// A pool where each allocation is a slice of a bigger page.
+    #[serde(rename = "sliced_pages")]
+    SlicedPages {
+        /// The size of the pages to allocate.
+        page_size: u64,
+        /// The maximum number of bytes of a slice in this pool.
+        max_slice_size: u64,
+    },
+}
@@ -2,6 +2,8 @@
 pub mod autotune;
 /// Compilation config module.
 pub mod compilation;
+/// Memory config module.
+pub mod memory;
 /// Profiling config module.
 pub mod profiling;
 
@@ -1,3 +1,6 @@
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+
 use crate::id::KernelId;
 
 /// Implement this trait to create a [kernel definition](KernelDefinition).
@@ -10,3 +13,89 @@ pub trait KernelMetadata: Send + Sync + 'static {
     /// Identifier for the kernel, used for caching kernel compilation.
     fn id(&self) -> KernelId;
 }
+
+/// An error that happened while compiling a kernel, usually because the backend doesn't support
+/// one of the operations or types used by the kernel.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(std_io, derive(serde::Serialize, serde::Deserialize))]
+pub struct CompilationError {
+    /// The name of the kernel that failed to compile, if known.
+    pub kernel: Option<String>,
+    /// The backend the kernel was compiled for.
+    pub backend: String,
+    /// The IR operation that couldn't be compiled, if the error is tied to one.
+    pub operation: Option<String>,
+    /// Why the compilation failed.
+    pub reason: String,
+}
+
+impl CompilationError {
+    /// Create a new compilation error for the given backend.
+    pub fn new(backend: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            kernel: None,
+            backend: backend.into(),
+            operation: None,
+            reason: reason.into(),
+        }
+    }
+
+    /// Set the IR operation that caused the error.
+    pub fn with_operation(mut self, operation: impl Display) -> Self {
+        self.operation = Some(operation.to_string());
+        self
+    }
+
+    /// Set the name of the kernel that failed to compile, unless it's already set.
+    pub fn with_kernel(mut self, kernel: impl Into<String>) -> Self {
+        self.kernel.get_or_insert_with(|| kernel.into());
+        self
+    }
+}
+
+impl Display for CompilationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.kernel {
+            Some(kernel) => write!(f, "Failed to compile kernel {kernel} for {}", self.backend)?,
+            None => write!(f, "Failed to compile kernel for {}", self.backend)?,
+        }
+        write!(f, ": {}", self.reason)?;
+        if let Some(operation) = &self.operation {
+            write!(f, "\nOperation: {operation}")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for CompilationError {}
+
+/// Keeps the first error found while compiling a kernel, so a compiler can emit a placeholder for
+/// an unsupported operation or type, keep going and fail once the whole kernel was visited.
+#[derive(Debug, Clone, Default)]
+pub struct CompilationErrorRecorder {
+    operation: Option<String>,
+    error: Option<(Option<String>, String)>,
+}
+
+impl CompilationErrorRecorder {
+    /// Set the IR operation being compiled, which is attached to the errors recorded until the
+    /// next one.
+    pub fn set_operation(&mut self, operation: impl Display) {
+        self.operation = Some(operation.to_string());
+    }
+
+    /// Record that the current operation can't be compiled, unless an error was already recorded.
+    pub fn unsupported(&mut self, reason: impl Into<String>) {
+        if self.error.is_none() {
+            self.error = Some((self.operation.clone(), reason.into()));
+        }
+    }
+
+    /// Take the recorded error, if any, as an error of the given backend.
+    pub fn take(&mut self, backend: impl Into<String>) -> Option<CompilationError> {
+        let (operation, reason) = self.error.take()?;
+        let mut err = CompilationError::new(backend, reason);
+        err.operation = operation;
+        Some(err)
+    }
+}
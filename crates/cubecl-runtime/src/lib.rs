@@ -47,3 +47,6 @@ pub mod tma;
 
 /// Simple system profiling using timestamps.
 pub mod timestamp_profiler;
+
+/// Out-of-bounds validation for kernels executed with [ExecutionMode::Validate](cubecl_common::ExecutionMode::Validate).
+pub mod validation;
@@ -80,6 +80,35 @@ impl core::fmt::Display for MemoryUsage {
     }
 }
 
+/// Error returned when a reservation doesn't fit in the memory limit, even after releasing the
+/// unused memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutOfMemory {
+    /// Number of bytes of the reservation.
+    pub size: u64,
+    /// Number of bytes that had to be allocated on the device for the reservation.
+    pub alloc_size: u64,
+    /// The memory limit in bytes.
+    pub limit: u64,
+    /// The memory usage after releasing the unused memory.
+    pub usage: MemoryUsage,
+}
+
+impl core::fmt::Display for OutOfMemory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(
+            f,
+            "Out of memory: reserving {} requires allocating {}, which exceeds the memory limit of {}.",
+            bytes_format(self.size),
+            bytes_format(self.alloc_size),
+            bytes_format(self.limit),
+        )?;
+        write!(f, "{}", self.usage)
+    }
+}
+
+impl core::error::Error for OutOfMemory {}
+
 /// The managed tensor buffer handle that points to some memory segment.
 /// It should not contain actual data.
 pub trait MemoryHandle<Binding>: Clone + Send + Sync + core::fmt::Debug {
@@ -1,8 +1,14 @@
 use super::{
-    MemoryConfiguration, MemoryDeviceProperties, MemoryPoolOptions, MemoryUsage, PoolType,
+    MemoryConfiguration, MemoryDeviceProperties, MemoryPoolOptions, MemoryUsage, OutOfMemory,
+    PoolType,
     memory_pool::{ExclusiveMemoryPool, MemoryPool, SlicedPool, StaticPool},
+    trace::{
+        MemoryEventKind, MemorySnapshot, MemoryTrace, MemoryTracer, PoolSnapshot, TracedStorage,
+    },
 };
+use crate::config::{GlobalConfig, memory::MemoryConfig};
 use crate::storage::{ComputeStorage, StorageHandle, StorageId};
+use alloc::string::String;
 #[cfg(not(exclusive_memory_only))]
 use alloc::vec;
 use alloc::vec::Vec;
@@ -18,6 +24,22 @@ enum DynamicPool {
     Exclusive(ExclusiveMemoryPool),
 }
 
+impl DynamicPool {
+    fn alloc_size(&self, size: u64) -> u64 {
+        match self {
+            DynamicPool::Sliced(m) => m.alloc_size(),
+            DynamicPool::Exclusive(m) => m.alloc_size(size),
+        }
+    }
+
+    fn snapshot(&self) -> PoolSnapshot {
+        match self {
+            DynamicPool::Sliced(m) => m.snapshot(),
+            DynamicPool::Exclusive(m) => m.snapshot(),
+        }
+    }
+}
+
 impl MemoryPool for DynamicPool {
     fn get(&self, binding: &SliceBinding) -> Option<&StorageHandle> {
         match self {
@@ -85,6 +107,8 @@ pub struct MemoryManagement<Storage> {
     storage: Storage,
     alloc_reserve_count: u64,
     mode: MemoryAllocationMode,
+    memory_limit: Option<u64>,
+    tracer: MemoryTracer,
 }
 
 /// Exclude certain storage buffers from being selected when reserving memory.
@@ -145,12 +169,42 @@ const BASE_DEALLOC_PERIOD: u64 = 5000;
 
 impl<Storage: ComputeStorage> MemoryManagement<Storage> {
     /// Creates the options from device limits.
+    ///
+    /// The [memory section](MemoryConfig) of the [global configuration](GlobalConfig) takes
+    /// precedence over the given configuration and device limits.
     pub fn from_configuration(
         storage: Storage,
         properties: &MemoryDeviceProperties,
         config: MemoryConfiguration,
     ) -> Self {
-        let pool_options = match config {
+        Self::from_configuration_with_overrides(
+            storage,
+            properties,
+            config,
+            &GlobalConfig::get().memory,
+        )
+    }
+
+    fn from_configuration_with_overrides(
+        storage: Storage,
+        properties: &MemoryDeviceProperties,
+        config: MemoryConfiguration,
+        overrides: &MemoryConfig,
+    ) -> Self {
+        let properties = &MemoryDeviceProperties {
+            max_page_size: overrides
+                .max_page_size
+                .map_or(properties.max_page_size, |size| {
+                    size.min(properties.max_page_size)
+                }),
+            alignment: properties.alignment,
+        };
+        let config = match overrides.pools.clone() {
+            Some(pools) => pools.into(),
+            None => config,
+        };
+
+        let mut pool_options = match config {
             #[cfg(not(exclusive_memory_only))]
             MemoryConfiguration::SubSlices => {
                 // Round chunk size to be aligned.
@@ -243,6 +297,12 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> {
             MemoryConfiguration::Custom { pool_options } => pool_options,
         };
 
+        if let Some(dealloc_period) = overrides.dealloc_period {
+            for options in pool_options.iter_mut() {
+                options.dealloc_period = Some(dealloc_period);
+            }
+        }
+
         for pool in pool_options.iter() {
             log::trace!("Using memory pool: \n {pool:?}");
         }
@@ -274,6 +334,8 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> {
             storage,
             alloc_reserve_count: 0,
             mode: MemoryAllocationMode::Auto,
+            memory_limit: overrides.memory_limit,
+            tracer: MemoryTracer::default(),
         }
     }
 
@@ -282,8 +344,38 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> {
         self.mode = mode;
     }
 
+    /// Change the maximum number of bytes reserved on the storage, or remove the limit with `None`.
+    pub fn memory_limit(&mut self, limit: Option<u64>) {
+        self.memory_limit = limit;
+    }
+
     /// Cleanup allocations in pools that are deemed unnecessary.
     pub fn cleanup(&mut self, explicit: bool) {
+        if self.tracer.is_enabled() {
+            let time = self.alloc_reserve_count;
+            let mut storage = TracedStorage {
+                storage: &mut self.storage,
+                tracer: &mut self.tracer,
+                time,
+                pool: None,
+                freed: 0,
+            };
+
+            self.static_pool.cleanup(&mut storage, time, explicit);
+            for (index, pool) in self.pools.iter_mut().enumerate() {
+                storage.pool = Some(index);
+                pool.cleanup(&mut storage, time, explicit);
+            }
+
+            let freed = storage.freed;
+            // Implicit cleanups happen often, only record the ones doing something
+            if explicit || freed > 0 {
+                self.tracer
+                    .record(time, MemoryEventKind::Cleanup, freed, None, None);
+            }
+            return;
+        }
+
         self.static_pool
             .cleanup(&mut self.storage, self.alloc_reserve_count, explicit);
 
@@ -292,6 +384,36 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> {
         }
     }
 
+    /// Enable or disable the recording of memory events, see [Self::trace].
+    ///
+    /// Disabling tracing discards the events recorded so far.
+    pub fn tracing(&mut self, enabled: bool) {
+        self.tracer.enable(enabled);
+    }
+
+    /// Set the tag attached to the memory events recorded from now on.
+    pub fn trace_tag(&mut self, tag: Option<String>) {
+        self.tracer.tag(tag);
+    }
+
+    /// Take the memory events recorded since tracing was enabled or since the last call, along
+    /// with a snapshot of the current state of the pools.
+    pub fn trace(&mut self) -> MemoryTrace {
+        MemoryTrace {
+            events: self.tracer.take_events(),
+            snapshot: self.snapshot(),
+        }
+    }
+
+    /// Get a snapshot of the pages and slices of every pool.
+    pub fn snapshot(&self) -> MemorySnapshot {
+        MemorySnapshot {
+            time: self.alloc_reserve_count,
+            pools: self.pools.iter().map(|pool| pool.snapshot()).collect(),
+            static_pool: self.static_pool.snapshot(),
+        }
+    }
+
     /// Returns the storage from the specified binding
     pub fn get(&mut self, binding: SliceBinding) -> Option<StorageHandle> {
         if let Some(val) = self.static_pool.get(&binding) {
@@ -324,9 +446,29 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> {
     }
 
     /// Finds a spot in memory for a resource with the given size in bytes, and returns a handle to it
+    ///
+    /// # Panics
+    ///
+    /// Panics when the reservation exceeds the memory limit, see [Self::try_reserve].
     pub fn reserve(&mut self, size: u64, exclude: Option<&StorageExclude>) -> SliceHandle {
+        self.try_reserve(size, exclude)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Finds a spot in memory for a resource with the given size in bytes, and returns a handle to it
+    ///
+    /// When allocating new memory would exceed the [memory limit](Self::memory_limit), the unused
+    /// memory is released first, and an error is returned if the reservation still doesn't fit.
+    pub fn try_reserve(
+        &mut self,
+        size: u64,
+        exclude: Option<&StorageExclude>,
+    ) -> Result<SliceHandle, OutOfMemory> {
         if let MemoryAllocationMode::Static = self.mode {
-            return self.static_pool.alloc(&mut self.storage, size);
+            self.check_memory_limit(size, size)?;
+            let slice = self.alloc(None, size);
+            self.record_reserve(None, &slice, size);
+            return Ok(slice);
         }
 
         // If this happens every nanosecond, counts overflows after 585 years, so not worth thinking too
@@ -334,17 +476,91 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> {
         self.alloc_reserve_count += 1;
 
         // Find first pool that fits this allocation
-        let pool = self
+        let pool_index = self
             .pools
-            .iter_mut()
-            .find(|p| p.max_alloc_size() >= size)
+            .iter()
+            .position(|p| p.max_alloc_size() >= size)
             .unwrap_or_else(|| panic!("No pool handles allocation of size {size}"));
 
-        if let Some(slice) = pool.try_reserve(size, exclude) {
-            return slice;
+        let slice = match self.pools[pool_index].try_reserve(size, exclude) {
+            Some(slice) => slice,
+            None => {
+                self.check_memory_limit(size, self.pools[pool_index].alloc_size(size))?;
+                self.alloc(Some(pool_index), size)
+            }
+        };
+        self.record_reserve(Some(pool_index), &slice, size);
+
+        Ok(slice)
+    }
+
+    // Allocates a new slice in the given pool, or in the static pool.
+    fn alloc(&mut self, pool: Option<usize>, size: u64) -> SliceHandle {
+        if self.tracer.is_enabled() {
+            let mut storage = TracedStorage {
+                storage: &mut self.storage,
+                tracer: &mut self.tracer,
+                time: self.alloc_reserve_count,
+                pool,
+                freed: 0,
+            };
+            return match pool {
+                Some(index) => self.pools[index].alloc(&mut storage, size),
+                None => self.static_pool.alloc(&mut storage, size),
+            };
+        }
+
+        match pool {
+            Some(index) => self.pools[index].alloc(&mut self.storage, size),
+            None => self.static_pool.alloc(&mut self.storage, size),
+        }
+    }
+
+    fn record_reserve(&mut self, pool: Option<usize>, slice: &SliceHandle, size: u64) {
+        if !self.tracer.is_enabled() {
+            return;
         }
 
-        pool.alloc(&mut self.storage, size)
+        let binding = slice.clone().binding();
+        let storage = match pool {
+            Some(index) => self.pools[index].get(&binding),
+            None => self.static_pool.get(&binding),
+        };
+        let storage = storage.map(|handle| handle.id);
+
+        self.tracer.record(
+            self.alloc_reserve_count,
+            MemoryEventKind::Reserve,
+            size,
+            pool,
+            storage,
+        );
+    }
+
+    // Checks that allocating the given number of bytes fits in the memory limit, releasing the
+    // unused memory when it doesn't.
+    fn check_memory_limit(&mut self, size: u64, alloc_size: u64) -> Result<(), OutOfMemory> {
+        let Some(limit) = self.memory_limit else {
+            return Ok(());
+        };
+
+        if self.memory_usage().bytes_reserved + alloc_size <= limit {
+            return Ok(());
+        }
+
+        self.cleanup(true);
+
+        let usage = self.memory_usage();
+        if usage.bytes_reserved + alloc_size <= limit {
+            return Ok(());
+        }
+
+        Err(OutOfMemory {
+            size,
+            alloc_size,
+            limit,
+            usage,
+        })
     }
 
     /// Fetch the storage used by the memory manager.
@@ -397,7 +613,10 @@ impl<Storage> core::fmt::Debug for MemoryManagement<Storage> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{memory_management::MemoryManagement, storage::BytesStorage};
+    use crate::{
+        memory_management::{GapSnapshot, MemoryManagement, PoolKind},
+        storage::BytesStorage,
+    };
 
     const DUMMY_MEM_PROPS: MemoryDeviceProperties = MemoryDeviceProperties {
         max_page_size: 128 * 1024 * 1024,
@@ -834,4 +1053,248 @@ mod tests {
         assert_eq!(usage_before.bytes_in_use, usage_after.bytes_in_use);
         assert_eq!(usage_before.bytes_reserved, usage_after.bytes_reserved);
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn config_overrides_pools() {
+        let config: crate::config::GlobalConfig = toml::from_str(
+            r#"
+            [memory]
+            dealloc_period = 10
+
+            [[memory.pools.custom]]
+            type = "sliced_pages"
+            page_size = 1024
+            max_slice_size = 512
+            "#,
+        )
+        .unwrap();
+        let mut memory_management = MemoryManagement::from_configuration_with_overrides(
+            BytesStorage::default(),
+            &DUMMY_MEM_PROPS,
+            MemoryConfiguration::ExclusivePages,
+            &config.memory,
+        );
+
+        let _handle = memory_management.reserve(100, None);
+        let _other_handle = memory_management.reserve(100, None);
+        let usage = memory_management.memory_usage();
+        // Both slices fit in a single page
+        assert_eq!(usage.bytes_reserved, 1024);
+    }
+
+    #[test]
+    #[should_panic(expected = "No pool handles allocation")]
+    fn config_overrides_max_page_size() {
+        let mut memory_management = MemoryManagement::from_configuration_with_overrides(
+            BytesStorage::default(),
+            &DUMMY_MEM_PROPS,
+            MemoryConfiguration::ExclusivePages,
+            &MemoryConfig {
+                max_page_size: Some(1024 * 1024),
+                ..Default::default()
+            },
+        );
+
+        let _handle = memory_management.reserve(2 * 1024 * 1024, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the memory limit")]
+    fn config_memory_limit() {
+        let mut memory_management = MemoryManagement::from_configuration_with_overrides(
+            BytesStorage::default(),
+            &DUMMY_MEM_PROPS,
+            MemoryConfiguration::Custom {
+                pool_options: vec![MemoryPoolOptions {
+                    pool_type: PoolType::ExclusivePages {
+                        max_alloc_size: 1024,
+                    },
+                    dealloc_period: None,
+                }],
+            },
+            &MemoryConfig {
+                memory_limit: Some(1024),
+                ..Default::default()
+            },
+        );
+
+        let _handle = memory_management.reserve(512, None);
+        let _other_handle = memory_management.reserve(512, None);
+        let _too_many = memory_management.reserve(512, None);
+    }
+
+    #[test]
+    fn memory_limit_releases_unused_memory() {
+        let pool_options = [512, 1024]
+            .map(|max_alloc_size| MemoryPoolOptions {
+                pool_type: PoolType::ExclusivePages { max_alloc_size },
+                dealloc_period: None,
+            })
+            .to_vec();
+        let mut memory_management = MemoryManagement::from_configuration(
+            BytesStorage::default(),
+            &DUMMY_MEM_PROPS,
+            MemoryConfiguration::Custom { pool_options },
+        );
+        memory_management.memory_limit(Some(1024));
+
+        let handle = memory_management.reserve(512, None);
+        drop(handle);
+        // The unused page of the first pool is deallocated to fit the new one
+        let _handle = memory_management.try_reserve(1024, None).unwrap();
+
+        assert_eq!(memory_management.memory_usage().bytes_reserved, 1024);
+    }
+
+    #[test]
+    fn memory_limit_out_of_memory() {
+        let mut memory_management = MemoryManagement::from_configuration(
+            BytesStorage::default(),
+            &DUMMY_MEM_PROPS,
+            MemoryConfiguration::Custom {
+                pool_options: vec![MemoryPoolOptions {
+                    pool_type: PoolType::ExclusivePages {
+                        max_alloc_size: 1024,
+                    },
+                    dealloc_period: None,
+                }],
+            },
+        );
+        memory_management.memory_limit(Some(1024));
+
+        let _handle = memory_management.reserve(512, None);
+        let _other_handle = memory_management.reserve(512, None);
+        let err = memory_management.try_reserve(512, None).unwrap_err();
+
+        assert_eq!(err.size, 512);
+        assert_eq!(err.limit, 1024);
+        assert_eq!(err.usage.bytes_reserved, 1024);
+        assert_eq!(err.usage.number_allocs, 2);
+
+        // Removing the limit allows the reservation
+        memory_management.memory_limit(None);
+        assert!(memory_management.try_reserve(512, None).is_ok());
+    }
+
+    #[test]
+    fn trace_records_events() {
+        let mut memory_management = MemoryManagement::from_configuration(
+            BytesStorage::default(),
+            &DUMMY_MEM_PROPS,
+            MemoryConfiguration::Custom {
+                pool_options: vec![MemoryPoolOptions {
+                    pool_type: PoolType::ExclusivePages {
+                        max_alloc_size: 1024,
+                    },
+                    dealloc_period: None,
+                }],
+            },
+        );
+        memory_management.tracing(true);
+        memory_management.trace_tag(Some("forward".into()));
+
+        let handle = memory_management.reserve(512, None);
+        drop(handle);
+        memory_management.cleanup(true);
+
+        let events = memory_management.trace().events;
+        let kinds: Vec<_> = events.iter().map(|event| event.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                MemoryEventKind::Alloc,
+                MemoryEventKind::Reserve,
+                MemoryEventKind::Free,
+                MemoryEventKind::Cleanup
+            ]
+        );
+        assert_eq!(events[0].storage, events[1].storage);
+        assert_eq!(events[2].storage, events[1].storage);
+        assert_eq!(events[1].size, 512);
+        assert_eq!(events[2].size, events[0].size);
+        assert_eq!(events[3].size, events[0].size);
+        assert!(
+            events
+                .iter()
+                .all(|event| event.tag.as_deref() == Some("forward"))
+        );
+
+        // Events are only returned once
+        assert!(memory_management.trace().events.is_empty());
+    }
+
+    #[test]
+    fn trace_disabled_records_nothing() {
+        let mut memory_management = MemoryManagement::from_configuration(
+            BytesStorage::default(),
+            &DUMMY_MEM_PROPS,
+            MemoryConfiguration::ExclusivePages,
+        );
+
+        let _handle = memory_management.reserve(512, None);
+        memory_management.cleanup(true);
+
+        assert!(memory_management.trace().events.is_empty());
+    }
+
+    #[test]
+    fn snapshot_free_gaps() {
+        let mut memory_management = MemoryManagement::from_configuration(
+            BytesStorage::default(),
+            &DUMMY_MEM_PROPS,
+            MemoryConfiguration::Custom {
+                pool_options: vec![MemoryPoolOptions {
+                    pool_type: PoolType::SlicedPages {
+                        page_size: 1024,
+                        max_slice_size: 1024,
+                    },
+                    dealloc_period: None,
+                }],
+            },
+        );
+
+        let first = memory_management.reserve(100, None);
+        let _second = memory_management.reserve(100, None);
+        drop(first);
+
+        let snapshot = memory_management.snapshot();
+        assert_eq!(snapshot.pools.len(), 1);
+        assert_eq!(snapshot.pools[0].kind, PoolKind::Sliced);
+        assert_eq!(snapshot.pools[0].pages.len(), 1);
+
+        let page = &snapshot.pools[0].pages[0];
+        assert_eq!(page.size, 1024);
+        assert_eq!(
+            page.free_gaps,
+            vec![
+                GapSnapshot {
+                    offset: 0,
+                    size: 128
+                },
+                GapSnapshot {
+                    offset: 256,
+                    size: 768
+                }
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(std_io)]
+    fn trace_to_json() {
+        let mut memory_management = MemoryManagement::from_configuration(
+            BytesStorage::default(),
+            &DUMMY_MEM_PROPS,
+            MemoryConfiguration::ExclusivePages,
+        );
+        memory_management.tracing(true);
+
+        let _handle = memory_management.reserve(512, None);
+        let json = memory_management.trace().to_json();
+
+        assert!(json.contains(r#""kind":"alloc""#));
+        assert!(json.contains(r#""kind":"exclusive""#));
+        assert!(json.contains(r#""free_gaps""#));
+    }
 }
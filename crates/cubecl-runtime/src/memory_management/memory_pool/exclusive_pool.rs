@@ -1,9 +1,11 @@
 use crate::{
-    memory_management::{MemoryUsage, StorageExclude},
+    memory_management::{
+        MemoryUsage, PageSnapshot, PoolKind, PoolSnapshot, SliceSnapshot, StorageExclude,
+    },
     storage::{ComputeStorage, StorageHandle, StorageUtilization},
 };
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 use super::{MemoryPool, Slice, SliceBinding, SliceHandle, calculate_padding};
 
@@ -65,14 +67,39 @@ impl ExclusiveMemoryPool {
             .min_by_key(|page| page.free_count)
     }
 
+    pub(crate) fn snapshot(&self) -> PoolSnapshot {
+        PoolSnapshot {
+            kind: PoolKind::Exclusive,
+            max_alloc_size: self.max_alloc_size,
+            pages: self
+                .pages
+                .iter()
+                .map(|page| {
+                    let slice = SliceSnapshot {
+                        offset: 0,
+                        size: page.slice.storage.size(),
+                        padding: page.slice.padding,
+                        free: page.slice.is_free(),
+                    };
+                    PageSnapshot::new(page.slice.storage.id, page.alloc_size, vec![slice])
+                })
+                .collect(),
+        }
+    }
+
+    /// Number of bytes of the page allocated to hold a new allocation of the given size.
+    pub(crate) fn alloc_size(&self, size: u64) -> u64 {
+        (self.cur_avg_size as u64)
+            .max(size)
+            .next_multiple_of(self.alignment)
+    }
+
     fn alloc_page<Storage: ComputeStorage>(
         &mut self,
         storage: &mut Storage,
         size: u64,
     ) -> &mut MemoryPage {
-        let alloc_size = (self.cur_avg_size as u64)
-            .max(size)
-            .next_multiple_of(self.alignment);
+        let alloc_size = self.alloc_size(size);
 
         let storage = storage.alloc(alloc_size);
 
@@ -1,7 +1,9 @@
 use super::index::SearchIndex;
 use super::{MemoryPool, RingBuffer, Slice, SliceBinding, SliceHandle, SliceId};
 use crate::memory_management::memory_pool::calculate_padding;
-use crate::memory_management::{MemoryUsage, StorageExclude};
+use crate::memory_management::{
+    MemoryUsage, PageSnapshot, PoolKind, PoolSnapshot, SliceSnapshot, StorageExclude,
+};
 use crate::storage::{ComputeStorage, StorageHandle, StorageId, StorageUtilization};
 use alloc::vec::Vec;
 use hashbrown::HashMap;
@@ -215,6 +217,39 @@ impl SlicedPool {
         Slice::new(storage, handle, padding)
     }
 
+    pub(crate) fn snapshot(&self) -> PoolSnapshot {
+        let mut pages: Vec<_> = self
+            .pages
+            .iter()
+            .map(|(storage, page)| {
+                let slices = page
+                    .slices
+                    .values()
+                    .filter_map(|id| self.slices.get(id))
+                    .map(|slice| SliceSnapshot {
+                        offset: slice.storage.offset(),
+                        size: slice.storage.size(),
+                        padding: slice.padding,
+                        free: slice.is_free(),
+                    })
+                    .collect();
+                PageSnapshot::new(*storage, self.page_size, slices)
+            })
+            .collect();
+        pages.sort_by_key(|page| page.storage);
+
+        PoolSnapshot {
+            kind: PoolKind::Sliced,
+            max_alloc_size: self.max_alloc_size,
+            pages,
+        }
+    }
+
+    /// Number of bytes of the page allocated to hold a new allocation.
+    pub(crate) fn alloc_size(&self) -> u64 {
+        self.page_size
+    }
+
     /// Creates a page of given size by allocating on the storage.
     fn create_page<Storage: ComputeStorage>(&mut self, storage: &mut Storage) -> StorageId {
         let storage = storage.alloc(self.page_size);
@@ -1,5 +1,5 @@
-use crate::memory_management::MemoryUsage;
-use alloc::vec::Vec;
+use crate::memory_management::{MemoryUsage, PageSnapshot, PoolKind, PoolSnapshot, SliceSnapshot};
+use alloc::{vec, vec::Vec};
 use hashbrown::HashMap;
 
 use super::{MemoryPool, Slice, SliceHandle, SliceId, calculate_padding};
@@ -16,6 +16,30 @@ impl StaticPool {
             max_alloc_size,
         }
     }
+
+    pub(crate) fn snapshot(&self) -> PoolSnapshot {
+        let mut pages: Vec<_> = self
+            .slices
+            .values()
+            .map(|slice| {
+                let size = slice.storage.size();
+                let snapshot = SliceSnapshot {
+                    offset: 0,
+                    size: size - slice.padding,
+                    padding: slice.padding,
+                    free: slice.is_free(),
+                };
+                PageSnapshot::new(slice.storage.id, size, vec![snapshot])
+            })
+            .collect();
+        pages.sort_by_key(|page| page.storage);
+
+        PoolSnapshot {
+            kind: PoolKind::Static,
+            max_alloc_size: self.max_alloc_size,
+            pages,
+        }
+    }
 }
 
 impl MemoryPool for StaticPool {
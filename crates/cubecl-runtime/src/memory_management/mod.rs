@@ -6,12 +6,16 @@ pub use base::*;
 
 /// Dynamic memory management strategy.
 mod memory_manage;
+/// Tracing of memory events and snapshots of the memory pools.
+mod trace;
 use cubecl_common::CubeDim;
 pub use memory_manage::*;
+pub use trace::*;
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
+use crate::config::memory::{MemoryPoolConfig, MemoryPoolsConfig};
 use crate::server::CubeCount;
 
 /// The type of memory pool to use.
@@ -75,6 +79,45 @@ impl Default for MemoryConfiguration {
     }
 }
 
+impl From<MemoryPoolsConfig> for MemoryConfiguration {
+    fn from(config: MemoryPoolsConfig) -> Self {
+        match config {
+            #[cfg(not(exclusive_memory_only))]
+            MemoryPoolsConfig::SubSlices => MemoryConfiguration::SubSlices,
+            #[cfg(exclusive_memory_only)]
+            MemoryPoolsConfig::SubSlices => {
+                log::warn!("Sub slices aren't supported on this platform, using exclusive pages");
+                MemoryConfiguration::ExclusivePages
+            }
+            MemoryPoolsConfig::ExclusivePages => MemoryConfiguration::ExclusivePages,
+            MemoryPoolsConfig::Custom(pools) => MemoryConfiguration::Custom {
+                pool_options: pools
+                    .into_iter()
+                    .map(|pool| match pool {
+                        MemoryPoolConfig::ExclusivePages {
+                            max_alloc_size,
+                            dealloc_period,
+                        } => MemoryPoolOptions {
+                            pool_type: PoolType::ExclusivePages { max_alloc_size },
+                            dealloc_period,
+                        },
+                        MemoryPoolConfig::SlicedPages {
+                            page_size,
+                            max_slice_size,
+                        } => MemoryPoolOptions {
+                            pool_type: PoolType::SlicedPages {
+                                page_size,
+                                max_slice_size,
+                            },
+                            dealloc_period: None,
+                        },
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
+
 /// Properties of the device related to allocation.
 #[derive(Debug, Clone)]
 pub struct MemoryDeviceProperties {
@@ -0,0 +1,280 @@
+use crate::storage::{ComputeStorage, StorageHandle, StorageId};
+use alloc::string::String;
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+
+/// The kind of a recorded [memory event](MemoryEvent).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum MemoryEventKind {
+    /// A slice was handed out for a reservation, reusing memory or not.
+    #[serde(rename = "reserve")]
+    Reserve,
+    /// A page was allocated on the storage.
+    #[serde(rename = "alloc")]
+    Alloc,
+    /// A page was deallocated from the storage.
+    #[serde(rename = "free")]
+    Free,
+    /// The pools were cleaned up, with the size being the number of bytes deallocated.
+    #[serde(rename = "cleanup")]
+    Cleanup,
+}
+
+/// An event recorded by the memory management when tracing is enabled.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MemoryEvent {
+    /// Number of reservations done before the event, used as a logical timestamp.
+    pub time: u64,
+    /// What happened.
+    pub kind: MemoryEventKind,
+    /// Number of bytes reserved, allocated or deallocated.
+    pub size: u64,
+    /// Index of the pool in the [snapshot](MemorySnapshot::pools), or `None` for the static pool
+    /// and for cleanups of all pools.
+    pub pool: Option<usize>,
+    /// The storage of the page holding the memory, or `None` for cleanups.
+    pub storage: Option<StorageId>,
+    /// The tag set when the event happened.
+    pub tag: Option<String>,
+}
+
+/// The kind of a memory pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum PoolKind {
+    /// Every allocation is a slice of a bigger page.
+    #[serde(rename = "sliced")]
+    Sliced,
+    /// Every allocation is a separate page.
+    #[serde(rename = "exclusive")]
+    Exclusive,
+    /// Allocations are never reused, used with [static allocations](super::MemoryAllocationMode::Static).
+    #[serde(rename = "static")]
+    Static,
+}
+
+/// State of the memory pools at a given time.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MemorySnapshot {
+    /// Number of reservations done before the snapshot, comparable to [MemoryEvent::time].
+    pub time: u64,
+    /// The dynamic pools, in the order they are used.
+    pub pools: Vec<PoolSnapshot>,
+    /// The pool of static allocations.
+    pub static_pool: PoolSnapshot,
+}
+
+/// State of a single memory pool.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PoolSnapshot {
+    /// The kind of pool.
+    pub kind: PoolKind,
+    /// The maximum size of an allocation in the pool.
+    pub max_alloc_size: u64,
+    /// The pages of the pool, sorted by storage.
+    pub pages: Vec<PageSnapshot>,
+}
+
+/// State of a page of a memory pool.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PageSnapshot {
+    /// The storage of the page.
+    pub storage: StorageId,
+    /// Size of the page in bytes.
+    pub size: u64,
+    /// The slices of the page, sorted by offset.
+    pub slices: Vec<SliceSnapshot>,
+    /// Ranges of the page that aren't in use, merging consecutive free slices.
+    pub free_gaps: Vec<GapSnapshot>,
+}
+
+/// State of a slice of a page.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SliceSnapshot {
+    /// Offset of the slice in the page.
+    pub offset: u64,
+    /// Number of bytes of the slice.
+    pub size: u64,
+    /// Number of bytes of padding after the slice.
+    pub padding: u64,
+    /// Whether the slice can be reused.
+    pub free: bool,
+}
+
+/// A range of a page that isn't in use.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct GapSnapshot {
+    /// Offset of the gap in the page.
+    pub offset: u64,
+    /// Number of bytes of the gap.
+    pub size: u64,
+}
+
+impl PageSnapshot {
+    /// Creates the snapshot of a page from its slices, computing the free gaps.
+    pub(crate) fn new(storage: StorageId, size: u64, mut slices: Vec<SliceSnapshot>) -> Self {
+        slices.sort_by_key(|slice| slice.offset);
+
+        let mut free_gaps: Vec<GapSnapshot> = Vec::new();
+        let mut push_gap = |offset: u64, end: u64| {
+            if end <= offset {
+                return;
+            }
+            match free_gaps.last_mut() {
+                Some(last) if last.offset + last.size == offset => last.size += end - offset,
+                _ => free_gaps.push(GapSnapshot {
+                    offset,
+                    size: end - offset,
+                }),
+            }
+        };
+
+        let mut cursor = 0;
+        for slice in slices.iter() {
+            push_gap(cursor, slice.offset);
+            let end = slice.offset + slice.size + slice.padding;
+            if slice.free {
+                push_gap(slice.offset, end);
+            }
+            cursor = cursor.max(end);
+        }
+        push_gap(cursor, size);
+
+        Self {
+            storage,
+            size,
+            slices,
+            free_gaps,
+        }
+    }
+}
+
+/// Everything recorded by the memory management since tracing was enabled or last exported.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MemoryTrace {
+    /// The recorded events, in order.
+    pub events: Vec<MemoryEvent>,
+    /// The state of the pools when the trace was exported.
+    pub snapshot: MemorySnapshot,
+}
+
+impl MemoryTrace {
+    /// Serializes the trace to JSON.
+    #[cfg(std_io)]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Memory traces should be serializable")
+    }
+}
+
+/// Records the events of the memory management.
+#[derive(Debug, Default)]
+pub(crate) struct MemoryTracer {
+    enabled: bool,
+    tag: Option<String>,
+    events: Vec<MemoryEvent>,
+    page_sizes: HashMap<StorageId, u64>,
+}
+
+impl MemoryTracer {
+    pub(crate) fn enable(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.events.clear();
+            self.page_sizes.clear();
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub(crate) fn tag(&mut self, tag: Option<String>) {
+        self.tag = tag;
+    }
+
+    pub(crate) fn take_events(&mut self) -> Vec<MemoryEvent> {
+        core::mem::take(&mut self.events)
+    }
+
+    pub(crate) fn record(
+        &mut self,
+        time: u64,
+        kind: MemoryEventKind,
+        size: u64,
+        pool: Option<usize>,
+        storage: Option<StorageId>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        match kind {
+            MemoryEventKind::Alloc => {
+                if let Some(storage) = storage {
+                    self.page_sizes.insert(storage, size);
+                }
+            }
+            MemoryEventKind::Free => {
+                if let Some(storage) = storage {
+                    self.page_sizes.remove(&storage);
+                }
+            }
+            _ => {}
+        }
+
+        self.events.push(MemoryEvent {
+            time,
+            kind,
+            size,
+            pool,
+            storage,
+            tag: self.tag.clone(),
+        });
+    }
+
+    /// Size of a page allocated while tracing, or 0 if it was allocated before.
+    fn page_size(&self, storage: &StorageId) -> u64 {
+        self.page_sizes.get(storage).copied().unwrap_or(0)
+    }
+}
+
+/// A storage recording the pages allocated and deallocated by a pool.
+pub(crate) struct TracedStorage<'a, Storage> {
+    pub(crate) storage: &'a mut Storage,
+    pub(crate) tracer: &'a mut MemoryTracer,
+    pub(crate) time: u64,
+    pub(crate) pool: Option<usize>,
+    /// Number of bytes deallocated through this storage.
+    pub(crate) freed: u64,
+}
+
+impl<Storage: ComputeStorage> ComputeStorage for TracedStorage<'_, Storage> {
+    type Resource = Storage::Resource;
+
+    fn alignment(&self) -> usize {
+        self.storage.alignment()
+    }
+
+    fn get(&mut self, handle: &StorageHandle) -> Self::Resource {
+        self.storage.get(handle)
+    }
+
+    fn alloc(&mut self, size: u64) -> StorageHandle {
+        let handle = self.storage.alloc(size);
+        self.tracer.record(
+            self.time,
+            MemoryEventKind::Alloc,
+            size,
+            self.pool,
+            Some(handle.id),
+        );
+        handle
+    }
+
+    fn dealloc(&mut self, id: StorageId) {
+        let size = self.tracer.page_size(&id);
+        self.freed += size;
+        self.tracer
+            .record(self.time, MemoryEventKind::Free, size, self.pool, Some(id));
+        self.storage.dealloc(id);
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
-    kernel::KernelMetadata,
+    kernel::{CompilationError, KernelMetadata},
     logging::ServerLogger,
     memory_management::{
-        MemoryAllocationMode, MemoryHandle, MemoryUsage,
+        MemoryAllocationMode, MemoryHandle, MemoryTrace, MemoryUsage, OutOfMemory,
         memory_pool::{SliceBinding, SliceHandle},
     },
     storage::{BindingResource, ComputeStorage},
@@ -58,7 +58,17 @@ where
     ) -> BindingResource<<Self::Storage as ComputeStorage>::Resource>;
 
     /// Given a resource as bytes, stores it and returns the memory handle.
-    fn create(&mut self, data: &[u8]) -> Handle;
+    ///
+    /// # Panics
+    ///
+    /// Panics when running out of memory, see [Self::try_create].
+    fn create(&mut self, data: &[u8]) -> Handle {
+        self.try_create(data).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Given a resource as bytes, stores it and returns the memory handle, or an error when it
+    /// doesn't fit in the memory limit.
+    fn try_create(&mut self, data: &[u8]) -> Result<Handle, OutOfMemory>;
 
     /// Given a resource as bytes with `shape`, stores it and returns the tensor handle.
     /// May or may not be contiguous, depending on what's best for the given runtime. Always use
@@ -73,7 +83,17 @@ where
     ) -> Vec<(Handle, Vec<usize>)>;
 
     /// Reserves `size` bytes in the storage, and returns a handle over them.
-    fn empty(&mut self, size: usize) -> Handle;
+    ///
+    /// # Panics
+    ///
+    /// Panics when running out of memory, see [Self::try_empty].
+    fn empty(&mut self, size: usize) -> Handle {
+        self.try_empty(size).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Reserves `size` bytes in the storage, and returns a handle over them, or an error when they
+    /// don't fit in the memory limit.
+    fn try_empty(&mut self, size: usize) -> Result<Handle, OutOfMemory>;
 
     /// Reserves `shape` bytes in the storage, and returns a handle to it.
     fn empty_tensors(
@@ -82,11 +102,32 @@ where
         elem_sizes: Vec<usize>,
     ) -> Vec<(Handle, Vec<usize>)>;
 
+    /// Copies the bytes of `src` into `dst` on the device, after the tasks already submitted.
+    ///
+    /// The destination must be at least as big as the source.
+    fn copy(&mut self, src: Binding, dst: Binding);
+
+    /// Copies a resource of another server of the same type into a new buffer of `size` bytes,
+    /// without going through host memory.
+    ///
+    /// The source must not have pending writes on its own server. Returns `None` without
+    /// allocating anything when the server doesn't support peer copies.
+    fn copy_from_peer(
+        &mut self,
+        _src: BindingResource<<Self::Storage as ComputeStorage>::Resource>,
+        _size: usize,
+    ) -> Option<Handle> {
+        None
+    }
+
     /// Executes the `kernel` over the given memory `handles`.
     ///
     /// Kernels have mutable access to every resource they are given
     /// and are responsible of determining which should be read or written.
     ///
+    /// Returns an error without executing anything if the kernel can't be compiled for this
+    /// server.
+    ///
     /// # Safety
     ///
     /// When executing with mode [ExecutionMode::Unchecked], out-of-bound reads and writes can happen.
@@ -97,7 +138,7 @@ where
         bindings: Bindings,
         kind: ExecutionMode,
         logger: Arc<ServerLogger>,
-    );
+    ) -> Result<(), CompilationError>;
 
     /// Flush all outstanding tasks in the server.
     fn flush(&mut self);
@@ -116,6 +157,15 @@ where
 
     /// Update the memory mode of allocation in the server.
     fn allocation_mode(&mut self, mode: MemoryAllocationMode);
+
+    /// Enable or disable the tracing of memory events.
+    fn memory_tracing(&mut self, enabled: bool);
+
+    /// Set the tag attached to the memory events recorded from now on.
+    fn memory_trace_tag(&mut self, tag: Option<String>);
+
+    /// Take the recorded memory events with a snapshot of the memory pools.
+    fn memory_trace(&mut self) -> MemoryTrace;
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
@@ -5,6 +5,12 @@ use crate::{server::Binding, storage_id_type};
 // This ID is used to map a handle to its actual data.
 storage_id_type!(StorageId);
 
+impl serde::Serialize for StorageId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.value as u64)
+    }
+}
+
 /// Defines if data uses a full memory chunk or a slice of it.
 #[derive(Clone, Debug)]
 pub struct StorageUtilization {
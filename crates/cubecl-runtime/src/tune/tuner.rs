@@ -14,6 +14,7 @@ use cubecl_common::benchmark::{BenchmarkComputations, BenchmarkDurations};
 use crate::channel::ComputeChannel;
 use crate::client::ComputeClient;
 use crate::config::{Logger, autotune::AutotuneLogLevel};
+use crate::kernel::CompilationError;
 use crate::server::ComputeServer;
 use crate::tune::{TuneBenchmark, TuneCache};
 
@@ -69,6 +70,8 @@ pub enum AutotuneError {
     InvalidSamples,
     /// The autotune is skipped manually.
     Skip,
+    /// The kernel of the tunable couldn't be compiled for this backend.
+    Compilation(CompilationError),
 }
 
 impl From<String> for AutotuneError {
@@ -77,6 +80,12 @@ impl From<String> for AutotuneError {
     }
 }
 
+impl From<CompilationError> for AutotuneError {
+    fn from(value: CompilationError) -> Self {
+        Self::Compilation(value)
+    }
+}
+
 #[allow(clippy::new_without_default)]
 impl<K: AutotuneKey> Tuner<K> {
     /// Returns a tuner with cache initialized from persistent cache
@@ -0,0 +1,112 @@
+use core::fmt::Display;
+
+use alloc::vec::Vec;
+
+use crate::{
+    id::KernelId,
+    server::{Binding, Bindings, Handle, MetadataBinding},
+};
+
+/// The maximum number of out-of-bounds accesses recorded per kernel launch.
+pub const MAX_RECORDS: u32 = 32;
+/// The number of `u32` values in a single record: the buffer id, the index and the buffer length.
+pub const RECORD_LEN: u32 = 3;
+/// The number of `u32` values in the error buffer. The first value counts every out-of-bounds
+/// access, even the ones that didn't fit, and is followed by the records.
+pub const ERROR_BUFFER_LEN: u32 = 1 + MAX_RECORDS * RECORD_LEN;
+
+/// An out-of-bounds access recorded by a kernel executed with
+/// [ExecutionMode::Validate](cubecl_common::ExecutionMode::Validate).
+#[derive(Debug, Clone)]
+pub struct OutOfBoundsRecord {
+    /// The name of the kernel that performed the access.
+    pub kernel_name: &'static str,
+    /// The id of the kernel that performed the access.
+    pub kernel_id: KernelId,
+    /// The id of the buffer binding that was accessed.
+    pub buffer: u32,
+    /// The offending index.
+    pub index: u32,
+    /// The length of the buffer at the time of the access.
+    pub length: u32,
+}
+
+impl Display for OutOfBoundsRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Out-of-bounds access in kernel {}: index {} into buffer {} of length {}",
+            self.kernel_name, self.index, self.buffer, self.length
+        )
+    }
+}
+
+/// A validated kernel launch whose error buffer hasn't been read yet.
+#[derive(Debug)]
+pub(crate) struct PendingValidation {
+    pub kernel_name: &'static str,
+    pub kernel_id: KernelId,
+    pub handle: Handle,
+}
+
+impl PendingValidation {
+    /// Parse the error buffer, returning the records and the total number of out-of-bounds
+    /// accesses, which may be higher than the number of records.
+    pub fn records(&self, data: &[u8]) -> (Vec<OutOfBoundsRecord>, u32) {
+        let data: &[u32] = bytemuck::cast_slice(data);
+        let count = data[0];
+        let records = data[1..]
+            .chunks_exact(RECORD_LEN as usize)
+            .take(count.min(MAX_RECORDS) as usize)
+            .map(|record| OutOfBoundsRecord {
+                kernel_name: self.kernel_name,
+                kernel_id: self.kernel_id.clone(),
+                buffer: record[0],
+                index: record[1],
+                length: record[2],
+            })
+            .collect();
+        (records, count)
+    }
+}
+
+/// Bind the error buffer after every other buffer, matching the binding the compiler adds for
+/// validated kernels, and insert its lengths into the metadata.
+pub(crate) fn bind_error_buffer(bindings: &mut Bindings, binding: Binding) {
+    let num_meta = bindings.buffers.len() + bindings.tensor_maps.len();
+    insert_error_buffer_meta(&mut bindings.metadata, num_meta);
+    bindings.buffers.push(binding);
+}
+
+fn insert_error_buffer_meta(metadata: &mut MetadataBinding, num_meta: usize) {
+    // See `cubecl_core::Metadata` for the layout. The buffer lengths and logical lengths of every
+    // buffer come first, followed by the extended metadata which contains absolute offsets.
+    let num_ext = (metadata.static_len - num_meta * 2) / 3;
+
+    metadata.data.insert(num_meta, ERROR_BUFFER_LEN);
+    metadata.data.insert(num_meta * 2 + 1, ERROR_BUFFER_LEN);
+    let offsets_start = num_meta * 2 + 2 + num_ext;
+    for offset in &mut metadata.data[offsets_start..offsets_start + num_ext * 2] {
+        *offset += 2;
+    }
+    metadata.static_len += 2;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_buffer_meta_is_inserted_after_buffers() {
+        // An array of length 4, then a tensor with shape [2, 3] and strides [3, 1]
+        let mut metadata = MetadataBinding::new(vec![4, 6, 4, 6, 2, 7, 9, 2, 3, 3, 1], 7);
+        insert_error_buffer_meta(&mut metadata, 2);
+
+        let len = ERROR_BUFFER_LEN;
+        assert_eq!(metadata.static_len, 9);
+        assert_eq!(
+            metadata.data,
+            vec![4, 6, len, 4, 6, len, 2, 9, 11, 2, 3, 3, 1]
+        );
+    }
+}
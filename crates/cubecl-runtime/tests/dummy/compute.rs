@@ -20,6 +20,12 @@ pub type DummyClient = ComputeClient<DummyServer, DummyChannel>;
 static RUNTIME: ComputeRuntime<DummyDevice, DummyServer, DummyChannel> = ComputeRuntime::new();
 
 pub fn init_client() -> ComputeClient<DummyServer, MutexComputeChannel<DummyServer>> {
+    init_client_with_memory_limit(None)
+}
+
+pub fn init_client_with_memory_limit(
+    memory_limit: Option<u64>,
+) -> ComputeClient<DummyServer, MutexComputeChannel<DummyServer>> {
     let storage = BytesStorage::default();
     let mem_properties = MemoryDeviceProperties {
         max_page_size: 1024 * 1024 * 512,
@@ -37,11 +43,12 @@ pub fn init_client() -> ComputeClient<DummyServer, MutexComputeChannel<DummyServ
         num_tensor_cores: None,
         min_tensor_cores_dim: None,
     };
-    let memory_management = MemoryManagement::from_configuration(
+    let mut memory_management = MemoryManagement::from_configuration(
         storage,
         &mem_properties,
         MemoryConfiguration::default(),
     );
+    memory_management.memory_limit(memory_limit);
     let server = DummyServer::new(memory_management);
     let channel = MutexComputeChannel::new(server);
     ComputeClient::new(
@@ -1,4 +1,4 @@
-use cubecl_runtime::{id::KernelId, storage::BytesResource};
+use cubecl_runtime::{id::KernelId, kernel::CompilationError, storage::BytesResource};
 
 /// The DummyKernel trait should be implemented for every supported operation
 pub trait DummyKernel: Sync + Send + 'static + core::fmt::Debug {
@@ -9,6 +9,10 @@ pub trait DummyKernel: Sync + Send + 'static + core::fmt::Debug {
     fn name(&self) -> &'static str {
         core::any::type_name::<Self>()
     }
+
+    fn compile(&self) -> Result<(), CompilationError> {
+        Ok(())
+    }
 }
 
 /// Contains the algorithm for element-wise addition
@@ -2,14 +2,14 @@ use cubecl_common::ExecutionMode;
 use cubecl_common::future::DynFut;
 use cubecl_common::profile::ProfileDuration;
 use cubecl_runtime::id::KernelId;
-use cubecl_runtime::kernel::KernelMetadata;
+use cubecl_runtime::kernel::{CompilationError, KernelMetadata};
 use cubecl_runtime::logging::ServerLogger;
 use cubecl_runtime::server::{BindingWithMeta, Bindings, ProfileError, ProfilingToken};
 use cubecl_runtime::timestamp_profiler::TimestampProfiler;
 use std::sync::Arc;
 
 use super::DummyKernel;
-use cubecl_runtime::memory_management::MemoryUsage;
+use cubecl_runtime::memory_management::{MemoryUsage, OutOfMemory};
 use cubecl_runtime::server::CubeCount;
 use cubecl_runtime::storage::{BindingResource, BytesResource, ComputeStorage};
 use cubecl_runtime::{
@@ -48,6 +48,10 @@ impl KernelTask {
         }
     }
 
+    pub fn compile(&self) -> Result<(), CompilationError> {
+        self.kernel.compile()
+    }
+
     pub fn compute(&self, resources: &mut [&BytesResource]) {
         self.kernel.compute(resources);
     }
@@ -85,15 +89,15 @@ impl ComputeServer for DummyServer {
         BindingResource::new(binding, self.memory_management.storage().get(&handle))
     }
 
-    fn create(&mut self, data: &[u8]) -> Handle {
-        let handle = self.empty(data.len());
+    fn try_create(&mut self, data: &[u8]) -> Result<Handle, OutOfMemory> {
+        let handle = self.try_empty(data.len())?;
         let resource = self.get_resource(handle.clone().binding());
         let bytes = resource.resource().write();
         for (i, val) in data.iter().enumerate() {
             bytes[i] = *val;
         }
 
-        handle
+        Ok(handle)
     }
 
     fn create_tensors(
@@ -117,13 +121,13 @@ impl ComputeServer for DummyServer {
             .collect()
     }
 
-    fn empty(&mut self, size: usize) -> Handle {
-        Handle::new(
-            self.memory_management.reserve(size as u64, None),
+    fn try_empty(&mut self, size: usize) -> Result<Handle, OutOfMemory> {
+        Ok(Handle::new(
+            self.memory_management.try_reserve(size as u64, None)?,
             None,
             None,
             size as u64,
-        )
+        ))
     }
 
     fn empty_tensors(
@@ -148,6 +152,29 @@ impl ComputeServer for DummyServer {
             .collect()
     }
 
+    fn copy(&mut self, src: Binding, dst: Binding) {
+        let src = self.get_resource(src);
+        let dst = self.get_resource(dst);
+        let data = src.resource().read();
+
+        dst.resource().write()[..data.len()].copy_from_slice(data);
+    }
+
+    fn copy_from_peer(
+        &mut self,
+        src: BindingResource<BytesResource>,
+        size: usize,
+    ) -> Option<Handle> {
+        let handle = self.empty(size);
+        let resource = self.get_resource(handle.clone().binding());
+        resource
+            .resource()
+            .write()
+            .copy_from_slice(&src.resource().read()[..size]);
+
+        Some(handle)
+    }
+
     unsafe fn execute(
         &mut self,
         kernel: Self::Kernel,
@@ -155,7 +182,9 @@ impl ComputeServer for DummyServer {
         bindings: Bindings,
         _mode: ExecutionMode,
         _logger: Arc<ServerLogger>,
-    ) {
+    ) -> Result<(), CompilationError> {
+        kernel.compile()?;
+
         let mut resources: Vec<_> = bindings
             .buffers
             .into_iter()
@@ -174,6 +203,8 @@ impl ComputeServer for DummyServer {
         let mut resources: Vec<_> = resources.iter().map(|x| x.resource()).collect();
 
         kernel.compute(&mut resources);
+
+        Ok(())
     }
 
     fn flush(&mut self) {
@@ -199,6 +230,18 @@ impl ComputeServer for DummyServer {
     fn allocation_mode(&mut self, mode: cubecl_runtime::memory_management::MemoryAllocationMode) {
         self.memory_management.mode(mode)
     }
+
+    fn memory_tracing(&mut self, enabled: bool) {
+        self.memory_management.tracing(enabled);
+    }
+
+    fn memory_trace_tag(&mut self, tag: Option<String>) {
+        self.memory_management.trace_tag(tag);
+    }
+
+    fn memory_trace(&mut self) -> cubecl_runtime::memory_management::MemoryTrace {
+        self.memory_management.trace()
+    }
 }
 
 impl DummyServer {
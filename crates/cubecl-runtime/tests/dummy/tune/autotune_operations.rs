@@ -24,7 +24,7 @@ impl TuneFn for OneKernelAutotuneOperation {
             self.kernel.clone(),
             CubeCount::Static(1, 1, 1),
             Bindings::new().with_buffers(inputs),
-        );
+        )?;
 
         Ok(())
     }
@@ -1,6 +1,6 @@
 use std::{thread::sleep, time::Duration};
 
-use cubecl_runtime::{id::KernelId, storage::BytesResource};
+use cubecl_runtime::{id::KernelId, kernel::CompilationError, storage::BytesResource};
 
 use crate::dummy::DummyKernel;
 
@@ -12,6 +12,8 @@ pub struct DummyElementwiseAdditionSlowWrong;
 pub struct DummyElementwiseMultiplication;
 #[derive(Debug)]
 pub struct DummyElementwiseMultiplicationSlowWrong;
+#[derive(Debug)]
+pub struct DummyElementwiseAdditionUnsupported;
 
 #[derive(Debug)]
 pub struct CacheTestFastOn3;
@@ -137,3 +139,17 @@ impl DummyKernel for ParameteredKernel {
         KernelId::new::<Self>()
     }
 }
+
+impl DummyKernel for DummyElementwiseAdditionUnsupported {
+    fn compute(&self, _inputs: &mut [&BytesResource]) {
+        unreachable!("Should never be executed since it can't be compiled")
+    }
+
+    fn id(&self) -> KernelId {
+        KernelId::new::<Self>()
+    }
+
+    fn compile(&self) -> Result<(), CompilationError> {
+        Err(CompilationError::new("dummy", "Addition isn't supported").with_kernel(self.name()))
+    }
+}
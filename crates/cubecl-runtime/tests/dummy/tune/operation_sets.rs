@@ -8,7 +8,7 @@ use crate::dummy::{
     DummyElementwiseMultiplicationSlowWrong, KernelTask, OneKernelAutotuneOperation,
 };
 
-use super::DummyElementwiseAdditionSlowWrong;
+use super::{DummyElementwiseAdditionSlowWrong, DummyElementwiseAdditionUnsupported};
 
 #[allow(clippy::ptr_arg, reason = "Needed for type inference")]
 fn clone_bindings(_key: &String, bindings: &Vec<Binding>) -> Vec<Binding> {
@@ -35,6 +35,21 @@ pub fn addition_set(
     )))
 }
 
+pub fn unsupported_addition_set(client: DummyClient, shapes: Vec<Vec<usize>>) -> TestSet {
+    TestSet::new(
+        move |_input: &Vec<Binding>| format!("{}-{}", "add", log_shape_input_key(&shapes)),
+        clone_bindings,
+    )
+    .with(Tunable::new(OneKernelAutotuneOperation::new(
+        KernelTask::new(DummyElementwiseAdditionUnsupported),
+        client.clone(),
+    )))
+    .with(Tunable::new(OneKernelAutotuneOperation::new(
+        KernelTask::new(DummyElementwiseAddition),
+        client.clone(),
+    )))
+}
+
 pub fn multiplication_set(client: DummyClient, shapes: Vec<Vec<usize>>) -> TestSet {
     TestSet::new(
         move |_input: &Vec<Binding>| format!("{}-{}", "mul", log_shape_input_key(&shapes)),
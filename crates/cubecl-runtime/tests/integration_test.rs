@@ -28,6 +28,40 @@ fn empty_allocates_memory() {
     assert_eq!(empty_resource.len(), 4);
 }
 
+#[test]
+fn copy_duplicates_resource() {
+    let client = test_client(&DummyDevice);
+    let src = client.create(&[0, 1, 2, 3]);
+    let dst = client.empty(4);
+
+    client.copy(src.binding(), dst.clone().binding());
+
+    assert_eq!(client.read_one(dst.binding()), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn transfer_to_copies_resource_to_other_client() {
+    let client = init_client();
+    let other = init_client();
+    let src = client.create(&[0, 1, 2, 3]);
+
+    let dst = client.transfer_to(&src, &other);
+
+    assert_eq!(other.read_one(dst.binding()), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn try_empty_returns_out_of_memory_over_limit() {
+    let client = init_client_with_memory_limit(Some(0));
+
+    let err = client.try_empty(4).unwrap_err();
+    assert_eq!(err.size, 4);
+    assert_eq!(err.limit, 0);
+    assert_eq!(err.usage.bytes_reserved, 0);
+
+    assert!(client.try_create(&[0, 1, 2]).is_err());
+}
+
 #[test]
 fn execute_elementwise_addition() {
     let client = test_client(&DummyDevice);
@@ -35,17 +69,43 @@ fn execute_elementwise_addition() {
     let rhs = client.create(&[4, 4, 4]);
     let out = client.empty(3);
 
-    client.execute(
-        KernelTask::new(DummyElementwiseAddition),
-        CubeCount::Static(1, 1, 1),
-        Bindings::new().with_buffers(vec![lhs.binding(), rhs.binding(), out.clone().binding()]),
-    );
+    client
+        .execute(
+            KernelTask::new(DummyElementwiseAddition),
+            CubeCount::Static(1, 1, 1),
+            Bindings::new().with_buffers(vec![lhs.binding(), rhs.binding(), out.clone().binding()]),
+        )
+        .unwrap();
 
     let obtained_resource = client.read_one(out.binding());
 
     assert_eq!(obtained_resource, Vec::from([4, 5, 6]))
 }
 
+#[test]
+fn execute_unsupported_kernel_returns_compilation_error() {
+    let client = test_client(&DummyDevice);
+
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out = client.empty(3);
+
+    let err = client
+        .execute(
+            KernelTask::new(DummyElementwiseAdditionUnsupported),
+            CubeCount::Static(1, 1, 1),
+            Bindings::new().with_buffers(vec![lhs.binding(), rhs.binding(), out.binding()]),
+        )
+        .unwrap_err();
+
+    assert_eq!(err.backend, "dummy");
+    assert!(
+        err.kernel
+            .unwrap()
+            .ends_with("DummyElementwiseAdditionUnsupported")
+    );
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn autotune_basic_addition_execution() {
@@ -96,3 +156,28 @@ fn autotune_basic_multiplication_execution() {
     // If slow kernel was selected it would output [0, 1, 2]
     assert_eq!(obtained_resource, Vec::from([0, 4, 8]));
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn autotune_skips_kernels_that_fail_to_compile() {
+    static TUNER: LocalTuner<String, String> =
+        local_tuner!("autotune_skips_kernels_that_fail_to_compile");
+
+    let client = test_client(&DummyDevice);
+
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out = client.empty(3);
+    let handles = vec![lhs.binding(), rhs.binding(), out.clone().binding()];
+
+    let test_set = TUNER.init(|| {
+        let client = test_client(&DummyDevice);
+        let shapes = vec![vec![1, 3], vec![1, 3], vec![1, 3]];
+        dummy::unsupported_addition_set(client, shapes)
+    });
+    TUNER.execute(&"test".to_string(), &client, test_set, handles);
+
+    let obtained_resource = client.read_one(out.binding());
+
+    assert_eq!(obtained_resource, Vec::from([4, 5, 6]));
+}
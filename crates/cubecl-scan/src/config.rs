@@ -0,0 +1,140 @@
+use cubecl_core::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::ScanStrategy;
+
+// TODO: Should we allows the user to change that?
+const SHARED_CUBE_DIM: u32 = 256;
+const ITEMS_PER_UNIT: u32 = 4;
+const PLANE_COUNT: u32 = 8;
+
+/// The number of items scanned by a cube with the decoupled look-back.
+pub const LOOK_BACK_TILE_SIZE: u32 = SHARED_CUBE_DIM * ITEMS_PER_UNIT;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ScanMode {
+    /// The output at a given coordinate includes the input at the same coordinate.
+    Inclusive,
+    /// The output at a given coordinate only includes the inputs at smaller coordinates,
+    /// starting with the identity of the operator.
+    Exclusive,
+}
+
+/// Compile-time settings of the scan kernels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScanParams {
+    pub mode: ScanMode,
+    pub line_size: u32,
+    /// The number of units in a cube, for strategies using shared memory.
+    pub cube_size: u32,
+    /// The number of consecutive items of a tile scanned by each unit, for strategies using shared memory.
+    pub items_per_unit: u32,
+}
+
+impl ScanParams {
+    pub fn tile_size(&self) -> u32 {
+        self.cube_size * self.items_per_unit
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ScanConfig {
+    pub cube_count: CubeCount,
+    pub cube_dim: CubeDim,
+    pub line_size: u32,
+    /// The axis along which items are grouped in lines.
+    pub line_axis: usize,
+    /// The number of independent scans of lines.
+    pub num_rows: u32,
+    /// The number of tiles per row with the decoupled look-back.
+    pub tiles_per_row: u32,
+}
+
+impl ScanConfig {
+    pub(crate) fn generate<R: Runtime, N: CubePrimitive>(
+        client: &ComputeClient<R::Server, R::Channel>,
+        input: &TensorHandleRef<R>,
+        output: &TensorHandleRef<R>,
+        axis: usize,
+        strategy: &ScanStrategy,
+    ) -> ScanConfig {
+        let (line_size, line_axis) = Self::line_size::<R, N>(input, output, axis);
+        let shape_axis = input.shape[axis] as u32;
+        let num_rows = (input.size() / input.shape[axis]) as u32 / line_size;
+
+        let (cube_dim, num_cubes, tiles_per_row) = match strategy {
+            ScanStrategy::Plane => {
+                let plane_dim = client.properties().hardware.plane_size_min;
+                (
+                    CubeDim::new_2d(plane_dim, PLANE_COUNT),
+                    num_rows.div_ceil(PLANE_COUNT),
+                    1,
+                )
+            }
+            ScanStrategy::Shared => (CubeDim::new_1d(SHARED_CUBE_DIM), num_rows, 1),
+            ScanStrategy::DecoupledLookBack => {
+                let tiles_per_row = shape_axis.div_ceil(LOOK_BACK_TILE_SIZE);
+                (
+                    CubeDim::new_1d(SHARED_CUBE_DIM),
+                    num_rows * tiles_per_row,
+                    tiles_per_row,
+                )
+            }
+        };
+
+        ScanConfig {
+            cube_count: calculate_cube_count_elemwise(
+                num_cubes as usize * cube_dim.num_elems() as usize,
+                cube_dim,
+            ),
+            cube_dim,
+            line_size,
+            line_axis,
+            num_rows,
+            tiles_per_row,
+        }
+    }
+
+    pub(crate) fn params(&self, mode: ScanMode) -> ScanParams {
+        ScanParams {
+            mode,
+            line_size: self.line_size,
+            cube_size: self.cube_dim.num_elems(),
+            items_per_unit: ITEMS_PER_UNIT,
+        }
+    }
+
+    /// Lines group the items of independent scans along the contiguous axis, so they can only be
+    /// used when the scanned axis isn't contiguous and both tensors share the same layout.
+    fn line_size<R: Runtime, N: CubePrimitive>(
+        input: &TensorHandleRef<R>,
+        output: &TensorHandleRef<R>,
+        axis: usize,
+    ) -> (u32, usize) {
+        let rank = input.shape.len();
+        let Some(line_axis) =
+            (0..rank).find(|dim| input.strides[*dim] == 1 && input.shape[*dim] > 1)
+        else {
+            return (1, axis);
+        };
+
+        if line_axis == axis || input.strides != output.strides {
+            return (1, line_axis);
+        }
+
+        let elem = N::as_elem_native_unchecked();
+        let line_size = R::line_size_elem(&elem)
+            .map(|line_size| line_size as usize)
+            .filter(|line_size| {
+                input.shape[line_axis] % line_size == 0
+                    && (0..rank).all(|dim| {
+                        dim == line_axis
+                            || input.shape[dim] == 1
+                            || input.strides[dim] % line_size == 0
+                    })
+            })
+            .max()
+            .unwrap_or(1);
+
+        (line_size as u32, line_axis)
+    }
+}
@@ -0,0 +1,67 @@
+use core::fmt;
+
+use cubecl_core::CompilationError;
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum ScanError {
+    /// Indicate that the hardware / API doesn't support SIMT plane instructions.
+    PlanesUnavailable,
+    /// Indicate that min_plane_dim != max_plane_dim, thus the exact plane_dim is not fixed.
+    ImprecisePlaneDim,
+    /// Indicate that the atomic operations required by the decoupled look-back are not supported.
+    AtomicsUnavailable,
+    /// Indicate that the fences required by the decoupled look-back are not supported.
+    DeviceFenceUnavailable,
+    /// Indicate the axis is too large.
+    InvalidAxis { axis: usize, rank: usize },
+    /// Indicate that the shape of the output tensor is different from the shape of the input tensor.
+    MismatchShape {
+        expected_shape: Vec<usize>,
+        output_shape: Vec<usize>,
+    },
+    /// Indicate that the scan kernel couldn't be compiled for the client.
+    Compilation(CompilationError),
+}
+
+impl From<CompilationError> for ScanError {
+    fn from(value: CompilationError) -> Self {
+        Self::Compilation(value)
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PlanesUnavailable => write!(
+                f,
+                "Trying to launch a kernel using plane instructions, but there are not supported by the hardware."
+            ),
+            Self::ImprecisePlaneDim => write!(
+                f,
+                "Trying to launch a kernel using plane instructions, but the min and max plane dimensions are different."
+            ),
+            Self::AtomicsUnavailable => write!(
+                f,
+                "Trying to launch a decoupled look-back, but atomic loads, stores and additions on u32 are not supported by the client."
+            ),
+            Self::DeviceFenceUnavailable => write!(
+                f,
+                "Trying to launch a decoupled look-back, but fences across cubes are not supported by the client."
+            ),
+            Self::InvalidAxis { axis, rank } => write!(
+                f,
+                "The provided axis ({axis}) must be smaller than the input tensor rank ({rank})."
+            ),
+            Self::MismatchShape {
+                expected_shape,
+                output_shape,
+            } => {
+                write!(
+                    f,
+                    "The output shape (currently {output_shape:?}) should be {expected_shape:?}."
+                )
+            }
+            Self::Compilation(err) => write!(f, "{err}"),
+        }
+    }
+}
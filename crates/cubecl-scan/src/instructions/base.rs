@@ -0,0 +1,36 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+pub trait ScanFamily: Send + Sync + 'static + std::fmt::Debug {
+    type Instruction<N: Numeric>: ScanInstruction<N, Config = Self::Config>;
+    type Config: CubeComptime + Send + Sync;
+}
+
+/// An associative operator for a scan algorithm that works with [`Line`].
+///
+/// The lanes of a line belong to independent scans, so all the operations are applied lane-wise.
+/// See a provided implementation, such as [`Sum`](super::Sum) or [`Max`](super::Max) for an example how
+/// to implement this trait for a custom operator.
+///
+/// The operator must be associative, but it doesn't need to be commutative: items are always combined
+/// in the order of their coordinates along the scanned axis.
+#[cube]
+pub trait ScanInstruction<N: Numeric>: Send + Sync + 'static + std::fmt::Debug + CubeType {
+    type Config: CubeComptime + Send + Sync;
+
+    fn from_config(#[comptime] config: Self::Config) -> Self;
+
+    /// The identity of the operator.
+    ///
+    /// This is the first item of an exclusive scan and it is used to pad partial tiles.
+    fn identity(this: &Self, #[comptime] line_size: u32) -> Line<N>;
+
+    /// Combine the partial results of two consecutive ranges of items, `lhs` preceding `rhs`.
+    fn combine(this: &Self, lhs: Line<N>, rhs: Line<N>) -> Line<N>;
+
+    /// The inclusive scan of the values held by the units of a plane, ordered by `UNIT_POS_PLANE`.
+    ///
+    /// Operators without a dedicated plane instruction can use
+    /// [`plane_inclusive_scan`](crate::primitives::plane_inclusive_scan).
+    fn plane_inclusive(this: &Self, value: Line<N>) -> Line<N>;
+}
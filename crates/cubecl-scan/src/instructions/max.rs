@@ -0,0 +1,36 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::primitives::plane_inclusive_scan;
+
+use super::{ScanFamily, ScanInstruction};
+
+/// Compute the cumulative maximum.
+#[derive(Debug, CubeType, Clone)]
+pub struct Max {}
+
+impl ScanFamily for Max {
+    type Instruction<N: Numeric> = Self;
+    type Config = ();
+}
+
+#[cube]
+impl<N: Numeric> ScanInstruction<N> for Max {
+    type Config = ();
+
+    fn from_config(_config: Self::Config) -> Self {
+        Max {}
+    }
+
+    fn identity(_this: &Self, #[comptime] line_size: u32) -> Line<N> {
+        Line::empty(line_size).fill(N::min_value())
+    }
+
+    fn combine(_this: &Self, lhs: Line<N>, rhs: Line<N>) -> Line<N> {
+        select_many(lhs.greater_than(rhs), lhs, rhs)
+    }
+
+    fn plane_inclusive(this: &Self, value: Line<N>) -> Line<N> {
+        plane_inclusive_scan::<N, Self>(this, value)
+    }
+}
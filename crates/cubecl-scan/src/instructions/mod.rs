@@ -0,0 +1,11 @@
+mod base;
+mod max;
+mod min;
+mod prod;
+mod sum;
+
+pub use base::*;
+pub use max::*;
+pub use min::*;
+pub use prod::*;
+pub use sum::*;
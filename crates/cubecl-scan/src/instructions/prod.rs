@@ -0,0 +1,34 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use super::{ScanFamily, ScanInstruction};
+
+/// Compute the cumulative product.
+#[derive(Debug, CubeType, Clone)]
+pub struct Prod {}
+
+impl ScanFamily for Prod {
+    type Instruction<N: Numeric> = Self;
+    type Config = ();
+}
+
+#[cube]
+impl<N: Numeric> ScanInstruction<N> for Prod {
+    type Config = ();
+
+    fn from_config(_config: Self::Config) -> Self {
+        Prod {}
+    }
+
+    fn identity(_this: &Self, #[comptime] line_size: u32) -> Line<N> {
+        Line::empty(line_size).fill(N::from_int(1))
+    }
+
+    fn combine(_this: &Self, lhs: Line<N>, rhs: Line<N>) -> Line<N> {
+        lhs * rhs
+    }
+
+    fn plane_inclusive(_this: &Self, value: Line<N>) -> Line<N> {
+        plane_inclusive_prod(value)
+    }
+}
@@ -0,0 +1,326 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+use cubecl_std::tensor::TensorHandle;
+
+use crate::instructions::*;
+use crate::primitives::*;
+use crate::{ScanConfig, ScanError, ScanMode, ScanParams, ScanStrategy};
+
+/// The tile published its own aggregate.
+const STATUS_AGGREGATE: u32 = 1;
+/// The tile published the aggregate of all the items of its row up to and including itself.
+const STATUS_PREFIX: u32 = 2;
+
+/// Launch a scan kernel. This function assumes that all parameters are already validated.
+/// See the main entrypoint `scan` in `lib.rs` for an example how to call this function
+/// with the appropriate assumptions.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn launch_scan<R: Runtime, N: Numeric, S: ScanFamily>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<R>,
+    output: TensorHandleRef<R>,
+    axis: u32,
+    mode: ScanMode,
+    config: ScanConfig,
+    strategy: ScanStrategy,
+    inst: S::Config,
+) -> Result<(), ScanError> {
+    let params = config.params(mode);
+    let line_size = config.line_size as u8;
+
+    unsafe {
+        match strategy {
+            ScanStrategy::Plane => scan_plane_kernel::launch_unchecked::<N, S, R>(
+                client,
+                config.cube_count,
+                config.cube_dim,
+                input.as_tensor_arg(line_size),
+                output.as_tensor_arg(line_size),
+                ScalarArg::new(axis),
+                ScalarArg::new(config.line_axis as u32),
+                ScalarArg::new(config.num_rows),
+                params,
+                inst,
+            )?,
+            ScanStrategy::Shared => scan_shared_kernel::launch_unchecked::<N, S, R>(
+                client,
+                config.cube_count,
+                config.cube_dim,
+                input.as_tensor_arg(line_size),
+                output.as_tensor_arg(line_size),
+                ScalarArg::new(axis),
+                ScalarArg::new(config.line_axis as u32),
+                ScalarArg::new(config.num_rows),
+                params,
+                inst,
+            )?,
+            ScanStrategy::DecoupledLookBack => {
+                let num_tiles = (config.num_rows * config.tiles_per_row) as usize;
+                // The first value is the counter used to assign tiles to cubes.
+                let status = TensorHandle::<R, u32>::zeros(client, vec![num_tiles + 1]);
+                let partials =
+                    TensorHandle::<R, N>::empty(client, vec![2 * num_tiles * line_size as usize]);
+
+                scan_look_back_kernel::launch_unchecked::<N, S, R>(
+                    client,
+                    config.cube_count,
+                    config.cube_dim,
+                    input.as_tensor_arg(line_size),
+                    output.as_tensor_arg(line_size),
+                    status.as_arg(1),
+                    partials.as_arg(line_size),
+                    ScalarArg::new(axis),
+                    ScalarArg::new(config.line_axis as u32),
+                    ScalarArg::new(config.tiles_per_row),
+                    params,
+                    inst,
+                )?
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Each plane scans a row, iterating over tiles of `PLANE_DIM` items.
+#[cube(launch_unchecked)]
+pub fn scan_plane_kernel<N: Numeric, S: ScanFamily>(
+    input: &Tensor<Line<N>>,
+    output: &mut Tensor<Line<N>>,
+    axis: u32,
+    line_axis: u32,
+    num_rows: u32,
+    #[comptime] params: ScanParams,
+    #[comptime] config: S::Config,
+) {
+    let row = CUBE_POS * CUBE_DIM_Y + UNIT_POS_Y;
+
+    if row >= num_rows {
+        terminate!();
+    }
+
+    let inst = &S::Instruction::<N>::from_config(config);
+    let (offset_input, offset_output) =
+        row_offsets::<N>(input, output, row, axis, line_axis, params.line_size);
+    let stride_input = input.stride(axis) / params.line_size;
+    let stride_output = output.stride(axis) / params.line_size;
+    let shape_axis = input.shape(axis);
+
+    let mut carry = S::Instruction::<N>::identity(inst, params.line_size);
+    let num_tiles = div_ceil(shape_axis, PLANE_DIM);
+
+    for tile in 0..num_tiles {
+        let position = tile * PLANE_DIM + UNIT_POS_PLANE;
+        let in_bounds = position < shape_axis;
+
+        let value = if in_bounds {
+            input[offset_input + position * stride_input]
+        } else {
+            S::Instruction::<N>::identity(inst, params.line_size)
+        };
+
+        let inclusive = S::Instruction::<N>::combine(
+            inst,
+            carry,
+            S::Instruction::<N>::plane_inclusive(inst, value),
+        );
+
+        let result = if comptime![params.mode == ScanMode::Inclusive] {
+            inclusive
+        } else {
+            let previous = plane_shuffle_up(inclusive, 1);
+            select(UNIT_POS_PLANE == 0, carry, previous)
+        };
+
+        if in_bounds {
+            output[offset_output + position * stride_output] = result;
+        }
+
+        carry = plane_broadcast(inclusive, PLANE_DIM - 1);
+    }
+}
+
+/// Each cube scans a row, iterating over tiles stored in shared memory.
+#[cube(launch_unchecked)]
+pub fn scan_shared_kernel<N: Numeric, S: ScanFamily>(
+    input: &Tensor<Line<N>>,
+    output: &mut Tensor<Line<N>>,
+    axis: u32,
+    line_axis: u32,
+    num_rows: u32,
+    #[comptime] params: ScanParams,
+    #[comptime] config: S::Config,
+) {
+    let row = CUBE_POS;
+
+    if row >= num_rows {
+        terminate!();
+    }
+
+    let inst = &S::Instruction::<N>::from_config(config);
+    let (offset_input, offset_output) =
+        row_offsets::<N>(input, output, row, axis, line_axis, params.line_size);
+    let stride_input = input.stride(axis) / params.line_size;
+    let stride_output = output.stride(axis) / params.line_size;
+    let shape_axis = input.shape(axis);
+
+    let tile_size = comptime![params.tile_size()];
+    let mut tile = SharedMemory::<N>::new_lined(tile_size, params.line_size);
+    let mut totals = SharedMemory::<N>::new_lined(params.cube_size, params.line_size);
+
+    let mut carry = S::Instruction::<N>::identity(inst, params.line_size);
+    let num_tiles = div_ceil(shape_axis, tile_size);
+
+    for t in 0..num_tiles {
+        let tile_start = t * tile_size;
+
+        load_tile::<N, S::Instruction<N>>(
+            inst,
+            input,
+            &mut tile,
+            offset_input,
+            stride_input,
+            tile_start,
+            shape_axis,
+            params,
+        );
+        let aggregate =
+            tile_inclusive_scan::<N, S::Instruction<N>>(inst, &mut tile, &mut totals, params);
+        store_tile::<N, S::Instruction<N>>(
+            inst,
+            output,
+            &tile,
+            carry,
+            offset_output,
+            stride_output,
+            tile_start,
+            shape_axis,
+            params,
+        );
+
+        carry = S::Instruction::<N>::combine(inst, carry, aggregate);
+
+        // The tile is overwritten by the next iteration.
+        sync_cube();
+    }
+}
+
+/// Each cube scans a single tile of a row and gets the aggregate of the previous tiles of the row
+/// with a decoupled look-back.
+///
+/// The first value of `status` is a counter assigning tiles in the order the cubes start, so a cube
+/// only ever waits on cubes that already started, which guarantees forward progress. Each tile
+/// publishes its aggregate in `partials` as soon as it is scanned, then the aggregate of the whole
+/// prefix once the look-back is done, and its `status` tells which of them is available.
+///
+/// Only the first unit of the cube publishes and reads `partials` and `status`. A `device_fence`
+/// between writing a value and its status, and between reading a status and the value it tells
+/// about, makes sure a value is read once it was fully written, even by a cube on another core.
+#[cube(launch_unchecked)]
+#[allow(clippy::too_many_arguments)]
+pub fn scan_look_back_kernel<N: Numeric, S: ScanFamily>(
+    input: &Tensor<Line<N>>,
+    output: &mut Tensor<Line<N>>,
+    status: &mut Tensor<Atomic<u32>>,
+    partials: &mut Tensor<Line<N>>,
+    axis: u32,
+    line_axis: u32,
+    tiles_per_row: u32,
+    #[comptime] params: ScanParams,
+    #[comptime] config: S::Config,
+) {
+    let mut tile_index = SharedMemory::<u32>::new(1);
+    if UNIT_POS == 0 {
+        tile_index[0] = Atomic::add(&status[0], 1);
+    }
+    sync_cube();
+
+    let tile_id = tile_index[0];
+
+    if tile_id + 1 >= status.len() {
+        terminate!();
+    }
+
+    let row = tile_id / tiles_per_row;
+    let tile_in_row = tile_id % tiles_per_row;
+    let is_first_tile = tile_in_row == 0;
+
+    let inst = &S::Instruction::<N>::from_config(config);
+    let (offset_input, offset_output) =
+        row_offsets::<N>(input, output, row, axis, line_axis, params.line_size);
+    let stride_input = input.stride(axis) / params.line_size;
+    let stride_output = output.stride(axis) / params.line_size;
+    let shape_axis = input.shape(axis);
+
+    let tile_size = comptime![params.tile_size()];
+    let tile_start = tile_in_row * tile_size;
+    let mut tile = SharedMemory::<N>::new_lined(tile_size, params.line_size);
+    let mut totals = SharedMemory::<N>::new_lined(params.cube_size, params.line_size);
+
+    load_tile::<N, S::Instruction<N>>(
+        inst,
+        input,
+        &mut tile,
+        offset_input,
+        stride_input,
+        tile_start,
+        shape_axis,
+        params,
+    );
+    let aggregate =
+        tile_inclusive_scan::<N, S::Instruction<N>>(inst, &mut tile, &mut totals, params);
+
+    let mut carry = SharedMemory::<N>::new_lined(1, params.line_size);
+
+    // The aggregate of a tile is stored at `2 * tile_id` and its prefix at `2 * tile_id + 1`.
+    // The aggregate of the first tile of a row is already its prefix.
+    if UNIT_POS == 0 {
+        let index = select(is_first_tile, 2 * tile_id + 1, 2 * tile_id);
+        partials[index] = aggregate;
+        device_fence();
+
+        let flag = select(is_first_tile, STATUS_PREFIX, STATUS_AGGREGATE);
+        Atomic::store(&status[tile_id + 1], flag);
+
+        let mut exclusive = S::Instruction::<N>::identity(inst, params.line_size);
+
+        if !is_first_tile {
+            let mut previous = tile_id - 1;
+
+            // The first tile of a row always publishes a prefix, which ends the look-back.
+            loop {
+                let flag = Atomic::load(&status[previous + 1]);
+                device_fence();
+
+                if flag == STATUS_PREFIX {
+                    let prefix = partials[2 * previous + 1];
+                    exclusive = S::Instruction::<N>::combine(inst, prefix, exclusive);
+                    break;
+                } else if flag == STATUS_AGGREGATE {
+                    let aggregate = partials[2 * previous];
+                    exclusive = S::Instruction::<N>::combine(inst, aggregate, exclusive);
+                    previous -= 1;
+                }
+            }
+
+            partials[2 * tile_id + 1] = S::Instruction::<N>::combine(inst, exclusive, aggregate);
+            device_fence();
+            Atomic::store(&status[tile_id + 1], STATUS_PREFIX);
+        }
+
+        carry[0] = exclusive;
+    }
+    sync_cube();
+
+    store_tile::<N, S::Instruction<N>>(
+        inst,
+        output,
+        &tile,
+        carry[0],
+        offset_output,
+        stride_output,
+        tile_start,
+        shape_axis,
+        params,
+    );
+}
@@ -0,0 +1,141 @@
+//! This provides different implementations of the prefix-scan algorithm which
+//! can run on multiple GPU backends using CubeCL.
+//!
+//! A scan maps a tensor to a tensor of the same shape, where each item along a given axis
+//! is replaced by the combination, with some associative operator, of all the items before it.
+//! With the sum, this computes the cumulative sum of the tensor.
+//!
+//! This crate provides a main entrypoint as the [`scan`] function which allows to perform a scan
+//! for a given instruction implementing the [`ScanInstruction`] trait and a given [`ScanStrategy`].
+//! Scans are either [inclusive or exclusive](ScanMode).
+//! It also provides implementation of the [`ScanInstruction`] trait for common operations in the [`instructions`] module.
+//! Finally, it provides reusable primitives to build other scan algorithms in the [`primitives`] module.
+
+pub mod instructions;
+pub mod primitives;
+
+mod config;
+mod error;
+mod launch;
+mod strategy;
+
+pub use config::*;
+pub use error::*;
+pub use instructions::ScanFamily;
+pub use instructions::ScanInstruction;
+pub use strategy::*;
+
+use launch::*;
+
+pub use launch::{scan_look_back_kernel, scan_plane_kernel, scan_shared_kernel};
+
+#[cfg(feature = "export_tests")]
+pub mod test;
+
+use cubecl_core::prelude::*;
+
+/// Scan the given `axis` of the `input` tensor using the instruction `Inst` and write the result into `output`.
+///
+/// An optional [`ScanStrategy`] can be provided to force the scan to use a specific algorithm. If omitted, a best effort
+/// is done to try and pick the best strategy supported for the provided `client`.
+///
+/// Return an error if `strategy` is `Some(strategy)` and the specified strategy is not supported by the `client`.
+/// Also returns an error if the `axis` is larger than the `input` rank or if the shape of `output` is invalid.
+/// The shape of `output` must be the same as the shape of `input`.
+///
+/// # Example
+///
+/// This examples show how to compute the cumulative sum of the columns of a small `2 x 2` matrix.
+/// For more details, see the CubeCL documentation.
+///
+/// ```ignore
+/// use cubecl_scan::instructions::Sum;
+///
+/// let client = /* ... */;
+/// let size_f32 = std::mem::size_of::<f32>();
+/// let axis = 1; // 0 for rows, 1 for columns in the case of a matrix.
+///
+/// // Create input and output handles.
+/// let input_handle = client.create(f32::as_bytes(&[0, 1, 2, 3]));
+/// let input = unsafe {
+///     TensorHandleRef::<R>::from_raw_parts(
+///         &input_handle,
+///         &[2, 1],
+///         &[2, 2],
+///         size_f32,
+///     )
+/// };
+///
+/// let output_handle = client.empty(4 * size_f32);
+/// let output = unsafe {
+///     TensorHandleRef::<R>::from_raw_parts(
+///         &output_handle,
+///         &[2, 1],
+///         &[2, 2],
+///         size_f32,
+///     )
+/// };
+///
+/// // Here `R` is a `cubecl::Runtime`.
+/// let result = scan::<R, f32, Sum>(&client, input, output, axis, ScanMode::Inclusive, None, ());
+///
+/// if result.is_ok() {
+///        let binding = output_handle.binding();
+///        let bytes = client.read_one(binding);
+///        let output_values = f32::from_bytes(&bytes);
+///        println!("Output = {:?}", output_values); // Should print [0, 1, 2, 5].
+/// }
+/// ```
+pub fn scan<R: Runtime, N: Numeric, Inst: ScanFamily>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<R>,
+    output: TensorHandleRef<R>,
+    axis: usize,
+    mode: ScanMode,
+    strategy: Option<ScanStrategy>,
+    inst_config: Inst::Config,
+) -> Result<(), ScanError> {
+    validate_axis(input.shape.len(), axis)?;
+    valid_output_shape(input.shape, output.shape)?;
+
+    if input.shape.contains(&0) {
+        return Ok(());
+    }
+
+    let shape_axis = input.shape[axis];
+    let num_rows = input.size() / shape_axis;
+    let strategy = strategy
+        .map(|s| s.validate::<R>(client))
+        .unwrap_or(Ok(ScanStrategy::new::<R>(client, num_rows, shape_axis)))?;
+    let config = ScanConfig::generate::<R, N>(client, &input, &output, axis, &strategy);
+
+    launch_scan::<R, N, Inst>(
+        client,
+        input,
+        output,
+        axis as u32,
+        mode,
+        config,
+        strategy,
+        inst_config,
+    )
+}
+
+// Check that the given axis is less than the rank of the input.
+fn validate_axis(rank: usize, axis: usize) -> Result<(), ScanError> {
+    if axis >= rank {
+        return Err(ScanError::InvalidAxis { axis, rank });
+    }
+    Ok(())
+}
+
+// Check that the output shape match the input shape.
+fn valid_output_shape(input_shape: &[usize], output_shape: &[usize]) -> Result<(), ScanError> {
+    if output_shape != input_shape {
+        return Err(ScanError::MismatchShape {
+            expected_shape: input_shape.to_vec(),
+            output_shape: output_shape.to_vec(),
+        });
+    }
+    Ok(())
+}
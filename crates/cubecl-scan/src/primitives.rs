@@ -0,0 +1,187 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::{ScanMode, ScanParams, instructions::ScanInstruction};
+
+/// The inclusive scan of the values held by the units of a plane, ordered by `UNIT_POS_PLANE`.
+///
+/// This works for any operator with `log2(PLANE_DIM)` shuffles, following Hillis and Steele,
+/// "Data parallel algorithms", 1986.
+#[cube]
+pub fn plane_inclusive_scan<N: Numeric, I: ScanInstruction<N>>(
+    inst: &I,
+    value: Line<N>,
+) -> Line<N> {
+    let mut result = value;
+    let mut offset = 1;
+
+    while offset < PLANE_DIM {
+        let previous = plane_shuffle_up(result, offset);
+        let combined = I::combine(inst, previous, result);
+        result = select(UNIT_POS_PLANE >= offset, combined, result);
+        offset *= 2;
+    }
+
+    result
+}
+
+/// The offsets, in lines, of the first items of a row in the input and the output.
+///
+/// Rows are ordered by the coordinates of their first item over all the axes except `axis`,
+/// the coordinates along `line_axis` being counted in lines.
+#[cube]
+pub fn row_offsets<N: Numeric>(
+    input: &Tensor<Line<N>>,
+    output: &Tensor<Line<N>>,
+    row: u32,
+    axis: u32,
+    line_axis: u32,
+    #[comptime] line_size: u32,
+) -> (u32, u32) {
+    let rank = input.rank();
+    let mut remainder = row;
+    let mut offset_input = 0;
+    let mut offset_output = 0;
+
+    for i in 0..rank {
+        let dim = rank - 1 - i;
+
+        if dim != axis {
+            let is_line_axis = comptime![line_size > 1] && dim == line_axis;
+            let shape = select(is_line_axis, input.shape(dim) / line_size, input.shape(dim));
+            let factor = select(is_line_axis, line_size, 1);
+
+            let coordinate = remainder % shape;
+            remainder /= shape;
+
+            offset_input += coordinate * factor * input.stride(dim);
+            offset_output += coordinate * factor * output.stride(dim);
+        }
+    }
+
+    (offset_input / line_size, offset_output / line_size)
+}
+
+/// Load a tile of `params.tile_size()` items of a row, starting at `tile_start`, into shared memory.
+/// Items outside of the row are replaced by the identity.
+///
+/// Consecutive units load consecutive items to coalesce the memory accesses.
+#[cube]
+#[allow(clippy::too_many_arguments)]
+pub fn load_tile<N: Numeric, I: ScanInstruction<N>>(
+    inst: &I,
+    input: &Tensor<Line<N>>,
+    tile: &mut SharedMemory<Line<N>>,
+    offset: u32,
+    stride: u32,
+    tile_start: u32,
+    shape_axis: u32,
+    #[comptime] params: ScanParams,
+) {
+    #[unroll]
+    for k in 0..params.items_per_unit {
+        let index = k * params.cube_size + UNIT_POS;
+        let position = tile_start + index;
+
+        tile[index] = if position < shape_axis {
+            input[offset + position * stride]
+        } else {
+            I::identity(inst, params.line_size)
+        };
+    }
+}
+
+/// Compute the inclusive scan of a tile in place and return the aggregate of the whole tile.
+///
+/// Each unit scans `params.items_per_unit` consecutive items, then the totals of the units, stored in
+/// `totals`, are scanned following Hillis and Steele before being combined with the items.
+#[cube]
+pub fn tile_inclusive_scan<N: Numeric, I: ScanInstruction<N>>(
+    inst: &I,
+    tile: &mut SharedMemory<Line<N>>,
+    totals: &mut SharedMemory<Line<N>>,
+    #[comptime] params: ScanParams,
+) -> Line<N> {
+    sync_cube();
+
+    let start = UNIT_POS * params.items_per_unit;
+    let mut total = tile[start];
+
+    #[unroll]
+    for k in 1..params.items_per_unit {
+        total = I::combine(inst, total, tile[start + k]);
+        tile[start + k] = total;
+    }
+
+    totals[UNIT_POS] = total;
+    sync_cube();
+
+    let mut offset = 1;
+    while offset < params.cube_size {
+        let current = totals[UNIT_POS];
+        let previous = if UNIT_POS >= offset {
+            totals[UNIT_POS - offset]
+        } else {
+            I::identity(inst, params.line_size)
+        };
+        sync_cube();
+
+        totals[UNIT_POS] = I::combine(inst, previous, current);
+        sync_cube();
+
+        offset *= 2;
+    }
+
+    if UNIT_POS > 0 {
+        let prefix = totals[UNIT_POS - 1];
+
+        #[unroll]
+        for k in 0..params.items_per_unit {
+            tile[start + k] = I::combine(inst, prefix, tile[start + k]);
+        }
+    }
+    sync_cube();
+
+    totals[params.cube_size - 1]
+}
+
+/// Write a tile scanned with [tile_inclusive_scan], combined with `carry`, the aggregate of the
+/// items of the row before the tile.
+#[cube]
+#[allow(clippy::too_many_arguments)]
+pub fn store_tile<N: Numeric, I: ScanInstruction<N>>(
+    inst: &I,
+    output: &mut Tensor<Line<N>>,
+    tile: &SharedMemory<Line<N>>,
+    carry: Line<N>,
+    offset: u32,
+    stride: u32,
+    tile_start: u32,
+    shape_axis: u32,
+    #[comptime] params: ScanParams,
+) {
+    #[unroll]
+    for k in 0..params.items_per_unit {
+        let index = k * params.cube_size + UNIT_POS;
+        let position = tile_start + index;
+
+        if position < shape_axis {
+            let result = if comptime![params.mode == ScanMode::Inclusive] {
+                I::combine(inst, carry, tile[index])
+            } else if index == 0 {
+                carry
+            } else {
+                I::combine(inst, carry, tile[index - 1])
+            };
+
+            output[offset + position * stride] = result;
+        }
+    }
+}
+
+#[cube]
+#[allow(unknown_lints)] // `manual_div_ceil` only appeared in 1.83
+#[allow(clippy::manual_div_ceil)]
+pub(crate) fn div_ceil(a: u32, b: u32) -> u32 {
+    (a + b - 1) / b
+}
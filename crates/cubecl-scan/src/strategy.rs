@@ -0,0 +1,99 @@
+use cubecl_core::{
+    AtomicFeature, Feature,
+    ir::{Elem, UIntKind},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{LOOK_BACK_TILE_SIZE, ScanError};
+
+/// Rows at least this many tiles long are scanned with a decoupled look-back when possible.
+const LOOK_BACK_MIN_TILES_PER_ROW: usize = 4;
+
+/// Scans with at least this many rows already have enough parallelism without a decoupled look-back.
+const LOOK_BACK_MAX_ROWS: usize = 256;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub enum ScanStrategy {
+    /// Each plane scans a single row using plane instructions, iterating over tiles of `PLANE_DIM` items.
+    Plane,
+    /// All units within a single cube cooperate to scan a single row, iterating over tiles stored in
+    /// shared memory.
+    Shared,
+    /// Each cube scans a single tile in shared memory and the tiles of a row are chained with a
+    /// decoupled look-back, as described in Merrill and Garland, "Single-pass Parallel Prefix Scan
+    /// with Decoupled Look-back", 2016.
+    ///
+    /// This scans long rows with many cubes in a single pass.
+    DecoupledLookBack,
+}
+
+impl ScanStrategy {
+    pub fn validate<R: Runtime>(
+        self,
+        client: &ComputeClient<R::Server, R::Channel>,
+    ) -> Result<Self, ScanError> {
+        match self {
+            ScanStrategy::Plane => {
+                if !support_plane::<R>(client) {
+                    return Err(ScanError::PlanesUnavailable);
+                }
+                if !precise_plane_dim::<R>(client) {
+                    return Err(ScanError::ImprecisePlaneDim);
+                }
+            }
+            ScanStrategy::Shared => {}
+            ScanStrategy::DecoupledLookBack => {
+                if !support_atomics::<R>(client) {
+                    return Err(ScanError::AtomicsUnavailable);
+                }
+                if !support_device_fence::<R>(client) {
+                    return Err(ScanError::DeviceFenceUnavailable);
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Pick a strategy supported by the client for a scan of `num_rows` rows of `shape_axis` items.
+    pub fn new<R: Runtime>(
+        client: &ComputeClient<R::Server, R::Channel>,
+        num_rows: usize,
+        shape_axis: usize,
+    ) -> Self {
+        let long_rows = shape_axis >= LOOK_BACK_MIN_TILES_PER_ROW * LOOK_BACK_TILE_SIZE as usize;
+
+        if long_rows
+            && num_rows < LOOK_BACK_MAX_ROWS
+            && support_atomics::<R>(client)
+            && support_device_fence::<R>(client)
+        {
+            ScanStrategy::DecoupledLookBack
+        } else if support_plane::<R>(client) && precise_plane_dim::<R>(client) {
+            ScanStrategy::Plane
+        } else {
+            ScanStrategy::Shared
+        }
+    }
+}
+
+fn support_plane<R: Runtime>(client: &ComputeClient<R::Server, R::Channel>) -> bool {
+    client.properties().feature_enabled(Feature::Plane)
+}
+
+fn precise_plane_dim<R: Runtime>(client: &ComputeClient<R::Server, R::Channel>) -> bool {
+    let hw_props = &client.properties().hardware;
+    hw_props.plane_size_min == hw_props.plane_size_max
+}
+
+fn support_atomics<R: Runtime>(client: &ComputeClient<R::Server, R::Channel>) -> bool {
+    let props = client.properties();
+    props.feature_enabled(Feature::Type(Elem::AtomicUInt(UIntKind::U32)))
+        && props.feature_enabled(Feature::AtomicUInt(AtomicFeature::LoadStore))
+        && props.feature_enabled(Feature::AtomicUInt(AtomicFeature::Add))
+}
+
+fn support_device_fence<R: Runtime>(client: &ComputeClient<R::Server, R::Channel>) -> bool {
+    client.properties().feature_enabled(Feature::DeviceFence)
+}
@@ -0,0 +1,414 @@
+#![allow(missing_docs)]
+
+use cubecl_core::prelude::*;
+use rand::{
+    SeedableRng,
+    distr::{Distribution, Uniform},
+    rngs::StdRng,
+};
+
+use crate::{
+    ScanError, ScanFamily, ScanMode, ScanStrategy,
+    instructions::{Max, Min, Prod, Sum},
+    scan,
+};
+
+// This macro generate all the tests.
+#[macro_export]
+macro_rules! testgen_scan {
+    // Generate all the tests for a list of types.
+    ([$($float:ident), *]) => {
+        mod test_scan {
+            use super::*;
+            ::paste::paste! {
+                $(mod [<$float _ty>] {
+                    use super::*;
+
+                    $crate::testgen_scan!($float);
+                })*
+            }
+        }
+    };
+
+    // Generate all the tests for f32
+    () => {
+        mod test_scan {
+            use super::*;
+            $crate::testgen_scan!(f32);
+        }
+    };
+
+    // Generate all the tests for a specific float type.
+    ($float:ident) => {
+        use cubecl_scan::test::TestCase;
+
+        $crate::impl_test_scan!(
+            $float,
+            [
+                {
+                    id: "vector_small",
+                    shape: [22],
+                    stride: [1],
+                    axis: 0,
+                },
+                {
+                    id: "vector_large",
+                    shape: [4096],
+                    stride: [1],
+                    axis: 0,
+                },
+                {
+                    id: "parallel_matrix",
+                    shape: [8, 300],
+                    stride: [300, 1],
+                    axis: 1,
+                },
+                {
+                    id: "perpendicular_matrix",
+                    shape: [300, 8],
+                    stride: [8, 1],
+                    axis: 0,
+                },
+                {
+                    id: "perpendicular_matrix_with_jumps",
+                    shape: [8, 8],
+                    stride: [64, 1],
+                    axis: 0,
+                },
+                {
+                    id: "rank_three_tensor_unexact_shape",
+                    shape: [11, 12, 13],
+                    stride: [156, 13, 1],
+                    axis: 1,
+                },
+                {
+                    id: "perpendicular_rank_three_tensor_large",
+                    shape: [2, 1100, 4],
+                    stride: [4400, 4, 1],
+                    axis: 1,
+                },
+                {
+                    id: "permuted_rank_three_tensor",
+                    shape: [16, 16, 16],
+                    stride: [1, 256, 16],
+                    axis: 0,
+                }
+            ]
+        );
+    };
+}
+
+// For a given tensor description, run the tests for `Sum`, `Prod`, `Max` and `Min`
+// for all strategies in both modes.
+// For each test, a reference scan is computed on the CPU to compare the outcome of the kernel.
+#[macro_export]
+macro_rules! impl_test_scan {
+    (
+        $float:ident,
+        [
+            $(
+                {
+                    id: $id:literal,
+                    shape: $shape:expr,
+                    stride: $stride:expr,
+                    axis: $axis:expr,
+                }
+            ),*
+        ]
+    ) => {
+        $(
+            $crate::impl_test_scan_with_strategy!{
+                $float,
+                {
+                    id: $id,
+                    shape: $shape,
+                    stride: $stride,
+                    axis: $axis,
+                },
+                [ strategy: Plane, mode: Inclusive ],
+                [ strategy: Plane, mode: Exclusive ],
+                [ strategy: Shared, mode: Inclusive ],
+                [ strategy: Shared, mode: Exclusive ],
+                [ strategy: DecoupledLookBack, mode: Inclusive ],
+                [ strategy: DecoupledLookBack, mode: Exclusive ]
+            }
+        )*
+    };
+}
+
+#[macro_export]
+macro_rules! impl_test_scan_with_strategy {
+    (
+        $float:ident,
+        {
+            id: $id:literal,
+            shape: $shape:expr,
+            stride: $stride:expr,
+            axis: $axis:expr,
+        },
+        $([strategy: $strategy:ident, mode: $mode:ident]),*
+    ) => {
+        ::paste::paste! {
+            $(
+                #[test]
+                pub fn [< sum_ $strategy:snake _ $mode:snake _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: $axis,
+                        mode: $crate::ScanMode::$mode,
+                        strategy: Some($crate::ScanStrategy::$strategy),
+                    };
+                    test.test_sum::<$float, TestRuntime>(&Default::default());
+                }
+
+                #[test]
+                pub fn [< prod_ $strategy:snake _ $mode:snake _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: $axis,
+                        mode: $crate::ScanMode::$mode,
+                        strategy: Some($crate::ScanStrategy::$strategy),
+                    };
+                    test.test_prod::<$float, TestRuntime>(&Default::default());
+                }
+
+                #[test]
+                pub fn [< max_ $strategy:snake _ $mode:snake _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: $axis,
+                        mode: $crate::ScanMode::$mode,
+                        strategy: Some($crate::ScanStrategy::$strategy),
+                    };
+                    test.test_max::<$float, TestRuntime>(&Default::default());
+                }
+
+                #[test]
+                pub fn [< min_ $strategy:snake _ $mode:snake _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: $axis,
+                        mode: $crate::ScanMode::$mode,
+                        strategy: Some($crate::ScanStrategy::$strategy),
+                    };
+                    test.test_min::<$float, TestRuntime>(&Default::default());
+                }
+            )*
+        }
+    };
+}
+
+#[derive(Debug)]
+pub struct TestCase {
+    pub shape: Vec<usize>,
+    pub stride: Vec<usize>,
+    pub axis: usize,
+    pub mode: ScanMode,
+    pub strategy: Option<ScanStrategy>,
+}
+
+impl TestCase {
+    pub fn test_sum<F, R>(&self, device: &R::Device)
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        // Small integers keep all the partial sums exact, even for f16 and bf16.
+        let input_values: Vec<F> = self.random_input_values(-1, 1);
+        let expected_values = self.cpu_scan(&input_values, F::new(0.0), |lhs, rhs| lhs + rhs);
+        self.run_scan_test::<F, R, Sum>(device, input_values, expected_values)
+    }
+
+    pub fn test_prod<F, R>(&self, device: &R::Device)
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        // Only use -1 and 1 to avoid overflows.
+        let input_values: Vec<F> = self
+            .random_input_values(0, 1)
+            .into_iter()
+            .map(|value: F| value * F::new(2.0) - F::new(1.0))
+            .collect();
+        let expected_values = self.cpu_scan(&input_values, F::new(1.0), |lhs, rhs| lhs * rhs);
+        self.run_scan_test::<F, R, Prod>(device, input_values, expected_values)
+    }
+
+    pub fn test_max<F, R>(&self, device: &R::Device)
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F> = self.random_input_values(-1000, 1000);
+        let expected_values = self.cpu_scan(&input_values, F::min_value(), |lhs, rhs| {
+            if rhs > lhs { rhs } else { lhs }
+        });
+        self.run_scan_test::<F, R, Max>(device, input_values, expected_values)
+    }
+
+    pub fn test_min<F, R>(&self, device: &R::Device)
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F> = self.random_input_values(-1000, 1000);
+        let expected_values = self.cpu_scan(&input_values, F::max_value(), |lhs, rhs| {
+            if rhs < lhs { rhs } else { lhs }
+        });
+        self.run_scan_test::<F, R, Min>(device, input_values, expected_values)
+    }
+
+    /// Scan each row of the input on the CPU. Values outside of the tensor are left to zero.
+    fn cpu_scan<F: Float>(&self, values: &[F], identity: F, combine: impl Fn(F, F) -> F) -> Vec<F> {
+        let mut expected = vec![F::new(0.0); values.len()];
+
+        for input_index in 0..values.len() {
+            let Some(coordinate) = self.to_input_coordinate(input_index) else {
+                continue;
+            };
+            if coordinate[self.axis] != 0 {
+                continue;
+            }
+
+            let mut accumulator = identity;
+            for position in 0..self.shape[self.axis] {
+                let index = input_index + position * self.stride[self.axis];
+                let next = combine(accumulator, values[index]);
+                expected[index] = match self.mode {
+                    ScanMode::Inclusive => next,
+                    ScanMode::Exclusive => accumulator,
+                };
+                accumulator = next;
+            }
+        }
+        expected
+    }
+
+    pub fn run_scan_test<F, R, K>(
+        &self,
+        device: &R::Device,
+        input_values: Vec<F>,
+        expected_values: Vec<F>,
+    ) where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+        K: ScanFamily<Config = ()>,
+    {
+        let client = R::client(device);
+
+        let input_handle = client.create(F::as_bytes(&input_values));
+
+        // Zero initialize an output with the same layout as the input.
+        let output_handle = client.create(F::as_bytes(&vec![F::from_int(0); input_values.len()]));
+
+        let input = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &input_handle,
+                &self.stride,
+                &self.shape,
+                size_of::<F>(),
+            )
+        };
+        let output = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &output_handle,
+                &self.stride,
+                &self.shape,
+                size_of::<F>(),
+            )
+        };
+
+        let result = scan::<R, F, K>(
+            &client,
+            input,
+            output,
+            self.axis,
+            self.mode,
+            self.strategy,
+            (),
+        );
+        if result.is_err_and(|e| {
+            e == ScanError::PlanesUnavailable
+                || e == ScanError::ImprecisePlaneDim
+                || e == ScanError::AtomicsUnavailable
+                || e == ScanError::DeviceFenceUnavailable
+        }) {
+            return; // We don't test in that case.
+        }
+
+        let binding = output_handle.binding();
+        let bytes = client.read_one(binding);
+        let output_values = F::from_bytes(&bytes);
+        assert_approx_equal(output_values, &expected_values);
+    }
+
+    fn to_input_coordinate(&self, index: usize) -> Option<Vec<usize>> {
+        let coordinate = self
+            .stride
+            .iter()
+            .zip(self.shape.iter())
+            .map(|(stride, shape)| (index / stride) % shape)
+            .collect::<Vec<usize>>();
+        self.validate_input_index(index, &coordinate)
+            .then_some(coordinate)
+    }
+
+    fn validate_input_index(&self, index: usize, coordinate: &[usize]) -> bool {
+        coordinate
+            .iter()
+            .zip(self.stride.iter())
+            .map(|(c, s)| c * s)
+            .sum::<usize>()
+            == index
+    }
+
+    fn random_input_values<F: Float>(&self, low: i32, high: i32) -> Vec<F> {
+        let size = self.input_size();
+        let rng = StdRng::seed_from_u64(self.pseudo_random_seed());
+        let distribution = Uniform::new_inclusive(low, high).unwrap();
+        distribution
+            .sample_iter(rng)
+            .take(size)
+            .map(|r| F::new(r as f32))
+            .collect()
+    }
+
+    fn input_size(&self) -> usize {
+        let (stride, shape) = self
+            .stride
+            .iter()
+            .zip(self.shape.iter())
+            .max_by_key(|(stride, _)| *stride)
+            .unwrap();
+        stride * shape
+    }
+
+    // We don't need a fancy crypto-secure seed as this is only for testing.
+    fn pseudo_random_seed(&self) -> u64 {
+        123456789
+    }
+}
+
+pub fn assert_approx_equal<N: Numeric>(actual: &[N], expected: &[N]) {
+    for (i, (a, e)) in actual.iter().zip(expected.iter()).enumerate() {
+        let a = a.to_f32().unwrap();
+        let e = e.to_f32().unwrap();
+        let diff = (a - e).abs();
+        if e == 0.0 {
+            assert!(
+                diff < 1e-10,
+                "Values are not approx equal: index={i} actual={a}, expected={e}, difference={diff}",
+            );
+        } else {
+            let rel_diff = diff / e.abs();
+            assert!(
+                rel_diff < 0.0625,
+                "Values are not approx equal: index={i} actual={a}, expected={e}"
+            );
+        }
+    }
+}
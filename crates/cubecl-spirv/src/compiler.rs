@@ -1,5 +1,8 @@
 use cubecl_common::ExecutionMode;
-use cubecl_core::{Metadata, WgpuCompilationOptions, ir as core, prelude::FastMath};
+use cubecl_core::{
+    CompilationError, CompilationErrorRecorder, Metadata, WgpuCompilationOptions, ir as core,
+    post_processing::checked_io::register_error_buffer, prelude::FastMath,
+};
 use cubecl_opt::{BasicBlock, NodeIndex, Optimizer, OptimizerBuilder, Uniformity};
 use cubecl_runtime::config::{GlobalConfig, compilation::CompilationLogLevel};
 use std::{
@@ -22,7 +25,7 @@ use crate::{
     item::Item,
     lookups::LookupTables,
     target::{GLCompute, SpirvTarget},
-    transformers::{BitwiseTransform, ErfTransform},
+    transformers::{BitwiseTransform, ErfTransform, MathTransform},
 };
 
 pub struct SpirvCompiler<Target: SpirvTarget = GLCompute> {
@@ -46,7 +49,9 @@ pub struct SpirvCompiler<Target: SpirvTarget = GLCompute> {
     pub ext_meta_pos: Vec<u32>,
     pub metadata: Metadata,
     pub debug_info: Option<DebugInfo>,
+    error_buffer: Option<core::Variable>,
     compilation_options: WgpuCompilationOptions,
+    pub errors: CompilationErrorRecorder,
 }
 
 unsafe impl<T: SpirvTarget> Send for SpirvCompiler<T> {}
@@ -74,7 +79,9 @@ impl<T: SpirvTarget> Clone for SpirvCompiler<T> {
             metadata: self.metadata.clone(),
             debug_info: self.debug_info.clone(),
             ext_meta_pos: self.ext_meta_pos.clone(),
+            error_buffer: self.error_buffer,
             compilation_options: self.compilation_options.clone(),
+            errors: self.errors.clone(),
         }
     }
 }
@@ -107,7 +114,9 @@ impl<T: SpirvTarget> Default for SpirvCompiler<T> {
             metadata: Default::default(),
             debug_info: Default::default(),
             ext_meta_pos: Default::default(),
+            error_buffer: Default::default(),
             compilation_options: Default::default(),
+            errors: Default::default(),
         }
     }
 }
@@ -132,10 +141,11 @@ impl<T: SpirvTarget> Compiler for SpirvCompiler<T> {
 
     fn compile(
         &mut self,
-        value: KernelDefinition,
+        mut value: KernelDefinition,
         compilation_options: &Self::CompilationOptions,
         mode: ExecutionMode,
-    ) -> Self::Representation {
+    ) -> Result<Self::Representation, CompilationError> {
+        self.error_buffer = register_error_buffer(&mut value, mode);
         let bindings = value.buffers.clone();
         let scalars = value
             .scalars
@@ -168,13 +178,17 @@ impl<T: SpirvTarget> Compiler for SpirvCompiler<T> {
         self.ext_meta_pos = ext_meta_pos;
 
         let (module, optimizer) = self.compile_kernel(value);
-        SpirvKernel {
+        if let Some(err) = self.errors.take("spirv") {
+            return Err(err);
+        }
+
+        Ok(SpirvKernel {
             module,
             optimizer,
             bindings,
             scalars,
             has_metadata: self.metadata.static_len() > 0,
-        }
+        })
     }
 
     fn elem_size(&self, elem: core::Elem) -> usize {
@@ -220,7 +234,9 @@ impl<Target: SpirvTarget> SpirvCompiler<Target> {
 
         let mut opt = OptimizerBuilder::default()
             .with_transformer(ErfTransform)
+            .with_transformer(MathTransform)
             .with_transformer(BitwiseTransform)
+            .with_error_buffer(self.error_buffer)
             .optimize(kernel.body, kernel.cube_dim, self.mode);
 
         self.uniformity = opt.analysis::<Uniformity>();
@@ -429,3 +445,37 @@ fn convert_math_mode(math_mode: FastMath) -> FPFastMathMode {
 
     flags
 }
+
+#[cfg(test)]
+mod tests {
+    use cubecl_core as cubecl;
+    use cubecl_core::{
+        KernelSettings,
+        compute::KernelBuilder,
+        ir::{Elem, FloatKind, Item},
+        prelude::*,
+    };
+    use half::bf16;
+
+    use super::*;
+
+    #[cube]
+    fn bf16_constant(output: &mut Array<bf16>) {
+        output[0] = bf16::new(1.5);
+    }
+
+    #[test]
+    fn unsupported_constant_is_a_compilation_error() {
+        let mut builder = KernelBuilder::default();
+        let output = builder.output_array(Item::new(Elem::Float(FloatKind::BF16)));
+        bf16_constant::expand(&mut builder.scope, output.into());
+        let kernel = builder.build(KernelSettings::default());
+
+        let result = SpirvCompiler::<GLCompute>::default().compile(
+            kernel,
+            &Default::default(),
+            ExecutionMode::Unchecked,
+        );
+        assert!(result.is_err());
+    }
+}
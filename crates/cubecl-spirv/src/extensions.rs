@@ -12,11 +12,24 @@ pub trait TargetExtensions<T: SpirvTarget> {
     fn ceil(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
     fn sin(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
     fn cos(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn tan(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
     fn tanh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn sinh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn cosh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn asin(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn acos(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn atan(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn asinh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn acosh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn atanh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn atan2(b: &mut SpirvCompiler<T>, ty: Word, lhs: Word, rhs: Word, out: Word);
     fn pow(b: &mut SpirvCompiler<T>, ty: Word, lhs: Word, rhs: Word, out: Word);
     fn exp(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn exp2(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
     fn log(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn log2(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
     fn sqrt(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
+    fn inverse_sqrt(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word);
     fn f_min(b: &mut SpirvCompiler<T>, ty: Word, lhs: Word, rhs: Word, out: Word);
     fn u_min(b: &mut SpirvCompiler<T>, ty: Word, lhs: Word, rhs: Word, out: Word);
     fn s_min(b: &mut SpirvCompiler<T>, ty: Word, lhs: Word, rhs: Word, out: Word);
@@ -67,10 +80,50 @@ pub mod glcompute {
             b.cos_id(ty, Some(out), input).unwrap();
         }
 
+        fn tan(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.tan_id(ty, Some(out), input).unwrap();
+        }
+
         fn tanh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
             b.tanh_id(ty, Some(out), input).unwrap();
         }
 
+        fn sinh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.sinh_id(ty, Some(out), input).unwrap();
+        }
+
+        fn cosh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.cosh_id(ty, Some(out), input).unwrap();
+        }
+
+        fn asin(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.asin_id(ty, Some(out), input).unwrap();
+        }
+
+        fn acos(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.acos_id(ty, Some(out), input).unwrap();
+        }
+
+        fn atan(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.atan_id(ty, Some(out), input).unwrap();
+        }
+
+        fn asinh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.asinh_id(ty, Some(out), input).unwrap();
+        }
+
+        fn acosh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.acosh_id(ty, Some(out), input).unwrap();
+        }
+
+        fn atanh(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.atanh_id(ty, Some(out), input).unwrap();
+        }
+
+        fn atan2(b: &mut SpirvCompiler<T>, ty: Word, lhs: Word, rhs: Word, out: Word) {
+            b.atan2_id(ty, Some(out), lhs, rhs).unwrap();
+        }
+
         fn pow(b: &mut SpirvCompiler<T>, ty: Word, lhs: Word, rhs: Word, out: Word) {
             b.pow_id(ty, Some(out), lhs, rhs).unwrap();
         }
@@ -79,14 +132,26 @@ pub mod glcompute {
             b.exp_id(ty, Some(out), input).unwrap();
         }
 
+        fn exp2(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.exp2_id(ty, Some(out), input).unwrap();
+        }
+
         fn log(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
             b.log_id(ty, Some(out), input).unwrap();
         }
 
+        fn log2(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.log2_id(ty, Some(out), input).unwrap();
+        }
+
         fn sqrt(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
             b.sqrt_id(ty, Some(out), input).unwrap();
         }
 
+        fn inverse_sqrt(b: &mut SpirvCompiler<T>, ty: Word, input: Word, out: Word) {
+            b.inverse_sqrt_id(ty, Some(out), input).unwrap();
+        }
+
         fn f_min(b: &mut SpirvCompiler<T>, ty: Word, lhs: Word, rhs: Word, out: Word) {
             b.f_min_id(ty, Some(out), lhs, rhs).unwrap();
         }
@@ -15,6 +15,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
         if !matches!(inst.operation, Operation::NonSemantic(_)) {
             self.set_source_loc(&inst.source_loc);
         }
+        self.errors.set_operation(&inst);
         let uniform = matches!(inst.out, Some(out) if self.uniformity.is_var_uniform(out));
         match inst.operation {
             Operation::Copy(var) => {
@@ -40,8 +41,8 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
             Operation::Synchronization(sync) => self.compile_sync(sync),
             Operation::CoopMma(cmma) => self.compile_cmma(cmma, inst.out),
             Operation::NonSemantic(debug) => self.compile_debug(debug),
-            Operation::Barrier(_) => panic!("Barrier not supported in SPIR-V"),
-            Operation::Tma(_) => panic!("TMA not supported in SPIR-V"),
+            Operation::Barrier(_) => self.errors.unsupported("Barrier not supported in SPIR-V"),
+            Operation::Tma(_) => self.errors.unsupported("TMA not supported in SPIR-V"),
         }
     }
 
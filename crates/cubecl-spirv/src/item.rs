@@ -120,7 +120,10 @@ impl Item {
                 let elem = item.constant(b, value);
                 b.constant_composite(ty, (0..*len).map(|_| elem))
             }
-            Item::RuntimeArray(_) => unimplemented!("Can't create constant runtime array"),
+            Item::RuntimeArray(_) => {
+                b.errors.unsupported("Can't create constant runtime array");
+                scalar
+            }
             Item::Struct(elems) => {
                 let items = elems
                     .iter()
@@ -128,8 +131,14 @@ impl Item {
                     .collect::<Vec<_>>();
                 b.constant_composite(ty, items)
             }
-            Item::Pointer(_, _) => unimplemented!("Can't create constant pointer"),
-            Item::CoopMatrix { .. } => unimplemented!("Can't create constant cmma matrix"),
+            Item::Pointer(_, _) => {
+                b.errors.unsupported("Can't create constant pointer");
+                scalar
+            }
+            Item::CoopMatrix { .. } => {
+                b.errors.unsupported("Can't create constant cmma matrix");
+                scalar
+            }
         }
     }
 
@@ -240,7 +249,11 @@ impl Item {
                 | (Elem::Relaxed, Elem::Float(_)) => b.f_convert(ty, out_id, obj).unwrap(),
                 (Elem::Bool, Elem::Bool) => b.copy_object(ty, out_id, obj).unwrap(),
                 (Elem::Relaxed, Elem::Relaxed) => b.copy_object(ty, out_id, obj).unwrap(),
-                (from, to) => panic!("Invalid cast from {from:?} to {to:?}"),
+                (from, to) => {
+                    b.errors
+                        .unsupported(format!("Invalid cast from {from:?} to {to:?}"));
+                    obj
+                }
             }
         };
 
@@ -326,13 +339,22 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 | core::FloatKind::E4M3
                 | core::FloatKind::E5M2
                 | core::FloatKind::UE8M0,
-            ) => panic!("Minifloat not supported in SPIR-V"),
-            core::Elem::Float(core::FloatKind::BF16) => panic!("BFloat16 not supported in SPIR-V"),
+            ) => {
+                self.errors.unsupported("Minifloat not supported in SPIR-V");
+                Elem::Float(32)
+            }
+            core::Elem::Float(core::FloatKind::BF16) => {
+                self.errors.unsupported("BFloat16 not supported in SPIR-V");
+                Elem::Float(32)
+            }
             core::Elem::Float(FloatKind::F16) => {
                 self.capabilities.insert(Capability::Float16);
                 Elem::Float(16)
             }
-            core::Elem::Float(FloatKind::TF32) => panic!("TF32 not supported in SPIR-V"),
+            core::Elem::Float(FloatKind::TF32) => {
+                self.errors.unsupported("TF32 not supported in SPIR-V");
+                Elem::Float(32)
+            }
             core::Elem::Float(FloatKind::Flex32) => Elem::Relaxed,
             core::Elem::Float(FloatKind::F32) => Elem::Float(32),
             core::Elem::Float(FloatKind::F64) => {
@@ -356,12 +378,17 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 | core::FloatKind::E4M3
                 | core::FloatKind::E5M2
                 | core::FloatKind::UE8M0,
-            ) => panic!("Minifloat not supported in SPIR-V"),
+            ) => {
+                self.errors.unsupported("Minifloat not supported in SPIR-V");
+                Elem::Float(32)
+            }
             core::Elem::AtomicFloat(core::FloatKind::BF16) => {
-                panic!("BFloat16 not supported in SPIR-V")
+                self.errors.unsupported("BFloat16 not supported in SPIR-V");
+                Elem::Float(32)
             }
             core::Elem::AtomicFloat(core::FloatKind::TF32) => {
-                panic!("TF32 not supported in SPIR-V")
+                self.errors.unsupported("TF32 not supported in SPIR-V");
+                Elem::Float(32)
             }
             core::Elem::Int(IntKind::I8) => {
                 self.capabilities.insert(Capability::Int8);
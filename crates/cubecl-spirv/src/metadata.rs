@@ -116,7 +116,11 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
             Variable::SharedMemory(_, _, len)
             | Variable::ConstantArray(_, _, len)
             | Variable::LocalArray(_, _, len) => self.const_u32(*len),
-            var => unimplemented!("Var {var:?} doesn't have length"),
+            var => {
+                self.errors
+                    .unsupported(format!("Var {var:?} doesn't have length"));
+                self.const_u32(0)
+            }
         };
         if let Some(out) = out {
             self.write(out, id);
@@ -132,7 +136,12 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
 
         let position = match var {
             Variable::GlobalInputArray(_, _, pos) | Variable::GlobalOutputArray(_, _, pos) => *pos,
-            _ => panic!("Only Input and Output have a buffer length, got: {var:?}"),
+            _ => {
+                self.errors.unsupported(format!(
+                    "Only Input and Output have a buffer length, got: {var:?}"
+                ));
+                0
+            }
         };
         let offset = self.metadata.buffer_len_index(position);
         let id = self.load_const_metadata(offset, out_id);
@@ -169,11 +178,15 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
         self.read_indexed_unchecked(out, &info, index)
     }
 
-    fn ext_pos(&self, var: &Variable) -> u32 {
-        let pos = match var {
-            Variable::GlobalInputArray(_, _, pos) | Variable::GlobalOutputArray(_, _, pos) => *pos,
-            _ => panic!("Only global buffers have rank"),
-        };
-        self.ext_meta_pos[pos as usize]
+    fn ext_pos(&mut self, var: &Variable) -> u32 {
+        match var {
+            Variable::GlobalInputArray(_, _, pos) | Variable::GlobalOutputArray(_, _, pos) => {
+                self.ext_meta_pos[*pos as usize]
+            }
+            _ => {
+                self.errors.unsupported("Only global buffers have rank");
+                0
+            }
+        }
     }
 }
@@ -30,7 +30,15 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 self.control_barrier(scope_exec, scope_mem, semantics)
                     .unwrap();
             }
-            Synchronization::SyncProxyShared => panic!("TMA proxy sync not supported in SPIR-V"),
+            Synchronization::DeviceFence => {
+                let scope = self.const_u32(Scope::Device as u32);
+                let semantics = MemorySemantics::ACQUIRE_RELEASE | MemorySemantics::UNIFORM_MEMORY;
+                let semantics = self.const_u32(semantics.bits());
+                self.memory_barrier(scope, semantics).unwrap();
+            }
+            Synchronization::SyncProxyShared => self
+                .errors
+                .unsupported("TMA proxy sync not supported in SPIR-V"),
         }
     }
 }
@@ -3,7 +3,7 @@ use cubecl_core::{
         Arithmetic, Bitwise, Elem, ExpandElement, Instruction, IntKind, Operation, Scope, UIntKind,
         Variable,
     },
-    prelude::{IntExpand, assign, expand_erf},
+    prelude::{IntExpand, assign, expand_erf, expand_hypot, expand_log10},
 };
 use cubecl_opt::{IrTransformer, TransformAction};
 
@@ -26,6 +26,28 @@ impl IrTransformer for ErfTransform {
     }
 }
 
+/// Expand math functions that have no GLSL.std.450 instruction
+#[derive(Debug)]
+pub(crate) struct MathTransform;
+
+impl IrTransformer for MathTransform {
+    fn maybe_transform(&self, scope: &mut Scope, inst: &Instruction) -> TransformAction {
+        match &inst.operation {
+            Operation::Arithmetic(Arithmetic::Log10(op)) => {
+                let mut scope = scope.child();
+                expand_log10(&mut scope, op.input, inst.out());
+                TransformAction::Replace(into_instructions(scope))
+            }
+            Operation::Arithmetic(Arithmetic::Hypot(op)) => {
+                let mut scope = scope.child();
+                expand_hypot(&mut scope, op.lhs, op.rhs, inst.out());
+                TransformAction::Replace(into_instructions(scope))
+            }
+            _ => TransformAction::Ignore,
+        }
+    }
+}
+
 /// Transform operations that only support 32 bits using polyfills
 #[derive(Debug)]
 pub(crate) struct BitwiseTransform;
@@ -89,10 +89,11 @@ impl ConstVal {
         }
     }
 
+    /// The value truncated to 32 bits.
     pub fn as_u32(&self) -> u32 {
         match self {
             ConstVal::Bit32(val) => *val,
-            ConstVal::Bit64(_) => panic!("Truncating 64 bit variable to 32 bit"),
+            ConstVal::Bit64(val) => *val as u32,
         }
     }
 
@@ -151,21 +152,6 @@ impl ConstVal {
     }
 }
 
-impl From<ConstantScalarValue> for ConstVal {
-    fn from(value: ConstantScalarValue) -> Self {
-        let width = value.elem().size() as u32 * 8;
-        match value {
-            ConstantScalarValue::Int(val, _) => ConstVal::from_int(val, width),
-            ConstantScalarValue::Float(_, FloatKind::BF16) => {
-                panic!("bf16 not supported in SPIR-V")
-            }
-            ConstantScalarValue::Float(val, _) => ConstVal::from_float(val, width),
-            ConstantScalarValue::UInt(val, _) => ConstVal::from_uint(val, width),
-            ConstantScalarValue::Bool(val) => ConstVal::from_bool(val),
-        }
-    }
-}
-
 impl From<u32> for ConstVal {
     fn from(value: u32) -> Self {
         ConstVal::Bit32(value)
@@ -198,7 +184,10 @@ impl Variable {
             Variable::SharedMemory(id, _, _) => *id,
             Variable::ConstantArray(id, _, _) => *id,
             Variable::LocalArray(id, _, _) => *id,
-            Variable::CoopMatrix(_, _) => unimplemented!("Can't get ID from matrix var"),
+            Variable::CoopMatrix(_, _) => {
+                b.errors.unsupported("Can't get ID from matrix var");
+                b.id()
+            }
             Variable::SubgroupSize(id) => *id,
             Variable::Id(id) => *id,
             Variable::LocalInvocationIndex(id) => *id,
@@ -318,12 +307,26 @@ pub enum IndexedVariable {
 }
 
 impl<T: SpirvTarget> SpirvCompiler<T> {
+    fn const_val(&mut self, value: ConstantScalarValue) -> ConstVal {
+        let width = value.elem().size() as u32 * 8;
+        match value {
+            ConstantScalarValue::Int(val, _) => ConstVal::from_int(val, width),
+            ConstantScalarValue::Float(val, FloatKind::BF16) => {
+                self.errors.unsupported("bf16 not supported in SPIR-V");
+                ConstVal::from_float(val, 32)
+            }
+            ConstantScalarValue::Float(val, _) => ConstVal::from_float(val, width),
+            ConstantScalarValue::UInt(val, _) => ConstVal::from_uint(val, width),
+            ConstantScalarValue::Bool(val) => ConstVal::from_bool(val),
+        }
+    }
+
     pub fn compile_variable(&mut self, variable: ir::Variable) -> Variable {
         let item = variable.item;
         match variable.kind {
             ir::VariableKind::ConstantScalar(value) => {
                 let item = self.compile_item(ir::Item::new(value.elem()));
-                let const_val = value.into();
+                let const_val = self.const_val(value);
 
                 if let Some(existing) = self.state.constants.get(&(const_val, item.clone())) {
                     Variable::ConstantScalar(*existing, const_val, item.elem())
@@ -415,9 +418,18 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                     Variable::CoopMatrix(id, elem)
                 }
             }
-            ir::VariableKind::Pipeline { .. } => panic!("Pipeline not supported."),
-            ir::VariableKind::Barrier { .. } => panic!("Barrier not supported."),
-            ir::VariableKind::TensorMap(_) => panic!("Tensor map not supported."),
+            ir::VariableKind::Pipeline { .. } => {
+                self.errors.unsupported("Pipeline not supported.");
+                self.compile_variable(0u32.into())
+            }
+            ir::VariableKind::Barrier { .. } => {
+                self.errors.unsupported("Barrier not supported.");
+                self.compile_variable(0u32.into())
+            }
+            ir::VariableKind::TensorMap(_) => {
+                self.errors.unsupported("Tensor map not supported.");
+                self.compile_variable(0u32.into())
+            }
         }
     }
 
@@ -537,7 +549,10 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let id = access_chain(self, ptr_ty, None, *id, vec![index_id]).unwrap();
                 IndexedVariable::Pointer(id, item.clone())
             }
-            var => unimplemented!("Can't index into {var:?}"),
+            var => {
+                self.errors.unsupported(format!("Can't index into {var:?}"));
+                IndexedVariable::Scalar(var.clone())
+            }
         }
     }
 
@@ -715,9 +730,9 @@ fn is_always_in_bounds(var: &Variable, index: &Variable) -> bool {
     };
 
     let const_index = match index {
-        Variable::ConstantScalar(_, value, _) => value.as_u32(),
+        Variable::ConstantScalar(_, value, _) => value.as_u64(),
         _ => return false,
     };
 
-    const_index < len
+    const_index < len as u64
 }
@@ -20,3 +20,51 @@ impl SymQ8 {
         val as f32 * scaling
     }
 }
+
+/// Represent the quantization of a f32 into an i8 using the asymmetric scheme, where
+/// `val = (quantized - zero_point) * scaling`.
+#[derive(Clone, Copy)]
+pub struct AsymQ8;
+
+impl AsymQ8 {
+    pub fn quantize(val: f32, scaling: f32, zero_point: f32) -> i8 {
+        (val / scaling + zero_point)
+            .round()
+            .clamp(i8::MIN as f32, i8::MAX as f32) as i8
+    }
+
+    pub fn dequantize(val: i8, scaling: f32, zero_point: f32) -> f32 {
+        (val as f32 - zero_point) * scaling
+    }
+}
+
+/// Represent the quantization of a f32 into a signed 4-bit integer, where
+/// `val = (quantized - zero_point) * scaling`.
+///
+/// Two values are packed in a byte, the first one in the low nibble.
+#[derive(Clone, Copy)]
+pub struct Q4;
+
+impl Q4 {
+    pub const MIN: i8 = -8;
+    pub const MAX: i8 = 7;
+
+    pub fn quantize(val: f32, scaling: f32, zero_point: f32) -> i8 {
+        (val / scaling + zero_point)
+            .round()
+            .clamp(Self::MIN as f32, Self::MAX as f32) as i8
+    }
+
+    pub fn dequantize(val: i8, scaling: f32, zero_point: f32) -> f32 {
+        (val as f32 - zero_point) * scaling
+    }
+
+    pub fn pack(low: i8, high: i8) -> i8 {
+        (low & 0x0F) | (high << 4)
+    }
+
+    pub fn unpack(packed: i8) -> (i8, i8) {
+        // Shift the low nibble up first so that its sign is extended
+        ((packed << 4) >> 4, packed >> 4)
+    }
+}
@@ -311,7 +311,8 @@ pub fn into_contiguous_ref<R: Runtime, E: CubePrimitive>(
         shape,
         stride,
         elems_per_unit,
-    );
+    )
+    .expect("Should be able to compile the into_contiguous kernel");
 }
 
 /// Checks if the tensor associated with the given shape and strides is contiguous.
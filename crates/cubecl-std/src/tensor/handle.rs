@@ -172,6 +172,7 @@ where
                     vectorization_factor,
                 ),
             )
+            .expect("Should be able to compile the zeros kernel")
         };
 
         output
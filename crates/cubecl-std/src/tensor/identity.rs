@@ -33,8 +33,8 @@ fn identity_kernel<C: Numeric>(output: &mut Tensor<Line<C>>, gap: u32) {
 pub fn launch<R: Runtime, C: Numeric>(
     client: &ComputeClient<R::Server, R::Channel>,
     output: &TensorHandle<R, C>,
-) {
-    launch_ref::<R, C>(client, &output.as_ref());
+) -> Result<(), CompilationError> {
+    launch_ref::<R, C>(client, &output.as_ref())
 }
 
 /// Launch identity matrix kernel by ref.
@@ -43,7 +43,7 @@ pub fn launch<R: Runtime, C: Numeric>(
 pub fn launch_ref<R: Runtime, C: Numeric>(
     client: &ComputeClient<R::Server, R::Channel>,
     output: &TensorHandleRef<R>,
-) {
+) -> Result<(), CompilationError> {
     assert_eq!(2, output.shape.len(), "input should be a matrix");
     assert_eq!(
         output.shape[0], output.shape[1],
@@ -75,6 +75,6 @@ pub fn launch_ref<R: Runtime, C: Numeric>(
                 vectorization_factor,
             ),
             ScalarArg::new(output.strides[0] as u32 + 1),
-        );
+        )
     }
 }
@@ -34,7 +34,8 @@ pub fn run_test_read_global<R: Runtime>(
             CubeDim::new_1d(2),
             ArrayArg::from_raw_parts::<i8>(&input, 4 / line_size, line_size as u8),
             ArrayArg::from_raw_parts::<f16>(&output, 2, 1),
-        );
+        )
+        .unwrap();
     }
 
     let actual = client.read_one(output.binding());
@@ -73,7 +74,8 @@ pub fn run_test_write_global<R: Runtime>(
             CubeDim::new_1d(2),
             ArrayArg::from_raw_parts::<i8>(&output, 4 / line_size, line_size as u8),
             ArrayArg::from_raw_parts::<f16>(&input, 2, 1),
-        );
+        )
+        .unwrap();
     }
 
     let actual = client.read_one(output.binding());
@@ -116,7 +118,8 @@ pub fn run_test_read_shared_memory<R: Runtime>(client: ComputeClient<R::Server,
             CubeCount::new_single(),
             CubeDim::new_1d(2),
             ArrayArg::from_raw_parts::<f16>(&output, 2, 1),
-        );
+        )
+        .unwrap();
     }
 
     let actual = client.read_one(output.binding());
@@ -155,7 +158,8 @@ pub fn run_test_write_shared_memory<R: Runtime>(client: ComputeClient<R::Server,
             CubeDim::new_1d(2),
             ArrayArg::from_raw_parts::<i8>(&output, 1, 4),
             ArrayArg::from_raw_parts::<f16>(&input, 2, 1),
-        );
+        )
+        .unwrap();
     }
 
     let actual = client.read_one(output.binding());
@@ -17,7 +17,7 @@ pub fn test_identity<R: Runtime, C: Numeric + CubeElement + Display>(
     let expected = identity_cpu::<C>(dim);
 
     let identity = TensorHandle::<R, C>::empty(&client, [dim, dim].to_vec());
-    tensor::identity::launch(&client, &identity);
+    tensor::identity::launch(&client, &identity).unwrap();
 
     let actual = client.read_one_tensor(identity.handle.clone().binding_with_meta(
         identity.shape,
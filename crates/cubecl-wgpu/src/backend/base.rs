@@ -65,7 +65,7 @@ impl WgpuServer {
                     // This is because the WebGPU specification only makes loose guarantees that Cube can't rely on.
                     bounds_checks: false,
                     // Loop bounds are only checked in checked mode.
-                    force_loop_bounding: mode == ExecutionMode::Checked,
+                    force_loop_bounding: mode != ExecutionMode::Unchecked,
                 };
 
                 // SAFETY: Cube guarantees OOB safety when launching in checked mode. Launching in unchecked mode
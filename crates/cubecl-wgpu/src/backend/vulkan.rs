@@ -6,7 +6,7 @@ use cubecl_core::{
     AtomicFeature, ExecutionMode, Feature, WgpuCompilationOptions,
     compute::Visibility,
     ir::{Elem, FloatKind, IntKind, UIntKind},
-    prelude::CompiledKernel,
+    prelude::{CompilationError, CompiledKernel},
     server::ComputeServer,
 };
 use cubecl_runtime::DeviceProperties;
@@ -161,6 +161,7 @@ fn register_features(
     register_types(props, &extended_feat);
     comp_options.supports_u64 = true;
     props.register_feature(Feature::SyncPlane);
+    props.register_feature(Feature::DeviceFence);
 
     if let Some(atomic_float) = &extended_feat.atomic_float {
         if atomic_float.shader_buffer_float32_atomics == TRUE {
@@ -301,12 +302,12 @@ pub(crate) fn compile(
     server: &mut WgpuServer,
     kernel: <WgpuServer as ComputeServer>::Kernel,
     mode: ExecutionMode,
-) -> CompiledKernel<AutoCompiler> {
+) -> Result<CompiledKernel<AutoCompiler>, CompilationError> {
     log::debug!("Compiling {}", kernel.name());
-    let compiled = kernel.compile(dyn_comp, &server.compilation_options, mode);
+    let compiled = kernel.compile(dyn_comp, &server.compilation_options, mode)?;
     #[cfg(feature = "spirv-dump")]
     dump_spirv(&compiled, kernel.name(), kernel.id());
-    compiled
+    Ok(compiled)
 }
 
 #[cfg(feature = "spirv-dump")]
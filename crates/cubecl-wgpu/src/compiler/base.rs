@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use cubecl_common::ExecutionMode;
 use cubecl_core::{
-    Compiler, WgpuCompilationOptions,
+    CompilationError, Compiler, WgpuCompilationOptions,
     prelude::{CompiledKernel, KernelDefinition},
     server::ComputeServer,
 };
@@ -76,23 +76,25 @@ impl Compiler for AutoCompiler {
         kernel: KernelDefinition,
         compilation_options: &Self::CompilationOptions,
         mode: ExecutionMode,
-    ) -> Self::Representation {
-        match self {
+    ) -> Result<Self::Representation, CompilationError> {
+        let repr = match self {
             AutoCompiler::Wgsl(wgsl_compiler) => {
-                Compiler::compile(wgsl_compiler, kernel, compilation_options, mode).into()
+                Compiler::compile(wgsl_compiler, kernel, compilation_options, mode)?.into()
             }
             #[cfg(feature = "spirv")]
             AutoCompiler::SpirV(spirv_compiler) => {
-                Compiler::compile(spirv_compiler, kernel, compilation_options, mode).into()
+                Compiler::compile(spirv_compiler, kernel, compilation_options, mode)?.into()
             }
             #[cfg(feature = "msl")]
             AutoCompiler::Msl(msl_compiler) => {
                 // override compilation options with cpp compiler options for metal
                 use cubecl_cpp;
                 let compilation_options = cubecl_cpp::shared::CompilationOptions::default();
-                Compiler::compile(msl_compiler, kernel, &compilation_options, mode).into()
+                Compiler::compile(msl_compiler, kernel, &compilation_options, mode)?.into()
             }
-        }
+        };
+
+        Ok(repr)
     }
 
     fn elem_size(&self, elem: cubecl_core::ir::Elem) -> usize {
@@ -122,7 +124,7 @@ impl AutoCompiler {
         server: &mut WgpuServer,
         kernel: <WgpuServer as ComputeServer>::Kernel,
         mode: ExecutionMode,
-    ) -> CompiledKernel<Self> {
+    ) -> Result<CompiledKernel<Self>, CompilationError> {
         match self {
             AutoCompiler::Wgsl(_) => kernel.compile(self, &server.compilation_options, mode),
             #[cfg(feature = "spirv")]
@@ -5,12 +5,12 @@ use crate::compiler::wgsl;
 
 use cubecl_common::ExecutionMode;
 use cubecl_core::ir::{ConstantScalarValue, Processor, UIntKind};
-use cubecl_core::post_processing::checked_io::CheckedIoProcessor;
+use cubecl_core::post_processing::checked_io::{CheckedIoProcessor, register_error_buffer};
 use cubecl_core::prelude::*;
 use cubecl_core::{
-    Metadata, WgpuCompilationOptions, compute,
+    CompilationErrorRecorder, Metadata, WgpuCompilationOptions, compute,
     ir::{self as cube, Scope},
-    prelude::expand_erf,
+    prelude::{expand_erf, expand_hypot, expand_log10},
 };
 
 /// Wgsl Compiler.
@@ -36,8 +36,10 @@ pub struct WgslCompiler {
     #[allow(dead_code)]
     compilation_options: WgpuCompilationOptions,
     strategy: ExecutionMode,
+    error_buffer: Option<cube::Variable>,
     subgroup_instructions_used: bool,
     f16_used: bool,
+    errors: CompilationErrorRecorder,
 }
 
 impl core::fmt::Debug for WgslCompiler {
@@ -55,9 +57,14 @@ impl cubecl_core::Compiler for WgslCompiler {
         shader: compute::KernelDefinition,
         compilation_options: &Self::CompilationOptions,
         mode: ExecutionMode,
-    ) -> Self::Representation {
+    ) -> Result<Self::Representation, CompilationError> {
         self.compilation_options = compilation_options.clone();
-        self.compile_shader(shader, mode)
+        let shader = self.compile_shader(shader, mode);
+
+        match self.errors.take("wgsl") {
+            Some(err) => Err(err),
+            None => Ok(shader),
+        }
     }
 
     fn elem_size(&self, elem: cube::Elem) -> usize {
@@ -76,6 +83,7 @@ impl WgslCompiler {
         mode: ExecutionMode,
     ) -> wgsl::ComputeShader {
         self.strategy = mode;
+        self.error_buffer = register_error_buffer(&mut value, mode);
 
         let num_meta = value.buffers.len();
 
@@ -142,7 +150,13 @@ impl WgslCompiler {
             2 => wgsl::Item::Vec2(elem),
             3 => wgsl::Item::Vec3(elem),
             4 => wgsl::Item::Vec4(elem),
-            _ => panic!("Unsupported vectorizations scheme {:?}", item.vectorization),
+            _ => {
+                self.errors.unsupported(format!(
+                    "Unsupported vectorizations scheme {:?}",
+                    item.vectorization
+                ));
+                wgsl::Item::Scalar(elem)
+            }
         }
     }
 
@@ -154,13 +168,23 @@ impl WgslCompiler {
                 | cube::FloatKind::E3M2
                 | cube::FloatKind::E4M3
                 | cube::FloatKind::E5M2
-                | cube::FloatKind::UE8M0 => panic!("Minifloat is not a valid WgpuElement"),
+                | cube::FloatKind::UE8M0 => {
+                    self.errors
+                        .unsupported("Minifloat is not a valid WgpuElement");
+                    wgsl::Elem::F32
+                }
                 cube::FloatKind::F16 => {
                     self.f16_used = true;
                     wgsl::Elem::F16
                 }
-                cube::FloatKind::BF16 => panic!("bf16 is not a valid WgpuElement"),
-                cube::FloatKind::TF32 => panic!("tf32 is not a valid WgpuElement"),
+                cube::FloatKind::BF16 => {
+                    self.errors.unsupported("bf16 is not a valid WgpuElement");
+                    wgsl::Elem::F32
+                }
+                cube::FloatKind::TF32 => {
+                    self.errors.unsupported("tf32 is not a valid WgpuElement");
+                    wgsl::Elem::F32
+                }
                 cube::FloatKind::Flex32 => wgsl::Elem::F32,
                 cube::FloatKind::F32 => wgsl::Elem::F32,
                 cube::FloatKind::F64 => wgsl::Elem::F64,
@@ -168,25 +192,45 @@ impl WgslCompiler {
             cube::Elem::Int(i) => match i {
                 cube::IntKind::I32 => wgsl::Elem::I32,
                 cube::IntKind::I64 => wgsl::Elem::I64,
-                kind => panic!("{kind:?} is not a valid WgpuElement"),
+                kind => {
+                    self.errors
+                        .unsupported(format!("{kind:?} is not a valid WgpuElement"));
+                    wgsl::Elem::I32
+                }
             },
             cube::Elem::UInt(kind) => match kind {
                 cube::UIntKind::U32 => wgsl::Elem::U32,
                 cube::UIntKind::U64 => wgsl::Elem::U64,
-                kind => panic!("{kind:?} is not a valid WgpuElement"),
+                kind => {
+                    self.errors
+                        .unsupported(format!("{kind:?} is not a valid WgpuElement"));
+                    wgsl::Elem::U32
+                }
             },
             cube::Elem::Bool => wgsl::Elem::Bool,
             cube::Elem::AtomicFloat(i) => match i {
                 cube::FloatKind::F32 => wgsl::Elem::AtomicF32,
-                kind => panic!("atomic<{kind:?}> is not a valid WgpuElement"),
+                kind => {
+                    self.errors
+                        .unsupported(format!("atomic<{kind:?}> is not a valid WgpuElement"));
+                    wgsl::Elem::AtomicF32
+                }
             },
             cube::Elem::AtomicInt(i) => match i {
                 cube::IntKind::I32 => wgsl::Elem::AtomicI32,
-                kind => panic!("atomic<{kind:?}> is not a valid WgpuElement"),
+                kind => {
+                    self.errors
+                        .unsupported(format!("atomic<{kind:?}> is not a valid WgpuElement"));
+                    wgsl::Elem::AtomicI32
+                }
             },
             cube::Elem::AtomicUInt(kind) => match kind {
                 cube::UIntKind::U32 => wgsl::Elem::AtomicU32,
-                kind => panic!("{kind:?} is not a valid WgpuElement"),
+                kind => {
+                    self.errors
+                        .unsupported(format!("atomic<{kind:?}> is not a valid WgpuElement"));
+                    wgsl::Elem::AtomicU32
+                }
             },
         }
     }
@@ -334,15 +378,22 @@ impl WgslCompiler {
                 }
             },
             cube::VariableKind::Matrix { .. } => {
-                panic!("Cooperative matrix-multiply and accumulate not supported.")
+                self.errors
+                    .unsupported("Cooperative matrix-multiply and accumulate not supported.");
+                self.constant_var(0)
             }
             cube::VariableKind::Pipeline { .. } => {
-                panic!("Pipeline not supported.")
+                self.errors.unsupported("Pipeline not supported.");
+                self.constant_var(0)
             }
             cube::VariableKind::Barrier { .. } => {
-                panic!("Barrier not supported.")
+                self.errors.unsupported("Barrier not supported.");
+                self.constant_var(0)
+            }
+            cube::VariableKind::TensorMap(_) => {
+                self.errors.unsupported("Tensor map not supported.");
+                self.constant_var(0)
             }
-            cube::VariableKind::TensorMap(_) => panic!("Tensor map not supported."),
         }
     }
 
@@ -369,7 +420,8 @@ impl WgslCompiler {
             .collect::<Vec<_>>();
         self.const_arrays.extend(const_arrays);
 
-        let checked_io: Box<dyn Processor> = Box::new(CheckedIoProcessor::new(self.strategy));
+        let checked_io: Box<dyn Processor> =
+            Box::new(CheckedIoProcessor::new(self.strategy, self.error_buffer));
         let processing = scope.process([checked_io]);
 
         for var in processing.variables {
@@ -378,10 +430,10 @@ impl WgslCompiler {
             });
         }
 
-        processing
-            .instructions
-            .into_iter()
-            .for_each(|op| self.compile_operation(&mut instructions, op.operation, op.out, scope));
+        for op in processing.instructions {
+            self.errors.set_operation(&op);
+            self.compile_operation(&mut instructions, op.operation, op.out, scope);
+        }
 
         instructions
     }
@@ -411,17 +463,17 @@ impl WgslCompiler {
                 self.compile_synchronization(instructions, val)
             }
             cube::Operation::Plane(op) => self.compile_subgroup(instructions, op, out),
-            cube::Operation::CoopMma(_) => {
-                panic!("Cooperative matrix-multiply and accumulate isn't supported on wgpu.")
-            }
+            cube::Operation::CoopMma(_) => self
+                .errors
+                .unsupported("Cooperative matrix-multiply and accumulate isn't supported on wgpu."),
             cube::Operation::NonSemantic(cube::NonSemantic::Comment { content }) => {
                 self.compile_comment(instructions, content)
             }
             cube::Operation::NonSemantic(_) => {}
             cube::Operation::Barrier(_) => {
-                panic!("Barrier isn't supported on wgpu.")
+                self.errors.unsupported("Barrier isn't supported on wgpu.")
             }
-            cube::Operation::Tma(_) => panic!("TMA isn't supported on wgpu."),
+            cube::Operation::Tma(_) => self.errors.unsupported("TMA isn't supported on wgpu."),
         }
     }
 
@@ -455,6 +507,26 @@ impl WgslCompiler {
                 rhs: self.compile_variable(op.rhs),
                 out: self.compile_variable(out),
             },
+            cube::Plane::Shuffle(op) => Subgroup::Shuffle {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(out),
+            },
+            cube::Plane::ShuffleXor(op) => Subgroup::ShuffleXor {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(out),
+            },
+            cube::Plane::ShuffleUp(op) => Subgroup::ShuffleUp {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(out),
+            },
+            cube::Plane::ShuffleDown(op) => Subgroup::ShuffleDown {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(out),
+            },
             cube::Plane::Sum(op) => Subgroup::Sum {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
@@ -516,6 +588,7 @@ impl WgslCompiler {
             }),
             cube::Branch::Return => instructions.push(wgsl::Instruction::Return),
             cube::Branch::Break => instructions.push(wgsl::Instruction::Break),
+            cube::Branch::Continue => instructions.push(wgsl::Instruction::Continue),
             cube::Branch::RangeLoop(mut range_loop) => {
                 instructions.push(wgsl::Instruction::RangeLoop {
                     i: self.compile_variable(range_loop.i),
@@ -541,13 +614,18 @@ impl WgslCompiler {
             cube::Synchronization::SyncCube => {
                 instructions.push(wgsl::Instruction::WorkgroupBarrier)
             }
-            cube::Synchronization::SyncPlane => {
-                panic!("Synchronization within a plane is not supported in WGSL")
-            }
+            cube::Synchronization::SyncPlane => self
+                .errors
+                .unsupported("Synchronization within a plane is not supported in WGSL"),
             cube::Synchronization::SyncStorage => {
                 instructions.push(wgsl::Instruction::StorageBarrier)
             }
-            cube::Synchronization::SyncProxyShared => panic!("TMA is not supported in WGSL"),
+            cube::Synchronization::SyncProxyShared => {
+                self.errors.unsupported("TMA is not supported in WGSL")
+            }
+            cube::Synchronization::DeviceFence => self
+                .errors
+                .unsupported("Fences across workgroups are not supported in WGSL"),
         };
     }
 
@@ -689,6 +767,10 @@ impl WgslCompiler {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
             }),
+            cube::Arithmetic::Exp2(op) => instructions.push(wgsl::Instruction::Exp2 {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(out),
+            }),
             cube::Arithmetic::Log(op) => instructions.push(wgsl::Instruction::Log {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
@@ -697,6 +779,15 @@ impl WgslCompiler {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
             }),
+            cube::Arithmetic::Log2(op) => instructions.push(wgsl::Instruction::Log2 {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(out),
+            }),
+            cube::Arithmetic::Log10(op) => {
+                let mut scope = scope.child();
+                expand_log10(&mut scope, op.input, out);
+                instructions.extend(self.compile_scope(&mut scope));
+            }
             cube::Arithmetic::Cos(op) => instructions.push(wgsl::Instruction::Cos {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
@@ -705,19 +796,71 @@ impl WgslCompiler {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
             }),
+            cube::Arithmetic::Tan(op) => instructions.push(wgsl::Instruction::Tan {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(out),
+            }),
             cube::Arithmetic::Tanh(op) => instructions.push(wgsl::Instruction::Tanh {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
             }),
+            cube::Arithmetic::Sinh(op) => instructions.push(wgsl::Instruction::Sinh {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(out),
+            }),
+            cube::Arithmetic::Cosh(op) => instructions.push(wgsl::Instruction::Cosh {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(out),
+            }),
+            cube::Arithmetic::ArcCos(op) => instructions.push(wgsl::Instruction::ArcCos {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(out),
+            }),
+            cube::Arithmetic::ArcSin(op) => instructions.push(wgsl::Instruction::ArcSin {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(out),
+            }),
+            cube::Arithmetic::ArcTan(op) => instructions.push(wgsl::Instruction::ArcTan {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(out),
+            }),
+            cube::Arithmetic::ArcSinh(op) => instructions.push(wgsl::Instruction::ArcSinh {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(out),
+            }),
+            cube::Arithmetic::ArcCosh(op) => instructions.push(wgsl::Instruction::ArcCosh {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(out),
+            }),
+            cube::Arithmetic::ArcTanh(op) => instructions.push(wgsl::Instruction::ArcTanh {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(out),
+            }),
+            cube::Arithmetic::ArcTan2(op) => instructions.push(wgsl::Instruction::ArcTan2 {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(out),
+            }),
             cube::Arithmetic::Powf(op) => instructions.push(wgsl::Instruction::Powf {
                 lhs: self.compile_variable(op.lhs),
                 rhs: self.compile_variable(op.rhs),
                 out: self.compile_variable(out),
             }),
+            cube::Arithmetic::Hypot(op) => {
+                let mut scope = scope.child();
+                expand_hypot(&mut scope, op.lhs, op.rhs, out);
+                instructions.extend(self.compile_scope(&mut scope));
+            }
             cube::Arithmetic::Sqrt(op) => instructions.push(wgsl::Instruction::Sqrt {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
             }),
+            cube::Arithmetic::InverseSqrt(op) => {
+                instructions.push(wgsl::Instruction::InverseSqrt {
+                    input: self.compile_variable(op.input),
+                    out: self.compile_variable(out),
+                })
+            }
             cube::Arithmetic::Round(op) => instructions.push(wgsl::Instruction::Round {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
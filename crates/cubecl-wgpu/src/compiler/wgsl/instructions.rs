@@ -54,6 +54,7 @@ pub enum Instruction {
     },
     Return,
     Break,
+    Continue,
     WorkgroupBarrier,
     StorageBarrier,
     // Index handles casting to correct local variable.
@@ -101,6 +102,10 @@ pub enum Instruction {
         input: Variable,
         out: Variable,
     },
+    Exp2 {
+        input: Variable,
+        out: Variable,
+    },
     Log {
         input: Variable,
         out: Variable,
@@ -109,6 +114,10 @@ pub enum Instruction {
         input: Variable,
         out: Variable,
     },
+    Log2 {
+        input: Variable,
+        out: Variable,
+    },
     Cos {
         input: Variable,
         out: Variable,
@@ -117,10 +126,51 @@ pub enum Instruction {
         input: Variable,
         out: Variable,
     },
+    Tan {
+        input: Variable,
+        out: Variable,
+    },
     Tanh {
         input: Variable,
         out: Variable,
     },
+    Sinh {
+        input: Variable,
+        out: Variable,
+    },
+    Cosh {
+        input: Variable,
+        out: Variable,
+    },
+    ArcCos {
+        input: Variable,
+        out: Variable,
+    },
+    ArcSin {
+        input: Variable,
+        out: Variable,
+    },
+    ArcTan {
+        input: Variable,
+        out: Variable,
+    },
+    ArcSinh {
+        input: Variable,
+        out: Variable,
+    },
+    ArcCosh {
+        input: Variable,
+        out: Variable,
+    },
+    ArcTanh {
+        input: Variable,
+        out: Variable,
+    },
+    ArcTan2 {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
     Powf {
         lhs: Variable,
         rhs: Variable,
@@ -130,6 +180,10 @@ pub enum Instruction {
         input: Variable,
         out: Variable,
     },
+    InverseSqrt {
+        input: Variable,
+        out: Variable,
+    },
     Recip {
         input: Variable,
         out: Variable,
@@ -535,10 +589,18 @@ impl Display for Instruction {
                 let out = out.fmt_left();
                 writeln!(f, "{out} = exp({input});")
             }
+            Instruction::Exp2 { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = exp2({input});")
+            }
             Instruction::Log { input, out } => {
                 let out = out.fmt_left();
                 writeln!(f, "{out} = log({input});")
             }
+            Instruction::Log2 { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = log2({input});")
+            }
             Instruction::Clamp {
                 input,
                 min_value,
@@ -563,6 +625,10 @@ impl Display for Instruction {
                 let out = out.fmt_left();
                 writeln!(f, "{out} = sqrt({input});")
             }
+            Instruction::InverseSqrt { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = inverseSqrt({input});")
+            }
             Instruction::Log1p { input, out } => {
                 let out = out.fmt_left();
                 writeln!(f, "{out} = log({input} + 1.0);")
@@ -575,6 +641,10 @@ impl Display for Instruction {
                 let out = out.fmt_left();
                 writeln!(f, "{out} = sin({input});")
             }
+            Instruction::Tan { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = tan({input});")
+            }
             Instruction::Tanh { input, out } => {
                 let out = out.fmt_left();
                 #[cfg(target_os = "macos")]
@@ -584,6 +654,42 @@ impl Display for Instruction {
 
                 result
             }
+            Instruction::Sinh { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = sinh({input});")
+            }
+            Instruction::Cosh { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = cosh({input});")
+            }
+            Instruction::ArcCos { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = acos({input});")
+            }
+            Instruction::ArcSin { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = asin({input});")
+            }
+            Instruction::ArcTan { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = atan({input});")
+            }
+            Instruction::ArcSinh { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = asinh({input});")
+            }
+            Instruction::ArcCosh { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = acosh({input});")
+            }
+            Instruction::ArcTanh { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = atanh({input});")
+            }
+            Instruction::ArcTan2 { lhs, rhs, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = atan2({lhs}, {rhs});")
+            }
             Instruction::Recip { input, out } => {
                 let out = out.fmt_left();
                 write!(f, "{out} = 1.0 / {input};")
@@ -734,6 +840,7 @@ for (var {i}: {i_ty} = {start}; {i} {cmp} {end}; {increment}) {{
             }
             Instruction::Return => f.write_str("return;\n"),
             Instruction::Break => f.write_str("break;\n"),
+            Instruction::Continue => f.write_str("continue;\n"),
             Instruction::WorkgroupBarrier => f.write_str("workgroupBarrier();\n"),
             Instruction::StorageBarrier => f.write_str("storageBarrier();\n"),
             Instruction::Length { var, out } => {
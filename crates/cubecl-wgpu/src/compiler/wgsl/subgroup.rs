@@ -24,6 +24,26 @@ pub enum Subgroup {
         rhs: Variable,
         out: Variable,
     },
+    Shuffle {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    ShuffleXor {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    ShuffleUp {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    ShuffleDown {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
     Sum {
         input: Variable,
         out: Variable,
@@ -123,6 +143,22 @@ impl Display for Subgroup {
                 let out = out.fmt_left();
                 writeln!(f, "{out} = subgroupBroadcast({lhs}, {rhs});")
             }
+            Subgroup::Shuffle { lhs, rhs, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = subgroupShuffle({lhs}, {rhs});")
+            }
+            Subgroup::ShuffleXor { lhs, rhs, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = subgroupShuffleXor({lhs}, {rhs});")
+            }
+            Subgroup::ShuffleUp { lhs, rhs, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = subgroupShuffleUp({lhs}, {rhs});")
+            }
+            Subgroup::ShuffleDown { lhs, rhs, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = subgroupShuffleDown({lhs}, {rhs});")
+            }
             Subgroup::Ballot { input, out } => {
                 let out = out.fmt_left();
                 writeln!(f, "{out} = subgroupBallot({input});")
@@ -3,7 +3,7 @@ use cubecl_core::{
     server::{Binding, Handle},
 };
 use cubecl_runtime::{
-    memory_management::{MemoryDeviceProperties, MemoryManagement, StorageExclude},
+    memory_management::{MemoryDeviceProperties, MemoryManagement, OutOfMemory, StorageExclude},
     storage::ComputeStorage,
 };
 use wgpu::BufferUsages;
@@ -45,13 +45,18 @@ impl WgpuMemManager {
         }
     }
 
-    pub(crate) fn reserve(&mut self, size: u64, exclude_pending_operations: bool) -> Handle {
+    pub(crate) fn reserve(
+        &mut self,
+        size: u64,
+        exclude_pending_operations: bool,
+    ) -> Result<Handle, OutOfMemory> {
         let exclude = if exclude_pending_operations {
             Some(&self.pending_operations)
         } else {
             None
         };
-        Handle::new(self.memory_pool.reserve(size, exclude), None, None, size)
+        let memory = self.memory_pool.try_reserve(size, exclude)?;
+        Ok(Handle::new(memory, None, None, size))
     }
 
     pub(crate) fn get_resource(&mut self, binding: Binding) -> WgpuResource {
@@ -92,4 +97,16 @@ impl WgpuMemManager {
     pub(crate) fn mode(&mut self, mode: cubecl_runtime::memory_management::MemoryAllocationMode) {
         self.memory_pool.mode(mode);
     }
+
+    pub(crate) fn tracing(&mut self, enabled: bool) {
+        self.memory_pool.tracing(enabled);
+    }
+
+    pub(crate) fn trace_tag(&mut self, tag: Option<String>) {
+        self.memory_pool.trace_tag(tag);
+    }
+
+    pub(crate) fn trace(&mut self) -> cubecl_runtime::memory_management::MemoryTrace {
+        self.memory_pool.trace()
+    }
 }
@@ -14,7 +14,7 @@ use cubecl_core::{
 use cubecl_runtime::logging::ServerLogger;
 use cubecl_runtime::memory_management::offset_handles;
 use cubecl_runtime::{
-    memory_management::MemoryDeviceProperties,
+    memory_management::{MemoryDeviceProperties, OutOfMemory},
     server::{self, ComputeServer},
     storage::BindingResource,
 };
@@ -67,16 +67,16 @@ impl WgpuServer {
         kernel: <Self as ComputeServer>::Kernel,
         mode: ExecutionMode,
         logger: Arc<ServerLogger>,
-    ) -> Arc<ComputePipeline> {
+    ) -> Result<Arc<ComputePipeline>, CompilationError> {
         let mut kernel_id = kernel.id();
         kernel_id.mode(mode);
 
         if let Some(pipeline) = self.pipelines.get(&kernel_id) {
-            return pipeline.clone();
+            return Ok(pipeline.clone());
         }
 
         let mut compiler = compiler(self.backend);
-        let mut compile = compiler.compile(self, kernel, mode);
+        let mut compile = compiler.compile(self, kernel, mode)?;
 
         if logger.compilation_activated() {
             compile.debug_info = Some(DebugInformation::new(
@@ -110,7 +110,7 @@ impl WgpuServer {
         let pipeline = self.create_pipeline(compile, mode);
         self.pipelines.insert(kernel_id.clone(), pipeline.clone());
 
-        pipeline
+        Ok(pipeline)
     }
 }
 
@@ -134,12 +134,12 @@ impl ComputeServer for WgpuServer {
     ///
     /// This is important, otherwise the compute passes are going to be too small and we won't be able to
     /// fully utilize the GPU.
-    fn create(&mut self, data: &[u8]) -> server::Handle {
-        self.stream.create(data)
+    fn try_create(&mut self, data: &[u8]) -> Result<server::Handle, OutOfMemory> {
+        self.stream.try_create(data)
     }
 
-    fn empty(&mut self, size: usize) -> server::Handle {
-        self.stream.empty(size as u64)
+    fn try_empty(&mut self, size: usize) -> Result<server::Handle, OutOfMemory> {
+        self.stream.try_empty(size as u64)
     }
 
     unsafe fn execute(
@@ -149,9 +149,11 @@ impl ComputeServer for WgpuServer {
         bindings: Bindings,
         mode: ExecutionMode,
         logger: Arc<ServerLogger>,
-    ) {
-        let pipeline = self.pipeline(kernel, mode, logger);
+    ) -> Result<(), CompilationError> {
+        let pipeline = self.pipeline(kernel, mode, logger)?;
         self.stream.register(pipeline, bindings, &count);
+
+        Ok(())
     }
 
     fn flush(&mut self) {
@@ -237,9 +239,25 @@ impl ComputeServer for WgpuServer {
         handles.into_iter().zip(strides).collect()
     }
 
+    fn copy(&mut self, src: Binding, dst: Binding) {
+        self.stream.copy(src, dst);
+    }
+
     fn allocation_mode(&mut self, mode: cubecl_runtime::memory_management::MemoryAllocationMode) {
         self.stream.mem_manage.mode(mode);
     }
+
+    fn memory_tracing(&mut self, enabled: bool) {
+        self.stream.mem_manage.tracing(enabled);
+    }
+
+    fn memory_trace_tag(&mut self, tag: Option<String>) {
+        self.stream.mem_manage.trace_tag(tag);
+    }
+
+    fn memory_trace(&mut self) -> cubecl_runtime::memory_management::MemoryTrace {
+        self.stream.mem_manage.trace()
+    }
 }
 
 fn compiler(backend: wgpu::Backend) -> AutoCompiler {
@@ -6,7 +6,8 @@ use cubecl_core::{
     server::{Binding, Bindings, Handle, ProfileError, ProfilingToken},
 };
 use cubecl_runtime::{
-    memory_management::MemoryDeviceProperties, timestamp_profiler::TimestampProfiler,
+    memory_management::{MemoryDeviceProperties, OutOfMemory},
+    timestamp_profiler::TimestampProfiler,
 };
 use std::{future::Future, num::NonZero, pin::Pin, sync::Arc};
 use wgpu::ComputePipeline;
@@ -61,7 +62,11 @@ impl WgpuStream {
 
         // Allocate a small buffer to use for synchronization.
         #[cfg(target_family = "wasm")]
-        let sync_buffer = Some(mem_manage.reserve(32, false));
+        let sync_buffer = Some(
+            mem_manage
+                .reserve(32, false)
+                .expect("The sync buffer should fit in memory"),
+        );
 
         #[cfg(not(target_family = "wasm"))]
         let sync_buffer = None;
@@ -347,11 +352,15 @@ impl WgpuStream {
         }
     }
 
-    pub fn empty(&mut self, size: u64) -> Handle {
+    pub fn try_empty(&mut self, size: u64) -> Result<Handle, OutOfMemory> {
         self.mem_manage.reserve(size, false)
     }
 
     pub fn create(&mut self, data: &[u8]) -> Handle {
+        self.try_create(data).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn try_create(&mut self, data: &[u8]) -> Result<Handle, OutOfMemory> {
         // Copying into a buffer has to be 4 byte aligned. We can safely do so, as
         // memory is 32 bytes aligned (see WgpuStorage).
         let align = wgpu::COPY_BUFFER_ALIGNMENT;
@@ -362,10 +371,10 @@ impl WgpuStream {
         // a buffer we copy to MUST not have any outstanding compute work associated with it.
         // Any handles with compute work are kept in pending operations,
         // and the allocation here won't try to use that buffer.
-        let alloc = self.mem_manage.reserve(aligned_len, true);
+        let alloc = self.mem_manage.reserve(aligned_len, true)?;
         self.copy_to_handle(alloc.clone(), data);
 
-        alloc
+        Ok(alloc)
     }
 
     pub fn copy_to_handle(&mut self, handle: Handle, data: &[u8]) {
@@ -404,6 +413,59 @@ impl WgpuStream {
         self.flush_if_needed();
     }
 
+    pub fn copy(&mut self, src: Binding, dst: Binding) {
+        // Copies are recorded on the encoder, so the current compute pass has to end for the copy
+        // to be ordered after the tasks already registered.
+        self.compute_pass = None;
+
+        // Copying between buffers has to be 4 byte aligned. We can safely do so, as
+        // memory is 32 bytes aligned (see WgpuStorage).
+        let align = wgpu::COPY_BUFFER_ALIGNMENT;
+        let src = self.mem_manage.get_resource(src);
+        let dst = self.mem_manage.get_resource(dst);
+        assert!(
+            dst.size() >= src.size(),
+            "The destination of a copy should be at least as big as the source"
+        );
+        let aligned_len = src.size().div_ceil(align) * align;
+
+        if src.buffer() == dst.buffer() {
+            // Handles can be sub-allocated from the same buffer, and a copy within a single
+            // buffer isn't allowed, so the data goes through a temporary buffer.
+            let staging_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: None,
+                size: aligned_len,
+                usage: wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            self.encoder.copy_buffer_to_buffer(
+                src.buffer(),
+                src.offset(),
+                &staging_buffer,
+                0,
+                aligned_len,
+            );
+            self.encoder.copy_buffer_to_buffer(
+                &staging_buffer,
+                0,
+                dst.buffer(),
+                dst.offset(),
+                aligned_len,
+            );
+        } else {
+            self.encoder.copy_buffer_to_buffer(
+                src.buffer(),
+                src.offset(),
+                dst.buffer(),
+                dst.offset(),
+                aligned_len,
+            );
+        }
+        self.tasks_count += 1;
+
+        self.flush_if_needed();
+    }
+
     fn flush_if_needed(&mut self) {
         // Flush when there are too many tasks, or when too many handles are locked.
         // Locked handles should only accumulate in rare circumstances (where uniforms
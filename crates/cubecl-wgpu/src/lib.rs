@@ -36,6 +36,8 @@ mod tests {
     cubecl_matmul::testgen_matmul_simple!([flex32, f32]);
     cubecl_matmul::testgen_matmul_unit!();
     cubecl_reduce::testgen_reduce!();
+    cubecl_reduce::testgen_reduce_axes!();
+    cubecl_scan::testgen_scan!();
     cubecl_random::testgen_random!();
     cubecl_reduce::testgen_shared_sum!([f32]);
 }
@@ -53,6 +55,8 @@ mod tests_spirv {
     cubecl_matmul::testgen_matmul_plane_accelerated!();
     cubecl_matmul::testgen_matmul_unit!();
     cubecl_reduce::testgen_reduce!();
+    cubecl_reduce::testgen_reduce_axes!();
+    cubecl_scan::testgen_scan!();
     cubecl_random::testgen_random!();
     cubecl_reduce::testgen_shared_sum!([f32]);
 }
@@ -71,6 +75,8 @@ mod tests_msl {
     cubecl_matmul::testgen_matmul_plane_accelerated!();
     cubecl_matmul::testgen_matmul_unit!();
     cubecl_reduce::testgen_reduce!();
+    cubecl_reduce::testgen_reduce_axes!();
+    cubecl_scan::testgen_scan!();
     cubecl_random::testgen_random!();
     cubecl_reduce::testgen_shared_sum!([f32]);
 }
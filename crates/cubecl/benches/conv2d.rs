@@ -31,21 +31,24 @@ impl<R: Runtime, MP: MatmulPrecision> Benchmark for Conv2dBench<R, MP> {
             MP::EI::from_int(0),
             MP::EI::from_int(1),
             input.as_ref(),
-        );
+        )
+        .unwrap();
         let weight = TensorHandle::<R, MP::EI>::empty(&client, self.weight_shape.to_vec());
         random_uniform::<R, MP::EI>(
             &client,
             MP::EI::from_int(0),
             MP::EI::from_int(1),
             weight.as_ref(),
-        );
+        )
+        .unwrap();
         let bias = TensorHandle::<R, MP::EI>::empty(&client, vec![self.bias_shape]);
         random_uniform::<R, MP::EI>(
             &client,
             MP::EI::from_int(0),
             MP::EI::from_int(1),
             bias.as_ref(),
-        );
+        )
+        .unwrap();
 
         (input, weight, bias)
     }
@@ -121,6 +124,7 @@ fn run<R: Runtime, MP: MatmulPrecision>(device: R::Device) {
             stride: [4, 4],
             padding: [0, 0],
             dilation: [1, 1],
+            groups: 1,
         },
         client: client.clone(),
         device: device.clone(),
@@ -135,6 +139,7 @@ fn run<R: Runtime, MP: MatmulPrecision>(device: R::Device) {
             stride: [1, 1],
             padding: [0, 0],
             dilation: [1, 1],
+            groups: 1,
         },
         client: client.clone(),
         device: device.clone(),
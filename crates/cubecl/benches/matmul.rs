@@ -43,7 +43,8 @@ impl<R: Runtime, MP: MatmulPrecision> Benchmark for MatmulBench<R, MP> {
             MP::EI::from_int(0),
             MP::EI::from_int(1),
             lhs.as_ref(),
-        );
+        )
+        .unwrap();
 
         let mut rhs = TensorHandle::<R, MP::EI>::empty(&client, vec![self.b, self.k, self.n]);
 
@@ -57,7 +58,8 @@ impl<R: Runtime, MP: MatmulPrecision> Benchmark for MatmulBench<R, MP> {
             MP::EI::from_int(0),
             MP::EI::from_int(1),
             rhs.as_ref(),
-        );
+        )
+        .unwrap();
 
         (lhs, None, rhs, None)
     }
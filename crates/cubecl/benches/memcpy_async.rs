@@ -571,7 +571,7 @@ fn launch_ref<R: Runtime, E: Float>(
     output: &TensorHandleRef<R>,
     smem_size: u32,
     double_buffering: bool,
-) {
+) -> Result<(), CompilationError> {
     let cube_count = CubeCount::Static(1, 1, 1);
     let plane_dim = 32;
     let num_planes = 8;
@@ -732,9 +732,9 @@ impl<R: Runtime, E: Float> Benchmark for MemcpyAsyncBench<R, E> {
         let client = R::client(&self.device);
 
         let a = TensorHandle::<R, E>::empty(&client, vec![self.data_count]);
-        random_uniform::<R, E>(&client, E::from_int(0), E::from_int(1), a.as_ref());
+        random_uniform::<R, E>(&client, E::from_int(0), E::from_int(1), a.as_ref()).unwrap();
         let b = TensorHandle::<R, E>::empty(&client, vec![self.window_size]);
-        random_uniform::<R, E>(&client, E::from_int(0), E::from_int(1), b.as_ref());
+        random_uniform::<R, E>(&client, E::from_int(0), E::from_int(1), b.as_ref()).unwrap();
 
         (a, b)
     }
@@ -748,7 +748,8 @@ impl<R: Runtime, E: Float> Benchmark for MemcpyAsyncBench<R, E> {
             &args.1.as_ref(),
             smem_size,
             self.double_buffering,
-        );
+        )
+        .unwrap();
     }
 
     fn name(&self) -> String {
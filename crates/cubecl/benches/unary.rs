@@ -29,11 +29,11 @@ impl<R: Runtime, E: Float> Benchmark for UnaryBench<R, E> {
         let client = R::client(&self.device);
 
         let lhs = TensorHandle::<R, E>::empty(&client, self.shape.clone());
-        random_uniform::<R, E>(&client, E::from_int(0), E::from_int(1), lhs.as_ref());
+        random_uniform::<R, E>(&client, E::from_int(0), E::from_int(1), lhs.as_ref()).unwrap();
         let rhs = TensorHandle::<R, E>::empty(&client, self.shape.clone());
-        random_uniform::<R, E>(&client, E::from_int(0), E::from_int(1), rhs.as_ref());
+        random_uniform::<R, E>(&client, E::from_int(0), E::from_int(1), rhs.as_ref()).unwrap();
         let out = TensorHandle::<R, E>::empty(&client, self.shape.clone());
-        random_uniform::<R, E>(&client, E::from_int(0), E::from_int(1), out.as_ref());
+        random_uniform::<R, E>(&client, E::from_int(0), E::from_int(1), out.as_ref()).unwrap();
 
         (lhs, rhs, out)
     }
@@ -53,6 +53,7 @@ impl<R: Runtime, E: Float> Benchmark for UnaryBench<R, E> {
             rhs.as_arg(self.vectorization),
             out.as_arg(self.vectorization),
         )
+        .unwrap()
     }
 
     fn name(&self) -> String {
@@ -24,8 +24,14 @@ pub use cubecl_std as std;
 #[cfg(feature = "reduce")]
 pub use cubecl_reduce as reduce;
 
+#[cfg(feature = "scan")]
+pub use cubecl_scan as scan;
+
 #[cfg(feature = "random")]
 pub use cubecl_random as random;
 
 #[cfg(feature = "cpu")]
 pub use cubecl_cpu as cpu;
+
+#[cfg(feature = "interpreter")]
+pub use cubecl_interpreter as interpreter;
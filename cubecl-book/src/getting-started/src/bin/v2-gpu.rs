@@ -26,6 +26,7 @@ pub fn launch<R: Runtime, F: Float + CubeElement>(device: &R::Device) {
             input.into_tensor_arg(1),
             output.into_tensor_arg(1),
         )
+        .unwrap()
     };
 
     println!(
@@ -37,7 +37,8 @@ impl<R: Runtime, F: Float + CubeElement> Benchmark for ReductionBench<R, F> {
                 CubeDim::new(1, 1, 1),
                 input.into_tensor_arg(1),
                 output.into_tensor_arg(1),
-            );
+            )
+            .unwrap();
         }
 
         output
@@ -37,7 +37,8 @@ impl<R: Runtime, F: Float + CubeElement> Benchmark for ReductionBench<R, F> {
                 CubeDim::new(self.input_shape[0] as u32, 1, 1), // Add parallelization on the first dimension
                 input.into_tensor_arg(1),
                 output.into_tensor_arg(1),
-            );
+            )
+            .unwrap();
         }
 
         output
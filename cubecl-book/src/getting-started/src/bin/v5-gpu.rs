@@ -39,7 +39,8 @@ impl<R: Runtime, F: Float + CubeElement> Benchmark for ReductionBench<R, F> {
                 CubeDim::new(self.input_shape[0] as u32, 1, 1),
                 input.into_tensor_arg(LINE_SIZE as u8),
                 output.into_tensor_arg(LINE_SIZE as u8),
-            );
+            )
+            .unwrap();
         }
 
         output
@@ -5,5 +5,6 @@ fn main() {
         let device_cubecl = cubecl::wgpu::init_device(setup_shared.clone(), Default::default());
         device_sharing::assert_wgpu_device_existing(&device_cubecl);
         sum_things::launch::<cubecl::wgpu::WgpuRuntime>(&device_cubecl);
+        device_sharing::transfer_to_default_device(&device_cubecl);
     }
 }
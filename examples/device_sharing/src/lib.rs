@@ -1,6 +1,7 @@
 #[cfg(feature = "wgpu")]
 mod device_sharing_wgpu {
-    use cubecl::wgpu::{AutoGraphicsApi, GraphicsApi, WgpuDevice, WgpuSetup};
+    use cubecl::prelude::*;
+    use cubecl::wgpu::{AutoGraphicsApi, GraphicsApi, WgpuDevice, WgpuRuntime, WgpuSetup};
     use wgpu::Features;
 
     pub fn create_wgpu_setup_from_raw() -> WgpuSetup {
@@ -35,6 +36,19 @@ mod device_sharing_wgpu {
         }
     }
 
+    /// Moves data created on the shared device to the default device, without reading it back
+    /// by hand.
+    pub fn transfer_to_default_device(device: &WgpuDevice) {
+        let client = WgpuRuntime::client(device);
+        let other = WgpuRuntime::client(&WgpuDevice::default());
+
+        let input = client.create(f32::as_bytes(&[-1., 10., 1., 5.]));
+        let output = client.transfer_to(&input, &other);
+
+        let bytes = other.read_one(output.binding());
+        println!("Transferred: {:?}", f32::from_bytes(&bytes));
+    }
+
     pub fn assert_wgpu_device_existing(device: &WgpuDevice) {
         assert!(
             matches!(device, cubecl::wgpu::WgpuDevice::Existing(_)),